@@ -22,7 +22,16 @@
 //! the native runtimes.
 #![allow(missing_docs)]
 
-use cord_primitives::{AccountId, Balance, Block, Nonce};
+use cord_primitives::{AccountId, Balance, Block, BlockNumber, DidIdentifier, Hash, Nonce};
+use identifier_runtime_api::{EventEntryOf, IdentifierTypeOf};
+use pallet_asset_runtime_api::{AssetTransferRecord, RawAssetClassDetails, RawAssetHolding};
+use pallet_did_runtime_api::{DidHistoricalKeys, RawDidLinkedInfo};
+use pallet_registry_runtime_api::{
+	DelegateUsage, RawNameSpaceDetails, RawRegistryDetails, RawRegistryEntryDetails,
+	RawSpaceDetails, RegistryDelegateUsage, RegistryEntryStatus, SpaceUsage, Ss58Identifier,
+	StatementInfo, StatementVerdict,
+};
+use pallet_schema_runtime_api::SchemaVersionOf;
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 pub use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -213,6 +222,134 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_did_runtime_api::DidApi<Block, DidIdentifier, AccountId, Hash, BlockNumber> for Runtime {
+		fn query_by_name(
+			_: Vec<u8>,
+		) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>> {
+			unimplemented!()
+		}
+
+		fn query(
+			_: DidIdentifier,
+		) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>> {
+			unimplemented!()
+		}
+
+		fn query_name(_: DidIdentifier) -> Option<Vec<u8>> {
+			unimplemented!()
+		}
+
+		fn query_at_version(
+			_: DidIdentifier,
+			_: u32,
+		) -> Option<DidHistoricalKeys<Hash, BlockNumber, AccountId>> {
+			unimplemented!()
+		}
+
+		fn query_at_block(
+			_: DidIdentifier,
+			_: BlockNumber,
+		) -> Option<DidHistoricalKeys<Hash, BlockNumber, AccountId>> {
+			unimplemented!()
+		}
+
+		fn is_deactivated(_: DidIdentifier) -> bool {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_registry_runtime_api::RegistryApi<Block, AccountId, Hash> for Runtime {
+		fn registry(_: Ss58Identifier) -> Option<RawRegistryDetails<AccountId, Hash>> {
+			unimplemented!()
+		}
+
+		fn registry_entry(_: Ss58Identifier) -> Option<RawRegistryEntryDetails<AccountId, Hash>> {
+			unimplemented!()
+		}
+
+		fn registry_entry_status(_: Ss58Identifier) -> Option<RegistryEntryStatus> {
+			unimplemented!()
+		}
+
+		fn namespace(_: Ss58Identifier) -> Option<RawNameSpaceDetails<AccountId, Hash>> {
+			unimplemented!()
+		}
+
+		fn registry_delegate_usage(_: Ss58Identifier) -> Vec<(AccountId, RegistryDelegateUsage)> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_registry_runtime_api::StatementApi<Block, AccountId, Hash> for Runtime {
+		fn statement(_: Ss58Identifier) -> Option<StatementInfo<AccountId, Hash>> {
+			unimplemented!()
+		}
+
+		fn verify(
+			_: Hash,
+			_: Ss58Identifier,
+			_: Option<Ss58Identifier>,
+		) -> StatementVerdict<Ss58Identifier, AccountId> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_registry_runtime_api::ChainSpaceApi<Block, AccountId, Hash> for Runtime {
+		fn space(_: Ss58Identifier) -> Option<RawSpaceDetails<AccountId, Hash>> {
+			unimplemented!()
+		}
+
+		fn sub_spaces(_: Ss58Identifier) -> Vec<Ss58Identifier> {
+			unimplemented!()
+		}
+
+		fn usage(_: Ss58Identifier) -> Option<SpaceUsage> {
+			unimplemented!()
+		}
+
+		fn delegate_usage(_: Ss58Identifier) -> Vec<(AccountId, DelegateUsage)> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_schema_runtime_api::SchemaApi<Block> for Runtime {
+		fn schema_history(_: Ss58Identifier) -> Vec<(Ss58Identifier, SchemaVersionOf)> {
+			unimplemented!()
+		}
+	}
+
+	impl pallet_asset_runtime_api::AssetApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn asset_class(_: Ss58Identifier) -> Option<RawAssetClassDetails<AccountId, BlockNumber>> {
+			unimplemented!()
+		}
+
+		fn assets_by_class(_: Ss58Identifier) -> Vec<Ss58Identifier> {
+			unimplemented!()
+		}
+
+		fn holdings(_: AccountId) -> Vec<RawAssetHolding<AccountId>> {
+			unimplemented!()
+		}
+
+		fn asset_instances(_: Ss58Identifier) -> Vec<RawAssetHolding<AccountId>> {
+			unimplemented!()
+		}
+
+		fn instance_history(_: Ss58Identifier) -> Vec<AssetTransferRecord<AccountId, Hash>> {
+			unimplemented!()
+		}
+	}
+
+	impl identifier_runtime_api::IdentifierApi<Block> for Runtime {
+		fn timeline(
+			_: Ss58Identifier,
+			_: u32,
+			_: u32,
+		) -> Vec<(IdentifierTypeOf, Vec<EventEntryOf>)> {
+			unimplemented!()
+		}
+	}
+
 	impl crate::fake_runtime_api::GetLastTimestamp<Block> for Runtime {
 		fn get_last_timestamp() -> u64 {
 			unimplemented!()
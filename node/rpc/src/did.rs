@@ -0,0 +1,358 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! `did_resolve` RPC method rendering `did:cord` identifiers as W3C DID
+//! Documents, following the DID Core and DID Resolution specifications.
+//!
+//! Both `did:cord:<ss58>` and `<name>@cord` are accepted. Keys are exposed as
+//! `Multikey` verification methods, except account keys which are exposed as
+//! CAIP-10 blockchain accounts of the chain the node is following. Deleted
+//! DIDs resolve to an empty document marked as deactivated.
+
+use std::sync::Arc;
+
+use cord_primitives::{AccountId, Block, BlockNumber, DidIdentifier, Hash};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_did_runtime_api::{
+	DidApi, DidEncryptionKey, DidPublicKey, DidVerificationKey, RawDidLinkedInfo, ServiceEndpoint,
+};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::crypto::Ss58Codec;
+
+/// The DID method handled by this resolver.
+const DID_PREFIX: &str = "did:cord:";
+/// The separator between the prefix and the suffix of a DID name.
+const DID_NAME_SEPARATOR: char = '@';
+/// The network suffix every registered DID name, nested or not, ends with.
+const DID_NAME_NETWORK: &str = "cord";
+
+const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const MULTIKEY_CONTEXT: &str = "https://w3id.org/security/multikey/v1";
+const BLOCKCHAIN_CONTEXT: &str = "https://w3id.org/security/suites/blockchain-2021/v1";
+const DID_LD_JSON: &str = "application/did+ld+json";
+
+/// Multicodec prefixes of the supported public key types, varint encoded.
+const ED25519_PUB: [u8; 2] = [0xed, 0x01];
+const SR25519_PUB: [u8; 2] = [0xef, 0x01];
+const SECP256K1_PUB: [u8; 2] = [0xe7, 0x01];
+const X25519_PUB: [u8; 2] = [0xec, 0x01];
+const P256_PUB: [u8; 2] = [0x80, 0x24];
+const BLS12_381_G1G2_PUB: [u8; 2] = [0xee, 0x01];
+
+type LinkedInfo = RawDidLinkedInfo<DidIdentifier, AccountId, Hash, BlockNumber>;
+
+/// A verification method of a DID Document.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+	pub id: String,
+	#[serde(rename = "type")]
+	pub method_type: String,
+	pub controller: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub public_key_multibase: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub blockchain_account_id: Option<String>,
+}
+
+/// A service of a DID Document.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidService {
+	pub id: String,
+	#[serde(rename = "type")]
+	pub service_types: Vec<String>,
+	pub service_endpoint: Vec<String>,
+}
+
+/// A W3C DID Document.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+	#[serde(rename = "@context")]
+	pub context: Vec<String>,
+	pub id: String,
+	pub controller: String,
+	pub verification_method: Vec<VerificationMethod>,
+	pub authentication: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub assertion_method: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub capability_delegation: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub key_agreement: Vec<String>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub service: Vec<DidService>,
+}
+
+/// Metadata about the resolution process.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub content_type: Option<String>,
+	/// `invalidDid` or `notFound` if the DID could not be resolved.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
+/// Metadata about the resolved DID Document.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentMetadata {
+	/// The transaction counter of the DID, which changes with every DID operation.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub version_id: Option<String>,
+	/// The name the DID was resolved through or is registered under.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// Whether the DID has been deleted.
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub deactivated: bool,
+}
+
+/// The result of resolving a DID.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResponse {
+	pub did_document: Option<DidDocument>,
+	pub did_resolution_metadata: DidResolutionMetadata,
+	pub did_document_metadata: DidDocumentMetadata,
+}
+
+impl DidResolutionResponse {
+	fn error(error: &str) -> Self {
+		Self {
+			did_document: None,
+			did_resolution_metadata: DidResolutionMetadata {
+				content_type: None,
+				error: Some(error.into()),
+			},
+			did_document_metadata: Default::default(),
+		}
+	}
+}
+
+/// CORD DID resolution RPC methods.
+#[rpc(server)]
+pub trait DidResolverApi {
+	/// Resolves `did`, given either as `did:cord:<ss58>` or as a DID name such
+	/// as `<name>@cord` or `<name>@<parent>.cord`, into a W3C DID Document
+	/// along with the resolution metadata.
+	#[method(name = "did_resolve")]
+	fn resolve(&self, did: String, at: Option<Hash>) -> RpcResult<DidResolutionResponse>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+/// Provides the `did_resolve` RPC method.
+pub struct DidResolver<C> {
+	client: Arc<C>,
+}
+
+impl<C> DidResolver<C> {
+	/// Creates a new instance of the DID resolver RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> DidResolverApiServer for DidResolver<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DidApi<Block, DidIdentifier, AccountId, Hash, BlockNumber>,
+{
+	fn resolve(&self, did: String, at: Option<Hash>) -> RpcResult<DidResolutionResponse> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+
+		let (result, identifier) = if let Some(address) = did.strip_prefix(DID_PREFIX) {
+			match DidIdentifier::from_ss58check(address) {
+				Ok(identifier) => (api.query(at, identifier.clone()), Some(identifier)),
+				Err(_) => return Ok(DidResolutionResponse::error("invalidDid")),
+			}
+		} else if did.contains(DID_NAME_SEPARATOR) && did.ends_with(DID_NAME_NETWORK) {
+			(api.query_by_name(at, did.into_bytes()), None)
+		} else {
+			return Ok(DidResolutionResponse::error("invalidDid"));
+		};
+
+		let Some(info) = result.map_err(|e| runtime_error("Unable to query DID.", e))? else {
+			// Deleted DIDs resolve to an empty document, as the DID Core
+			// specification expects for deactivated DIDs. Runtimes older than
+			// version 2 of the API cannot tell them apart from unknown DIDs.
+			let api_version = api
+				.api_version::<dyn DidApi<Block, DidIdentifier, AccountId, Hash, BlockNumber>>(at)
+				.map_err(|e| runtime_error("Unable to query the DID API version.", e))?;
+			if let (Some(identifier), Some(2..)) = (identifier, api_version) {
+				if api
+					.is_deactivated(at, identifier.clone())
+					.map_err(|e| runtime_error("Unable to query DID.", e))?
+				{
+					return Ok(render_deactivated(identifier));
+				}
+			}
+			return Ok(DidResolutionResponse::error("notFound"));
+		};
+
+		let chain_id =
+			format!("polkadot:{}", &format!("{:x}", self.client.info().genesis_hash)[..32]);
+
+		Ok(render(info, &chain_id))
+	}
+}
+
+/// Builds the DID Document and its metadata out of the on-chain DID details.
+fn render(info: LinkedInfo, chain_id: &str) -> DidResolutionResponse {
+	let did = format!("{}{}", DID_PREFIX, info.identifier.to_ss58check());
+	let name = info.name.map(|name| String::from_utf8_lossy(&name).into_owned());
+	let details = info.details;
+	let key_id = |key: &Hash| format!("{}#{:#x}", did, key);
+
+	let mut context = vec![DID_CONTEXT.to_string(), MULTIKEY_CONTEXT.to_string()];
+	let verification_method: Vec<VerificationMethod> = details
+		.public_keys
+		.iter()
+		.map(|(key, details)| {
+			let (method_type, public_key_multibase, blockchain_account_id) = match &details.key {
+				DidPublicKey::PublicVerificationKey(DidVerificationKey::Ed25519(key)) =>
+					("Multikey", Some(multibase(&ED25519_PUB, key.as_ref())), None),
+				DidPublicKey::PublicVerificationKey(DidVerificationKey::Sr25519(key)) =>
+					("Multikey", Some(multibase(&SR25519_PUB, key.as_ref())), None),
+				DidPublicKey::PublicVerificationKey(DidVerificationKey::Ecdsa(key)) =>
+					("Multikey", Some(multibase(&SECP256K1_PUB, key.as_ref())), None),
+				DidPublicKey::PublicVerificationKey(DidVerificationKey::P256(key)) =>
+					("Multikey", Some(multibase(&P256_PUB, key.as_ref())), None),
+				// The BLS12-381 key is the concatenation of its G1 and G2 public keys.
+				DidPublicKey::PublicVerificationKey(DidVerificationKey::Bls381(key)) =>
+					("Multikey", Some(multibase(&BLS12_381_G1G2_PUB, key.as_ref())), None),
+				DidPublicKey::PublicVerificationKey(DidVerificationKey::Account(account)) => (
+					"BlockchainVerificationMethod2021",
+					None,
+					Some(format!("{}:{}", chain_id, account.to_ss58check())),
+				),
+				DidPublicKey::PublicEncryptionKey(DidEncryptionKey::X25519(key)) =>
+					("Multikey", Some(multibase(&X25519_PUB, key)), None),
+			};
+
+			VerificationMethod {
+				id: key_id(key),
+				method_type: method_type.into(),
+				controller: did.clone(),
+				public_key_multibase,
+				blockchain_account_id,
+			}
+		})
+		.collect();
+
+	if verification_method.iter().any(|method| method.blockchain_account_id.is_some()) {
+		context.push(BLOCKCHAIN_CONTEXT.to_string());
+	}
+
+	let service = info
+		.service_endpoints
+		.into_iter()
+		.map(|ServiceEndpoint { id, service_types, urls }| DidService {
+			id: format!("{}#{}", did, String::from_utf8_lossy(&id)),
+			service_types: service_types
+				.iter()
+				.map(|t| String::from_utf8_lossy(t).into())
+				.collect(),
+			service_endpoint: urls.iter().map(|url| String::from_utf8_lossy(url).into()).collect(),
+		})
+		.collect();
+
+	let document = DidDocument {
+		context,
+		id: did.clone(),
+		controller: did.clone(),
+		verification_method,
+		authentication: vec![key_id(&details.authentication_key)],
+		assertion_method: details.assertion_key.iter().map(key_id).collect(),
+		capability_delegation: details.delegation_key.iter().map(key_id).collect(),
+		key_agreement: details.key_agreement_keys.iter().map(key_id).collect(),
+		service,
+	};
+
+	DidResolutionResponse {
+		did_document: Some(document),
+		did_resolution_metadata: DidResolutionMetadata {
+			content_type: Some(DID_LD_JSON.into()),
+			error: None,
+		},
+		did_document_metadata: DidDocumentMetadata {
+			version_id: Some(details.last_tx_counter.to_string()),
+			name,
+			deactivated: false,
+		},
+	}
+}
+
+/// Builds the empty DID Document of a deleted DID, marked as deactivated in
+/// its metadata.
+fn render_deactivated(identifier: DidIdentifier) -> DidResolutionResponse {
+	let did = format!("{}{}", DID_PREFIX, identifier.to_ss58check());
+
+	let document = DidDocument {
+		context: vec![DID_CONTEXT.to_string()],
+		id: did.clone(),
+		controller: did,
+		verification_method: Vec::new(),
+		authentication: Vec::new(),
+		assertion_method: Vec::new(),
+		capability_delegation: Vec::new(),
+		key_agreement: Vec::new(),
+		service: Vec::new(),
+	};
+
+	DidResolutionResponse {
+		did_document: Some(document),
+		did_resolution_metadata: DidResolutionMetadata {
+			content_type: Some(DID_LD_JSON.into()),
+			error: None,
+		},
+		did_document_metadata: DidDocumentMetadata { deactivated: true, ..Default::default() },
+	}
+}
+
+/// Encodes a public key as a base58btc multibase string of its multicodec form.
+fn multibase(codec: &[u8], key: &[u8]) -> String {
+	format!("z{}", bs58::encode([codec, key].concat()).into_string())
+}
+
+fn runtime_error(message: &'static str, e: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string()))
+}
@@ -18,9 +18,12 @@
 
 //! A collection of CORD specific RPC methods.
 
+pub mod did;
+pub mod registry;
+
 use std::sync::Arc;
 
-use cord_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
+use cord_primitives::{AccountId, Balance, Block, BlockNumber, DidIdentifier, Hash, Nonce};
 use jsonrpsee::RpcModule;
 use sc_client_api::AuxStore;
 use sc_consensus_babe::BabeWorkerHandle;
@@ -106,12 +109,21 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: pallet_did_runtime_api::DidApi<Block, DidIdentifier, AccountId, Hash, BlockNumber>,
+	C::Api: pallet_registry_runtime_api::RegistryApi<Block, AccountId, Hash>,
+	C::Api: pallet_registry_runtime_api::StatementApi<Block, AccountId, Hash>,
+	C::Api: pallet_registry_runtime_api::ChainSpaceApi<Block, AccountId, Hash>,
+	C::Api: pallet_schema_runtime_api::SchemaApi<Block>,
+	C::Api: identifier_runtime_api::IdentifierApi<Block>,
+	C::Api: pallet_asset_runtime_api::AssetApi<Block, AccountId, Hash, BlockNumber>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use did::{DidResolver, DidResolverApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use registry::{Cord, CordApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
 	use sc_rpc::dev::{Dev, DevApiServer};
@@ -138,6 +150,8 @@ where
 
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(Cord::new(client.clone()).into_rpc())?;
+	io.merge(DidResolver::new(client.clone()).into_rpc())?;
 
 	io.merge(
 		Babe::new(client.clone(), babe_worker_handle.clone(), keystore, select_chain, deny_unsafe)
@@ -0,0 +1,930 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! `cord_*` RPC methods for reading registries, registry entries, namespaces,
+//! schemas, statements, chain spaces, asset holdings and identifier timelines
+//! without decoding raw storage.
+
+use std::sync::Arc;
+
+use cord_primitives::{AccountId, Block, BlockNumber, Hash};
+use identifier_runtime_api::{CallTypeOf, EventEntryOf, IdentifierApi, IdentifierTypeOf};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_asset_runtime_api::{AssetApi, AssetStatusOf, AssetTransferRecord, RawAssetHolding};
+use pallet_registry_runtime_api::{
+	ChainSpaceApi, DelegateUsage, RawNameSpaceDetails, RawRegistryDetails, RawRegistryEntryDetails,
+	RawSpaceDetails, RegistryApi, RegistryDelegateUsage, RegistryEntryStatus, SpaceUsage,
+	Ss58Identifier, StatementApi, StatementInfo, StatementVerdict, StatementVerificationStatus,
+	Timepoint,
+};
+use pallet_schema_runtime_api::{SchemaApi, SchemaVersionOf};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+/// Registry details as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryResponse {
+	pub creator: AccountId,
+	pub revoked: bool,
+	pub archived: bool,
+	pub digest: Hash,
+	pub namespace_id: String,
+	pub schema_id: Option<String>,
+}
+
+impl From<RawRegistryDetails<AccountId, Hash>> for RegistryResponse {
+	fn from(details: RawRegistryDetails<AccountId, Hash>) -> Self {
+		Self {
+			creator: details.creator,
+			revoked: details.revoked,
+			archived: details.archived,
+			digest: details.digest,
+			namespace_id: identifier_to_string(&details.namespace_id),
+			schema_id: details.schema_id.as_ref().map(identifier_to_string),
+		}
+	}
+}
+
+/// Registry entry details as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistryEntryResponse {
+	pub digest: Hash,
+	pub revoked: bool,
+	pub creator: AccountId,
+	pub registry_id: String,
+	/// The effective status, taking the validity window into account.
+	pub status: EntryStatus,
+}
+
+impl RegistryEntryResponse {
+	fn new(details: RawRegistryEntryDetails<AccountId, Hash>, status: RegistryEntryStatus) -> Self {
+		Self {
+			digest: details.digest,
+			revoked: details.revoked,
+			creator: details.creator,
+			registry_id: identifier_to_string(&details.registry_id),
+			status: status.into(),
+		}
+	}
+}
+
+/// Effective status of a registry entry as exposed over RPC.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EntryStatus {
+	Active,
+	Revoked,
+	NotYetValid,
+	Expired,
+}
+
+impl From<RegistryEntryStatus> for EntryStatus {
+	fn from(status: RegistryEntryStatus) -> Self {
+		match status {
+			RegistryEntryStatus::Active => Self::Active,
+			RegistryEntryStatus::Revoked => Self::Revoked,
+			RegistryEntryStatus::NotYetValid => Self::NotYetValid,
+			RegistryEntryStatus::Expired => Self::Expired,
+		}
+	}
+}
+
+/// Namespace details as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NameSpaceResponse {
+	pub digest: Hash,
+	pub creator: AccountId,
+	pub archive: bool,
+	pub registry_ids: Vec<String>,
+}
+
+impl From<RawNameSpaceDetails<AccountId, Hash>> for NameSpaceResponse {
+	fn from(details: RawNameSpaceDetails<AccountId, Hash>) -> Self {
+		Self {
+			digest: details.digest,
+			creator: details.creator,
+			archive: details.archive,
+			registry_ids: details
+				.registry_ids
+				.unwrap_or_default()
+				.iter()
+				.map(identifier_to_string)
+				.collect(),
+		}
+	}
+}
+
+/// Statement details and revocation status as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementResponse {
+	pub identifier: String,
+	pub digest: Hash,
+	pub space_id: String,
+	pub schema_id: Option<String>,
+	pub revoked: bool,
+	/// The account that revoked the latest digest, if it has been revoked.
+	pub revoked_by: Option<AccountId>,
+}
+
+impl From<StatementInfo<AccountId, Hash>> for StatementResponse {
+	fn from(info: StatementInfo<AccountId, Hash>) -> Self {
+		let (revoked, revoked_by) = match info.revocation {
+			Some(status) => (status.revoked, Some(status.creator)),
+			None => (false, None),
+		};
+
+		Self {
+			identifier: identifier_to_string(&info.identifier),
+			digest: info.details.digest,
+			space_id: identifier_to_string(&info.details.space),
+			schema_id: info.details.schema.as_ref().map(identifier_to_string),
+			revoked,
+			revoked_by,
+		}
+	}
+}
+
+/// Verification status of a statement digest as exposed over RPC.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VerificationStatus {
+	Unknown,
+	Active,
+	Revoked,
+	SpaceArchived,
+	SpaceUnapproved,
+	SchemaMismatch,
+	NotYetValid,
+	Expired,
+}
+
+impl From<StatementVerificationStatus> for VerificationStatus {
+	fn from(status: StatementVerificationStatus) -> Self {
+		match status {
+			StatementVerificationStatus::Unknown => Self::Unknown,
+			StatementVerificationStatus::Active => Self::Active,
+			StatementVerificationStatus::Revoked => Self::Revoked,
+			StatementVerificationStatus::SpaceArchived => Self::SpaceArchived,
+			StatementVerificationStatus::SpaceUnapproved => Self::SpaceUnapproved,
+			StatementVerificationStatus::SchemaMismatch => Self::SchemaMismatch,
+			StatementVerificationStatus::NotYetValid => Self::NotYetValid,
+			StatementVerificationStatus::Expired => Self::Expired,
+		}
+	}
+}
+
+/// Location of an on-chain change as exposed over RPC.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimepointResponse {
+	pub height: u32,
+	pub index: u32,
+}
+
+impl From<Timepoint> for TimepointResponse {
+	fn from(timepoint: Timepoint) -> Self {
+		Self { height: timepoint.height, index: timepoint.index }
+	}
+}
+
+/// Statement verification verdict as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementVerdictResponse {
+	pub status: VerificationStatus,
+	pub identifier: Option<String>,
+	pub creator: Option<AccountId>,
+	pub last_change: Option<TimepointResponse>,
+}
+
+impl From<StatementVerdict<Ss58Identifier, AccountId>> for StatementVerdictResponse {
+	fn from(verdict: StatementVerdict<Ss58Identifier, AccountId>) -> Self {
+		Self {
+			status: verdict.status.into(),
+			identifier: verdict.identifier.as_ref().map(identifier_to_string),
+			creator: verdict.creator,
+			last_change: verdict.last_change.map(Into::into),
+		}
+	}
+}
+
+/// Chain space details as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpaceResponse {
+	pub code: Hash,
+	pub creator: AccountId,
+	pub txn_capacity: u64,
+	pub txn_reserve: u64,
+	pub txn_count: u64,
+	pub approved: bool,
+	pub archive: bool,
+	pub parent: String,
+}
+
+impl From<RawSpaceDetails<AccountId, Hash>> for SpaceResponse {
+	fn from(details: RawSpaceDetails<AccountId, Hash>) -> Self {
+		Self {
+			code: details.code,
+			creator: details.creator,
+			txn_capacity: details.txn_capacity,
+			txn_reserve: details.txn_reserve,
+			txn_count: details.txn_count,
+			approved: details.approved,
+			archive: details.archive,
+			parent: identifier_to_string(&details.parent),
+		}
+	}
+}
+
+/// Usage of a chain space aggregated over its sub-spaces as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpaceUsageResponse {
+	pub txn_capacity: u64,
+	pub txn_reserve: u64,
+	pub txn_count: u64,
+	/// The usage of the space and all of its descendants.
+	pub total_txn_count: u64,
+	/// The number of descendants of the space.
+	pub sub_spaces: u32,
+}
+
+impl From<SpaceUsage> for SpaceUsageResponse {
+	fn from(usage: SpaceUsage) -> Self {
+		Self {
+			txn_capacity: usage.txn_capacity,
+			txn_reserve: usage.txn_reserve,
+			txn_count: usage.txn_count,
+			total_txn_count: usage.total_txn_count,
+			sub_spaces: usage.sub_spaces,
+		}
+	}
+}
+
+/// Usage of a chain space or registry by one of its delegates as exposed
+/// over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegateUsageResponse {
+	pub delegate: AccountId,
+	pub txn_count: u64,
+	pub txn_cap: Option<u64>,
+}
+
+impl From<(AccountId, DelegateUsage)> for DelegateUsageResponse {
+	fn from((delegate, usage): (AccountId, DelegateUsage)) -> Self {
+		Self { delegate, txn_count: usage.txn_count, txn_cap: usage.txn_cap }
+	}
+}
+
+impl From<(AccountId, RegistryDelegateUsage)> for DelegateUsageResponse {
+	fn from((delegate, usage): (AccountId, RegistryDelegateUsage)) -> Self {
+		Self { delegate, txn_count: usage.txn_count, txn_cap: usage.txn_cap }
+	}
+}
+
+/// A single version of a schema as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaVersionResponse {
+	pub identifier: String,
+	pub version: u32,
+	pub previous: Option<String>,
+	pub next: Option<String>,
+	pub deprecated: bool,
+}
+
+impl From<(Ss58Identifier, SchemaVersionOf)> for SchemaVersionResponse {
+	fn from((identifier, version): (Ss58Identifier, SchemaVersionOf)) -> Self {
+		Self {
+			identifier: identifier_to_string(&identifier),
+			version: version.version,
+			previous: version.previous.as_ref().map(identifier_to_string),
+			next: version.next.as_ref().map(identifier_to_string),
+			deprecated: version.deprecated,
+		}
+	}
+}
+
+/// A single timeline event as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEventResponse {
+	/// The action recorded, e.g. `Genesis`, `Update` or `Revoke`.
+	pub action: String,
+	pub location: TimepointResponse,
+}
+
+impl From<EventEntryOf> for TimelineEventResponse {
+	fn from(entry: EventEntryOf) -> Self {
+		Self { action: action_name(entry.action).into(), location: entry.location.into() }
+	}
+}
+
+/// The activity timeline of an identifier for one identifier type.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineResponse {
+	/// The identifier type the timeline was recorded under, e.g. `Statement`.
+	pub identifier_type: String,
+	pub events: Vec<TimelineEventResponse>,
+}
+
+impl From<(IdentifierTypeOf, Vec<EventEntryOf>)> for TimelineResponse {
+	fn from((identifier_type, events): (IdentifierTypeOf, Vec<EventEntryOf>)) -> Self {
+		Self {
+			identifier_type: identifier_type_name(identifier_type).into(),
+			events: events.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// Asset instance holding as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetHoldingResponse {
+	pub asset_id: String,
+	pub instance_id: String,
+	pub owner: AccountId,
+	pub qty: u64,
+	/// The instance status, e.g. `ACTIVE` or `INACTIVE`.
+	pub status: String,
+	pub is_vc: bool,
+}
+
+impl From<RawAssetHolding<AccountId>> for AssetHoldingResponse {
+	fn from(holding: RawAssetHolding<AccountId>) -> Self {
+		Self {
+			asset_id: identifier_to_string(&holding.asset_id),
+			instance_id: identifier_to_string(&holding.asset_instance_id),
+			owner: holding.asset_owner,
+			qty: holding.asset_qty,
+			status: asset_status_name(&holding.asset_instance_status).into(),
+			is_vc: holding.is_vc,
+		}
+	}
+}
+
+/// A single ownership change of an asset instance as exposed over RPC.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetTransferResponse {
+	/// The previous owner, `None` for the issuance.
+	pub from: Option<AccountId>,
+	pub to: AccountId,
+	pub qty: u64,
+	pub digest: Option<Hash>,
+	pub location: TimepointResponse,
+}
+
+impl From<AssetTransferRecord<AccountId, Hash>> for AssetTransferResponse {
+	fn from(record: AssetTransferRecord<AccountId, Hash>) -> Self {
+		Self {
+			from: record.from,
+			to: record.to,
+			qty: record.qty,
+			digest: record.digest,
+			location: record.location.into(),
+		}
+	}
+}
+
+/// CORD registry RPC methods.
+#[rpc(server)]
+pub trait CordApi {
+	/// Returns the registry anchored under `registry_id`.
+	#[method(name = "cord_registry")]
+	fn registry(
+		&self,
+		registry_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<RegistryResponse>>;
+
+	/// Returns the registry entry anchored under `registry_entry_id`.
+	#[method(name = "cord_registryEntry")]
+	fn registry_entry(
+		&self,
+		registry_entry_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<RegistryEntryResponse>>;
+
+	/// Returns the namespace anchored under `namespace_id`.
+	#[method(name = "cord_namespace")]
+	fn namespace(
+		&self,
+		namespace_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NameSpaceResponse>>;
+
+	/// Returns the usage of `registry_id` by each of its delegates.
+	#[method(name = "cord_registryDelegateUsage")]
+	fn registry_delegate_usage(
+		&self,
+		registry_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<DelegateUsageResponse>>;
+
+	/// Returns the statement anchored under `statement_id` along with the
+	/// revocation status of its latest digest.
+	#[method(name = "cord_statement")]
+	fn statement(
+		&self,
+		statement_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<StatementResponse>>;
+
+	/// Verifies `digest` against `space_id` and, optionally, the expected
+	/// `schema_id`, returning a single verdict for it.
+	#[method(name = "cord_verifyStatement")]
+	fn verify_statement(
+		&self,
+		digest: Hash,
+		space_id: String,
+		schema_id: Option<String>,
+		at: Option<Hash>,
+	) -> RpcResult<StatementVerdictResponse>;
+
+	/// Returns the chain space anchored under `space_id`.
+	#[method(name = "cord_space")]
+	fn space(&self, space_id: String, at: Option<Hash>) -> RpcResult<Option<SpaceResponse>>;
+
+	/// Returns the identifiers of the sub-spaces directly below `space_id`.
+	#[method(name = "cord_subSpaces")]
+	fn sub_spaces(&self, space_id: String, at: Option<Hash>) -> RpcResult<Vec<String>>;
+
+	/// Returns the usage of `space_id` aggregated over all of its
+	/// descendants.
+	#[method(name = "cord_spaceUsage")]
+	fn space_usage(
+		&self,
+		space_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<SpaceUsageResponse>>;
+
+	/// Returns the usage of `space_id` by each of its delegates.
+	#[method(name = "cord_spaceDelegateUsage")]
+	fn space_delegate_usage(
+		&self,
+		space_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<DelegateUsageResponse>>;
+
+	/// Returns every version of the schema chain `schema_id` belongs to,
+	/// ordered from the first to the latest.
+	#[method(name = "cord_schemaHistory")]
+	fn schema_history(
+		&self,
+		schema_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<SchemaVersionResponse>>;
+
+	/// Returns page `page` of the activity timeline of `identifier`,
+	/// including entries moved to the archive. Pages hold at most `limit`
+	/// events, capped by the runtime.
+	#[method(name = "cord_timeline")]
+	fn timeline(
+		&self,
+		identifier: String,
+		page: u32,
+		limit: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<TimelineResponse>>;
+
+	/// Returns the asset instances held by `owner`.
+	#[method(name = "cord_assetHoldings")]
+	fn asset_holdings(
+		&self,
+		owner: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AssetHoldingResponse>>;
+
+	/// Returns the instances issued from `asset_id`.
+	#[method(name = "cord_assetInstances")]
+	fn asset_instances(
+		&self,
+		asset_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AssetHoldingResponse>>;
+
+	/// Returns the most recent ownership changes of `instance_id`, oldest
+	/// first.
+	#[method(name = "cord_assetInstanceHistory")]
+	fn asset_instance_history(
+		&self,
+		instance_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AssetTransferResponse>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The supplied identifier is not a valid SS58 identifier.
+	InvalidIdentifier,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::InvalidIdentifier => 2,
+		}
+	}
+}
+
+/// Provides the `cord_*` RPC methods.
+pub struct Cord<C> {
+	client: Arc<C>,
+}
+
+impl<C> Cord<C> {
+	/// Creates a new instance of the CORD registry RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+impl<C> CordApiServer for Cord<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RegistryApi<Block, AccountId, Hash>,
+	C::Api: StatementApi<Block, AccountId, Hash>,
+	C::Api: ChainSpaceApi<Block, AccountId, Hash>,
+	C::Api: SchemaApi<Block>,
+	C::Api: IdentifierApi<Block>,
+	C::Api: AssetApi<Block, AccountId, Hash, BlockNumber>,
+{
+	fn registry(
+		&self,
+		registry_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<RegistryResponse>> {
+		let registry_id = parse_identifier(registry_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let details = self
+			.client
+			.runtime_api()
+			.registry(at, registry_id)
+			.map_err(|e| runtime_error("Unable to query registry.", e))?;
+
+		Ok(details.map(Into::into))
+	}
+
+	fn registry_entry(
+		&self,
+		registry_entry_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<RegistryEntryResponse>> {
+		let registry_entry_id = parse_identifier(registry_entry_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let api = self.client.runtime_api();
+		let Some(details) = api
+			.registry_entry(at, registry_entry_id.clone())
+			.map_err(|e| runtime_error("Unable to query registry entry.", e))?
+		else {
+			return Ok(None);
+		};
+
+		let fallback = if details.revoked {
+			RegistryEntryStatus::Revoked
+		} else {
+			RegistryEntryStatus::Active
+		};
+		let status = api
+			.registry_entry_status(at, registry_entry_id)
+			.map_err(|e| runtime_error("Unable to query registry entry status.", e))?
+			.unwrap_or(fallback);
+
+		Ok(Some(RegistryEntryResponse::new(details, status)))
+	}
+
+	fn namespace(
+		&self,
+		namespace_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<NameSpaceResponse>> {
+		let namespace_id = parse_identifier(namespace_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let details = self
+			.client
+			.runtime_api()
+			.namespace(at, namespace_id)
+			.map_err(|e| runtime_error("Unable to query namespace.", e))?;
+
+		Ok(details.map(Into::into))
+	}
+
+	fn registry_delegate_usage(
+		&self,
+		registry_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<DelegateUsageResponse>> {
+		let registry_id = parse_identifier(registry_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let usage = self
+			.client
+			.runtime_api()
+			.registry_delegate_usage(at, registry_id)
+			.map_err(|e| runtime_error("Unable to query registry delegate usage.", e))?;
+
+		Ok(usage.into_iter().map(Into::into).collect())
+	}
+
+	fn statement(
+		&self,
+		statement_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<StatementResponse>> {
+		let statement_id = parse_identifier(statement_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let info = self
+			.client
+			.runtime_api()
+			.statement(at, statement_id)
+			.map_err(|e| runtime_error("Unable to query statement.", e))?;
+
+		Ok(info.map(Into::into))
+	}
+
+	fn verify_statement(
+		&self,
+		digest: Hash,
+		space_id: String,
+		schema_id: Option<String>,
+		at: Option<Hash>,
+	) -> RpcResult<StatementVerdictResponse> {
+		let space_id = parse_identifier(space_id)?;
+		let schema_id = schema_id.map(parse_identifier).transpose()?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let verdict = self
+			.client
+			.runtime_api()
+			.verify(at, digest, space_id, schema_id)
+			.map_err(|e| runtime_error("Unable to verify statement.", e))?;
+
+		Ok(verdict.into())
+	}
+
+	fn space(&self, space_id: String, at: Option<Hash>) -> RpcResult<Option<SpaceResponse>> {
+		let space_id = parse_identifier(space_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let details = self
+			.client
+			.runtime_api()
+			.space(at, space_id)
+			.map_err(|e| runtime_error("Unable to query space.", e))?;
+
+		Ok(details.map(Into::into))
+	}
+
+	fn sub_spaces(&self, space_id: String, at: Option<Hash>) -> RpcResult<Vec<String>> {
+		let space_id = parse_identifier(space_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let sub_spaces = self
+			.client
+			.runtime_api()
+			.sub_spaces(at, space_id)
+			.map_err(|e| runtime_error("Unable to query sub-spaces.", e))?;
+
+		Ok(sub_spaces.iter().map(identifier_to_string).collect())
+	}
+
+	fn space_usage(
+		&self,
+		space_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Option<SpaceUsageResponse>> {
+		let space_id = parse_identifier(space_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let usage = self
+			.client
+			.runtime_api()
+			.usage(at, space_id)
+			.map_err(|e| runtime_error("Unable to query space usage.", e))?;
+
+		Ok(usage.map(Into::into))
+	}
+
+	fn space_delegate_usage(
+		&self,
+		space_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<DelegateUsageResponse>> {
+		let space_id = parse_identifier(space_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let usage = self
+			.client
+			.runtime_api()
+			.delegate_usage(at, space_id)
+			.map_err(|e| runtime_error("Unable to query space delegate usage.", e))?;
+
+		Ok(usage.into_iter().map(Into::into).collect())
+	}
+
+	fn schema_history(
+		&self,
+		schema_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<SchemaVersionResponse>> {
+		let schema_id = parse_identifier(schema_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let history = self
+			.client
+			.runtime_api()
+			.schema_history(at, schema_id)
+			.map_err(|e| runtime_error("Unable to query schema history.", e))?;
+
+		Ok(history.into_iter().map(Into::into).collect())
+	}
+
+	fn timeline(
+		&self,
+		identifier: String,
+		page: u32,
+		limit: u32,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<TimelineResponse>> {
+		let identifier = parse_identifier(identifier)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let timelines = self
+			.client
+			.runtime_api()
+			.timeline(at, identifier, page, limit)
+			.map_err(|e| runtime_error("Unable to query identifier timeline.", e))?;
+
+		Ok(timelines.into_iter().map(Into::into).collect())
+	}
+
+	fn asset_holdings(
+		&self,
+		owner: AccountId,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AssetHoldingResponse>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let holdings = self
+			.client
+			.runtime_api()
+			.holdings(at, owner)
+			.map_err(|e| runtime_error("Unable to query asset holdings.", e))?;
+
+		Ok(holdings.into_iter().map(Into::into).collect())
+	}
+
+	fn asset_instances(
+		&self,
+		asset_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AssetHoldingResponse>> {
+		let asset_id = parse_identifier(asset_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let instances = self
+			.client
+			.runtime_api()
+			.asset_instances(at, asset_id)
+			.map_err(|e| runtime_error("Unable to query asset instances.", e))?;
+
+		Ok(instances.into_iter().map(Into::into).collect())
+	}
+
+	fn asset_instance_history(
+		&self,
+		instance_id: String,
+		at: Option<Hash>,
+	) -> RpcResult<Vec<AssetTransferResponse>> {
+		let instance_id = parse_identifier(instance_id)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let history = self
+			.client
+			.runtime_api()
+			.instance_history(at, instance_id)
+			.map_err(|e| runtime_error("Unable to query asset instance history.", e))?;
+
+		Ok(history.into_iter().map(Into::into).collect())
+	}
+}
+
+fn identifier_to_string(identifier: &Ss58Identifier) -> String {
+	String::from_utf8_lossy(identifier.inner()).into_owned()
+}
+
+/// The name a timeline action is exposed under, independent of its `Debug`
+/// output.
+fn action_name(action: CallTypeOf) -> &'static str {
+	match action {
+		CallTypeOf::Archive => "Archive",
+		CallTypeOf::Authorization => "Authorization",
+		CallTypeOf::Capacity => "Capacity",
+		CallTypeOf::CouncilRevoke => "CouncilRevoke",
+		CallTypeOf::CouncilRestore => "CouncilRestore",
+		CallTypeOf::Deauthorization => "Deauthorization",
+		CallTypeOf::Approved => "Approved",
+		CallTypeOf::Genesis => "Genesis",
+		CallTypeOf::Update => "Update",
+		CallTypeOf::Revoke => "Revoke",
+		CallTypeOf::Restore => "Restore",
+		CallTypeOf::Remove => "Remove",
+		CallTypeOf::PartialRemove => "PartialRemove",
+		CallTypeOf::PresentationAdded => "PresentationAdded",
+		CallTypeOf::PresentationRemoved => "PresentationRemoved",
+		CallTypeOf::Rotate => "Rotate",
+		CallTypeOf::Usage => "Usage",
+		CallTypeOf::Transfer => "Transfer",
+		CallTypeOf::Debit => "Debit",
+		CallTypeOf::Credit => "Credit",
+		CallTypeOf::Issue => "Issue",
+		CallTypeOf::Reinstate => "Reinstate",
+		CallTypeOf::Expire => "Expire",
+		CallTypeOf::RecoveryInitiated => "RecoveryInitiated",
+		CallTypeOf::RecoveryCancelled => "RecoveryCancelled",
+		CallTypeOf::Recovered => "Recovered",
+		CallTypeOf::RecoveryApprovalWithdrawn => "RecoveryApprovalWithdrawn",
+		CallTypeOf::TransferRequest => "TransferRequest",
+	}
+}
+
+/// The name an identifier type is exposed under, independent of its `Debug`
+/// output.
+fn identifier_type_name(identifier_type: IdentifierTypeOf) -> &'static str {
+	match identifier_type {
+		IdentifierTypeOf::Asset => "Asset",
+		IdentifierTypeOf::Auth => "Auth",
+		IdentifierTypeOf::ChainSpace => "ChainSpace",
+		IdentifierTypeOf::Did => "Did",
+		IdentifierTypeOf::Rating => "Rating",
+		IdentifierTypeOf::Registry => "Registry",
+		IdentifierTypeOf::Statement => "Statement",
+		IdentifierTypeOf::Schema => "Schema",
+		IdentifierTypeOf::Template => "Template",
+		IdentifierTypeOf::Registries => "Registries",
+		IdentifierTypeOf::Entries => "Entries",
+		IdentifierTypeOf::RegistryAuthorization => "RegistryAuthorization",
+		IdentifierTypeOf::SchemaAccounts => "SchemaAccounts",
+		IdentifierTypeOf::NameSpace => "NameSpace",
+		IdentifierTypeOf::NameSpaceAuthorization => "NameSpaceAuthorization",
+	}
+}
+
+/// The name an asset instance status is exposed under, independent of its
+/// `Debug` output.
+fn asset_status_name(status: &AssetStatusOf) -> &'static str {
+	match status {
+		AssetStatusOf::ACTIVE => "ACTIVE",
+		AssetStatusOf::INACTIVE => "INACTIVE",
+		AssetStatusOf::EXPIRED => "EXPIRED",
+	}
+}
+
+fn parse_identifier(identifier: String) -> Result<Ss58Identifier, ErrorObjectOwned> {
+	Ss58Identifier::try_from(identifier.into_bytes()).map_err(|e| {
+		ErrorObject::owned(
+			Error::InvalidIdentifier.into(),
+			"Invalid SS58 identifier.",
+			Some(format!("{:?}", e)),
+		)
+	})
+}
+
+fn runtime_error(message: &'static str, e: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string()))
+}
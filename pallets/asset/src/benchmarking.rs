@@ -9,7 +9,7 @@ use frame_system::RawOrigin;
 
 use identifier::{IdentifierType, Ss58Identifier};
 use pallet_chain_space::{SpaceCodeOf, SpaceIdOf};
-use sp_runtime::BoundedVec;
+use sp_runtime::{BoundedVec, DispatchError};
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
@@ -33,12 +33,133 @@ pub fn generate_asset_instance_id<T: Config>(digest: &SpaceCodeOf<T>) -> AssetIn
 		.unwrap()
 }
 
+/// Anchors a schema in `space_id` directly in storage, bypassing the
+/// content checks of the schema pallet.
+pub fn anchor_schema<T: Config + pallet_schema::Config>(
+	space_id: &SpaceIdOf,
+	creator: &T::SpaceCreatorId,
+) -> SchemaIdOf {
+	let schema = [72u8; 256].to_vec();
+	let digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+	let schema_id =
+		Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Schema).unwrap();
+
+	pallet_schema::Schemas::<T>::insert(
+		&schema_id,
+		pallet_schema::SchemaEntryOf::<T> {
+			schema: BoundedVec::try_from(schema).unwrap(),
+			digest,
+			creator: creator.clone(),
+			space: space_id.clone(),
+		},
+	);
+
+	schema_id
+}
+
+pub fn generate_class_id<T: Config>(
+	class_name: &AssetClassNameOf<T>,
+	schema_id: &SchemaIdOf,
+	space_id: &SpaceIdOf,
+	issuer: &T::SpaceCreatorId,
+) -> AssetClassIdOf {
+	let id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[
+			&class_name.encode()[..],
+			&schema_id.encode()[..],
+			&space_id.encode()[..],
+			&issuer.encode()[..],
+		]
+		.concat()[..],
+	);
+	generate_asset_id::<T>(&id_digest)
+}
+
+/// Creates an asset in a new space and issues an instance of it to `did`.
+pub fn issue_asset_instance<T: Config>(
+	origin: <T as frame_system::Config>::RuntimeOrigin,
+	did: &T::SpaceCreatorId,
+) -> Result<(AssetIdOf, AssetInstanceIdOf), DispatchError> {
+	let raw_space = [56u8; 256].to_vec();
+	let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+	let auth_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<T> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+	let digest = <T as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+	let create_id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+	);
+	let asset_id: Ss58Identifier = generate_asset_id::<T>(&create_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<T> {
+		asset_id: asset_id.clone(),
+		asset_owner: did.clone(),
+		asset_issuance_qty: Some(10),
+	};
+	let issue_entry_digest =
+		<T as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+	let issue_id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&did.encode()[..],
+			&space_id.encode()[..],
+			&did.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+	let instance_id = generate_asset_instance_id::<T>(&issue_id_digest);
+
+	pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest)?;
+	pallet_chain_space::Pallet::<T>::approve(RawOrigin::Root.into(), space_id, 5u64)?;
+	Pallet::<T>::create(origin.clone(), entry, digest, authorization_id.clone())?;
+	Pallet::<T>::issue(origin, issue_entry, issue_entry_digest, authorization_id)?;
+
+	Ok((asset_id, instance_id))
+}
+
+/// Requests a transfer of an instance from `did` to `new_owner`.
+pub fn request_asset_transfer<T: Config>(
+	origin: <T as frame_system::Config>::RuntimeOrigin,
+	asset_id: &AssetIdOf,
+	instance_id: &AssetInstanceIdOf,
+	did: &T::SpaceCreatorId,
+	new_owner: &T::SpaceCreatorId,
+) -> Result<(), DispatchError> {
+	let transfer_entry = AssetTransferEntryOf::<T> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: did.clone(),
+		new_asset_owner: new_owner.clone(),
+	};
+	let transfer_entry_digest =
+		<T as frame_system::Config>::Hashing::hash(&[&transfer_entry.encode()[..]].concat()[..]);
+
+	Pallet::<T>::transfer(origin, transfer_entry, transfer_entry_digest)
+}
+
 const SEED: u32 = 0;
 
 benchmarks! {
 		where_clause {
 			where
 			<T as Config>::EnsureOrigin: GenerateBenchmarkOrigin<T::RuntimeOrigin, T::AccountId, T::SpaceCreatorId>,
+			T: pallet_schema::Config,
 		}
 
 		create {
@@ -95,9 +216,14 @@ benchmarks! {
 			let chain_space_origin = RawOrigin::Root.into();
 
 			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
-			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity).expect("Approval should not fail.");
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity).expect("Approval should not fail.");
+
+			let class_name: AssetClassNameOf<T> = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let schema_id = anchor_schema::<T>(&space_id, &did);
+			let class_id = generate_class_id::<T>(&class_name, &schema_id, &space_id, &did);
+			Pallet::<T>::class_create(origin.clone(), class_name, schema_id, authorization_id.clone())?;
 
-		}: _<T::RuntimeOrigin>(origin, entry, digest, authorization_id)
+		}: create_with_options<T::RuntimeOrigin>(origin, entry, digest, authorization_id, Some(class_id), Some(10u32.into()))
 		verify {
 			assert_last_event::<T>(Event::Create { identifier: asset_id, issuer: did.clone() }.into());
 		}
@@ -353,5 +479,384 @@ benchmarks! {
 			assert_last_event::<T>(Event::StatusChange { identifier: asset_id.clone(), instance: Some(instance_id.clone()), status: new_status.clone() }.into());
 		}
 
+		split {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+
+			let did_split: T::SpaceCreatorId = account("did", 1, SEED);
+
+			let asset_desc = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_tag = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_meta = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_qty = 10;
+			let asset_value = 10;
+			let asset_type = AssetTypeOf::MF;
+
+			let raw_space = [56u8; 256].to_vec();
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+			let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]]
+					.concat()[..],
+			);
+
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+			let entry = AssetInputEntryOf::<T> {
+				asset_desc,
+				asset_qty,
+				asset_type,
+				asset_value,
+				asset_tag,
+				asset_meta,
+			};
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let capacity = 5u64;
+
+			let digest = <T as frame_system::Config>::Hashing::hash(
+				&[&entry.encode()[..]].concat()[..],
+			);
+
+			let create_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let asset_id: Ss58Identifier = generate_asset_id::<T>(&create_id_digest);
+
+			let chain_space_origin = RawOrigin::Root.into();
+
+			let issue_entry = AssetIssuanceEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_owner: did.clone(),
+				asset_issuance_qty: Some(10),
+			};
+
+			let issue_entry_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&issue_entry.encode()[..]].concat()[..],
+			);
+
+			let issue_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&asset_id.encode()[..], &did.encode()[..], &space_id.encode()[..], &did.encode()[..], &issue_entry_digest.encode()[..]].concat()[..],
+			);
+
+			let instance_id = generate_asset_instance_id::<T>(&issue_id_digest);
+
+			let split_entry = AssetSplitEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_instance_id: instance_id.clone(),
+				asset_owner: did.clone(),
+				new_asset_owner: did_split.clone(),
+				split_qty: 4,
+			};
+
+			let split_entry_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&split_entry.encode()[..]].concat()[..],
+			);
+
+			let split_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&asset_id.encode()[..], &instance_id.encode()[..], &did_split.encode()[..], &did.encode()[..], &split_entry_digest.encode()[..]].concat()[..],
+			);
+
+			let new_instance_id = generate_asset_instance_id::<T>(&split_id_digest);
+
+			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity).expect("Approval should not fail.");
+			Pallet::<T>::create(origin.clone(), entry, digest, authorization_id.clone())?;
+			Pallet::<T>::issue(origin.clone(), issue_entry, issue_entry_digest, authorization_id)?;
+
+		}: _<T::RuntimeOrigin>(origin, split_entry, split_entry_digest)
+		verify {
+			assert_last_event::<T>(Event::Split { identifier: asset_id, instance: instance_id, new_instance: new_instance_id, qty: 4, from: did, to: did_split }.into());
+		}
+
+		merge {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+
+			let asset_desc = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_tag = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_meta = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let asset_qty = 10;
+			let asset_value = 10;
+			let asset_type = AssetTypeOf::MF;
+
+			let raw_space = [56u8; 256].to_vec();
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+			let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]]
+					.concat()[..],
+			);
+
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+			let entry = AssetInputEntryOf::<T> {
+				asset_desc,
+				asset_qty,
+				asset_type,
+				asset_value,
+				asset_tag,
+				asset_meta,
+			};
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let capacity = 5u64;
+
+			let digest = <T as frame_system::Config>::Hashing::hash(
+				&[&entry.encode()[..]].concat()[..],
+			);
+
+			let create_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let asset_id: Ss58Identifier = generate_asset_id::<T>(&create_id_digest);
+
+			let chain_space_origin = RawOrigin::Root.into();
+
+			let issue_entry = AssetIssuanceEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_owner: did.clone(),
+				asset_issuance_qty: Some(10),
+			};
+
+			let issue_entry_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&issue_entry.encode()[..]].concat()[..],
+			);
+
+			let issue_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&asset_id.encode()[..], &did.encode()[..], &space_id.encode()[..], &did.encode()[..], &issue_entry_digest.encode()[..]].concat()[..],
+			);
+
+			let instance_id = generate_asset_instance_id::<T>(&issue_id_digest);
+
+			let split_entry = AssetSplitEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_instance_id: instance_id.clone(),
+				asset_owner: did.clone(),
+				new_asset_owner: did.clone(),
+				split_qty: 4,
+			};
+
+			let split_entry_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&split_entry.encode()[..]].concat()[..],
+			);
+
+			let split_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&asset_id.encode()[..], &instance_id.encode()[..], &did.encode()[..], &did.encode()[..], &split_entry_digest.encode()[..]].concat()[..],
+			);
+
+			let new_instance_id = generate_asset_instance_id::<T>(&split_id_digest);
+
+			let merge_entry = AssetMergeEntryOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_instance_id: instance_id.clone(),
+				merged_instance_id: new_instance_id.clone(),
+				asset_owner: did.clone(),
+			};
+
+			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity).expect("Approval should not fail.");
+			Pallet::<T>::create(origin.clone(), entry, digest, authorization_id.clone())?;
+			Pallet::<T>::issue(origin.clone(), issue_entry, issue_entry_digest, authorization_id)?;
+			Pallet::<T>::split(origin.clone(), split_entry, split_entry_digest)?;
+
+		}: _<T::RuntimeOrigin>(origin, merge_entry)
+		verify {
+			assert_last_event::<T>(Event::Merge { identifier: asset_id, instance: instance_id, merged_instance: new_instance_id, qty: 4, owner: did }.into());
+		}
+
+		class_create {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+
+			let raw_space = [56u8; 256].to_vec();
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+			let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let capacity = 5u64;
+			let chain_space_origin = RawOrigin::Root.into();
+
+			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity).expect("Approval should not fail.");
+
+			let class_name: AssetClassNameOf<T> = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let schema_id = anchor_schema::<T>(&space_id, &did);
+			let class_id = generate_class_id::<T>(&class_name, &schema_id, &space_id, &did);
+
+		}: _<T::RuntimeOrigin>(origin, class_name, schema_id.clone(), authorization_id)
+		verify {
+			assert_last_event::<T>(Event::ClassCreate { identifier: class_id, schema: schema_id, issuer: did }.into());
+		}
+
+		class_status_change {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+
+			let raw_space = [56u8; 256].to_vec();
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+			let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+
+			let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+			let auth_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let capacity = 5u64;
+			let chain_space_origin = RawOrigin::Root.into();
+
+			pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+			pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity).expect("Approval should not fail.");
+
+			let class_name: AssetClassNameOf<T> = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+			let schema_id = anchor_schema::<T>(&space_id, &did);
+			let class_id = generate_class_id::<T>(&class_name, &schema_id, &space_id, &did);
+			Pallet::<T>::class_create(origin.clone(), class_name, schema_id, authorization_id)?;
+
+			let new_status = AssetStatusOf::INACTIVE;
+
+		}: _<T::RuntimeOrigin>(origin, class_id.clone(), new_status.clone())
+		verify {
+			assert_last_event::<T>(Event::ClassStatusChange { identifier: class_id, status: new_status }.into());
+		}
+
+		set_transfer_policy {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let did_approver: T::SpaceCreatorId = account("did", 2, SEED);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let (asset_id, _) = issue_asset_instance::<T>(origin.clone(), &did)?;
+
+			let policy = AssetTransferPolicyOf::<T> {
+				require_acceptance: true,
+				approvers: BoundedVec::try_from(sp_std::vec![did_approver]).unwrap(),
+			};
+
+		}: _<T::RuntimeOrigin>(origin, asset_id.clone(), Some(policy))
+		verify {
+			assert_last_event::<T>(Event::TransferPolicyChange { identifier: asset_id }.into());
+		}
+
+		accept_transfer {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let caller_transfer: T::AccountId = account("caller", 1, SEED);
+			let did_transfer: T::SpaceCreatorId = account("did", 1, SEED);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let origin_transfer =  <T as Config>::EnsureOrigin::generate_origin(caller_transfer, did_transfer.clone());
+			let (asset_id, instance_id) = issue_asset_instance::<T>(origin.clone(), &did)?;
+
+			let policy = AssetTransferPolicyOf::<T> {
+				require_acceptance: true,
+				approvers: BoundedVec::default(),
+			};
+			Pallet::<T>::set_transfer_policy(origin.clone(), asset_id.clone(), Some(policy))?;
+			request_asset_transfer::<T>(origin, &asset_id, &instance_id, &did, &did_transfer)?;
+
+		}: _<T::RuntimeOrigin>(origin_transfer, instance_id.clone())
+		verify {
+			assert_last_event::<T>(Event::Transfer { identifier: asset_id, instance: instance_id, from: did, to: did_transfer }.into());
+		}
+
+		approve_transfer {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let did_transfer: T::SpaceCreatorId = account("did", 1, SEED);
+			let caller_approver: T::AccountId = account("caller", 2, SEED);
+			let did_approver: T::SpaceCreatorId = account("did", 2, SEED);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let origin_approver =  <T as Config>::EnsureOrigin::generate_origin(caller_approver, did_approver.clone());
+			let (asset_id, instance_id) = issue_asset_instance::<T>(origin.clone(), &did)?;
+
+			let policy = AssetTransferPolicyOf::<T> {
+				require_acceptance: false,
+				approvers: BoundedVec::try_from(sp_std::vec![did_approver]).unwrap(),
+			};
+			Pallet::<T>::set_transfer_policy(origin.clone(), asset_id.clone(), Some(policy))?;
+			request_asset_transfer::<T>(origin, &asset_id, &instance_id, &did, &did_transfer)?;
+
+		}: _<T::RuntimeOrigin>(origin_approver, instance_id.clone())
+		verify {
+			assert_last_event::<T>(Event::Transfer { identifier: asset_id, instance: instance_id, from: did, to: did_transfer }.into());
+		}
+
+		reject_transfer {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let caller_transfer: T::AccountId = account("caller", 1, SEED);
+			let did_transfer: T::SpaceCreatorId = account("did", 1, SEED);
+			let did_approver: T::SpaceCreatorId = account("did", 2, SEED);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let origin_transfer =  <T as Config>::EnsureOrigin::generate_origin(caller_transfer, did_transfer.clone());
+			let (asset_id, instance_id) = issue_asset_instance::<T>(origin.clone(), &did)?;
+
+			let policy = AssetTransferPolicyOf::<T> {
+				require_acceptance: true,
+				approvers: BoundedVec::try_from(sp_std::vec![did_approver]).unwrap(),
+			};
+			Pallet::<T>::set_transfer_policy(origin.clone(), asset_id.clone(), Some(policy))?;
+			request_asset_transfer::<T>(origin, &asset_id, &instance_id, &did, &did_transfer)?;
+
+		}: _<T::RuntimeOrigin>(origin_transfer, instance_id.clone())
+		verify {
+			assert_last_event::<T>(Event::TransferReject { identifier: asset_id, instance: instance_id, by: did_transfer }.into());
+		}
+
+		cancel_transfer {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let did_transfer: T::SpaceCreatorId = account("did", 1, SEED);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let (asset_id, instance_id) = issue_asset_instance::<T>(origin.clone(), &did)?;
+
+			let policy = AssetTransferPolicyOf::<T> {
+				require_acceptance: true,
+				approvers: BoundedVec::default(),
+			};
+			Pallet::<T>::set_transfer_policy(origin.clone(), asset_id.clone(), Some(policy))?;
+			request_asset_transfer::<T>(origin.clone(), &asset_id, &instance_id, &did, &did_transfer)?;
+
+		}: _<T::RuntimeOrigin>(origin, instance_id.clone())
+		verify {
+			assert_last_event::<T>(Event::TransferCancel { identifier: asset_id, instance: instance_id }.into());
+		}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -21,6 +21,7 @@
 
 pub mod types;
 
+pub mod migrations;
 pub mod weights;
 
 #[cfg(any(feature = "mock", test))]
@@ -33,13 +34,17 @@ pub mod benchmarking;
 mod tests;
 
 pub use crate::{pallet::*, types::*, weights::WeightInfo};
-use frame_support::{ensure, traits::Get};
+use cord_primitives::SchemaLookup;
+use cord_utilities::schedule;
+use frame_support::{ensure, traits::Get, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use pallet_chain_space::AuthorizationIdOf;
-use sp_runtime::traits::UniqueSaturatedInto;
+use pallet_chain_space::{AuthorizationIdOf, SpaceIdOf};
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto};
+use sp_std::vec::Vec;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -55,12 +60,21 @@ pub mod pallet {
 	};
 	use sp_std::{prelude::Clone, str};
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	///SS58 Asset Identifier
 	pub type AssetIdOf = Ss58Identifier;
 
 	///SS58 Asset Identifier
 	pub type AssetInstanceIdOf = Ss58Identifier;
 
+	///SS58 Asset Class Identifier
+	pub type AssetClassIdOf = Ss58Identifier;
+
+	///SS58 Schema Identifier
+	pub type SchemaIdOf = Ss58Identifier;
+
 	/// Type of a creator identifier.
 	pub type AssetCreatorOf<T> = pallet_chain_space::SpaceCreatorOf<T>;
 	/// Type of the identitiy.
@@ -71,6 +85,7 @@ pub mod pallet {
 	pub type AssetDescriptionOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedValueLength>;
 	pub type AssetTagOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedValueLength>;
 	pub type AssetMetadataOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedValueLength>;
+	pub type AssetClassNameOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedValueLength>;
 
 	pub type AssetInputEntryOf<T> =
 		AssetInputEntry<AssetDescriptionOf<T>, AssetTypeOf, AssetTagOf<T>, AssetMetadataOf<T>>;
@@ -112,6 +127,36 @@ pub mod pallet {
 
 	pub type AssetIssuanceEntryOf<T> = AssetIssuanceEntry<AssetIdOf, AssetCreatorOf<T>>;
 
+	pub type AssetSplitEntryOf<T> =
+		AssetSplitEntry<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf<T>>;
+
+	pub type AssetMergeEntryOf<T> =
+		AssetMergeEntry<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf<T>>;
+
+	pub type AssetHoldingOf<T> = AssetHolding<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf<T>>;
+
+	pub type AssetTransferRecordOf<T> = AssetTransferRecord<AssetCreatorOf<T>, EntryHashOf<T>>;
+
+	pub type AssetApproversOf<T> = BoundedVec<AssetCreatorOf<T>, <T as Config>::MaxAssetApprovers>;
+
+	pub type AssetTransferPolicyOf<T> = AssetTransferPolicy<AssetApproversOf<T>>;
+
+	pub type AssetPendingTransferOf<T> = AssetPendingTransfer<
+		AssetIdOf,
+		AssetCreatorOf<T>,
+		AssetApproversOf<T>,
+		EntryHashOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	pub type AssetClassEntryOf<T> = AssetClassEntry<
+		AssetClassNameOf<T>,
+		SchemaIdOf,
+		SpaceIdOf,
+		AssetCreatorOf<T>,
+		BlockNumberFor<T>,
+	>;
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config + pallet_chain_space::Config + identifier::Config
@@ -128,15 +173,40 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAssetDistribution: Get<u32>;
 
+		/// Maximum assets expiring at a single block
+		#[pallet::constant]
+		type MaxAssetExpiriesPerBlock: Get<u32>;
+
+		/// Maximum approvers in the transfer policy of an asset
+		#[pallet::constant]
+		type MaxAssetApprovers: Get<u32>;
+
+		/// Blocks after which a pending transfer can no longer be completed
+		#[pallet::constant]
+		type PendingTransferTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Maximum ownership changes kept in the transfer history of an
+		/// instance. Older changes are pruned as new ones are recorded.
+		#[pallet::constant]
+		type MaxInstanceTransfers: Get<u32>;
+
+		/// Source of the schemas asset classes are bound to.
+		type SchemaProvider: SchemaLookup<SchemaIdOf, SpaceIdOf>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_asset_expiries(n, remaining_weight)
+		}
+	}
 
 	/// asset entry identifiers with details stored on chain.
 	#[pallet::storage]
@@ -185,6 +255,102 @@ pub mod pallet {
 	pub type AssetLookup<T> =
 		StorageMap<_, Blake2_128Concat, EntryHashOf<T>, AssetIdOf, OptionQuery>;
 
+	/// asset instance lineage stored on chain.
+	/// It maps an asset instance to the instances it was split off from or
+	/// merged into.
+	#[pallet::storage]
+	pub type Lineage<T> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AssetIdOf,
+		Blake2_128Concat,
+		AssetInstanceIdOf,
+		AssetInstanceLineage<AssetInstanceIdOf>,
+		ValueQuery,
+	>;
+
+	/// asset (and vc asset) maturity or expiry blocks stored on chain.
+	#[pallet::storage]
+	pub type AssetExpiry<T> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf, BlockNumberFor<T>, OptionQuery>;
+
+	/// Scheduled asset expiries.
+	/// It maps a block number to the assets expiring at that block.
+	#[pallet::storage]
+	pub type AssetExpiries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<AssetIdOf, T::MaxAssetExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next block whose scheduled asset expiries have not been swept.
+	#[pallet::storage]
+	pub type NextAssetExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// asset class identifiers with details stored on chain.
+	#[pallet::storage]
+	pub type AssetClasses<T> =
+		StorageMap<_, Blake2_128Concat, AssetClassIdOf, AssetClassEntryOf<T>, OptionQuery>;
+
+	/// asset class of an asset (or vc asset) stored on chain.
+	#[pallet::storage]
+	pub type AssetClassOf<T> = StorageMap<_, Blake2_128Concat, AssetIdOf, AssetClassIdOf>;
+
+	/// Index of the asset (and vc asset) instances held by an owner.
+	/// It maps from an owner and an instance identifier to the asset the
+	/// instance belongs to.
+	#[pallet::storage]
+	pub type OwnerInstances<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetCreatorOf<T>,
+		Blake2_128Concat,
+		AssetInstanceIdOf,
+		AssetIdOf,
+		OptionQuery,
+	>;
+
+	/// Ownership changes of asset (and vc asset) instances stored on chain.
+	/// It maps from an instance identifier and a sequence number to the
+	/// ownership change. Only the last `MaxInstanceTransfers` changes of an
+	/// instance are kept.
+	#[pallet::storage]
+	pub type InstanceTransfers<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetInstanceIdOf,
+		Twox64Concat,
+		u32,
+		AssetTransferRecordOf<T>,
+		OptionQuery,
+	>;
+
+	/// Number of ownership changes recorded for an instance, including
+	/// the pruned ones.
+	#[pallet::storage]
+	pub type InstanceTransferCount<T> =
+		StorageMap<_, Blake2_128Concat, AssetInstanceIdOf, u32, ValueQuery>;
+
+	/// Index of the assets created under an asset class.
+	/// It maps from a class identifier to the identifiers of its assets.
+	#[pallet::storage]
+	pub type ClassAssets<T> =
+		StorageDoubleMap<_, Blake2_128Concat, AssetClassIdOf, Blake2_128Concat, AssetIdOf, ()>;
+
+	/// Transfer policies of assets (and vc assets) stored on chain.
+	/// It maps from an asset identifier to its transfer policy.
+	#[pallet::storage]
+	pub type TransferPolicies<T> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf, AssetTransferPolicyOf<T>, OptionQuery>;
+
+	/// Transfers awaiting acceptance or approval stored on chain.
+	/// It maps from an instance identifier to the pending transfer.
+	#[pallet::storage]
+	pub type PendingTransfers<T> =
+		StorageMap<_, Blake2_128Concat, AssetInstanceIdOf, AssetPendingTransferOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -210,6 +376,66 @@ pub mod pallet {
 			instance: Option<AssetInstanceIdOf>,
 			status: AssetStatusOf,
 		},
+		/// A part of an asset instance has been split off into a new instance.
+		/// \[asset entry identifier, instance identifier, new instance
+		/// identifier, quantity, owner, beneficiary\]
+		Split {
+			identifier: AssetIdOf,
+			instance: AssetInstanceIdOf,
+			new_instance: AssetInstanceIdOf,
+			qty: AssetQtyOf,
+			from: AssetCreatorOf<T>,
+			to: AssetCreatorOf<T>,
+		},
+		/// An asset instance has been merged into another instance.
+		/// \[asset entry identifier, instance identifier, merged instance
+		/// identifier, quantity, owner\]
+		Merge {
+			identifier: AssetIdOf,
+			instance: AssetInstanceIdOf,
+			merged_instance: AssetInstanceIdOf,
+			qty: AssetQtyOf,
+			owner: AssetCreatorOf<T>,
+		},
+		/// An asset has reached its maturity or expiry block.
+		/// \[asset entry identifier, expiry block\]
+		Expire { identifier: AssetIdOf, expired_at: BlockNumberFor<T> },
+		/// A new asset class has been registered.
+		/// \[asset class identifier, schema identifier, issuer\]
+		ClassCreate { identifier: AssetClassIdOf, schema: SchemaIdOf, issuer: AssetCreatorOf<T> },
+		/// An asset class has a new Status now
+		/// \[asset class identifier, new status\]
+		ClassStatusChange { identifier: AssetClassIdOf, status: AssetStatusOf },
+		/// The transfer policy of an asset has been set or removed.
+		/// \[asset entry identifier\]
+		TransferPolicyChange { identifier: AssetIdOf },
+		/// A transfer is awaiting acceptance or approval.
+		/// \[asset entry identifier, instance identifier, owner, beneficiary,
+		/// digest, expiry block\]
+		TransferRequest {
+			identifier: AssetIdOf,
+			instance: AssetInstanceIdOf,
+			from: AssetCreatorOf<T>,
+			to: AssetCreatorOf<T>,
+			digest: EntryHashOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A pending transfer has been accepted by the beneficiary.
+		/// \[asset entry identifier, instance identifier\]
+		TransferAccept { identifier: AssetIdOf, instance: AssetInstanceIdOf },
+		/// A pending transfer has been approved.
+		/// \[asset entry identifier, instance identifier, approver\]
+		TransferApprove {
+			identifier: AssetIdOf,
+			instance: AssetInstanceIdOf,
+			approver: AssetCreatorOf<T>,
+		},
+		/// A pending transfer has been rejected.
+		/// \[asset entry identifier, instance identifier, rejecting party\]
+		TransferReject { identifier: AssetIdOf, instance: AssetInstanceIdOf, by: AssetCreatorOf<T> },
+		/// A pending transfer has been cancelled.
+		/// \[asset entry identifier, instance identifier\]
+		TransferCancel { identifier: AssetIdOf, instance: AssetInstanceIdOf },
 	}
 
 	#[pallet::error]
@@ -246,6 +472,38 @@ pub mod pallet {
 		AssetInstanceNotFound,
 		/// Asset is in same status as asked for
 		AssetInSameState,
+		/// asset instance already exists
+		AssetInstanceAlreadyExists,
+		/// An asset instance cannot be merged into itself
+		InvalidInstanceMerge,
+		/// Asset expiry is not in the future
+		InvalidAssetExpiry,
+		/// Maximum number of assets expiring at the block reached
+		TooManyAssetExpiriesInBlock,
+		/// Asset class already added
+		AssetClassAlreadyExists,
+		/// Asset class identifier not found
+		AssetClassNotFound,
+		/// Asset class is not active
+		AssetClassNotActive,
+		/// Asset class belongs to a different space
+		AssetClassSpaceMismatch,
+		/// Schema not found
+		SchemaNotFound,
+		/// Schema has been deprecated
+		SchemaDeprecated,
+		/// Schema belongs to a different space
+		SchemaSpaceMismatch,
+		/// Transfer policy requires neither acceptance nor approval
+		InvalidTransferPolicy,
+		/// Asset instance has a pending transfer
+		TransferPending,
+		/// Pending transfer not found
+		PendingTransferNotFound,
+		/// Pending transfer has expired
+		PendingTransferExpired,
+		/// Pending transfer does not await this confirmation
+		TransferAlreadyConfirmed,
 	}
 
 	#[pallet::call]
@@ -286,46 +544,7 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
-				&authorization,
-				&creator.clone(),
-			)
-			.map_err(<pallet_chain_space::Error<T>>::from)?;
-
-			ensure!(entry.asset_qty > 0 && entry.asset_value > 0, Error::<T>::InvalidAssetValue);
-			ensure!(entry.asset_type.is_valid_asset_type(), Error::<T>::InvalidAssetType);
-
-			// Id Digest = concat (H(<scale_encoded_entry_digest>,
-			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier =
-				Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Asset)
-					.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(!<Assets<T>>::contains_key(&identifier), Error::<T>::AssetIdAlreadyExists);
-
-			let block_number = frame_system::Pallet::<T>::block_number();
-
-			<AssetLookup<T>>::insert(digest, &identifier);
-
-			<Assets<T>>::insert(
-				&identifier,
-				AssetEntryOf::<T> {
-					asset_detail: entry,
-					asset_issuance: Zero::zero(),
-					asset_status: AssetStatusOf::ACTIVE,
-					asset_issuer: creator.clone(),
-					created_at: block_number,
-				},
-			);
-
-			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
-			Self::deposit_event(Event::Create { identifier, issuer: creator });
-
-			Ok(())
+			Self::do_create(creator, entry, digest, authorization, None, None)
 		}
 
 		/// Issues new instances of an existing asset.
@@ -417,6 +636,15 @@ pub mod pallet {
 
 			<AssetLookup<T>>::insert(digest, &entry.asset_id);
 
+			Self::record_ownership(
+				&entry.asset_id,
+				&instance_id,
+				None,
+				&entry.asset_owner,
+				issuance_qty,
+				Some(digest),
+			);
+
 			<Issuance<T>>::insert(
 				&entry.asset_id,
 				&instance_id,
@@ -424,7 +652,7 @@ pub mod pallet {
 					asset_instance_detail: asset_instance.asset_detail,
 					asset_instance_parent: entry.asset_id.clone(),
 					asset_instance_status: AssetStatusOf::ACTIVE,
-					asset_instance_issuer: issuer,
+					asset_instance_issuer: issuer.clone(),
 					asset_instance_owner: entry.asset_owner,
 					created_at: block_number,
 				},
@@ -435,6 +663,9 @@ pub mod pallet {
 				AssetEntryOf::<T> { asset_issuance: overall_issuance, ..asset },
 			);
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &issuer, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&entry.asset_id, CallTypeOf::Issue).map_err(<Error<T>>::from)?;
 			Self::update_activity(&instance_id, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
 			Self::deposit_event(Event::Issue { identifier: entry.asset_id, instance: instance_id });
@@ -453,7 +684,11 @@ pub mod pallet {
 		/// - `origin`: The origin of the call, which must be signed by the current owner.
 		/// - `entry`: The details of the asset transfer, including asset ID, instance ID, current
 		///   owner, and new owner.
-		/// - `_digest`: The hash of the entry data (unused in this function).
+		/// - `digest`: The hash of the entry data, kept with the transfer record.
+		///
+		/// If the asset has a transfer policy the transfer is recorded as
+		/// pending and only completes once it has been accepted and approved
+		/// as the policy requires.
 		///
 		/// # Returns
 		/// Returns `Ok(())` if the asset instance was successfully transferred, or an `Err` with an
@@ -466,16 +701,19 @@ pub mod pallet {
 		///   of the asset instance.
 		/// - `AssetNotActive`: If the asset is not active.
 		/// - `InstanceNotActive`: If the asset instance is not active.
+		/// - `TransferPending`: If the asset instance already has a pending transfer
+		///   that has not expired.
 		/// - Propagates errors from `Self::update_activity` if it fails.
 		///
 		/// # Events
 		/// - `Event::Transfer`: Emitted when an asset instance is successfully transferred.
+		/// - `Event::TransferRequest`: Emitted instead when the transfer is pending.
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::transfer())]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			entry: AssetTransferEntryOf<T>,
-			_digest: EntryHashOf<T>,
+			digest: EntryHashOf<T>,
 		) -> DispatchResult {
 			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
@@ -495,9 +733,23 @@ pub mod pallet {
 				AssetStatusOf::ACTIVE == instance.asset_instance_status,
 				Error::<T>::InstanceNotActive
 			);
+			Self::ensure_no_pending_transfer(&entry.asset_id, &entry.asset_instance_id)?;
+
+			if let Some(policy) = <TransferPolicies<T>>::get(&entry.asset_id) {
+				return Self::request_transfer(entry, owner, digest, false, policy);
+			}
 
 			let block_number = frame_system::Pallet::<T>::block_number();
 
+			Self::record_ownership(
+				&entry.asset_id,
+				&entry.asset_instance_id,
+				Some(&owner),
+				&entry.new_asset_owner,
+				instance.asset_instance_detail.asset_qty,
+				Some(digest),
+			);
+
 			<Issuance<T>>::insert(
 				&entry.asset_id,
 				&entry.asset_instance_id,
@@ -637,46 +889,7 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
-				&authorization,
-				&creator.clone(),
-			)
-			.map_err(<pallet_chain_space::Error<T>>::from)?;
-
-			ensure!(asset_qty > 0, Error::<T>::InvalidAssetQty);
-
-			// Id Digest = concat (H(<scale_encoded_entry_digest>,
-			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier =
-				Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Asset)
-					.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(!<VCAssets<T>>::contains_key(&identifier), Error::<T>::AssetIdAlreadyExists);
-
-			let block_number = frame_system::Pallet::<T>::block_number();
-
-			<AssetLookup<T>>::insert(digest, &identifier);
-
-			<VCAssets<T>>::insert(
-				&identifier,
-				VCAssetEntryOf::<T> {
-					asset_qty,
-					digest,
-					asset_issuance: Zero::zero(),
-					asset_status: AssetStatusOf::ACTIVE,
-					asset_issuer: creator.clone(),
-					created_at: block_number,
-				},
-			);
-
-			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
-			Self::deposit_event(Event::Create { identifier, issuer: creator });
-
-			Ok(())
+			Self::do_vc_create(creator, asset_qty, digest, authorization, None, None)
 		}
 
 		/// Issues a new instance of a VC (Verifiable Credential) asset.
@@ -764,6 +977,15 @@ pub mod pallet {
 
 			<AssetLookup<T>>::insert(digest, &entry.asset_id);
 
+			Self::record_ownership(
+				&entry.asset_id,
+				&instance_id,
+				None,
+				&entry.asset_owner,
+				issuance_qty,
+				Some(digest),
+			);
+
 			<VCIssuance<T>>::insert(
 				&entry.asset_id,
 				&instance_id,
@@ -772,7 +994,7 @@ pub mod pallet {
 					asset_instance_parent: entry.asset_id.clone(),
 					digest,
 					asset_instance_status: AssetStatusOf::ACTIVE,
-					asset_instance_issuer: issuer,
+					asset_instance_issuer: issuer.clone(),
 					asset_instance_owner: entry.asset_owner,
 					created_at: block_number,
 				},
@@ -783,6 +1005,9 @@ pub mod pallet {
 				VCAssetEntryOf::<T> { asset_issuance: overall_issuance, ..asset },
 			);
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &issuer, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&entry.asset_id, CallTypeOf::Issue).map_err(<Error<T>>::from)?;
 			Self::update_activity(&instance_id, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
 			Self::deposit_event(Event::Issue { identifier: entry.asset_id, instance: instance_id });
@@ -814,10 +1039,13 @@ pub mod pallet {
 		/// - `UnauthorizedOperation`: If the caller is not the current owner of the asset instance.
 		/// - `AssetNotActive`: If the asset is not in an active state.
 		/// - `InstanceNotActive`: If the asset instance is not in an active state.
+		/// - `TransferPending`: If the asset instance already has a pending transfer
+		///   that has not expired.
 		/// - Propagates errors from `Self::update_activity` if it fails.
 		///
 		/// # Events
 		/// - `Event::Transfer`: Emitted when a VC asset instance is successfully transferred.
+		/// - `Event::TransferRequest`: Emitted instead when the asset has a transfer policy.
 		#[pallet::call_index(6)]
 		#[pallet::weight({0})]
 		pub fn vc_transfer(
@@ -843,9 +1071,23 @@ pub mod pallet {
 				AssetStatusOf::ACTIVE == instance.asset_instance_status,
 				Error::<T>::InstanceNotActive
 			);
+			Self::ensure_no_pending_transfer(&entry.asset_id, &entry.asset_instance_id)?;
+
+			if let Some(policy) = <TransferPolicies<T>>::get(&entry.asset_id) {
+				return Self::request_transfer(entry, owner, digest, true, policy);
+			}
 
 			let block_number = frame_system::Pallet::<T>::block_number();
 
+			Self::record_ownership(
+				&entry.asset_id,
+				&entry.asset_instance_id,
+				Some(&owner),
+				&entry.new_asset_owner,
+				instance.asset_qty,
+				Some(digest),
+			);
+
 			<VCIssuance<T>>::insert(
 				&entry.asset_id,
 				&entry.asset_instance_id,
@@ -950,14 +1192,1254 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	pub fn get_distributed_qty(asset_id: &AssetIdOf) -> u32 {
-		<Distribution<T>>::get(asset_id)
-			.map(|bounded_vec| bounded_vec.len() as u32)
-			.unwrap_or(0)
+		/// Splits part of an asset instance off into a new instance.
+		///
+		/// This function moves `split_qty` out of an asset instance into a new
+		/// instance owned by `new_asset_owner`, which may be the current owner.
+		/// The quantity held by both instances together is the quantity the
+		/// instance held before the split. The new instance records the
+		/// instance it was split off from, so that its origin can be traced
+		/// back to the asset.
+		///
+		/// If the asset has a transfer policy and `new_asset_owner` is not the
+		/// current owner, the new instance stays with the current owner and a
+		/// transfer of it to `new_asset_owner` is requested, to be accepted or
+		/// approved as the policy requires.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the current owner.
+		/// - `entry`: The details of the split, including asset ID, instance ID, current owner,
+		///   owner of the new instance and the quantity to split off.
+		/// - `digest`: The hash of the entry data.
+		///
+		/// # Returns
+		/// Returns `Ok(())` if the instance was successfully split, or an `Err` with an
+		/// appropriate error if the operation fails.
+		///
+		/// # Errors
+		/// - `AssetIdNotFound`: If the asset with the given ID does not exist.
+		/// - `AssetInstanceNotFound`: If the asset instance with the given ID does not exist.
+		/// - `UnauthorizedOperation`: If the caller or the specified current owner is not the owner
+		///   of the asset instance.
+		/// - `AssetNotActive`: If the asset is not active.
+		/// - `InstanceNotActive`: If the asset instance is not active.
+		/// - `TransferPending`: If the asset instance has a pending transfer that
+		///   has not expired.
+		/// - `InvalidAssetQty`: If `split_qty` is zero or not lower than the instance quantity.
+		/// - `InvalidIdentifierLength`: If the generated identifier is of invalid length.
+		/// - `AssetInstanceAlreadyExists`: If the new instance already exists.
+		/// - `DistributionLimitExceeded`: If the distribution limit is exceeded.
+		/// - Propagates errors from `Self::update_activity` if it fails.
+		///
+		/// # Events
+		/// - `Event::Split`: Emitted when an asset instance is successfully split.
+		/// - `Event::TransferRequest`: Emitted as well when the transfer of the new instance is
+		///   pending.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::split())]
+		pub fn split(
+			origin: OriginFor<T>,
+			entry: AssetSplitEntryOf<T>,
+			digest: EntryHashOf<T>,
+		) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let asset = <Assets<T>>::get(&entry.asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let instance = <Issuance<T>>::get(&entry.asset_id, &entry.asset_instance_id)
+				.ok_or(Error::<T>::AssetInstanceNotFound)?;
+
+			ensure!(instance.asset_instance_owner == owner, Error::<T>::UnauthorizedOperation);
+			ensure!(
+				instance.asset_instance_owner == entry.asset_owner,
+				Error::<T>::UnauthorizedOperation
+			);
+
+			ensure!(AssetStatusOf::ACTIVE == asset.asset_status, Error::<T>::AssetNotActive);
+
+			ensure!(
+				AssetStatusOf::ACTIVE == instance.asset_instance_status,
+				Error::<T>::InstanceNotActive
+			);
+
+			Self::ensure_no_pending_transfer(&entry.asset_id, &entry.asset_instance_id)?;
+
+			let instance_qty = instance.asset_instance_detail.asset_qty;
+			ensure!(
+				entry.split_qty > 0 && entry.split_qty < instance_qty,
+				Error::<T>::InvalidAssetQty
+			);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[
+					&entry.asset_id.encode()[..],
+					&entry.asset_instance_id.encode()[..],
+					&entry.new_asset_owner.encode()[..],
+					&owner.encode()[..],
+					&digest.encode()[..],
+				]
+				.concat()[..],
+			);
+
+			let new_instance_id = Ss58Identifier::create_identifier(
+				&(id_digest).encode()[..],
+				IdentifierType::AssetInstance,
+			)
+			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+			ensure!(
+				!<Issuance<T>>::contains_key(&entry.asset_id, &new_instance_id),
+				Error::<T>::AssetInstanceAlreadyExists
+			);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+
+			// A split to another owner is a transfer of the new instance, so it
+			// is subject to the transfer policy of the asset.
+			let policy = if entry.new_asset_owner != owner {
+				<TransferPolicies<T>>::get(&entry.asset_id)
+			} else {
+				None
+			};
+			let split_owner =
+				if policy.is_some() { owner.clone() } else { entry.new_asset_owner.clone() };
+
+			Distribution::<T>::try_mutate(&entry.asset_id, |dist_option| {
+				let dist = dist_option.get_or_insert_with(BoundedVec::default);
+				dist.try_push(new_instance_id.clone())
+					.map_err(|_| Error::<T>::DistributionLimitExceeded)
+			})?;
+
+			<AssetLookup<T>>::insert(digest, &entry.asset_id);
+
+			let mut split_detail = instance.asset_instance_detail.clone();
+			split_detail.asset_qty = entry.split_qty;
+
+			Self::record_ownership(
+				&entry.asset_id,
+				&new_instance_id,
+				Some(&owner),
+				&split_owner,
+				entry.split_qty,
+				Some(digest),
+			);
+
+			<Issuance<T>>::insert(
+				&entry.asset_id,
+				&new_instance_id,
+				AssetDistributionEntryOf::<T> {
+					asset_instance_detail: split_detail,
+					asset_instance_owner: split_owner.clone(),
+					created_at: block_number,
+					..instance.clone()
+				},
+			);
+
+			let mut remaining_detail = instance.asset_instance_detail.clone();
+			remaining_detail.asset_qty = instance_qty - entry.split_qty;
+
+			<Issuance<T>>::insert(
+				&entry.asset_id,
+				&entry.asset_instance_id,
+				AssetDistributionEntryOf::<T> {
+					asset_instance_detail: remaining_detail,
+					..instance
+				},
+			);
+
+			<Lineage<T>>::insert(
+				&entry.asset_id,
+				&new_instance_id,
+				AssetInstanceLineage {
+					split_from: Some(entry.asset_instance_id.clone()),
+					merged_into: None,
+				},
+			);
+
+			Self::update_activity(&entry.asset_instance_id, CallTypeOf::Debit)
+				.map_err(<Error<T>>::from)?;
+			Self::update_activity(&new_instance_id, CallTypeOf::Genesis)
+				.map_err(<Error<T>>::from)?;
+			Self::deposit_event(Event::Split {
+				identifier: entry.asset_id.clone(),
+				instance: entry.asset_instance_id,
+				new_instance: new_instance_id.clone(),
+				qty: entry.split_qty,
+				from: owner.clone(),
+				to: split_owner,
+			});
+
+			if let Some(policy) = policy {
+				let transfer = AssetTransferEntryOf::<T> {
+					asset_id: entry.asset_id,
+					asset_instance_id: new_instance_id,
+					asset_owner: owner.clone(),
+					new_asset_owner: entry.new_asset_owner,
+				};
+				return Self::request_transfer(transfer, owner, digest, false, policy);
+			}
+
+			Ok(())
+		}
+
+		/// Merges an asset instance into another instance of the same owner.
+		///
+		/// This function adds the quantity of `merged_instance_id` to
+		/// `asset_instance_id` and removes the merged instance from the
+		/// distribution of the asset. The lineage of the merged instance keeps
+		/// the instance it was merged into.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the owner of both instances.
+		/// - `entry`: The details of the merge, including asset ID, the instance to keep, the
+		///   instance to merge into it and the owner.
+		///
+		/// # Returns
+		/// Returns `Ok(())` if the instances were successfully merged, or an `Err` with an
+		/// appropriate error if the operation fails.
+		///
+		/// # Errors
+		/// - `InvalidInstanceMerge`: If an instance is merged into itself.
+		/// - `AssetIdNotFound`: If the asset with the given ID does not exist.
+		/// - `AssetInstanceNotFound`: If either asset instance does not exist.
+		/// - `UnauthorizedOperation`: If the caller or the specified owner is not the owner of both
+		///   asset instances.
+		/// - `AssetNotActive`: If the asset is not active.
+		/// - `InstanceNotActive`: If either asset instance is not active.
+		/// - `TransferPending`: If either asset instance has a pending transfer
+		///   that has not expired.
+		/// - Propagates errors from `Self::update_activity` if it fails.
+		///
+		/// # Events
+		/// - `Event::Merge`: Emitted when the instances are successfully merged.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::merge())]
+		pub fn merge(origin: OriginFor<T>, entry: AssetMergeEntryOf<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(
+				entry.asset_instance_id != entry.merged_instance_id,
+				Error::<T>::InvalidInstanceMerge
+			);
+
+			let asset = <Assets<T>>::get(&entry.asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let instance = <Issuance<T>>::get(&entry.asset_id, &entry.asset_instance_id)
+				.ok_or(Error::<T>::AssetInstanceNotFound)?;
+			let merged = <Issuance<T>>::get(&entry.asset_id, &entry.merged_instance_id)
+				.ok_or(Error::<T>::AssetInstanceNotFound)?;
+
+			ensure!(instance.asset_instance_owner == owner, Error::<T>::UnauthorizedOperation);
+			ensure!(merged.asset_instance_owner == owner, Error::<T>::UnauthorizedOperation);
+			ensure!(entry.asset_owner == owner, Error::<T>::UnauthorizedOperation);
+
+			ensure!(AssetStatusOf::ACTIVE == asset.asset_status, Error::<T>::AssetNotActive);
+
+			ensure!(
+				AssetStatusOf::ACTIVE == instance.asset_instance_status &&
+					AssetStatusOf::ACTIVE == merged.asset_instance_status,
+				Error::<T>::InstanceNotActive
+			);
+			Self::ensure_no_pending_transfer(&entry.asset_id, &entry.asset_instance_id)?;
+			Self::ensure_no_pending_transfer(&entry.asset_id, &entry.merged_instance_id)?;
+
+			let merged_qty = merged.asset_instance_detail.asset_qty;
+			let mut instance_detail = instance.asset_instance_detail.clone();
+			instance_detail.asset_qty = instance_detail.asset_qty.saturating_add(merged_qty);
+
+			<Issuance<T>>::insert(
+				&entry.asset_id,
+				&entry.asset_instance_id,
+				AssetDistributionEntryOf::<T> {
+					asset_instance_detail: instance_detail,
+					..instance
+				},
+			);
+			<Issuance<T>>::remove(&entry.asset_id, &entry.merged_instance_id);
+			<OwnerInstances<T>>::remove(&owner, &entry.merged_instance_id);
+
+			Distribution::<T>::mutate(&entry.asset_id, |dist_option| {
+				if let Some(dist) = dist_option {
+					dist.retain(|id| id != &entry.merged_instance_id);
+				}
+			});
+
+			<Lineage<T>>::mutate(&entry.asset_id, &entry.merged_instance_id, |lineage| {
+				lineage.merged_into = Some(entry.asset_instance_id.clone())
+			});
+
+			Self::update_activity(&entry.asset_instance_id, CallTypeOf::Credit)
+				.map_err(<Error<T>>::from)?;
+			Self::update_activity(&entry.merged_instance_id, CallTypeOf::Remove)
+				.map_err(<Error<T>>::from)?;
+			Self::deposit_event(Event::Merge {
+				identifier: entry.asset_id,
+				instance: entry.asset_instance_id,
+				merged_instance: entry.merged_instance_id,
+				qty: merged_qty,
+				owner,
+			});
+
+			Ok(())
+		}
+
+		/// Registers a new asset class within a specified space.
+		///
+		/// An asset class lets issuers define their own kinds of assets
+		/// without a runtime upgrade. Each class is bound to a schema
+		/// describing the metadata of the assets created under it.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the issuer.
+		/// - `class_name`: The name of the asset class.
+		/// - `class_schema`: The schema describing the `asset_meta` of assets of the class.
+		/// - `authorization`: The authorization ID used to validate the registration.
+		///
+		/// # Errors
+		/// - `SchemaNotFound`: If the schema does not exist.
+		/// - `SchemaDeprecated`: If the schema has been deprecated.
+		/// - `SchemaSpaceMismatch`: If the schema is scoped to a different space.
+		/// - `InvalidIdentifierLength`: If the generated identifier is of invalid length.
+		/// - `AssetClassAlreadyExists`: If the class has already been registered.
+		/// - Propagates errors from `pallet_chain_space::Pallet::ensure_authorization_origin` and
+		/// `Self::update_activity` if they fail.
+		///
+		/// # Events
+		/// - `Event::ClassCreate`: Emitted when an asset class is successfully registered.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::class_create())]
+		pub fn class_create(
+			origin: OriginFor<T>,
+			class_name: AssetClassNameOf<T>,
+			class_schema: SchemaIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let issuer = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&issuer,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::ensure_valid_schema(&class_schema, &space_id)?;
+
+			// Id Digest = concat (H(<scale_encoded_class_name>,
+			// <scale_encoded_schema_identifier>, <scale_encoded_space_identifier>,
+			// <scale_encoded_issuer_identifier>))
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[
+					&class_name.encode()[..],
+					&class_schema.encode()[..],
+					&space_id.encode()[..],
+					&issuer.encode()[..],
+				]
+				.concat()[..],
+			);
+
+			let identifier =
+				Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Asset)
+					.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+			ensure!(
+				!<AssetClasses<T>>::contains_key(&identifier),
+				Error::<T>::AssetClassAlreadyExists
+			);
+
+			<AssetClasses<T>>::insert(
+				&identifier,
+				AssetClassEntryOf::<T> {
+					class_name,
+					class_schema: class_schema.clone(),
+					class_space: space_id.clone(),
+					class_status: AssetStatusOf::ACTIVE,
+					class_issuer: issuer.clone(),
+					created_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &issuer, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+			Self::deposit_event(Event::ClassCreate { identifier, schema: class_schema, issuer });
+
+			Ok(())
+		}
+
+		/// Changes the status of an asset class.
+		///
+		/// Assets can only be created under active classes; assets already
+		/// created under a class are not affected by its status.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the class issuer.
+		/// - `class_id`: The identifier of the asset class.
+		/// - `new_status`: The new status of the asset class.
+		///
+		/// # Errors
+		/// - `AssetClassNotFound`: If the asset class does not exist.
+		/// - `UnauthorizedOperation`: If the caller is not the issuer of the class.
+		/// - `InvalidAssetStatus`: If the new status is invalid.
+		/// - `AssetInSameState`: If the class is already in the new status.
+		/// - Propagates errors from `Self::update_activity` if it fails.
+		///
+		/// # Events
+		/// - `Event::ClassStatusChange`: Emitted when the status of the class is changed.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::class_status_change())]
+		pub fn class_status_change(
+			origin: OriginFor<T>,
+			class_id: AssetClassIdOf,
+			new_status: AssetStatusOf,
+		) -> DispatchResult {
+			let issuer = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let class = <AssetClasses<T>>::get(&class_id).ok_or(Error::<T>::AssetClassNotFound)?;
+
+			ensure!(class.class_issuer == issuer, Error::<T>::UnauthorizedOperation);
+			ensure!(new_status.is_valid_status_type(), Error::<T>::InvalidAssetStatus);
+			ensure!(new_status != class.class_status, Error::<T>::AssetInSameState);
+
+			<AssetClasses<T>>::insert(
+				&class_id,
+				AssetClassEntryOf::<T> { class_status: new_status.clone(), ..class },
+			);
+
+			Self::update_activity(&class_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+			Self::deposit_event(Event::ClassStatusChange {
+				identifier: class_id,
+				status: new_status,
+			});
+
+			Ok(())
+		}
+
+		/// Sets or removes the transfer policy of an asset (or vc asset).
+		///
+		/// While an asset has a transfer policy, `transfer` and `vc_transfer`
+		/// only record a pending transfer. It completes once the new owner has
+		/// accepted it, if the policy requires so, and one of the approvers
+		/// has approved it, if the policy names any.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the issuer of the asset.
+		/// - `asset_id`: The identifier of the asset.
+		/// - `policy`: The new transfer policy, or `None` to remove it.
+		///
+		/// # Errors
+		/// - `AssetIdNotFound`: If the asset with the given ID does not exist.
+		/// - `UnauthorizedOperation`: If the caller is not the issuer of the asset.
+		/// - `InvalidTransferPolicy`: If the policy requires neither acceptance nor approval.
+		/// - Propagates errors from `Self::update_activity` if it fails.
+		///
+		/// # Events
+		/// - `Event::TransferPolicyChange`: Emitted when the policy is set or removed.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_transfer_policy())]
+		pub fn set_transfer_policy(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf,
+			policy: Option<AssetTransferPolicyOf<T>>,
+		) -> DispatchResult {
+			let issuer = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let asset_issuer = match <Assets<T>>::get(&asset_id) {
+				Some(asset) => asset.asset_issuer,
+				None => {
+					<VCAssets<T>>::get(&asset_id).ok_or(Error::<T>::AssetIdNotFound)?.asset_issuer
+				},
+			};
+			ensure!(asset_issuer == issuer, Error::<T>::UnauthorizedOperation);
+
+			match policy {
+				Some(policy) => {
+					ensure!(
+						policy.require_acceptance || !policy.approvers.is_empty(),
+						Error::<T>::InvalidTransferPolicy
+					);
+					<TransferPolicies<T>>::insert(&asset_id, policy);
+				},
+				None => <TransferPolicies<T>>::remove(&asset_id),
+			}
+
+			Self::update_activity(&asset_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+			Self::deposit_event(Event::TransferPolicyChange { identifier: asset_id });
+
+			Ok(())
+		}
+
+		/// Accepts a pending transfer of an asset instance.
+		///
+		/// The transfer completes if it does not await an approval any more.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the new owner.
+		/// - `instance_id`: The identifier of the asset instance.
+		///
+		/// # Errors
+		/// - `PendingTransferNotFound`: If the instance has no pending transfer.
+		/// - `UnauthorizedOperation`: If the caller is not the new owner.
+		/// - `TransferAlreadyConfirmed`: If the transfer does not await acceptance.
+		/// - `PendingTransferExpired`: If the transfer has expired.
+		/// - Propagates errors from `Self::settle_transfer` if it fails.
+		///
+		/// # Events
+		/// - `Event::TransferAccept`: Emitted when the transfer is accepted.
+		/// - `Event::Transfer`: Emitted when the transfer completes.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::accept_transfer())]
+		pub fn accept_transfer(
+			origin: OriginFor<T>,
+			instance_id: AssetInstanceIdOf,
+		) -> DispatchResult {
+			let beneficiary = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let mut pending = <PendingTransfers<T>>::get(&instance_id)
+				.ok_or(Error::<T>::PendingTransferNotFound)?;
+
+			ensure!(pending.new_asset_owner == beneficiary, Error::<T>::UnauthorizedOperation);
+			ensure!(pending.awaiting_acceptance, Error::<T>::TransferAlreadyConfirmed);
+			Self::ensure_not_expired(&pending)?;
+
+			pending.awaiting_acceptance = false;
+
+			Self::deposit_event(Event::TransferAccept {
+				identifier: pending.asset_id.clone(),
+				instance: instance_id.clone(),
+			});
+
+			Self::confirm_transfer(instance_id, pending)
+		}
+
+		/// Approves a pending transfer of an asset instance.
+		///
+		/// The transfer completes if it does not await the acceptance of the
+		/// new owner any more.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by an approver of the asset.
+		/// - `instance_id`: The identifier of the asset instance.
+		///
+		/// # Errors
+		/// - `PendingTransferNotFound`: If the instance has no pending transfer.
+		/// - `TransferAlreadyConfirmed`: If the transfer does not await approval.
+		/// - `UnauthorizedOperation`: If the caller is not an approver of the asset.
+		/// - `PendingTransferExpired`: If the transfer has expired.
+		/// - Propagates errors from `Self::settle_transfer` if it fails.
+		///
+		/// # Events
+		/// - `Event::TransferApprove`: Emitted when the transfer is approved.
+		/// - `Event::Transfer`: Emitted when the transfer completes.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_transfer())]
+		pub fn approve_transfer(
+			origin: OriginFor<T>,
+			instance_id: AssetInstanceIdOf,
+		) -> DispatchResult {
+			let approver = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let mut pending = <PendingTransfers<T>>::get(&instance_id)
+				.ok_or(Error::<T>::PendingTransferNotFound)?;
+
+			ensure!(pending.awaiting_approval, Error::<T>::TransferAlreadyConfirmed);
+			ensure!(pending.approvers.contains(&approver), Error::<T>::UnauthorizedOperation);
+			Self::ensure_not_expired(&pending)?;
+
+			pending.awaiting_approval = false;
+			pending.approved_by = Some(approver.clone());
+
+			Self::deposit_event(Event::TransferApprove {
+				identifier: pending.asset_id.clone(),
+				instance: instance_id.clone(),
+				approver,
+			});
+
+			Self::confirm_transfer(instance_id, pending)
+		}
+
+		/// Rejects a pending transfer of an asset instance.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the new owner or an approver
+		///   of the asset.
+		/// - `instance_id`: The identifier of the asset instance.
+		///
+		/// # Errors
+		/// - `PendingTransferNotFound`: If the instance has no pending transfer.
+		/// - `UnauthorizedOperation`: If the caller is neither the new owner nor an approver.
+		///
+		/// # Events
+		/// - `Event::TransferReject`: Emitted when the transfer is rejected.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_transfer())]
+		pub fn reject_transfer(
+			origin: OriginFor<T>,
+			instance_id: AssetInstanceIdOf,
+		) -> DispatchResult {
+			let who = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let pending = <PendingTransfers<T>>::get(&instance_id)
+				.ok_or(Error::<T>::PendingTransferNotFound)?;
+
+			ensure!(
+				pending.new_asset_owner == who || pending.approvers.contains(&who),
+				Error::<T>::UnauthorizedOperation
+			);
+
+			<PendingTransfers<T>>::remove(&instance_id);
+
+			Self::deposit_event(Event::TransferReject {
+				identifier: pending.asset_id,
+				instance: instance_id,
+				by: who,
+			});
+
+			Ok(())
+		}
+
+		/// Cancels a pending transfer of an asset instance.
+		///
+		/// The owner can cancel a pending transfer at any time. Once it has
+		/// expired, anyone can clear it.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call.
+		/// - `instance_id`: The identifier of the asset instance.
+		///
+		/// # Errors
+		/// - `PendingTransferNotFound`: If the instance has no pending transfer.
+		/// - `UnauthorizedOperation`: If the caller is not the owner and the transfer has not
+		///   expired.
+		///
+		/// # Events
+		/// - `Event::TransferCancel`: Emitted when the transfer is cancelled.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_transfer())]
+		pub fn cancel_transfer(
+			origin: OriginFor<T>,
+			instance_id: AssetInstanceIdOf,
+		) -> DispatchResult {
+			let who = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let pending = <PendingTransfers<T>>::get(&instance_id)
+				.ok_or(Error::<T>::PendingTransferNotFound)?;
+
+			ensure!(
+				pending.asset_owner == who || Self::ensure_not_expired(&pending).is_err(),
+				Error::<T>::UnauthorizedOperation
+			);
+
+			<PendingTransfers<T>>::remove(&instance_id);
+
+			Self::deposit_event(Event::TransferCancel {
+				identifier: pending.asset_id,
+				instance: instance_id,
+			});
+
+			Ok(())
+		}
+		/// Creates a new asset entry within a specified space under an asset
+		/// class, with a scheduled expiry, or both.
+		///
+		/// This behaves like `create`, and additionally binds the asset to
+		/// `asset_class` and schedules it to mature or expire at `asset_expiry`.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the creator.
+		/// - `entry`: The details of the asset being created, including quantity, value, and type.
+		/// - `digest`: The hash of the entry data.
+		/// - `authorization`: The authorization ID used to validate the creation.
+		/// - `asset_class`: An optional asset class the asset is created under.
+		/// - `asset_expiry`: An optional block at which the asset matures or expires.
+		///
+		/// # Errors
+		/// - The errors of `create`.
+		/// - `InvalidAssetExpiry`: If the asset expiry is not in the future.
+		/// - `AssetClassNotFound`, `AssetClassNotActive`, `AssetClassSpaceMismatch`,
+		///   `SchemaNotFound`, `SchemaDeprecated`: If the asset class cannot be used in the space.
+		/// - `TooManyAssetExpiriesInBlock`: If too many assets expire at the asset expiry.
+		///
+		/// # Events
+		/// - `Event::Create`: Emitted when an asset is successfully created.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create())]
+		pub fn create_with_options(
+			origin: OriginFor<T>,
+			entry: AssetInputEntryOf<T>,
+			digest: EntryHashOf<T>,
+			authorization: AuthorizationIdOf,
+			asset_class: Option<AssetClassIdOf>,
+			asset_expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			Self::do_create(creator, entry, digest, authorization, asset_class, asset_expiry)
+		}
+
+		/// Creates a new VC (Verifiable Credential) asset under an asset class,
+		/// with a scheduled expiry, or both.
+		///
+		/// This behaves like `vc_create`, and additionally binds the asset to
+		/// `asset_class` and schedules it to expire at `asset_expiry`.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the creator.
+		/// - `asset_qty`: The quantity of the asset to be created.
+		/// - `digest`: The hash of the entry data.
+		/// - `authorization`: The authorization ID used to validate the creation.
+		/// - `asset_class`: An optional asset class the asset is created under.
+		/// - `asset_expiry`: An optional block at which the asset expires.
+		///
+		/// # Errors
+		/// - The errors of `vc_create`.
+		/// - `InvalidAssetExpiry`: If the asset expiry is not in the future.
+		/// - `AssetClassNotFound`, `AssetClassNotActive`, `AssetClassSpaceMismatch`,
+		///   `SchemaNotFound`, `SchemaDeprecated`: If the asset class cannot be used in the space.
+		/// - `TooManyAssetExpiriesInBlock`: If too many assets expire at the asset expiry.
+		///
+		/// # Events
+		/// - `Event::Create`: Emitted when a VC asset is successfully created.
+		// TODO: Set actual weights
+		#[pallet::call_index(18)]
+		#[pallet::weight({0})]
+		pub fn vc_create_with_options(
+			origin: OriginFor<T>,
+			asset_qty: AssetQtyOf,
+			digest: EntryHashOf<T>,
+			authorization: AuthorizationIdOf,
+			asset_class: Option<AssetClassIdOf>,
+			asset_expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			Self::do_vc_create(creator, asset_qty, digest, authorization, asset_class, asset_expiry)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Creates an asset for `creator`, optionally under an asset class and
+	/// with a scheduled expiry. Shared by `create` and `create_with_options`.
+	fn do_create(
+		creator: AssetCreatorOf<T>,
+		entry: AssetInputEntryOf<T>,
+		digest: EntryHashOf<T>,
+		authorization: AuthorizationIdOf,
+		asset_class: Option<AssetClassIdOf>,
+		asset_expiry: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_create_origin(
+			&authorization,
+			&creator.clone(),
+			1,
+		)
+		.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+		ensure!(entry.asset_qty > 0 && entry.asset_value > 0, Error::<T>::InvalidAssetValue);
+		ensure!(entry.asset_type.is_valid_asset_type(), Error::<T>::InvalidAssetType);
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		if let Some(expiry) = asset_expiry {
+			ensure!(expiry > block_number, Error::<T>::InvalidAssetExpiry);
+		}
+
+		if let Some(ref class_id) = asset_class {
+			Self::ensure_valid_class(class_id, &space_id)?;
+		}
+
+		// Id Digest = concat (H(<scale_encoded_entry_digest>,
+		// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier =
+			Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Asset)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<Assets<T>>::contains_key(&identifier), Error::<T>::AssetIdAlreadyExists);
+
+		if let Some(expiry) = asset_expiry {
+			Self::schedule_asset_expiry(&identifier, expiry)?;
+		}
+
+		if let Some(class_id) = asset_class {
+			Self::bind_asset_class(&identifier, class_id);
+		}
+
+		<AssetLookup<T>>::insert(digest, &identifier);
+
+		<Assets<T>>::insert(
+			&identifier,
+			AssetEntryOf::<T> {
+				asset_detail: entry,
+				asset_issuance: Zero::zero(),
+				asset_status: AssetStatusOf::ACTIVE,
+				asset_issuer: creator.clone(),
+				created_at: block_number,
+			},
+		);
+
+		pallet_chain_space::Pallet::<T>::record_delegated_creates(
+			&authorization,
+			&space_id,
+			&creator,
+			1,
+		)
+		.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+		Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+		Self::deposit_event(Event::Create { identifier, issuer: creator });
+
+		Ok(())
+	}
+
+	/// Creates a VC asset for `creator`, optionally under an asset class and
+	/// with a scheduled expiry. Shared by `vc_create` and
+	/// `vc_create_with_options`.
+	fn do_vc_create(
+		creator: AssetCreatorOf<T>,
+		asset_qty: AssetQtyOf,
+		digest: EntryHashOf<T>,
+		authorization: AuthorizationIdOf,
+		asset_class: Option<AssetClassIdOf>,
+		asset_expiry: Option<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_create_origin(
+			&authorization,
+			&creator.clone(),
+			1,
+		)
+		.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+		ensure!(asset_qty > 0, Error::<T>::InvalidAssetQty);
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		if let Some(expiry) = asset_expiry {
+			ensure!(expiry > block_number, Error::<T>::InvalidAssetExpiry);
+		}
+
+		if let Some(ref class_id) = asset_class {
+			Self::ensure_valid_class(class_id, &space_id)?;
+		}
+
+		// Id Digest = concat (H(<scale_encoded_entry_digest>,
+		// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier =
+			Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Asset)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<VCAssets<T>>::contains_key(&identifier), Error::<T>::AssetIdAlreadyExists);
+
+		if let Some(expiry) = asset_expiry {
+			Self::schedule_asset_expiry(&identifier, expiry)?;
+		}
+
+		if let Some(class_id) = asset_class {
+			Self::bind_asset_class(&identifier, class_id);
+		}
+
+		<AssetLookup<T>>::insert(digest, &identifier);
+
+		<VCAssets<T>>::insert(
+			&identifier,
+			VCAssetEntryOf::<T> {
+				asset_qty,
+				digest,
+				asset_issuance: Zero::zero(),
+				asset_status: AssetStatusOf::ACTIVE,
+				asset_issuer: creator.clone(),
+				created_at: block_number,
+			},
+		);
+
+		pallet_chain_space::Pallet::<T>::record_delegated_creates(
+			&authorization,
+			&space_id,
+			&creator,
+			1,
+		)
+		.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+		Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+		Self::deposit_event(Event::Create { identifier, issuer: creator });
+
+		Ok(())
+	}
+
+	pub fn get_distributed_qty(asset_id: &AssetIdOf) -> u32 {
+		<Distribution<T>>::get(asset_id)
+			.map(|bounded_vec| bounded_vec.len() as u32)
+			.unwrap_or(0)
+	}
+
+	/// Returns the instances an asset instance was split off from, starting
+	/// with the one it was split off from directly and ending with the
+	/// instance issued from the asset.
+	pub fn instance_lineage(
+		asset_id: &AssetIdOf,
+		instance_id: &AssetInstanceIdOf,
+	) -> Vec<AssetInstanceIdOf> {
+		let mut lineage = Vec::new();
+		let mut current = instance_id.clone();
+		while let Some(parent) = <Lineage<T>>::get(asset_id, &current).split_from {
+			lineage.push(parent.clone());
+			current = parent;
+		}
+		lineage
+	}
+
+	/// Moves an instance to `to` in the owner index and appends the
+	/// ownership change to the transfer history of the instance, pruning the
+	/// oldest change once the history is full. A `from` of `None` records the
+	/// issuance of the instance.
+	fn record_ownership(
+		asset_id: &AssetIdOf,
+		instance_id: &AssetInstanceIdOf,
+		from: Option<&AssetCreatorOf<T>>,
+		to: &AssetCreatorOf<T>,
+		qty: AssetQtyOf,
+		digest: Option<EntryHashOf<T>>,
+	) {
+		if let Some(from) = from {
+			<OwnerInstances<T>>::remove(from, instance_id);
+		}
+		<OwnerInstances<T>>::insert(to, instance_id, asset_id);
+
+		let seq = <InstanceTransferCount<T>>::get(instance_id);
+		<InstanceTransfers<T>>::insert(
+			instance_id,
+			seq,
+			AssetTransferRecordOf::<T> {
+				from: from.cloned(),
+				to: to.clone(),
+				qty,
+				digest,
+				location: Self::timepoint(),
+			},
+		);
+		if let Some(pruned) = seq.checked_sub(T::MaxInstanceTransfers::get()) {
+			<InstanceTransfers<T>>::remove(instance_id, pruned);
+		}
+		<InstanceTransferCount<T>>::insert(instance_id, seq.saturating_add(1));
+	}
+
+	/// Records a transfer that has to be accepted or approved as required by
+	/// the transfer policy of the asset. The approvers of the policy are kept
+	/// with the transfer, so later policy changes do not affect it.
+	fn request_transfer(
+		entry: AssetTransferEntryOf<T>,
+		owner: AssetCreatorOf<T>,
+		digest: EntryHashOf<T>,
+		is_vc: bool,
+		policy: AssetTransferPolicyOf<T>,
+	) -> DispatchResult {
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let expires_at = block_number.saturating_add(T::PendingTransferTimeout::get());
+
+		<PendingTransfers<T>>::insert(
+			&entry.asset_instance_id,
+			AssetPendingTransferOf::<T> {
+				asset_id: entry.asset_id.clone(),
+				asset_owner: owner.clone(),
+				new_asset_owner: entry.new_asset_owner.clone(),
+				digest,
+				is_vc,
+				awaiting_acceptance: policy.require_acceptance,
+				awaiting_approval: !policy.approvers.is_empty(),
+				approvers: policy.approvers,
+				approved_by: None,
+				requested_at: block_number,
+				expires_at,
+			},
+		);
+
+		Self::update_activity(&entry.asset_instance_id, CallTypeOf::TransferRequest)
+			.map_err(<Error<T>>::from)?;
+		Self::deposit_event(Event::TransferRequest {
+			identifier: entry.asset_id,
+			instance: entry.asset_instance_id,
+			from: owner,
+			to: entry.new_asset_owner,
+			digest,
+			expires_at,
+		});
+
+		Ok(())
+	}
+
+	/// Stores a pending transfer, or settles it once it awaits neither
+	/// acceptance nor approval.
+	fn confirm_transfer(
+		instance_id: AssetInstanceIdOf,
+		pending: AssetPendingTransferOf<T>,
+	) -> DispatchResult {
+		if pending.awaiting_acceptance || pending.awaiting_approval {
+			<PendingTransfers<T>>::insert(&instance_id, pending);
+			return Ok(());
+		}
+
+		<PendingTransfers<T>>::remove(&instance_id);
+		Self::settle_transfer(instance_id, pending)
+	}
+
+	/// Moves an instance to the new owner of a confirmed transfer. The asset
+	/// and the instance are checked again as they may have changed while the
+	/// transfer was pending.
+	fn settle_transfer(
+		instance_id: AssetInstanceIdOf,
+		pending: AssetPendingTransferOf<T>,
+	) -> DispatchResult {
+		let block_number = frame_system::Pallet::<T>::block_number();
+
+		let qty = if pending.is_vc {
+			let asset = <VCAssets<T>>::get(&pending.asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let instance = <VCIssuance<T>>::get(&pending.asset_id, &instance_id)
+				.ok_or(Error::<T>::AssetInstanceNotFound)?;
+
+			ensure!(
+				instance.asset_instance_owner == pending.asset_owner,
+				Error::<T>::UnauthorizedOperation
+			);
+			ensure!(AssetStatusOf::ACTIVE == asset.asset_status, Error::<T>::AssetNotActive);
+			ensure!(
+				AssetStatusOf::ACTIVE == instance.asset_instance_status,
+				Error::<T>::InstanceNotActive
+			);
+
+			let qty = instance.asset_qty;
+			<VCIssuance<T>>::insert(
+				&pending.asset_id,
+				&instance_id,
+				VCAssetDistributionEntryOf::<T> {
+					asset_instance_owner: pending.new_asset_owner.clone(),
+					digest: pending.digest,
+					created_at: block_number,
+					..instance
+				},
+			);
+			qty
+		} else {
+			let asset = <Assets<T>>::get(&pending.asset_id).ok_or(Error::<T>::AssetIdNotFound)?;
+			let instance = <Issuance<T>>::get(&pending.asset_id, &instance_id)
+				.ok_or(Error::<T>::AssetInstanceNotFound)?;
+
+			ensure!(
+				instance.asset_instance_owner == pending.asset_owner,
+				Error::<T>::UnauthorizedOperation
+			);
+			ensure!(AssetStatusOf::ACTIVE == asset.asset_status, Error::<T>::AssetNotActive);
+			ensure!(
+				AssetStatusOf::ACTIVE == instance.asset_instance_status,
+				Error::<T>::InstanceNotActive
+			);
+
+			let qty = instance.asset_instance_detail.asset_qty;
+			<Issuance<T>>::insert(
+				&pending.asset_id,
+				&instance_id,
+				AssetDistributionEntryOf::<T> {
+					asset_instance_owner: pending.new_asset_owner.clone(),
+					created_at: block_number,
+					..instance
+				},
+			);
+			qty
+		};
+
+		Self::record_ownership(
+			&pending.asset_id,
+			&instance_id,
+			Some(&pending.asset_owner),
+			&pending.new_asset_owner,
+			qty,
+			Some(pending.digest),
+		);
+
+		Self::update_activity(&instance_id, CallTypeOf::Transfer).map_err(<Error<T>>::from)?;
+		Self::deposit_event(Event::Transfer {
+			identifier: pending.asset_id,
+			instance: instance_id,
+			from: pending.asset_owner,
+			to: pending.new_asset_owner,
+		});
+
+		Ok(())
+	}
+
+	/// Ensures a pending transfer can still be completed.
+	fn ensure_not_expired(pending: &AssetPendingTransferOf<T>) -> Result<(), Error<T>> {
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= pending.expires_at,
+			Error::<T>::PendingTransferExpired
+		);
+		Ok(())
+	}
+
+	/// Ensures an instance has no pending transfer that can still be
+	/// completed. An expired pending transfer no longer holds the instance and
+	/// is cleared.
+	fn ensure_no_pending_transfer(
+		asset_id: &AssetIdOf,
+		instance_id: &AssetInstanceIdOf,
+	) -> Result<(), Error<T>> {
+		let Some(pending) = <PendingTransfers<T>>::get(instance_id) else {
+			return Ok(());
+		};
+		ensure!(Self::ensure_not_expired(&pending).is_err(), Error::<T>::TransferPending);
+
+		<PendingTransfers<T>>::remove(instance_id);
+		Self::deposit_event(Event::TransferCancel {
+			identifier: asset_id.clone(),
+			instance: instance_id.clone(),
+		});
+
+		Ok(())
+	}
+
+	/// Returns the pending transfer of an asset (or vc asset) instance,
+	/// unless it has expired.
+	pub fn pending_transfer(instance_id: &AssetInstanceIdOf) -> Option<AssetPendingTransferOf<T>> {
+		<PendingTransfers<T>>::get(instance_id)
+			.filter(|pending| Self::ensure_not_expired(pending).is_ok())
+	}
+
+	/// Returns the holding details of an asset (or vc asset) instance.
+	pub fn holding(
+		asset_id: &AssetIdOf,
+		instance_id: &AssetInstanceIdOf,
+	) -> Option<AssetHoldingOf<T>> {
+		if let Some(instance) = <Issuance<T>>::get(asset_id, instance_id) {
+			return Some(AssetHoldingOf::<T> {
+				asset_id: asset_id.clone(),
+				asset_instance_id: instance_id.clone(),
+				asset_owner: instance.asset_instance_owner,
+				asset_qty: instance.asset_instance_detail.asset_qty,
+				asset_instance_status: instance.asset_instance_status,
+				is_vc: false,
+			});
+		}
+		<VCIssuance<T>>::get(asset_id, instance_id).map(|instance| AssetHoldingOf::<T> {
+			asset_id: asset_id.clone(),
+			asset_instance_id: instance_id.clone(),
+			asset_owner: instance.asset_instance_owner,
+			asset_qty: instance.asset_qty,
+			asset_instance_status: instance.asset_instance_status,
+			is_vc: true,
+		})
+	}
+
+	/// Returns the asset (and vc asset) instances held by `owner`.
+	pub fn holdings(owner: &AssetCreatorOf<T>) -> Vec<AssetHoldingOf<T>> {
+		<OwnerInstances<T>>::iter_prefix(owner)
+			.filter_map(|(instance_id, asset_id)| Self::holding(&asset_id, &instance_id))
+			.collect()
+	}
+
+	/// Returns the instances issued from an asset (or vc asset).
+	pub fn asset_instances(asset_id: &AssetIdOf) -> Vec<AssetHoldingOf<T>> {
+		<Distribution<T>>::get(asset_id)
+			.unwrap_or_default()
+			.iter()
+			.filter_map(|instance_id| Self::holding(asset_id, instance_id))
+			.collect()
+	}
+
+	/// Returns the last `MaxInstanceTransfers` ownership changes of an
+	/// instance, oldest first.
+	pub fn instance_history(instance_id: &AssetInstanceIdOf) -> Vec<AssetTransferRecordOf<T>> {
+		let count = <InstanceTransferCount<T>>::get(instance_id);
+		(count.saturating_sub(T::MaxInstanceTransfers::get())..count)
+			.filter_map(|seq| <InstanceTransfers<T>>::get(instance_id, seq))
+			.collect()
+	}
+
+	/// Returns the identifiers of the assets (and vc assets) created under
+	/// an asset class.
+	pub fn assets_by_class(class_id: &AssetClassIdOf) -> Vec<AssetIdOf> {
+		<ClassAssets<T>>::iter_key_prefix(class_id).collect()
+	}
+
+	/// Ensures a schema exists, has not been deprecated and, if it is scoped
+	/// to a space, belongs to `space_id`.
+	fn ensure_valid_schema(schema_id: &SchemaIdOf, space_id: &SpaceIdOf) -> Result<(), Error<T>> {
+		let schema = T::SchemaProvider::schema_info(schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+		ensure!(!schema.deprecated, Error::<T>::SchemaDeprecated);
+		ensure!(
+			schema.space.as_ref().map_or(true, |space| space == space_id),
+			Error::<T>::SchemaSpaceMismatch
+		);
+		Ok(())
+	}
+
+	/// Ensures assets can be created under an asset class in `space_id`.
+	fn ensure_valid_class(class_id: &AssetClassIdOf, space_id: &SpaceIdOf) -> Result<(), Error<T>> {
+		let class = <AssetClasses<T>>::get(class_id).ok_or(Error::<T>::AssetClassNotFound)?;
+		ensure!(AssetStatusOf::ACTIVE == class.class_status, Error::<T>::AssetClassNotActive);
+		ensure!(&class.class_space == space_id, Error::<T>::AssetClassSpaceMismatch);
+		Self::ensure_valid_schema(&class.class_schema, space_id)
+	}
+
+	/// Records an asset as created under an asset class.
+	fn bind_asset_class(asset_id: &AssetIdOf, class_id: AssetClassIdOf) {
+		<ClassAssets<T>>::insert(&class_id, asset_id, ());
+		<AssetClassOf<T>>::insert(asset_id, class_id);
+	}
+
+	/// Schedules an asset (or vc asset) to expire at block `expires_at`.
+	fn schedule_asset_expiry(
+		asset_id: &AssetIdOf,
+		expires_at: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		schedule::schedule::<NextAssetExpirySweep<T>, AssetExpiries<T>, _, _, _>(
+			expires_at,
+			asset_id.clone(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+		.map_err(|_| Error::<T>::TooManyAssetExpiriesInBlock)?;
+
+		<AssetExpiry<T>>::insert(asset_id, expires_at);
+
+		Ok(())
+	}
+
+	/// Expires the assets scheduled to expire up to and including block
+	/// `now`, as far as `remaining_weight` allows.
+	pub(crate) fn sweep_asset_expiries(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		schedule::sweep::<NextAssetExpirySweep<T>, AssetExpiries<T>, _, _, _>(
+			now,
+			remaining_weight,
+			db_weight,
+			db_weight.reads_writes(4, 2),
+			|asset_id, at| Self::expire_asset(&asset_id, at),
+		)
+	}
+
+	/// Marks an asset (or vc asset) as expired if it is still due to expire at
+	/// block `at`.
+	fn expire_asset(asset_id: &AssetIdOf, at: BlockNumberFor<T>) {
+		if <AssetExpiry<T>>::get(asset_id) != Some(at) {
+			return;
+		}
+
+		let mark_expired = |status: &mut AssetStatusOf| {
+			let expired = *status != AssetStatusOf::EXPIRED;
+			*status = AssetStatusOf::EXPIRED;
+			expired
+		};
+		let expired = <Assets<T>>::mutate(asset_id, |asset| {
+			asset.as_mut().is_some_and(|asset| mark_expired(&mut asset.asset_status))
+		}) || <VCAssets<T>>::mutate(asset_id, |asset| {
+			asset.as_mut().is_some_and(|asset| mark_expired(&mut asset.asset_status))
+		});
+		if !expired {
+			return;
+		}
+
+		let _ = Self::update_activity(asset_id, CallTypeOf::Expire);
+		Self::deposit_event(Event::Expire { identifier: asset_id.clone(), expired_at: at });
 	}
 
 	pub fn update_activity(tx_id: &AssetIdOf, tx_action: CallTypeOf) -> Result<(), Error<T>> {
@@ -0,0 +1,77 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) 2019-2022 Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the asset pallet.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+use sp_std::marker::PhantomData;
+
+pub mod v1 {
+	use super::*;
+
+	/// Adds the asset (and vc asset) instances issued before version 1 to
+	/// the owner index. Their transfer history was not recorded, so it
+	/// starts with the first ownership change after the upgrade.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut indexed = 0u64;
+			for (asset_id, instance_id, instance) in <Issuance<T>>::iter() {
+				<OwnerInstances<T>>::insert(&instance.asset_instance_owner, &instance_id, asset_id);
+				indexed.saturating_inc();
+			}
+			for (asset_id, instance_id, instance) in <VCIssuance<T>>::iter() {
+				<OwnerInstances<T>>::insert(&instance.asset_instance_owner, &instance_id, asset_id);
+				indexed.saturating_inc();
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(indexed.saturating_add(1), indexed.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let instances = <Issuance<T>>::iter_keys().count() as u64 +
+				<VCIssuance<T>>::iter_keys().count() as u64;
+			Ok(instances.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let instances = u64::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("failed to decode the instance count"))?;
+			ensure!(
+				<OwnerInstances<T>>::iter_keys().count() as u64 == instances,
+				"owner index does not match the issued instances"
+			);
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "storage version not updated");
+			Ok(())
+		}
+	}
+}
@@ -32,11 +32,14 @@ type Signature = MultiSignature;
 type AccountPublic = <Signature as Verify>::Signer;
 pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
+pub(crate) type Balance = u128;
 
 frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
+		Balances: pallet_balances,
 		Space: pallet_chain_space,
+		Schema: pallet_schema,
 		Asset: pallet_asset,
 		Identifier: identifier,
 		MockOrigin: mock_origin,
@@ -55,6 +58,18 @@ impl frame_system::Config for Test {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type SS58Prefix = SS58Prefix;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
 }
 
 impl mock_origin::Config for Test {
@@ -66,6 +81,10 @@ impl mock_origin::Config for Test {
 parameter_types! {
 	pub const MaxEncodedValueLength: u32 = 1_024;
 	pub const MaxAssetDistribution: u32 = 25;
+	pub const MaxAssetExpiriesPerBlock: u32 = 10;
+	pub const MaxAssetApprovers: u32 = 5;
+	pub const PendingTransferTimeout: u64 = 10;
+	pub const MaxInstanceTransfers: u32 = 2;
 }
 
 impl Config for Test {
@@ -74,9 +93,29 @@ impl Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type MaxEncodedValueLength = MaxEncodedValueLength;
 	type MaxAssetDistribution = MaxAssetDistribution;
+	type MaxAssetExpiriesPerBlock = MaxAssetExpiriesPerBlock;
+	type MaxAssetApprovers = MaxAssetApprovers;
+	type PendingTransferTimeout = PendingTransferTimeout;
+	type MaxInstanceTransfers = MaxInstanceTransfers;
+	type SchemaProvider = Schema;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub const ValidateSchemaContent: bool = false;
+}
+
+impl pallet_schema::Config for Test {
+	type SchemaCreatorId = SubjectId;
+	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
+	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type ValidateSchemaContent = ValidateSchemaContent;
+}
+
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
@@ -89,6 +128,12 @@ impl IsPermissioned for NetworkPermission {
 	}
 }
 
+parameter_types! {
+	pub const MaxSpaceDelegationExpiriesPerBlock: u32 = 2;
+	pub const CapacityPrice: Balance = 2;
+	pub const MaxSpaceDepth: u32 = 3;
+}
+
 impl pallet_chain_space::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
@@ -97,15 +142,25 @@ impl pallet_chain_space::Config for Test {
 	type MaxSpaceDelegates = MaxSpaceDelegates;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type MaxDelegationExpiriesPerBlock = MaxSpaceDelegationExpiriesPerBlock;
+	type MaxSpaceDepth = MaxSpaceDepth;
+	type Currency = Balances;
+	type CapacityPrice = CapacityPrice;
+	type CapacityPayment = ();
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MaxEventsHistory: u32 = 6u32;
+	pub const TimelineOverflow: identifier::TimelineOverflowMode =
+		identifier::TimelineOverflowMode::Drop;
+	pub const MaxArchivedEventsPerPage: u32 = 10u32;
 }
 
 impl identifier::Config for Test {
 	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
 }
 
 parameter_types! {
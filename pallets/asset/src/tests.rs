@@ -2,7 +2,12 @@ use super::*;
 use crate::{mock::*, types::AssetIssuanceEntry, Error};
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_support::{
+	assert_err, assert_ok,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use pallet_chain_space::{SpaceCodeOf, SpaceIdOf};
 use sp_runtime::{traits::Hash, AccountId32};
@@ -30,10 +35,19 @@ pub fn generate_asset_instance_id<T: Config>(digest: &SpaceCodeOf<T>) -> AssetIn
 		.unwrap()
 }
 
+/// Generates a schema ID from a digest.
+pub fn generate_schema_id<T: Config>(digest: &SpaceCodeOf<T>) -> SchemaIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::Schema).unwrap()
+}
+
 pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
 pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([2u8; 32]));
+pub(crate) const DID_02: SubjectId = SubjectId(AccountId32::new([3u8; 32]));
+pub(crate) const DID_03: SubjectId = SubjectId(AccountId32::new([4u8; 32]));
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
+pub(crate) const ACCOUNT_02: AccountId = AccountId::new([3u8; 32]);
+pub(crate) const ACCOUNT_03: AccountId = AccountId::new([4u8; 32]);
 
 #[test]
 fn asset_create_should_succeed() {
@@ -3161,3 +3175,1439 @@ fn asset_issue_should_fail_when_distribution_limit_exceeds() {
 		);
 	});
 }
+
+#[test]
+fn asset_split_and_merge_should_conserve_quantity() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let asset_desc = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+	let asset_tag = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+	let asset_meta = BoundedVec::try_from([72u8; 10].to_vec()).unwrap();
+	let asset_qty = 10;
+	let asset_value = 10;
+	let asset_type = AssetTypeOf::MF;
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc,
+		asset_qty,
+		asset_type,
+		asset_value,
+		asset_tag,
+		asset_meta,
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let issue_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&issue_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	let instance_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&creator.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let instance_id = generate_asset_instance_id::<Test>(&instance_id_digest);
+
+	let split_entry = AssetSplitEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: creator.clone(),
+		new_asset_owner: new_owner.clone(),
+		split_qty: 4,
+	};
+
+	let split_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&split_entry.encode()[..]].concat()[..]);
+
+	let split_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&instance_id.encode()[..],
+			&new_owner.encode()[..],
+			&creator.encode()[..],
+			&split_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let split_instance_id = generate_asset_instance_id::<Test>(&split_id_digest);
+
+	let resplit_entry = AssetSplitEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: split_instance_id.clone(),
+		asset_owner: new_owner.clone(),
+		new_asset_owner: new_owner.clone(),
+		split_qty: 1,
+	};
+
+	let resplit_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&resplit_entry.encode()[..]].concat()[..]);
+
+	let resplit_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&split_instance_id.encode()[..],
+			&new_owner.encode()[..],
+			&new_owner.encode()[..],
+			&resplit_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let resplit_instance_id = generate_asset_instance_id::<Test>(&resplit_id_digest);
+
+	let merge_entry = AssetMergeEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: split_instance_id.clone(),
+		merged_instance_id: resplit_instance_id.clone(),
+		asset_owner: new_owner.clone(),
+	};
+
+	let instance_qty = |instance_id: &AssetInstanceIdOf| {
+		Issuance::<Test>::get(&asset_id, instance_id).map(|i| i.asset_instance_detail.asset_qty)
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::issue(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			issue_entry.clone(),
+			issue_entry_digest,
+			authorization_id
+		));
+
+		/* The whole quantity cannot be split off */
+		assert_err!(
+			Asset::split(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				AssetSplitEntryOf::<Test> { split_qty: 10, ..split_entry.clone() },
+				split_entry_digest,
+			),
+			Error::<Test>::InvalidAssetQty
+		);
+
+		assert_ok!(Asset::split(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			split_entry.clone(),
+			split_entry_digest,
+		));
+		System::assert_last_event(
+			Event::Split {
+				identifier: asset_id.clone(),
+				instance: instance_id.clone(),
+				new_instance: split_instance_id.clone(),
+				qty: 4,
+				from: creator.clone(),
+				to: new_owner.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(instance_qty(&instance_id), Some(6));
+		assert_eq!(instance_qty(&split_instance_id), Some(4));
+		assert_eq!(
+			Issuance::<Test>::get(&asset_id, &split_instance_id).map(|i| i.asset_instance_owner),
+			Some(new_owner.clone())
+		);
+
+		assert_ok!(Asset::split(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			resplit_entry,
+			resplit_entry_digest,
+		));
+		assert_eq!(Asset::get_distributed_qty(&asset_id), 3);
+		assert_eq!(
+			Asset::instance_lineage(&asset_id, &resplit_instance_id),
+			vec![split_instance_id.clone(), instance_id.clone()]
+		);
+
+		/* Only instances of the same owner can be merged */
+		assert_err!(
+			Asset::merge(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				AssetMergeEntryOf::<Test> {
+					asset_instance_id: instance_id.clone(),
+					asset_owner: creator.clone(),
+					..merge_entry.clone()
+				},
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_err!(
+			Asset::merge(
+				DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+				AssetMergeEntryOf::<Test> {
+					merged_instance_id: split_instance_id.clone(),
+					..merge_entry.clone()
+				},
+			),
+			Error::<Test>::InvalidInstanceMerge
+		);
+
+		assert_ok!(Asset::merge(DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(), merge_entry));
+		assert_eq!(instance_qty(&split_instance_id), Some(4));
+		assert_eq!(instance_qty(&resplit_instance_id), None);
+		assert_eq!(Asset::get_distributed_qty(&asset_id), 2);
+		assert_eq!(
+			Lineage::<Test>::get(&asset_id, &resplit_instance_id).merged_into,
+			Some(split_instance_id.clone())
+		);
+
+		let issued: u64 = Issuance::<Test>::iter_prefix_values(&asset_id)
+			.map(|i| i.asset_instance_detail.asset_qty)
+			.sum();
+		assert_eq!(issued, Assets::<Test>::get(&asset_id).unwrap().asset_issuance);
+	});
+}
+
+#[test]
+fn asset_should_expire_at_scheduled_block() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&id_digest);
+
+	let vc_digest = <Test as frame_system::Config>::Hashing::hash(&[5u8; 32]);
+	let vc_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&vc_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let vc_asset_id: Ss58Identifier = generate_asset_id::<Test>(&vc_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		/* Expiry must be in the future */
+		assert_err!(
+			Asset::create_with_options(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				entry.clone(),
+				digest,
+				authorization_id.clone(),
+				None,
+				Some(1)
+			),
+			Error::<Test>::InvalidAssetExpiry
+		);
+
+		assert_ok!(Asset::create_with_options(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone(),
+			None,
+			Some(5)
+		));
+		assert_ok!(Asset::vc_create_with_options(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			10,
+			vc_digest,
+			authorization_id.clone(),
+			None,
+			Some(5)
+		));
+		assert_eq!(AssetExpiry::<Test>::get(&asset_id), Some(5));
+		assert_eq!(
+			AssetExpiries::<Test>::get(5).to_vec(),
+			vec![asset_id.clone(), vc_asset_id.clone()]
+		);
+
+		/* Nothing expires before the scheduled block */
+		System::set_block_number(4);
+		Asset::on_idle(4, Weight::MAX);
+		assert_eq!(Assets::<Test>::get(&asset_id).unwrap().asset_status, AssetStatusOf::ACTIVE);
+
+		System::set_block_number(5);
+		Asset::on_idle(5, Weight::MAX);
+		assert_eq!(Assets::<Test>::get(&asset_id).unwrap().asset_status, AssetStatusOf::EXPIRED);
+		assert_eq!(
+			VCAssets::<Test>::get(&vc_asset_id).unwrap().asset_status,
+			AssetStatusOf::EXPIRED
+		);
+		assert!(!AssetExpiries::<Test>::contains_key(5));
+		assert_eq!(NextAssetExpirySweep::<Test>::get(), Some(6));
+		System::assert_last_event(
+			Event::Expire { identifier: vc_asset_id.clone(), expired_at: 5 }.into(),
+		);
+
+		assert_err!(
+			Asset::issue(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				issue_entry,
+				issue_entry_digest,
+				authorization_id
+			),
+			Error::<Test>::AssetNotActive
+		);
+	});
+}
+
+#[test]
+fn asset_class_should_be_validated_and_indexed() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let tx_schema: pallet_schema::InputSchemaOf<Test> =
+		BoundedVec::try_from([4u8; 200].to_vec()).unwrap();
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id = generate_schema_id::<Test>(&schema_id_digest);
+
+	let class_name: AssetClassNameOf<Test> = BoundedVec::try_from(b"land-title".to_vec()).unwrap();
+	let class_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&class_name.encode()[..],
+			&schema_id.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+		]
+		.concat()[..],
+	);
+	let class_id = generate_asset_id::<Test>(&class_id_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::ART,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+	let other_entry = AssetInputEntryOf::<Test> { asset_value: 20, ..entry.clone() };
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+	let other_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&other_entry.encode()[..]].concat()[..]);
+	let vc_digest = <Test as frame_system::Config>::Hashing::hash(&[5u8; 32]);
+
+	let asset_id = generate_asset_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	));
+	let vc_asset_id = generate_asset_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+		&[&vc_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	));
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			tx_schema,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::class_create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			class_name.clone(),
+			schema_id.clone(),
+			authorization_id.clone()
+		));
+		System::assert_last_event(
+			Event::ClassCreate {
+				identifier: class_id.clone(),
+				schema: schema_id.clone(),
+				issuer: creator.clone(),
+			}
+			.into(),
+		);
+		assert_err!(
+			Asset::class_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				class_name,
+				schema_id,
+				authorization_id.clone()
+			),
+			Error::<Test>::AssetClassAlreadyExists
+		);
+
+		assert_ok!(Asset::create_with_options(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone(),
+			Some(class_id.clone()),
+			None
+		));
+		assert_ok!(Asset::vc_create_with_options(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			10,
+			vc_digest,
+			authorization_id.clone(),
+			Some(class_id.clone()),
+			None
+		));
+
+		let mut class_assets = Asset::assets_by_class(&class_id);
+		class_assets.sort();
+		let mut expected = vec![asset_id.clone(), vc_asset_id];
+		expected.sort();
+		assert_eq!(class_assets, expected);
+		assert_eq!(AssetClassOf::<Test>::get(&asset_id), Some(class_id.clone()));
+
+		/* Only the class issuer can change its status */
+		assert_err!(
+			Asset::class_status_change(
+				DoubleOrigin(author.clone(), DID_01).into(),
+				class_id.clone(),
+				AssetStatusOf::INACTIVE
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_ok!(Asset::class_status_change(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			class_id.clone(),
+			AssetStatusOf::INACTIVE
+		));
+
+		assert_err!(
+			Asset::create_with_options(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				other_entry.clone(),
+				other_digest,
+				authorization_id.clone(),
+				Some(class_id.clone()),
+				None
+			),
+			Error::<Test>::AssetClassNotActive
+		);
+		assert_err!(
+			Asset::create_with_options(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				other_entry,
+				other_digest,
+				authorization_id,
+				Some(asset_id),
+				None
+			),
+			Error::<Test>::AssetClassNotFound
+		);
+		assert_eq!(Asset::assets_by_class(&class_id).len(), 2);
+	});
+}
+
+#[test]
+fn asset_holdings_and_instance_history_should_follow_ownership() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let issue_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&issue_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	let instance_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&creator.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let instance_id = generate_asset_instance_id::<Test>(&instance_id_digest);
+
+	let transfer_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: creator.clone(),
+		new_asset_owner: new_owner.clone(),
+	};
+
+	let transfer_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&transfer_entry.encode()[..]].concat()[..]);
+
+	let return_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: new_owner.clone(),
+		new_asset_owner: creator.clone(),
+	};
+
+	let return_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&return_entry.encode()[..]].concat()[..]);
+
+	let split_entry = AssetSplitEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: new_owner.clone(),
+		new_asset_owner: creator.clone(),
+		split_qty: 3,
+	};
+
+	let split_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&split_entry.encode()[..]].concat()[..]);
+
+	let split_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&instance_id.encode()[..],
+			&creator.encode()[..],
+			&new_owner.encode()[..],
+			&split_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let split_instance_id = generate_asset_instance_id::<Test>(&split_id_digest);
+
+	let held = |owner: &SubjectId| {
+		let mut held: Vec<_> = Asset::holdings(owner)
+			.into_iter()
+			.map(|h| (h.asset_instance_id, h.asset_qty))
+			.collect();
+		held.sort();
+		held
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::issue(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			issue_entry,
+			issue_entry_digest,
+			authorization_id
+		));
+		assert_eq!(held(&creator), vec![(instance_id.clone(), 10)]);
+		assert!(held(&new_owner).is_empty());
+
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			transfer_entry,
+			transfer_entry_digest,
+		));
+		assert!(held(&creator).is_empty());
+		assert_eq!(held(&new_owner), vec![(instance_id.clone(), 10)]);
+
+		assert_ok!(Asset::split(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			split_entry,
+			split_entry_digest,
+		));
+		assert_eq!(held(&creator), vec![(split_instance_id.clone(), 3)]);
+		assert_eq!(held(&new_owner), vec![(instance_id.clone(), 7)]);
+		assert_eq!(Asset::asset_instances(&asset_id).len(), 2);
+
+		let history: Vec<_> = Asset::instance_history(&instance_id)
+			.into_iter()
+			.map(|r| (r.from, r.to, r.qty))
+			.collect();
+		assert_eq!(
+			history,
+			vec![(None, creator.clone(), 10), (Some(creator.clone()), new_owner.clone(), 10)]
+		);
+		assert_eq!(
+			Asset::instance_history(&split_instance_id)
+				.into_iter()
+				.map(|r| (r.from, r.to, r.qty))
+				.collect::<Vec<_>>(),
+			vec![(Some(new_owner.clone()), creator.clone(), 3)]
+		);
+
+		/* Only the last `MaxInstanceTransfers` changes are kept */
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			return_entry,
+			return_entry_digest,
+		));
+		let history: Vec<_> = Asset::instance_history(&instance_id)
+			.into_iter()
+			.map(|r| (r.from, r.to, r.qty))
+			.collect();
+		assert_eq!(
+			history,
+			vec![
+				(Some(creator.clone()), new_owner.clone(), 10),
+				(Some(new_owner.clone()), creator.clone(), 7)
+			]
+		);
+		assert!(InstanceTransfers::<Test>::get(&instance_id, 0).is_none());
+	});
+}
+
+#[test]
+fn migration_to_v1_should_index_existing_instances() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let issue_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&issue_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	let instance_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&creator.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let instance_id = generate_asset_instance_id::<Test>(&instance_id_digest);
+
+	let transfer_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: creator.clone(),
+		new_asset_owner: new_owner.clone(),
+	};
+
+	let transfer_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&transfer_entry.encode()[..]].concat()[..]);
+
+	let split_entry = AssetSplitEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: new_owner.clone(),
+		new_asset_owner: creator.clone(),
+		split_qty: 3,
+	};
+
+	let split_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&split_entry.encode()[..]].concat()[..]);
+
+	let split_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&instance_id.encode()[..],
+			&creator.encode()[..],
+			&new_owner.encode()[..],
+			&split_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let split_instance_id = generate_asset_instance_id::<Test>(&split_id_digest);
+
+	let held = |owner: &SubjectId| {
+		let mut held: Vec<_> = Asset::holdings(owner)
+			.into_iter()
+			.map(|h| (h.asset_instance_id, h.asset_qty))
+			.collect();
+		held.sort();
+		held
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::issue(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			issue_entry,
+			issue_entry_digest,
+			authorization_id
+		));
+
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			transfer_entry,
+			transfer_entry_digest,
+		));
+
+		assert_ok!(Asset::split(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			split_entry,
+			split_entry_digest,
+		));
+
+		/* Instances issued before version 1 are missing from the owner index */
+		let _ = OwnerInstances::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(0).put::<Asset>();
+		assert!(held(&creator).is_empty());
+		assert!(held(&new_owner).is_empty());
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(held(&creator), vec![(split_instance_id.clone(), 3)]);
+		assert_eq!(held(&new_owner), vec![(instance_id.clone(), 7)]);
+		assert_eq!(Asset::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn asset_transfer_should_await_acceptance_and_approval() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+	let approver = DID_02;
+
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let issue_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&issue_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	let instance_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&creator.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let instance_id = generate_asset_instance_id::<Test>(&instance_id_digest);
+
+	let transfer_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: creator.clone(),
+		new_asset_owner: new_owner.clone(),
+	};
+
+	let transfer_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&transfer_entry.encode()[..]].concat()[..]);
+
+	let return_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: new_owner.clone(),
+		new_asset_owner: creator.clone(),
+	};
+
+	let return_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&return_entry.encode()[..]].concat()[..]);
+
+	let policy = AssetTransferPolicyOf::<Test> {
+		require_acceptance: true,
+		approvers: BoundedVec::try_from(vec![approver.clone()]).unwrap(),
+	};
+
+	let instance_owner =
+		|| Issuance::<Test>::get(&asset_id, &instance_id).map(|i| i.asset_instance_owner);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::issue(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			issue_entry,
+			issue_entry_digest,
+			authorization_id
+		));
+
+		/* Only the issuer can set a policy, and it has to require something */
+		assert_err!(
+			Asset::set_transfer_policy(
+				DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+				asset_id.clone(),
+				Some(policy.clone()),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_err!(
+			Asset::set_transfer_policy(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				asset_id.clone(),
+				Some(AssetTransferPolicyOf::<Test> {
+					require_acceptance: false,
+					approvers: BoundedVec::default(),
+				}),
+			),
+			Error::<Test>::InvalidTransferPolicy
+		);
+		assert_ok!(Asset::set_transfer_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			asset_id.clone(),
+			Some(policy),
+		));
+
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			transfer_entry.clone(),
+			transfer_entry_digest,
+		));
+		System::assert_last_event(
+			Event::TransferRequest {
+				identifier: asset_id.clone(),
+				instance: instance_id.clone(),
+				from: creator.clone(),
+				to: new_owner.clone(),
+				digest: transfer_entry_digest,
+				expires_at: 11,
+			}
+			.into(),
+		);
+		assert_eq!(instance_owner(), Some(creator.clone()));
+		assert_err!(
+			Asset::transfer(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				transfer_entry,
+				transfer_entry_digest,
+			),
+			Error::<Test>::TransferPending
+		);
+
+		/* Acceptance alone does not complete the transfer */
+		assert_err!(
+			Asset::accept_transfer(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				instance_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_ok!(Asset::accept_transfer(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			instance_id.clone(),
+		));
+		assert_eq!(instance_owner(), Some(creator.clone()));
+		assert_err!(
+			Asset::accept_transfer(
+				DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+				instance_id.clone(),
+			),
+			Error::<Test>::TransferAlreadyConfirmed
+		);
+
+		assert_err!(
+			Asset::approve_transfer(
+				DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+				instance_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_ok!(Asset::approve_transfer(
+			DoubleOrigin(ACCOUNT_02, approver.clone()).into(),
+			instance_id.clone(),
+		));
+		assert_eq!(instance_owner(), Some(new_owner.clone()));
+		assert!(Asset::pending_transfer(&instance_id).is_none());
+		assert_eq!(
+			Asset::instance_history(&instance_id).last().map(|r| r.digest),
+			Some(Some(transfer_entry_digest))
+		);
+
+		/* The approver can reject a transfer */
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			return_entry.clone(),
+			return_entry_digest,
+		));
+		assert_ok!(Asset::reject_transfer(
+			DoubleOrigin(ACCOUNT_02, approver.clone()).into(),
+			instance_id.clone(),
+		));
+		assert!(Asset::pending_transfer(&instance_id).is_none());
+
+		/* An expired transfer cannot be completed and anyone can clear it */
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			return_entry,
+			return_entry_digest,
+		));
+		assert_err!(
+			Asset::cancel_transfer(
+				DoubleOrigin(ACCOUNT_02, approver.clone()).into(),
+				instance_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		System::set_block_number(12);
+
+		assert_err!(
+			Asset::accept_transfer(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				instance_id.clone(),
+			),
+			Error::<Test>::PendingTransferExpired
+		);
+		assert_ok!(Asset::cancel_transfer(
+			DoubleOrigin(ACCOUNT_02, approver.clone()).into(),
+			instance_id.clone(),
+		));
+		System::assert_last_event(
+			Event::TransferCancel { identifier: asset_id.clone(), instance: instance_id.clone() }
+				.into(),
+		);
+		assert_eq!(instance_owner(), Some(new_owner.clone()));
+	});
+}
+
+#[test]
+fn pending_transfer_should_keep_its_approvers_until_it_expires() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+	let approver = DID_02;
+	let other_approver = DID_03;
+
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let issue_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&issue_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	let instance_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&creator.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let instance_id = generate_asset_instance_id::<Test>(&instance_id_digest);
+
+	let transfer_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: creator.clone(),
+		new_asset_owner: new_owner.clone(),
+	};
+
+	let transfer_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&transfer_entry.encode()[..]].concat()[..]);
+
+	let return_entry = AssetTransferEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: new_owner.clone(),
+		new_asset_owner: creator.clone(),
+	};
+
+	let return_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&return_entry.encode()[..]].concat()[..]);
+
+	let policy = AssetTransferPolicyOf::<Test> {
+		require_acceptance: false,
+		approvers: BoundedVec::try_from(vec![approver.clone()]).unwrap(),
+	};
+
+	let other_policy = AssetTransferPolicyOf::<Test> {
+		require_acceptance: false,
+		approvers: BoundedVec::try_from(vec![other_approver.clone()]).unwrap(),
+	};
+
+	let instance_owner =
+		|| Issuance::<Test>::get(&asset_id, &instance_id).map(|i| i.asset_instance_owner);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::issue(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			issue_entry,
+			issue_entry_digest,
+			authorization_id
+		));
+
+		assert_ok!(Asset::set_transfer_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			asset_id.clone(),
+			Some(policy),
+		));
+
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			transfer_entry,
+			transfer_entry_digest,
+		));
+		let timeline = identifier::Identifiers::<Test>::get(&instance_id, IdentifierTypeOf::Asset)
+			.expect("Asset instance timeline should exist");
+		assert_eq!(timeline.last().map(|e| e.action), Some(CallTypeOf::TransferRequest));
+
+		/* A policy change does not affect who can approve a requested transfer */
+		assert_ok!(Asset::set_transfer_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			asset_id.clone(),
+			Some(other_policy),
+		));
+		assert_err!(
+			Asset::approve_transfer(
+				DoubleOrigin(ACCOUNT_03, other_approver.clone()).into(),
+				instance_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_ok!(Asset::approve_transfer(
+			DoubleOrigin(ACCOUNT_02, approver.clone()).into(),
+			instance_id.clone(),
+		));
+		assert_eq!(instance_owner(), Some(new_owner.clone()));
+
+		/* An expired transfer no longer holds the instance */
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			return_entry.clone(),
+			return_entry_digest,
+		));
+		assert_err!(
+			Asset::approve_transfer(
+				DoubleOrigin(ACCOUNT_02, approver.clone()).into(),
+				instance_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		System::set_block_number(12);
+
+		assert!(Asset::pending_transfer(&instance_id).is_none());
+		assert_ok!(Asset::set_transfer_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			asset_id.clone(),
+			None,
+		));
+		assert_ok!(Asset::transfer(
+			DoubleOrigin(ACCOUNT_01, new_owner.clone()).into(),
+			return_entry,
+			return_entry_digest,
+		));
+		assert!(!PendingTransfers::<Test>::contains_key(&instance_id));
+		assert_eq!(instance_owner(), Some(creator.clone()));
+	});
+}
+
+#[test]
+fn asset_split_to_another_owner_should_follow_the_transfer_policy() {
+	let creator = DID_00;
+	let new_owner = DID_01;
+	let approver = DID_02;
+
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let entry = AssetInputEntryOf::<Test> {
+		asset_desc: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_qty: 10,
+		asset_type: AssetTypeOf::MF,
+		asset_value: 10,
+		asset_tag: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+		asset_meta: BoundedVec::try_from([72u8; 10].to_vec()).unwrap(),
+	};
+
+	let digest = <Test as frame_system::Config>::Hashing::hash(&[&entry.encode()[..]].concat()[..]);
+
+	let issue_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let asset_id: Ss58Identifier = generate_asset_id::<Test>(&issue_id_digest);
+
+	let issue_entry = AssetIssuanceEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_owner: creator.clone(),
+		asset_issuance_qty: Some(10),
+	};
+
+	let issue_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&issue_entry.encode()[..]].concat()[..]);
+
+	let instance_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&creator.encode()[..],
+			&space_id.encode()[..],
+			&creator.encode()[..],
+			&issue_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let instance_id = generate_asset_instance_id::<Test>(&instance_id_digest);
+
+	let split_entry = AssetSplitEntryOf::<Test> {
+		asset_id: asset_id.clone(),
+		asset_instance_id: instance_id.clone(),
+		asset_owner: creator.clone(),
+		new_asset_owner: new_owner.clone(),
+		split_qty: 4,
+	};
+
+	let split_entry_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[&split_entry.encode()[..]].concat()[..]);
+
+	let split_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[
+			&asset_id.encode()[..],
+			&instance_id.encode()[..],
+			&new_owner.encode()[..],
+			&creator.encode()[..],
+			&split_entry_digest.encode()[..],
+		]
+		.concat()[..],
+	);
+
+	let split_instance_id = generate_asset_instance_id::<Test>(&split_id_digest);
+
+	let policy = AssetTransferPolicyOf::<Test> {
+		require_acceptance: false,
+		approvers: BoundedVec::try_from(vec![approver.clone()]).unwrap(),
+	};
+
+	let instance_owner = |instance_id: &AssetInstanceIdOf| {
+		Issuance::<Test>::get(&asset_id, instance_id).map(|i| i.asset_instance_owner)
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Asset::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			entry,
+			digest,
+			authorization_id.clone()
+		));
+
+		assert_ok!(Asset::issue(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			issue_entry,
+			issue_entry_digest,
+			authorization_id
+		));
+
+		assert_ok!(Asset::set_transfer_policy(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			asset_id.clone(),
+			Some(policy),
+		));
+
+		/* The split off instance stays with the owner until the transfer is approved */
+		assert_ok!(Asset::split(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			split_entry,
+			split_entry_digest,
+		));
+		assert_eq!(instance_owner(&split_instance_id), Some(creator.clone()));
+		assert_eq!(
+			PendingTransfers::<Test>::get(&split_instance_id).map(|p| p.new_asset_owner),
+			Some(new_owner.clone())
+		);
+		let timeline =
+			identifier::Identifiers::<Test>::get(&split_instance_id, IdentifierTypeOf::Asset)
+				.expect("Asset instance timeline should exist");
+		assert_eq!(timeline.last().map(|e| e.action), Some(CallTypeOf::TransferRequest));
+
+		assert_ok!(Asset::approve_transfer(
+			DoubleOrigin(ACCOUNT_02, approver.clone()).into(),
+			split_instance_id.clone(),
+		));
+		assert_eq!(instance_owner(&split_instance_id), Some(new_owner.clone()));
+		assert_eq!(instance_owner(&instance_id), Some(creator.clone()));
+		assert!(!PendingTransfers::<Test>::contains_key(&split_instance_id));
+	});
+}
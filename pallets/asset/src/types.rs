@@ -17,6 +17,7 @@
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
 use codec::{Decode, Encode, MaxEncodedLen};
+use identifier::types::Timepoint;
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 
@@ -184,3 +185,140 @@ pub struct AssetTransferEntry<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf> {
 	/// new asset owner
 	pub new_asset_owner: AssetCreatorOf,
 }
+
+#[derive(
+	Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+)]
+pub struct AssetSplitEntry<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf> {
+	/// type of the asset
+	pub asset_id: AssetIdOf,
+	/// asset instance identifier
+	pub asset_instance_id: AssetInstanceIdOf,
+	/// asset owner
+	pub asset_owner: AssetCreatorOf,
+	/// owner of the split off instance
+	pub new_asset_owner: AssetCreatorOf,
+	/// quantity moved to the split off instance
+	pub split_qty: u64,
+}
+
+#[derive(
+	Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+)]
+pub struct AssetMergeEntry<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf> {
+	/// type of the asset
+	pub asset_id: AssetIdOf,
+	/// asset instance identifier, kept after the merge
+	pub asset_instance_id: AssetInstanceIdOf,
+	/// asset instance identifier, removed by the merge
+	pub merged_instance_id: AssetInstanceIdOf,
+	/// asset owner
+	pub asset_owner: AssetCreatorOf,
+}
+
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	Default,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct AssetInstanceLineage<AssetInstanceIdOf> {
+	/// instance the quantity was split off from
+	pub split_from: Option<AssetInstanceIdOf>,
+	/// instance the quantity was merged into
+	pub merged_into: Option<AssetInstanceIdOf>,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct AssetClassEntry<AssetClassName, SchemaIdOf, SpaceIdOf, AssetCreatorOf, BlockNumber> {
+	/// name of the asset class
+	pub class_name: AssetClassName,
+	/// schema describing the metadata of assets of the class
+	pub class_schema: SchemaIdOf,
+	/// space the class is registered in
+	pub class_space: SpaceIdOf,
+	/// status of the class
+	pub class_status: AssetStatusOf,
+	/// class issuer
+	pub class_issuer: AssetCreatorOf,
+	/// class inclusion block
+	pub created_at: BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct AssetHolding<AssetIdOf, AssetInstanceIdOf, AssetCreatorOf> {
+	/// asset identifier
+	pub asset_id: AssetIdOf,
+	/// asset instance identifier
+	pub asset_instance_id: AssetInstanceIdOf,
+	/// asset owner
+	pub asset_owner: AssetCreatorOf,
+	/// asset instance quantity
+	pub asset_qty: AssetQtyOf,
+	/// status of the asset instance
+	pub asset_instance_status: AssetStatusOf,
+	/// whether the instance was issued from a vc asset
+	pub is_vc: bool,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct AssetTransferRecord<AssetCreatorOf, EntryHashOf> {
+	/// previous owner, `None` when the instance was issued
+	pub from: Option<AssetCreatorOf>,
+	/// new owner
+	pub to: AssetCreatorOf,
+	/// quantity held by the instance at the transfer
+	pub qty: AssetQtyOf,
+	/// digest submitted with the transfer
+	pub digest: Option<EntryHashOf>,
+	/// location of the transfer
+	pub location: Timepoint,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct AssetTransferPolicy<AssetApproversOf> {
+	/// whether the new owner has to accept a transfer
+	pub require_acceptance: bool,
+	/// parties any one of which has to approve a transfer
+	pub approvers: AssetApproversOf,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct AssetPendingTransfer<
+	AssetIdOf,
+	AssetCreatorOf,
+	AssetApproversOf,
+	EntryHashOf,
+	BlockNumber,
+> {
+	/// asset identifier
+	pub asset_id: AssetIdOf,
+	/// asset owner
+	pub asset_owner: AssetCreatorOf,
+	/// new asset owner
+	pub new_asset_owner: AssetCreatorOf,
+	/// digest submitted with the transfer
+	pub digest: EntryHashOf,
+	/// whether the instance was issued from a vc asset
+	pub is_vc: bool,
+	/// whether the new owner still has to accept the transfer
+	pub awaiting_acceptance: bool,
+	/// whether an approver still has to approve the transfer
+	pub awaiting_approval: bool,
+	/// parties any one of which can approve the transfer, as set in the
+	/// transfer policy when the transfer was requested
+	pub approvers: AssetApproversOf,
+	/// approver who approved the transfer
+	pub approved_by: Option<AssetCreatorOf>,
+	/// block the transfer was requested at
+	pub requested_at: BlockNumber,
+	/// block after which the transfer can no longer be completed
+	pub expires_at: BlockNumber,
+}
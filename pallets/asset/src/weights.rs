@@ -53,6 +53,15 @@ pub trait WeightInfo {
 	fn issue() -> Weight;
 	fn transfer() -> Weight;
 	fn status_change() -> Weight;
+	fn split() -> Weight;
+	fn merge() -> Weight;
+	fn class_create() -> Weight;
+	fn class_status_change() -> Weight;
+	fn set_transfer_policy() -> Weight;
+	fn accept_transfer() -> Weight;
+	fn approve_transfer() -> Weight;
+	fn reject_transfer() -> Weight;
+	fn cancel_transfer() -> Weight;
 }
 
 /// Weights for `pallet_asset` using the CORD node and recommended hardware.
@@ -66,16 +75,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetExpiries` (r:1 w:1)
+	/// Proof: `Asset::AssetExpiries` (`max_values`: None, `max_size`: Some(50022), added: 52497, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::NextAssetExpirySweep` (r:1 w:1)
+	/// Proof: `Asset::NextAssetExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClasses` (r:1 w:0)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::ClassAssets` (r:0 w:1)
+	/// Proof: `Asset::ClassAssets` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClassOf` (r:0 w:1)
+	/// Proof: `Asset::AssetClassOf` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetExpiry` (r:0 w:1)
+	/// Proof: `Asset::AssetExpiry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `719`
-		//  Estimated: `6667`
-		// Minimum execution time: 37_750_000 picoseconds.
-		Weight::from_parts(38_870_000, 6667)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Estimated: `53487`
+		// Minimum execution time: 54_150_000 picoseconds.
+		Weight::from_parts(55_580_000, 53487)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -87,18 +116,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:1)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:1)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `968`
 		//  Estimated: `6667`
-		// Minimum execution time: 53_110_000 picoseconds.
-		Weight::from_parts(54_730_000, 6667)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+		// Minimum execution time: 59_230_000 picoseconds.
+		Weight::from_parts(60_850_000, 6667)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -106,14 +145,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:1 w:0)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
-		// Minimum execution time: 30_110_000 picoseconds.
-		Weight::from_parts(30_910_000, 6799)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		// Minimum execution time: 42_320_000 picoseconds.
+		Weight::from_parts(43_120_000, 6799)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -121,14 +174,219 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
 		// Minimum execution time: 29_790_000 picoseconds.
 		Weight::from_parts(30_700_000, 6799)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:2 w:2)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Distribution` (r:1 w:1)
+	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:3 w:3)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:3 w:3)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:3 w:3)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetLookup` (r:0 w:1)
+	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Lineage` (r:0 w:1)
+	/// Proof: `Asset::Lineage` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:1)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:1 w:2)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:1 w:0)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	fn split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `12608`
+		// Minimum execution time: 74_280_000 picoseconds.
+		Weight::from_parts(76_986_000, 12608)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:2 w:2)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Distribution` (r:1 w:1)
+	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Lineage` (r:1 w:1)
+	/// Proof: `Asset::Lineage` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:2 w:2)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:1)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:2 w:2)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn merge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1079`
+		//  Estimated: `12608`
+		// Minimum execution time: 72_790_000 picoseconds.
+		Weight::from_parts(75_544_000, 12608)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::DelegateUsages` (r:1 w:1)
+	/// Proof: `ChainSpace::DelegateUsages` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClasses` (r:1 w:1)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn class_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1136`
+		//  Estimated: `19007`
+		// Minimum execution time: 58_130_000 picoseconds.
+		Weight::from_parts(60_554_000, 19007)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Asset::AssetClasses` (r:1 w:1)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn class_status_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `565`
+		//  Estimated: `4694`
+		// Minimum execution time: 26_260_000 picoseconds.
+		Weight::from_parts(27_360_000, 4694)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:0 w:1)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_transfer_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `580`
+		//  Estimated: `6667`
+		// Minimum execution time: 26_380_000 picoseconds.
+		Weight::from_parts(27_488_000, 6667)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:1 w:1)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn accept_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `884`
+		//  Estimated: `6799`
+		// Minimum execution time: 59_870_000 picoseconds.
+		Weight::from_parts(62_373_000, 6799)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:1 w:1)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn approve_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1008`
+		//  Estimated: `6799`
+		// Minimum execution time: 64_860_000 picoseconds.
+		Weight::from_parts(67_566_000, 6799)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn reject_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `535`
+		//  Estimated: `3721`
+		// Minimum execution time: 22_560_000 picoseconds.
+		Weight::from_parts(23_508_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn cancel_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3721`
+		// Minimum execution time: 17_330_000 picoseconds.
+		Weight::from_parts(18_056_000, 3721)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -142,16 +400,36 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetExpiries` (r:1 w:1)
+	/// Proof: `Asset::AssetExpiries` (`max_values`: None, `max_size`: Some(50022), added: 52497, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::NextAssetExpirySweep` (r:1 w:1)
+	/// Proof: `Asset::NextAssetExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClasses` (r:1 w:0)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::ClassAssets` (r:0 w:1)
+	/// Proof: `Asset::ClassAssets` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClassOf` (r:0 w:1)
+	/// Proof: `Asset::AssetClassOf` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetExpiry` (r:0 w:1)
+	/// Proof: `Asset::AssetExpiry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `719`
-		//  Estimated: `6667`
-		// Minimum execution time: 37_750_000 picoseconds.
-		Weight::from_parts(38_870_000, 6667)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Estimated: `53487`
+		// Minimum execution time: 54_150_000 picoseconds.
+		Weight::from_parts(55_580_000, 53487)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -163,18 +441,28 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:1)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:1)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `968`
 		//  Estimated: `6667`
-		// Minimum execution time: 53_110_000 picoseconds.
-		Weight::from_parts(54_730_000, 6667)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		// Minimum execution time: 59_230_000 picoseconds.
+		Weight::from_parts(60_850_000, 6667)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -182,14 +470,28 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:1 w:0)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
-		// Minimum execution time: 30_110_000 picoseconds.
-		Weight::from_parts(30_910_000, 6799)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		// Minimum execution time: 42_320_000 picoseconds.
+		Weight::from_parts(43_120_000, 6799)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -197,13 +499,218 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
 		// Minimum execution time: 29_790_000 picoseconds.
 		Weight::from_parts(30_700_000, 6799)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:2 w:2)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Distribution` (r:1 w:1)
+	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:3 w:3)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:3 w:3)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:3 w:3)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetLookup` (r:0 w:1)
+	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Lineage` (r:0 w:1)
+	/// Proof: `Asset::Lineage` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:1)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:1 w:2)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:1 w:0)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	fn split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `12608`
+		// Minimum execution time: 74_280_000 picoseconds.
+		Weight::from_parts(76_986_000, 12608)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:2 w:2)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Distribution` (r:1 w:1)
+	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Lineage` (r:1 w:1)
+	/// Proof: `Asset::Lineage` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:2 w:2)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:1)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:2 w:2)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn merge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1079`
+		//  Estimated: `12608`
+		// Minimum execution time: 72_790_000 picoseconds.
+		Weight::from_parts(75_544_000, 12608)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::DelegateUsages` (r:1 w:1)
+	/// Proof: `ChainSpace::DelegateUsages` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClasses` (r:1 w:1)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn class_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1136`
+		//  Estimated: `19007`
+		// Minimum execution time: 58_130_000 picoseconds.
+		Weight::from_parts(60_554_000, 19007)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Asset::AssetClasses` (r:1 w:1)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn class_status_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `565`
+		//  Estimated: `4694`
+		// Minimum execution time: 26_260_000 picoseconds.
+		Weight::from_parts(27_360_000, 4694)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:0 w:1)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_transfer_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `580`
+		//  Estimated: `6667`
+		// Minimum execution time: 26_380_000 picoseconds.
+		Weight::from_parts(27_488_000, 6667)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:1 w:1)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn accept_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `884`
+		//  Estimated: `6799`
+		// Minimum execution time: 59_870_000 picoseconds.
+		Weight::from_parts(62_373_000, 6799)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:1 w:1)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn approve_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1008`
+		//  Estimated: `6799`
+		// Minimum execution time: 64_860_000 picoseconds.
+		Weight::from_parts(67_566_000, 6799)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn reject_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `535`
+		//  Estimated: `3721`
+		// Minimum execution time: 22_560_000 picoseconds.
+		Weight::from_parts(23_508_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn cancel_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3721`
+		// Minimum execution time: 17_330_000 picoseconds.
+		Weight::from_parts(18_056_000, 3721)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
@@ -419,6 +419,159 @@ benchmarks! {
 			 assert_last_event::<T>(Event::Create { space: subspace_id, creator: did, authorization: authorization_id }.into());
 		 }
 
+		set_delegation_constraints {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let delegate_did: T::SpaceCreatorId = account("did", 1, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 5u64;
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_id_digest = T::Hashing::hash(
+				&[&space_id.encode()[..], &delegate_did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id = generate_authorization_id::<T>(&delegate_id_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let chain_space_origin = RawOrigin::Root.into();
+
+			Pallet::<T>::create(origin, space_digest )?;
+			Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			Pallet::<T>::add_delegate(origin, space_id.clone(), delegate_did, authorization_id.clone())?;
+
+			let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_authorization_id.clone(), Some(expires_at), Some(10), DelegationScope::CreateOnly, authorization_id)
+		verify {
+			assert_last_event::<T>(Event::DelegationConstraintsSet { space: space_id, authorization: delegate_authorization_id, expires_at: Some(expires_at), max_creates: Some(10), scope: DelegationScope::CreateOnly, authority: did }.into());
+		}
+
+		set_capacity_refill {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 5u64;
+			let interval: BlockNumberFor<T> = 10u32.into();
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+			let root_origin = RawOrigin::Root;
+
+			Pallet::<T>::create(origin, space_digest )?;
+			Pallet::<T>::approve(root_origin.clone().into(), space_id.clone(), capacity )?;
+
+		}: _<T::RuntimeOrigin>(root_origin.into(), space_id.clone(), 5u64, interval, false)
+		verify {
+			assert_last_event::<T>(Event::CapacityRefillSet { space: space_id, amount: 5u64, interval, reset_usage: false }.into());
+		}
+
+		remove_capacity_refill {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 5u64;
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+			let root_origin = RawOrigin::Root;
+
+			Pallet::<T>::create(origin, space_digest )?;
+			Pallet::<T>::approve(root_origin.clone().into(), space_id.clone(), capacity )?;
+			Pallet::<T>::set_capacity_refill(root_origin.clone().into(), space_id.clone(), 5u64, 1u32.into(), false)?;
+			frame_system::Pallet::<T>::set_block_number(frame_system::Pallet::<T>::block_number() + 1u32.into());
+
+		}: _<T::RuntimeOrigin>(root_origin.into(), space_id.clone())
+		verify {
+			assert_last_event::<T>(Event::CapacityRefillRemoved { space: space_id }.into());
+		}
+
+		purchase_capacity {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 5u64;
+			let amount = 10u64;
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let cost = T::CapacityPrice::get().saturating_mul(10u32.into());
+			CurrencyOf::<T>::make_free_balance_be(&caller, CurrencyOf::<T>::minimum_balance() + cost);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+
+			Pallet::<T>::create(origin, space_digest )?;
+			Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did);
+		}: _<T::RuntimeOrigin>(origin, space_id.clone(), amount)
+		verify {
+			assert_last_event::<T>(Event::CapacityPurchased { space: space_id, amount, cost, payer: caller }.into());
+		}
+
+		set_delegate_usage_cap {
+			let caller: T::AccountId = account("caller", 0, SEED);
+			let did: T::SpaceCreatorId = account("did", 0, SEED);
+			let delegate_did: T::SpaceCreatorId = account("did", 1, SEED);
+			let space = [2u8; 256].to_vec();
+			let capacity = 5u64;
+
+			let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_id_digest = T::Hashing::hash(
+				&[&space_id.encode()[..], &delegate_did.encode()[..], &did.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id = generate_authorization_id::<T>(&delegate_id_digest);
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			let chain_space_origin = RawOrigin::Root.into();
+
+			Pallet::<T>::create(origin, space_digest )?;
+			Pallet::<T>::approve(chain_space_origin, space_id.clone(), capacity ).expect("Approval should not fail.");
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+			Pallet::<T>::add_delegate(origin, space_id.clone(), delegate_did.clone(), authorization_id.clone())?;
+
+			let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		}: _<T::RuntimeOrigin>(origin, space_id.clone(), delegate_authorization_id, Some(10), authorization_id)
+		verify {
+			assert_last_event::<T>(Event::DelegateUsageCapSet { space: space_id, delegate: delegate_did, txn_cap: Some(10), authority: did }.into());
+		}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
@@ -46,6 +46,10 @@
 //!   permissions.
 //! - `add_audit_delegate`: Adds an audit delegate to a space, granting them audit permissions.
 //! - `remove_delegate`: Removes a delegate from a space, revoking their permissions.
+//! - `set_delegation_constraints`: Limits a delegation by expiry, creation quota or scope.
+//! - `set_capacity_refill`: Schedules the periodic refill of a space's capacity.
+//! - `remove_capacity_refill`: Stops the periodic refill of a space's capacity.
+//! - `purchase_capacity`: Buys additional capacity for a space.
 //!
 //! ## Permissions
 //!
@@ -53,6 +57,34 @@
 //! can perform within a space. Permissions are granular and can be assigned to
 //! different roles, such as an admin or a regular delegate.
 //!
+//! ## Delegation Constraints
+//!
+//! Space admins can further limit a delegation: it can expire at a block, be
+//! restricted to a maximum number of creations, or be scoped to creating
+//! only. The `ensure_authorization_*` helpers refuse lapsed delegations. A
+//! `DelegationLapsed` event is emitted when the quota is used up and, from
+//! `on_idle`, when the expiry block is reached.
+//!
+//! ## Sub-Spaces
+//!
+//! Spaces can be nested up to `MaxSpaceDepth` levels below a top-level
+//! space. A sub-space reserves its capacity in its parent, whose own capacity
+//! is reserved in turn in its parent, so the capacity of every sub-space is
+//! accounted for up the whole chain. A sub-space of a space with a limited
+//! capacity must have a limited capacity itself. Archiving a space or
+//! revoking its approval applies to all of its descendants, which become
+//! usable again once the space is restored.
+//!
+//! ## Capacity Refill and Billing
+//!
+//! The capacity of a space can be refilled on a schedule set by the
+//! `ChainSpaceOrigin`: every `interval` blocks either `amount` transactions
+//! are credited back to the space, or its usage is reset to zero. Refills are
+//! applied when the usage of the space is next checked or recorded. Space
+//! creators can also purchase additional capacity at `CapacityPrice` per
+//! transaction; the payment is handed to `CapacityPayment`, which may deposit
+//! it to an account such as the treasury or burn it.
+//!
 //! ## Data Privacy
 //!
 //! The ChainSpace pallet is designed with data privacy as a core consideration.
@@ -97,15 +129,24 @@ pub mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{ensure, storage::types::StorageMap, BoundedVec};
+use frame_support::{
+	ensure,
+	storage::types::StorageMap,
+	traits::{Currency, Get},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
 pub mod types;
 pub use crate::{pallet::*, types::*, weights::WeightInfo};
 use codec::Encode;
+use cord_utilities::schedule;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_runtime::traits::{Hash, Saturating, UniqueSaturatedInto, Zero};
+use sp_std::{vec, vec::Vec};
 
 /// Type of a CORD account.
 pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -122,12 +163,25 @@ pub type SpaceDetailsOf<T> = SpaceDetails<SpaceCodeOf<T>, SpaceCreatorOf<T>, Sta
 
 pub type SpaceAuthorizationOf<T> = SpaceAuthorization<SpaceIdOf, SpaceCreatorOf<T>, Permissions>;
 
+/// Type of the Space Delegation Constraints
+pub type DelegationConstraintsOf<T> = DelegationConstraints<BlockNumberFor<T>>;
+
+/// Type of the Space Capacity Refill schedule
+pub type CapacityRefillOf<T> = CapacityRefill<BlockNumberFor<T>>;
+
+pub(crate) type CurrencyOf<T> = <T as Config>::Currency;
+pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
+pub type NegativeImbalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::NegativeImbalance;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	pub use cord_primitives::{IsPermissioned, StatusOf};
 	use cord_utilities::traits::CallSources;
-	use frame_support::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{ExistenceRequirement, OnUnbalanced, WithdrawReasons},
+	};
 	use frame_system::pallet_prelude::*;
 	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
 
@@ -149,6 +203,25 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSpaceDelegates: Get<u32>;
 
+		/// Maximum delegations expiring at a single block
+		#[pallet::constant]
+		type MaxDelegationExpiriesPerBlock: Get<u32>;
+
+		/// Maximum number of levels of sub-spaces below a top-level space.
+		#[pallet::constant]
+		type MaxSpaceDepth: Get<u32>;
+
+		/// The currency used to purchase space capacity.
+		type Currency: Currency<AccountIdOf<Self>>;
+
+		/// The price of a single transaction of purchased space capacity.
+		#[pallet::constant]
+		type CapacityPrice: Get<BalanceOf<Self>>;
+
+		/// Handler for the payments made for purchased space capacity.
+		/// Use `()` to burn them.
+		type CapacityPayment: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -158,7 +231,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_delegation_expiries(n, remaining_weight)
+		}
+	}
 
 	/// Space information stored on chain.
 	/// It maps from an identifier to its details.
@@ -183,6 +260,54 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Space delegation constraints stored on chain.
+	/// It maps from an authorization identifier to the limits placed on it.
+	#[pallet::storage]
+	pub type AuthorizationConstraints<T> =
+		StorageMap<_, Blake2_128Concat, AuthorizationIdOf, DelegationConstraintsOf<T>, OptionQuery>;
+
+	/// Scheduled space delegation expiries.
+	/// It maps a block number to the authorizations expiring at that block.
+	#[pallet::storage]
+	pub type DelegationExpiries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<AuthorizationIdOf, T::MaxDelegationExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next block whose scheduled delegation expiries have not been swept.
+	#[pallet::storage]
+	pub type NextDelegationExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Sub-spaces stored on chain.
+	/// It maps from a parent space identifier to the identifiers of its
+	/// sub-spaces.
+	#[pallet::storage]
+	pub type SubSpaces<T> =
+		StorageDoubleMap<_, Blake2_128Concat, SpaceIdOf, Blake2_128Concat, SpaceIdOf, ()>;
+
+	/// Space capacity refill schedules stored on chain.
+	/// It maps from a space identifier to its refill schedule.
+	#[pallet::storage]
+	pub type CapacityRefills<T> =
+		StorageMap<_, Blake2_128Concat, SpaceIdOf, CapacityRefillOf<T>, OptionQuery>;
+
+	/// Space usage by delegate stored on chain.
+	/// It maps from a space identifier and a delegate to the usage of the
+	/// space by the delegate.
+	#[pallet::storage]
+	pub type DelegateUsages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SpaceIdOf,
+		Blake2_128Concat,
+		SpaceCreatorOf<T>,
+		DelegateUsage,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -223,6 +348,50 @@ pub mod pallet {
 		/// A chain space usage has been reset.
 		/// \[space identifier \]
 		ResetUsage { space: SpaceIdOf },
+		/// Constraints on a space delegation have been changed.
+		/// \[space identifier, authorization, expires at, max creates, scope, authority\]
+		DelegationConstraintsSet {
+			space: SpaceIdOf,
+			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			max_creates: Option<u32>,
+			scope: DelegationScope,
+			authority: SpaceCreatorOf<T>,
+		},
+		/// A space delegation has lapsed.
+		/// \[space identifier, authorization, reason\]
+		DelegationLapsed {
+			space: SpaceIdOf,
+			authorization: AuthorizationIdOf,
+			reason: DelegationLapse,
+		},
+		/// A chain space capacity refill schedule has been set.
+		/// \[space identifier, amount, interval, reset usage\]
+		CapacityRefillSet {
+			space: SpaceIdOf,
+			amount: u64,
+			interval: BlockNumberFor<T>,
+			reset_usage: bool,
+		},
+		/// A chain space capacity refill schedule has been removed.
+		/// \[space identifier \]
+		CapacityRefillRemoved { space: SpaceIdOf },
+		/// Chain space capacity has been purchased.
+		/// \[space identifier, amount, cost, payer\]
+		CapacityPurchased {
+			space: SpaceIdOf,
+			amount: u64,
+			cost: BalanceOf<T>,
+			payer: AccountIdOf<T>,
+		},
+		/// The usage cap of a space delegate has been changed.
+		/// \[space identifier, delegate, cap, authority\]
+		DelegateUsageCapSet {
+			space: SpaceIdOf,
+			delegate: SpaceCreatorOf<T>,
+			txn_cap: Option<u64>,
+			authority: SpaceCreatorOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -266,6 +435,28 @@ pub mod pallet {
 		CapacityValueMissing,
 		/// Type capacity overflow
 		TypeCapacityOverflow,
+		/// Delegation has expired.
+		DelegationExpired,
+		/// Delegation has used up its creation quota.
+		DelegationQuotaExhausted,
+		/// Delegation is not scoped for the operation.
+		DelegationScopeExceeded,
+		/// Delegation expiry is not in the future.
+		InvalidDelegationExpiry,
+		/// Maximum number of delegations expiring at the block reached.
+		TooManyDelegationExpiriesInBlock,
+		/// Capacity refill interval is zero.
+		InvalidRefillInterval,
+		/// Capacity refill schedule not found.
+		CapacityRefillNotFound,
+		/// Capacity cannot be purchased for the space.
+		CapacityNotPurchasable,
+		/// Maximum depth of sub-spaces reached.
+		SpaceDepthExceeded,
+		/// Sub-spaces of a space with a limited capacity must have a limited capacity.
+		InvalidSubSpaceCapacity,
+		/// The delegate has reached its usage cap in the space.
+		DelegateUsageCapExceeded,
 	}
 
 	#[pallet::call]
@@ -455,6 +646,7 @@ pub mod pallet {
 				Delegates::<T>::insert(&space_id, delegates);
 
 				Authorizations::<T>::remove(&remove_authorization);
+				Self::clear_delegation_constraints(&remove_authorization);
 
 				Self::decrement_usage(&space_id).map_err(Error::<T>::from)?;
 
@@ -752,6 +944,7 @@ pub mod pallet {
 			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
 			<Spaces<T>>::insert(&space_id, SpaceDetailsOf::<T> { archive: false, ..space_details });
+			Self::record_delegate_usage(&space_id, &creator, 1)?;
 
 			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Restore)
 				.map_err(Error::<T>::from)?;
@@ -799,28 +992,13 @@ pub mod pallet {
 				new_txn_capacity >= (space_details.txn_count + space_details.txn_reserve),
 				Error::<T>::CapacityLessThanUsage
 			);
+			Self::ensure_sub_spaces_limited(&space_id, &space_details, new_txn_capacity)?;
 
 			if space_id.clone() != space_details.parent.clone() {
 				let parent_details = Spaces::<T>::get(&space_details.parent.clone())
 					.ok_or(Error::<T>::SpaceNotFound)?;
 
-				// Ensure the new capacity is greater than the current usage
-				ensure!(
-					(parent_details.txn_capacity >=
-						(parent_details.txn_count +
-							parent_details.txn_reserve +
-							new_txn_capacity - space_details.txn_capacity)),
-					Error::<T>::CapacityLessThanUsage
-				);
-
-				<Spaces<T>>::insert(
-					&space_details.parent.clone(),
-					SpaceDetailsOf::<T> {
-						txn_reserve: parent_details.txn_reserve - space_details.txn_capacity +
-							new_txn_capacity,
-						..parent_details.clone()
-					},
-				);
+				Self::update_parent_reserve(&space_details, parent_details, new_txn_capacity)?;
 			}
 
 			<Spaces<T>>::insert(
@@ -1010,6 +1188,15 @@ pub mod pallet {
 			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 			ensure!(space_details.creator == creator.clone(), Error::<T>::UnauthorizedOperation);
 
+			// Ensure no ancestor is archived or unapproved and the new sub-space
+			// stays within the maximum depth.
+			let ancestors = Self::ancestors(&space_id, &space_details);
+			Self::ensure_active_ancestors(&ancestors)?;
+			ensure!(
+				(ancestors.len() as u32) < T::MaxSpaceDepth::get(),
+				Error::<T>::SpaceDepthExceeded
+			);
+
 			// Check if the network is permissioned
 			let is_permissioned = T::NetworkPermission::is_permissioned();
 
@@ -1021,13 +1208,15 @@ pub mod pallet {
 				None => 0,
 			};
 
-			// Ensure the new capacity is greater than the current usage
-			ensure!(
-				count <=
-					(space_details.txn_capacity -
-						(space_details.txn_count + space_details.txn_reserve)),
-				Error::<T>::CapacityLimitExceeded
-			);
+			// A sub-space of a limited space must be limited itself and fit
+			// within the capacity left in the parent.
+			if space_details.txn_capacity != 0 {
+				let available = space_details
+					.txn_capacity
+					.saturating_sub(space_details.txn_count + space_details.txn_reserve);
+				ensure!(count != 0, Error::<T>::InvalidSubSpaceCapacity);
+				ensure!(count <= available, Error::<T>::CapacityLimitExceeded);
+			}
 
 			// Id Digest = concat (H(<scale_encoded_registry_input>,
 			// <scale_encoded_creator_identifier>))
@@ -1092,9 +1281,10 @@ pub mod pallet {
 					txn_count: 0,
 					approved: true,
 					archive: false,
-					parent: space_id,
+					parent: space_id.clone(),
 				},
 			);
+			<SubSpaces<T>>::insert(&space_id, &identifier, ());
 
 			Self::update_activity(&identifier, IdentifierTypeOf::ChainSpace, CallTypeOf::Genesis)
 				.map_err(Error::<T>::from)?;
@@ -1157,22 +1347,8 @@ pub mod pallet {
 				Spaces::<T>::get(&space_details.parent.clone()).ok_or(Error::<T>::SpaceNotFound)?;
 			ensure!(parent_details.creator.clone() == creator, Error::<T>::UnauthorizedOperation);
 
-			// Ensure the new capacity is greater than the current usage
-			ensure!(
-				(parent_details.txn_capacity >=
-					(parent_details.txn_count + parent_details.txn_reserve + new_txn_capacity -
-						space_details.txn_capacity)),
-				Error::<T>::CapacityLessThanUsage
-			);
-
-			<Spaces<T>>::insert(
-				&space_details.parent.clone(),
-				SpaceDetailsOf::<T> {
-					txn_reserve: parent_details.txn_reserve - space_details.txn_capacity +
-						new_txn_capacity,
-					..parent_details.clone()
-				},
-			);
+			Self::ensure_sub_spaces_limited(&space_id, &space_details, new_txn_capacity)?;
+			Self::update_parent_reserve(&space_details, parent_details, new_txn_capacity)?;
 
 			<Spaces<T>>::insert(
 				&space_id,
@@ -1186,6 +1362,322 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets the constraints of a space delegation.
+		///
+		/// A delegation can be limited to expire at a block, to at most
+		/// `max_creates` creations, or to the `CreateOnly` scope, which allows
+		/// creating but no other operation. Creations made before the
+		/// constraints changed keep counting towards the quota. Passing no
+		/// expiry, no quota and the `Full` scope removes all constraints.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed by an admin of the
+		///   space.
+		/// - `space_id`: The identifier of the space.
+		/// - `delegate_authorization`: The authorization ID of the delegation to constrain.
+		/// - `expires_at`: The block at which the delegation lapses, if any.
+		/// - `max_creates`: The maximum number of creations allowed to the delegation, if any.
+		/// - `scope`: The operations the delegation is allowed to perform.
+		/// - `authorization`: The authorization ID used to validate the caller's admin permission.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the space, the delegation
+		///   belongs to another space or is the caller's own authorization.
+		/// - `AuthorizationNotFound`: If the delegation does not exist.
+		/// - `InvalidDelegationExpiry`: If `expires_at` is not in the future.
+		/// - `TooManyDelegationExpiriesInBlock`: If too many delegations expire at `expires_at`.
+		///
+		/// # Events
+		/// - `DelegationConstraintsSet`: Emitted when the constraints have been changed.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_delegation_constraints())]
+		pub fn set_delegation_constraints(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			delegate_authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			max_creates: Option<u32>,
+			scope: DelegationScope,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+
+			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
+			ensure!(delegate_authorization != authorization, Error::<T>::UnauthorizedOperation);
+
+			let delegation = <Authorizations<T>>::get(&delegate_authorization)
+				.ok_or(Error::<T>::AuthorizationNotFound)?;
+			ensure!(delegation.space_id == space_id, Error::<T>::UnauthorizedOperation);
+
+			if let Some(expiry) = expires_at {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidDelegationExpiry
+				);
+			}
+
+			let previous = AuthorizationConstraints::<T>::get(&delegate_authorization);
+			let constraints = DelegationConstraintsOf::<T> {
+				expires_at,
+				max_creates,
+				creates: previous.as_ref().map_or(0, |c| c.creates),
+				scope,
+			};
+
+			let previous_expiry = previous.and_then(|c| c.expires_at);
+			if previous_expiry != expires_at {
+				if let Some(expiry) = previous_expiry {
+					Self::unschedule_delegation_expiry(&delegate_authorization, expiry);
+				}
+				if let Some(expiry) = expires_at {
+					Self::schedule_delegation_expiry(&delegate_authorization, expiry)?;
+				}
+			}
+
+			if constraints.is_unconstrained() {
+				AuthorizationConstraints::<T>::remove(&delegate_authorization);
+			} else {
+				AuthorizationConstraints::<T>::insert(&delegate_authorization, constraints);
+			}
+
+			Self::update_activity(&space_id, IdentifierTypeOf::Auth, CallTypeOf::Authorization)?;
+
+			Self::deposit_event(Event::DelegationConstraintsSet {
+				space: space_id,
+				authorization: delegate_authorization,
+				expires_at,
+				max_creates,
+				scope,
+				authority: creator,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the schedule by which the capacity of a space is refilled.
+		///
+		/// Every `interval` blocks, starting from the current block, either
+		/// `amount` transactions are credited back to the space or, if
+		/// `reset_usage` is set, its usage is reset to zero. Refills still due
+		/// under a previous schedule are applied before it is replaced.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must pass the `ChainSpaceOrigin` check.
+		/// - `space_id`: The identifier of the space.
+		/// - `amount`: The number of transactions credited back on every refill.
+		/// - `interval`: The number of blocks between two refills.
+		/// - `reset_usage`: Whether the usage is reset to zero on every refill.
+		///
+		/// # Errors
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `ArchivedSpace`: If the space is archived.
+		/// - `SpaceNotApproved`: If the space has not been approved.
+		/// - `InvalidRefillInterval`: If `interval` is zero.
+		///
+		/// # Events
+		/// - `CapacityRefillSet`: Emitted when the schedule has been set.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_capacity_refill())]
+		pub fn set_capacity_refill(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			amount: u64,
+			interval: BlockNumberFor<T>,
+			reset_usage: bool,
+		) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			let space_details = Self::refill_usage(&space_id)?;
+			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
+			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
+			ensure!(!interval.is_zero(), Error::<T>::InvalidRefillInterval);
+
+			<CapacityRefills<T>>::insert(
+				&space_id,
+				CapacityRefillOf::<T> {
+					amount,
+					interval,
+					reset_usage,
+					last_refill: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Capacity)
+				.map_err(Error::<T>::from)?;
+
+			Self::deposit_event(Event::CapacityRefillSet {
+				space: space_id,
+				amount,
+				interval,
+				reset_usage,
+			});
+
+			Ok(())
+		}
+
+		/// Removes the capacity refill schedule of a space.
+		///
+		/// Refills still due under the schedule are applied before it is
+		/// removed.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must pass the `ChainSpaceOrigin` check.
+		/// - `space_id`: The identifier of the space.
+		///
+		/// # Errors
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `CapacityRefillNotFound`: If the space has no refill schedule.
+		///
+		/// # Events
+		/// - `CapacityRefillRemoved`: Emitted when the schedule has been removed.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_capacity_refill())]
+		pub fn remove_capacity_refill(origin: OriginFor<T>, space_id: SpaceIdOf) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				<CapacityRefills<T>>::contains_key(&space_id),
+				Error::<T>::CapacityRefillNotFound
+			);
+			Self::refill_usage(&space_id)?;
+			<CapacityRefills<T>>::remove(&space_id);
+
+			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Capacity)
+				.map_err(Error::<T>::from)?;
+
+			Self::deposit_event(Event::CapacityRefillRemoved { space: space_id });
+
+			Ok(())
+		}
+
+		/// Purchases additional transaction capacity for a space.
+		///
+		/// The sender of the call pays `CapacityPrice` for every transaction
+		/// added to the capacity of the space. The payment is handed over to
+		/// `CapacityPayment`. Only the creator of a top-level space with a
+		/// limited capacity may purchase capacity; sub-spaces draw their
+		/// capacity from their parent.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the creator of the space.
+		/// - `space_id`: The identifier of the space.
+		/// - `amount`: The number of transactions added to the capacity of the space.
+		///
+		/// # Errors
+		/// - `SpaceNotFound`: If the space does not exist.
+		/// - `UnauthorizedOperation`: If the caller is not the creator of the space.
+		/// - `ArchivedSpace`: If the space is archived.
+		/// - `SpaceNotApproved`: If the space has not been approved.
+		/// - `CapacityNotPurchasable`: If the space is a sub-space or its capacity is unlimited.
+		/// - `TypeCapacityOverflow`: If the new capacity overflows.
+		/// - Propagates errors from the currency if the payment cannot be made.
+		///
+		/// # Events
+		/// - `CapacityPurchased`: Emitted when the capacity has been purchased.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::purchase_capacity())]
+		pub fn purchase_capacity(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			amount: u64,
+		) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let creator = source.subject();
+			let payer = source.sender();
+
+			let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(space_details.creator == creator, Error::<T>::UnauthorizedOperation);
+			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
+			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
+			ensure!(
+				space_id == space_details.parent && space_details.txn_capacity != 0,
+				Error::<T>::CapacityNotPurchasable
+			);
+
+			let txn_capacity = space_details
+				.txn_capacity
+				.checked_add(amount)
+				.ok_or(Error::<T>::TypeCapacityOverflow)?;
+
+			let units: BalanceOf<T> = amount.unique_saturated_into();
+			let cost = T::CapacityPrice::get().saturating_mul(units);
+			let payment = CurrencyOf::<T>::withdraw(
+				&payer,
+				cost,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			T::CapacityPayment::on_unbalanced(payment);
+
+			<Spaces<T>>::insert(&space_id, SpaceDetailsOf::<T> { txn_capacity, ..space_details });
+
+			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Capacity)
+				.map_err(Error::<T>::from)?;
+
+			Self::deposit_event(Event::CapacityPurchased { space: space_id, amount, cost, payer });
+
+			Ok(())
+		}
+
+		/// Sets the maximum number of transactions a delegate may make in a
+		/// space.
+		///
+		/// Every transaction authorized through one of the delegate's
+		/// authorizations counts towards the cap, including the transactions
+		/// made before the cap was set. Passing no cap lets the delegate
+		/// transact until the capacity of the space is used up.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed by an admin of the
+		///   space.
+		/// - `space_id`: The identifier of the space.
+		/// - `delegate_authorization`: The authorization ID of the delegation to cap.
+		/// - `txn_cap`: The maximum number of transactions allowed to the delegate, if any.
+		/// - `authorization`: The authorization ID used to validate the caller's admin permission.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the space, the delegation
+		///   belongs to another space or is the caller's own authorization.
+		/// - `AuthorizationNotFound`: If the delegation does not exist.
+		///
+		/// # Events
+		/// - `DelegateUsageCapSet`: Emitted when the cap has been changed.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_delegate_usage_cap())]
+		pub fn set_delegate_usage_cap(
+			origin: OriginFor<T>,
+			space_id: SpaceIdOf,
+			delegate_authorization: AuthorizationIdOf,
+			txn_cap: Option<u64>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+
+			ensure!(auth_space_id == space_id, Error::<T>::UnauthorizedOperation);
+			ensure!(delegate_authorization != authorization, Error::<T>::UnauthorizedOperation);
+
+			let delegation = <Authorizations<T>>::get(&delegate_authorization)
+				.ok_or(Error::<T>::AuthorizationNotFound)?;
+			ensure!(delegation.space_id == space_id, Error::<T>::UnauthorizedOperation);
+
+			DelegateUsages::<T>::mutate(&space_id, &delegation.delegate, |usage| {
+				usage.txn_cap = txn_cap
+			});
+
+			Self::update_activity(&space_id, IdentifierTypeOf::Auth, CallTypeOf::Authorization)?;
+
+			Self::deposit_event(Event::DelegateUsageCapSet {
+				space: space_id,
+				delegate: delegation.delegate,
+				txn_cap,
+				authority: creator,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -1258,7 +1750,9 @@ impl<T: Config> Pallet<T> {
 	/// Verifies if a given delegate has a specific authorization.
 	///
 	/// This function checks if the provided delegate is associated with the
-	/// given authorization ID and has the 'ASSERT' permission.
+	/// given authorization ID and has the 'ASSERT' permission, and that the
+	/// delegate has not reached its usage cap in the space. The transaction
+	/// is not counted here; callers record it with `record_delegate_usage`.
 	pub fn ensure_authorization_origin(
 		authorization_id: &AuthorizationIdOf,
 		delegate: &SpaceCreatorOf<T>,
@@ -1268,11 +1762,57 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_transaction(&d.space_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+		ensure!(
+			DelegateUsages::<T>::get(&d.space_id, delegate).has_headroom(1),
+			Error::<T>::DelegateUsageCapExceeded
+		);
+
+		Ok(d.space_id)
+	}
+
+	/// Verifies that a delegate may make `count` new creations in a space.
+	///
+	/// This function performs the same checks as `ensure_authorization_origin`,
+	/// but also accepts `CreateOnly` delegations and checks that `count`
+	/// creations fit in the quota of the delegation and the usage cap of the
+	/// delegate. Nothing is counted here; callers record the creations they
+	/// actually made with `record_delegated_creates`.
+	pub fn ensure_authorization_create_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &SpaceCreatorOf<T>,
+		count: u32,
+	) -> Result<SpaceIdOf, Error<T>> {
+		let d =
+			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+
+		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+
+		let constraints =
+			Self::ensure_delegation_permits(authorization_id, DelegationScope::CreateOnly)?;
+
+		Self::increment_usage(&d.space_id)?;
+
+		Self::validate_space_for_transaction(&d.space_id)?;
+
+		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+
+		let within_quota = constraints.map_or(true, |c| {
+			c.max_creates.map_or(true, |max_creates| {
+				c.creates.checked_add(count).is_some_and(|creates| creates <= max_creates)
+			})
+		});
+		ensure!(within_quota, Error::<T>::DelegationQuotaExhausted);
+		ensure!(
+			DelegateUsages::<T>::get(&d.space_id, delegate).has_headroom(count.into()),
+			Error::<T>::DelegateUsageCapExceeded
+		);
 
 		Ok(d.space_id)
 	}
@@ -1286,11 +1826,17 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_restore_transaction(&d.space_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+		ensure!(
+			DelegateUsages::<T>::get(&d.space_id, delegate).has_headroom(1),
+			Error::<T>::DelegateUsageCapExceeded
+		);
 
 		Ok(d.space_id)
 	}
@@ -1310,6 +1856,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_transaction(&d.space_id)?;
@@ -1335,6 +1883,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::increment_usage(&d.space_id)?;
 
 		Self::validate_space_for_transaction(&d.space_id)?;
@@ -1362,6 +1912,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_space_for_transaction(&d.space_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1369,6 +1921,154 @@ impl<T: Config> Pallet<T> {
 		Ok(d.space_id)
 	}
 
+	/// Counts `count` transactions made by a delegate in a space.
+	///
+	/// Called by the dispatchables that act on space content on behalf of a
+	/// delegate; admin operations are not counted. Fails if the transactions
+	/// exceed the usage cap of the delegate in the space.
+	pub fn record_delegate_usage(
+		space_id: &SpaceIdOf,
+		delegate: &SpaceCreatorOf<T>,
+		count: u32,
+	) -> Result<(), Error<T>> {
+		DelegateUsages::<T>::try_mutate(space_id, delegate, |usage| {
+			ensure!(usage.has_headroom(count.into()), Error::<T>::DelegateUsageCapExceeded);
+			usage.txn_count = usage.txn_count.saturating_add(count.into());
+			Ok(())
+		})
+	}
+
+	/// Returns the usage of a space by each of its delegates.
+	pub fn delegate_usage(space_id: &SpaceIdOf) -> Vec<(SpaceCreatorOf<T>, DelegateUsage)> {
+		DelegateUsages::<T>::iter_prefix(space_id).collect()
+	}
+
+	/// Ensures that a delegation has not lapsed and is scoped for an operation.
+	///
+	/// Delegations without constraints always pass. The constraints, if any,
+	/// are returned so that callers can account for creations.
+	pub fn ensure_delegation_permits(
+		authorization_id: &AuthorizationIdOf,
+		scope: DelegationScope,
+	) -> Result<Option<DelegationConstraintsOf<T>>, Error<T>> {
+		let Some(constraints) = AuthorizationConstraints::<T>::get(authorization_id) else {
+			return Ok(None);
+		};
+
+		ensure!(
+			!constraints.is_expired(&frame_system::Pallet::<T>::block_number()),
+			Error::<T>::DelegationExpired
+		);
+		ensure!(
+			scope == DelegationScope::CreateOnly || constraints.scope == DelegationScope::Full,
+			Error::<T>::DelegationScopeExceeded
+		);
+
+		Ok(Some(constraints))
+	}
+
+	/// Counts `count` creations made by a delegate towards its usage of the
+	/// space and towards the quota of its delegation.
+	///
+	/// Emits `DelegationLapsed` once the quota has been used up.
+	pub fn record_delegated_creates(
+		authorization_id: &AuthorizationIdOf,
+		space_id: &SpaceIdOf,
+		delegate: &SpaceCreatorOf<T>,
+		count: u32,
+	) -> Result<(), Error<T>> {
+		Self::record_delegate_usage(space_id, delegate, count)?;
+
+		let Some(mut constraints) = AuthorizationConstraints::<T>::get(authorization_id) else {
+			return Ok(());
+		};
+		let Some(max_creates) = constraints.max_creates else {
+			return Ok(());
+		};
+
+		let creates = constraints
+			.creates
+			.checked_add(count)
+			.filter(|creates| *creates <= max_creates)
+			.ok_or(Error::<T>::DelegationQuotaExhausted)?;
+		constraints.creates = creates;
+		AuthorizationConstraints::<T>::insert(authorization_id, constraints);
+
+		if creates == max_creates {
+			Self::deposit_event(Event::DelegationLapsed {
+				space: space_id.clone(),
+				authorization: authorization_id.clone(),
+				reason: DelegationLapse::QuotaExhausted,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Removes the constraints of a delegation along with its scheduled expiry.
+	fn clear_delegation_constraints(authorization_id: &AuthorizationIdOf) {
+		if let Some(expiry) =
+			AuthorizationConstraints::<T>::take(authorization_id).and_then(|c| c.expires_at)
+		{
+			Self::unschedule_delegation_expiry(authorization_id, expiry);
+		}
+	}
+
+	/// Schedules a delegation to lapse at block `expires_at`.
+	fn schedule_delegation_expiry(
+		authorization_id: &AuthorizationIdOf,
+		expires_at: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		schedule::schedule::<NextDelegationExpirySweep<T>, DelegationExpiries<T>, _, _, _>(
+			expires_at,
+			authorization_id.clone(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+		.map_err(|_| Error::<T>::TooManyDelegationExpiriesInBlock)
+	}
+
+	/// Removes a delegation from the expiries scheduled at block `expires_at`.
+	fn unschedule_delegation_expiry(
+		authorization_id: &AuthorizationIdOf,
+		expires_at: BlockNumberFor<T>,
+	) {
+		schedule::unschedule::<DelegationExpiries<T>, _, _, _>(expires_at, authorization_id);
+	}
+
+	/// Reports the delegations scheduled to lapse up to and including block
+	/// `now`, as far as `remaining_weight` allows.
+	pub(crate) fn sweep_delegation_expiries(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		let db_weight = T::DbWeight::get();
+		schedule::sweep::<NextDelegationExpirySweep<T>, DelegationExpiries<T>, _, _, _>(
+			now,
+			remaining_weight,
+			db_weight,
+			db_weight.reads(2),
+			|authorization_id, at| Self::lapse_delegation(&authorization_id, at),
+		)
+	}
+
+	/// Reports a delegation as lapsed if it is still scheduled to expire at
+	/// block `at`.
+	fn lapse_delegation(authorization_id: &AuthorizationIdOf, at: BlockNumberFor<T>) {
+		let scheduled = AuthorizationConstraints::<T>::get(authorization_id)
+			.is_some_and(|constraints| constraints.expires_at == Some(at));
+		if !scheduled {
+			return;
+		}
+
+		if let Some(d) = <Authorizations<T>>::get(authorization_id) {
+			Self::deposit_event(Event::DelegationLapsed {
+				space: d.space_id,
+				authorization: authorization_id.clone(),
+				reason: DelegationLapse::Expired,
+			});
+		}
+	}
+
 	/// Validates that a space is eligible for a new transaction.
 	///
 	/// This function ensures that a space is not archived, is approved, and has
@@ -1376,7 +2076,7 @@ impl<T: Config> Pallet<T> {
 	/// recorded. It is a critical check that enforces the integrity and
 	/// constraints of space usage on the chain.
 	pub fn validate_space_for_transaction(space_id: &SpaceIdOf) -> Result<(), Error<T>> {
-		let space_details = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+		let (space_details, _) = Self::space_with_refills(space_id)?;
 
 		// Ensure the space is not archived.
 		ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
@@ -1384,6 +2084,9 @@ impl<T: Config> Pallet<T> {
 		// Ensure the space is approved for transactions.
 		ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
+		// Ensure no ancestor of the space is archived or unapproved.
+		Self::ensure_active_ancestors(&Self::ancestors(space_id, &space_details))?;
+
 		// Ensure the space has not exceeded its capacity limit.
 		if space_details.txn_capacity == 0 || space_details.txn_count < space_details.txn_capacity {
 			Ok(())
@@ -1399,7 +2102,7 @@ impl<T: Config> Pallet<T> {
 	/// performing any administrative actions on a space to ensure
 	/// that the space is in a proper state for such transactions.
 	pub fn validate_space_for_restore_transaction(space_id: &SpaceIdOf) -> Result<(), Error<T>> {
-		let space_details = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+		let (space_details, _) = Self::space_with_refills(space_id)?;
 
 		// Ensure the space is archived.
 		ensure!(space_details.archive, Error::<T>::SpaceNotArchived);
@@ -1427,7 +2130,7 @@ impl<T: Config> Pallet<T> {
 		space_id: &SpaceIdOf,
 		entries: u16,
 	) -> Result<(), Error<T>> {
-		let space_details = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+		let (space_details, _) = Self::space_with_refills(space_id)?;
 
 		// Ensure the space is not archived.
 		ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
@@ -1435,6 +2138,9 @@ impl<T: Config> Pallet<T> {
 		// Ensure the space is approved for adding new entries.
 		ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
+		// Ensure no ancestor of the space is archived or unapproved.
+		Self::ensure_active_ancestors(&Self::ancestors(space_id, &space_details))?;
+
 		// Calculate the new usage to check against the capacity.
 		let new_usage = space_details
 			.txn_count
@@ -1456,14 +2162,7 @@ impl<T: Config> Pallet<T> {
 	/// typically when a new delegate or entry is added. It ensures that the
 	/// usage count does not overflow.
 	pub fn increment_usage(tx_id: &SpaceIdOf) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_add(1);
-				Ok(())
-			} else {
-				Err(Error::<T>::SpaceNotFound)
-			}
-		})
+		Self::mutate_usage(tx_id, |txn_count| txn_count.saturating_add(1))
 	}
 
 	/// Decrements the usage count of a space by one unit.
@@ -1472,14 +2171,7 @@ impl<T: Config> Pallet<T> {
 	/// typically when a delegate or entry is removed. It ensures that the usage
 	/// count does not underflow.
 	pub fn decrement_usage(tx_id: &SpaceIdOf) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_sub(1);
-				Ok(())
-			} else {
-				Err(Error::<T>::SpaceNotFound)
-			}
-		})
+		Self::mutate_usage(tx_id, |txn_count| txn_count.saturating_sub(1))
 	}
 
 	/// Increments the usage count of a space by a specified unit.
@@ -1488,14 +2180,7 @@ impl<T: Config> Pallet<T> {
 	/// specified in `increment`, which is useful for batch operations.
 	/// It ensures that the usage count does not overflow.
 	pub fn increment_usage_entries(tx_id: &SpaceIdOf, increment: u16) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_add(increment.into());
-				Ok(())
-			} else {
-				Err(Error::<T>::SpaceNotFound)
-			}
-		})
+		Self::mutate_usage(tx_id, |txn_count| txn_count.saturating_add(increment.into()))
 	}
 
 	/// Decrements the usage count of a space by a specified amount.
@@ -1504,14 +2189,169 @@ impl<T: Config> Pallet<T> {
 	/// specified in `decrement`, which is useful for batch removals. It ensures
 	/// that the usage count does not underflow.
 	pub fn decrement_usage_entries(tx_id: &SpaceIdOf, decrement: u16) -> Result<(), Error<T>> {
-		Spaces::<T>::try_mutate(tx_id, |space_opt| {
-			if let Some(space_details) = space_opt {
-				space_details.txn_count = space_details.txn_count.saturating_sub(decrement.into());
-				Ok(())
-			} else {
-				Err(Error::<T>::SpaceNotFound)
+		Self::mutate_usage(tx_id, |txn_count| txn_count.saturating_sub(decrement.into()))
+	}
+
+	/// Returns the ancestors of a space, from its parent up to the top-level
+	/// space, as far as `MaxSpaceDepth` levels.
+	fn ancestors(
+		space_id: &SpaceIdOf,
+		space_details: &SpaceDetailsOf<T>,
+	) -> Vec<SpaceDetailsOf<T>> {
+		let mut ancestors = Vec::new();
+		let mut current = space_id.clone();
+		let mut parent = space_details.parent.clone();
+
+		while parent != current && (ancestors.len() as u32) < T::MaxSpaceDepth::get() {
+			let Some(parent_details) = Spaces::<T>::get(&parent) else {
+				break;
+			};
+			current = core::mem::replace(&mut parent, parent_details.parent.clone());
+			ancestors.push(parent_details);
+		}
+
+		ancestors
+	}
+
+	/// Ensures none of the given ancestors of a space is archived or
+	/// unapproved.
+	fn ensure_active_ancestors(ancestors: &[SpaceDetailsOf<T>]) -> Result<(), Error<T>> {
+		for ancestor in ancestors {
+			ensure!(!ancestor.archive, Error::<T>::ArchivedSpace);
+			ensure!(ancestor.approved, Error::<T>::SpaceNotApproved);
+		}
+		Ok(())
+	}
+
+	/// Returns the details of a space with the archival and approval status
+	/// of its ancestors applied: a space is archived if any of its ancestors
+	/// is archived, and approved only if all of them are approved.
+	pub fn effective_space_details(space_id: &SpaceIdOf) -> Option<SpaceDetailsOf<T>> {
+		let mut space_details = Spaces::<T>::get(space_id)?;
+		for ancestor in Self::ancestors(space_id, &space_details) {
+			space_details.archive |= ancestor.archive;
+			space_details.approved &= ancestor.approved;
+		}
+		Some(space_details)
+	}
+
+	/// Ensures an unlimited space with sub-spaces keeps an unlimited
+	/// capacity, as its sub-spaces may be unlimited themselves.
+	fn ensure_sub_spaces_limited(
+		space_id: &SpaceIdOf,
+		space_details: &SpaceDetailsOf<T>,
+		new_txn_capacity: u64,
+	) -> Result<(), Error<T>> {
+		if space_details.txn_capacity == 0 && new_txn_capacity != 0 {
+			ensure!(
+				SubSpaces::<T>::iter_key_prefix(space_id).next().is_none(),
+				Error::<T>::InvalidSubSpaceCapacity
+			);
+		}
+		Ok(())
+	}
+
+	/// Moves the reservation of a sub-space in its parent from its current
+	/// capacity to `new_txn_capacity`.
+	fn update_parent_reserve(
+		space_details: &SpaceDetailsOf<T>,
+		parent_details: SpaceDetailsOf<T>,
+		new_txn_capacity: u64,
+	) -> Result<(), Error<T>> {
+		let txn_reserve = parent_details
+			.txn_reserve
+			.saturating_sub(space_details.txn_capacity)
+			.saturating_add(new_txn_capacity);
+
+		if parent_details.txn_capacity != 0 {
+			ensure!(new_txn_capacity != 0, Error::<T>::InvalidSubSpaceCapacity);
+			// Ensure the parent can still accommodate its usage and reservations
+			ensure!(
+				parent_details.txn_capacity >= parent_details.txn_count.saturating_add(txn_reserve),
+				Error::<T>::CapacityLessThanUsage
+			);
+		}
+
+		<Spaces<T>>::insert(
+			&space_details.parent,
+			SpaceDetailsOf::<T> { txn_reserve, ..parent_details },
+		);
+
+		Ok(())
+	}
+
+	/// Returns the identifiers of the sub-spaces directly below a space.
+	pub fn sub_spaces(space_id: &SpaceIdOf) -> Vec<SpaceIdOf> {
+		SubSpaces::<T>::iter_key_prefix(space_id).collect()
+	}
+
+	/// Returns the usage of a space aggregated over all of its descendants.
+	pub fn aggregated_usage(space_id: &SpaceIdOf) -> Option<SpaceUsage> {
+		let space_details = Spaces::<T>::get(space_id)?;
+		let mut usage = SpaceUsage {
+			txn_capacity: space_details.txn_capacity,
+			txn_reserve: space_details.txn_reserve,
+			txn_count: space_details.txn_count,
+			total_txn_count: space_details.txn_count,
+			sub_spaces: 0,
+		};
+
+		let mut pending = vec![(space_id.clone(), 0u32)];
+		while let Some((parent, depth)) = pending.pop() {
+			if depth >= T::MaxSpaceDepth::get() {
+				continue;
 			}
-		})
+			for child in SubSpaces::<T>::iter_key_prefix(&parent) {
+				if let Some(child_details) = Spaces::<T>::get(&child) {
+					usage.total_txn_count =
+						usage.total_txn_count.saturating_add(child_details.txn_count);
+					usage.sub_spaces = usage.sub_spaces.saturating_add(1);
+					pending.push((child, depth + 1));
+				}
+			}
+		}
+
+		Some(usage)
+	}
+
+	/// Returns the details of a space with the refills due at the current
+	/// block applied to its usage, along with the refill schedule to store if
+	/// any refill was applied.
+	fn space_with_refills(
+		space_id: &SpaceIdOf,
+	) -> Result<(SpaceDetailsOf<T>, Option<CapacityRefillOf<T>>), Error<T>> {
+		let mut space_details = Spaces::<T>::get(space_id).ok_or(Error::<T>::SpaceNotFound)?;
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let refill = CapacityRefills::<T>::get(space_id).and_then(|mut refill| {
+			space_details.txn_count = refill.refill(space_details.txn_count, now)?;
+			Some(refill)
+		});
+
+		Ok((space_details, refill))
+	}
+
+	/// Applies and stores the refills due at the current block to the usage of
+	/// a space, returning its updated details.
+	fn refill_usage(space_id: &SpaceIdOf) -> Result<SpaceDetailsOf<T>, Error<T>> {
+		let (space_details, refill) = Self::space_with_refills(space_id)?;
+		if let Some(refill) = refill {
+			CapacityRefills::<T>::insert(space_id, refill);
+			Spaces::<T>::insert(space_id, &space_details);
+		}
+		Ok(space_details)
+	}
+
+	/// Changes the usage count of a space with `f`, after applying the
+	/// refills due at the current block.
+	fn mutate_usage(tx_id: &SpaceIdOf, f: impl FnOnce(u64) -> u64) -> Result<(), Error<T>> {
+		let (mut space_details, refill) = Self::space_with_refills(tx_id)?;
+		if let Some(refill) = refill {
+			CapacityRefills::<T>::insert(tx_id, refill);
+		}
+		space_details.txn_count = f(space_details.txn_count);
+		Spaces::<T>::insert(tx_id, space_details);
+		Ok(())
 	}
 
 	/// Updates the global timeline with a new activity event for a space.
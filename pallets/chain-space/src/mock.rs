@@ -31,10 +31,12 @@ type Signature = MultiSignature;
 type AccountPublic = <Signature as Verify>::Signer;
 pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
+pub(crate) type Balance = u128;
 
 frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
+		Balances: pallet_balances,
 		Space: pallet_chain_space,
 		Identifier: identifier,
 		MockOrigin: mock_origin,
@@ -53,6 +55,18 @@ impl frame_system::Config for Test {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type SS58Prefix = SS58Prefix;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
 }
 
 impl mock_origin::Config for Test {
@@ -73,6 +87,12 @@ impl IsPermissioned for NetworkPermission {
 	}
 }
 
+parameter_types! {
+	pub const MaxSpaceDelegationExpiriesPerBlock: u32 = 2;
+	pub const CapacityPrice: Balance = 2;
+	pub const MaxSpaceDepth: u32 = 3;
+}
+
 impl pallet_chain_space::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
@@ -81,15 +101,25 @@ impl pallet_chain_space::Config for Test {
 	type MaxSpaceDelegates = MaxSpaceDelegates;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type MaxDelegationExpiriesPerBlock = MaxSpaceDelegationExpiriesPerBlock;
+	type MaxSpaceDepth = MaxSpaceDepth;
+	type Currency = Balances;
+	type CapacityPrice = CapacityPrice;
+	type CapacityPayment = ();
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MaxEventsHistory: u32 = 6u32;
+	pub const TimelineOverflow: identifier::TimelineOverflowMode =
+		identifier::TimelineOverflowMode::Drop;
+	pub const MaxArchivedEventsPerPage: u32 = 10u32;
 }
 
 impl identifier::Config for Test {
 	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
 }
 
 parameter_types! {
@@ -108,10 +138,16 @@ pub fn space_events_since_last_call() -> Vec<super::Event<Test>> {
 	events.into_iter().skip(already_seen as usize).collect()
 }
 
+pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
+pub(crate) const INITIAL_BALANCE: Balance = 100;
+
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-	let t: sp_runtime::Storage =
-		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(ACCOUNT_00, INITIAL_BALANCE)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 	let mut ext = sp_io::TestExternalities::new(t);
 	#[cfg(feature = "runtime-benchmarks")]
 	let keystore = sp_keystore::testing::MemoryKeystore::new();
@@ -2,7 +2,7 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, error::BadOrigin};
+use frame_support::{assert_err, assert_ok, error::BadOrigin, traits::Hooks};
 use frame_system::RawOrigin;
 use sp_runtime::{traits::Hash, AccountId32};
 use sp_std::prelude::*;
@@ -18,7 +18,6 @@ pub fn generate_authorization_id<T: Config>(digest: &SpaceCodeOf<T>) -> Authoriz
 
 pub(crate) const DID_00: SubjectId = SubjectId(AccountId32::new([1u8; 32]));
 pub(crate) const DID_01: SubjectId = SubjectId(AccountId32::new([2u8; 32]));
-pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 
 //TEST FUNCTION FOR ADD ADMIN DELEGATE
 
@@ -1248,3 +1247,617 @@ fn add_delegator_should_fail_for_space_creator_delegating_themselves() {
 		);
 	});
 }
+
+#[test]
+fn delegation_constraints_should_limit_expiry_quota_and_scope() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let space = [2u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+		));
+
+		assert_err!(
+			Space::set_delegation_constraints(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				delegate_authorization_id.clone(),
+				Some(1),
+				None,
+				DelegationScope::Full,
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidDelegationExpiry
+		);
+
+		assert_ok!(Space::set_delegation_constraints(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(10),
+			Some(2),
+			DelegationScope::CreateOnly,
+			authorization_id.clone(),
+		));
+
+		assert_err!(
+			Space::ensure_authorization_origin(&delegate_authorization_id, &delegate),
+			Error::<Test>::DelegationScopeExceeded
+		);
+		assert_ok!(Space::ensure_authorization_create_origin(
+			&delegate_authorization_id,
+			&delegate,
+			2
+		));
+		assert_ok!(Space::record_delegated_creates(
+			&delegate_authorization_id,
+			&space_id,
+			&delegate,
+			2
+		));
+		System::assert_last_event(
+			Event::DelegationLapsed {
+				space: space_id.clone(),
+				authorization: delegate_authorization_id.clone(),
+				reason: DelegationLapse::QuotaExhausted,
+			}
+			.into(),
+		);
+		assert_err!(
+			Space::ensure_authorization_create_origin(&delegate_authorization_id, &delegate, 1),
+			Error::<Test>::DelegationQuotaExhausted
+		);
+
+		System::set_block_number(10);
+		Space::on_idle(10, Weight::MAX);
+
+		System::assert_last_event(
+			Event::DelegationLapsed {
+				space: space_id.clone(),
+				authorization: delegate_authorization_id.clone(),
+				reason: DelegationLapse::Expired,
+			}
+			.into(),
+		);
+		assert_err!(
+			Space::ensure_authorization_create_origin(&delegate_authorization_id, &delegate, 1),
+			Error::<Test>::DelegationExpired
+		);
+
+		assert_ok!(Space::remove_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(AuthorizationConstraints::<Test>::get(&delegate_authorization_id).is_none());
+	});
+}
+
+#[test]
+fn delegate_usage_should_be_tracked_and_capped() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let delegate = DID_01;
+	let space = [2u8; 256].to_vec();
+	let capacity = 20u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+		));
+
+		assert_ok!(Space::ensure_authorization_origin(&delegate_authorization_id, &delegate));
+		assert_ok!(Space::record_delegate_usage(&space_id, &delegate, 1));
+		assert_ok!(Space::set_delegate_usage_cap(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(2),
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::DelegateUsageCapSet {
+				space: space_id.clone(),
+				delegate: delegate.clone(),
+				txn_cap: Some(2),
+				authority: creator.clone(),
+			}
+			.into(),
+		);
+
+		assert_ok!(Space::ensure_authorization_origin(&delegate_authorization_id, &delegate));
+		assert_ok!(Space::record_delegate_usage(&space_id, &delegate, 1));
+		assert_err!(
+			Space::ensure_authorization_origin(&delegate_authorization_id, &delegate),
+			Error::<Test>::DelegateUsageCapExceeded
+		);
+
+		// Checking an authorization counts nothing, and admin operations of
+		// the creator are not counted.
+		assert_eq!(
+			Space::delegate_usage(&space_id),
+			vec![(delegate.clone(), DelegateUsage { txn_count: 2, txn_cap: Some(2) })]
+		);
+
+		assert_ok!(Space::set_delegate_usage_cap(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate_authorization_id.clone(),
+			None,
+			authorization_id.clone(),
+		));
+		assert_ok!(Space::ensure_authorization_origin(&delegate_authorization_id, &delegate));
+		assert_ok!(Space::record_delegate_usage(&space_id, &delegate, 1));
+		assert_eq!(DelegateUsages::<Test>::get(&space_id, &delegate).txn_count, 3);
+
+		assert_ok!(Space::set_delegate_usage_cap(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(4),
+			authorization_id.clone(),
+		));
+		assert_err!(
+			Space::ensure_authorization_create_origin(&delegate_authorization_id, &delegate, 2),
+			Error::<Test>::DelegateUsageCapExceeded
+		);
+
+		assert_err!(
+			Space::set_delegate_usage_cap(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				authorization_id.clone(),
+				Some(1),
+				authorization_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+	});
+}
+
+#[test]
+fn capacity_refill_should_credit_back_usage_every_interval() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 3u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_err!(
+			Space::set_capacity_refill(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				2,
+				10,
+				false,
+			),
+			BadOrigin
+		);
+		assert_err!(
+			Space::set_capacity_refill(RawOrigin::Root.into(), space_id.clone(), 2, 0, false),
+			Error::<Test>::InvalidRefillInterval
+		);
+		assert_ok!(Space::set_capacity_refill(
+			RawOrigin::Root.into(),
+			space_id.clone(),
+			2,
+			10,
+			false
+		));
+
+		assert_ok!(Space::increment_usage_entries(&space_id, 3));
+		assert_err!(
+			Space::validate_space_for_transaction(&space_id),
+			Error::<Test>::CapacityLimitExceeded
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Space::validate_space_for_transaction_entries(&space_id, 2));
+		assert_err!(
+			Space::validate_space_for_transaction_entries(&space_id, 3),
+			Error::<Test>::CapacityLimitExceeded
+		);
+
+		assert_ok!(Space::increment_usage(&space_id));
+		assert_eq!(Spaces::<Test>::get(&space_id).unwrap().txn_count, 2);
+		assert_eq!(CapacityRefills::<Test>::get(&space_id).unwrap().last_refill, 11);
+
+		// Two intervals have passed, crediting back more than the usage.
+		System::set_block_number(31);
+		assert_ok!(Space::increment_usage(&space_id));
+		assert_eq!(Spaces::<Test>::get(&space_id).unwrap().txn_count, 1);
+
+		assert_ok!(Space::remove_capacity_refill(RawOrigin::Root.into(), space_id.clone()));
+		System::assert_last_event(Event::CapacityRefillRemoved { space: space_id.clone() }.into());
+		assert_err!(
+			Space::remove_capacity_refill(RawOrigin::Root.into(), space_id.clone()),
+			Error::<Test>::CapacityRefillNotFound
+		);
+
+		System::set_block_number(41);
+		assert_ok!(Space::increment_usage(&space_id));
+		assert_eq!(Spaces::<Test>::get(&space_id).unwrap().txn_count, 2);
+	});
+}
+
+#[test]
+fn capacity_refill_should_reset_usage_every_interval() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let capacity = 3u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::set_capacity_refill(
+			RawOrigin::Root.into(),
+			space_id.clone(),
+			0,
+			5,
+			true
+		));
+		System::assert_last_event(
+			Event::CapacityRefillSet {
+				space: space_id.clone(),
+				amount: 0,
+				interval: 5,
+				reset_usage: true,
+			}
+			.into(),
+		);
+
+		assert_ok!(Space::increment_usage_entries(&space_id, 3));
+		System::set_block_number(5);
+		assert_err!(
+			Space::validate_space_for_transaction(&space_id),
+			Error::<Test>::CapacityLimitExceeded
+		);
+
+		System::set_block_number(6);
+		assert_ok!(Space::validate_space_for_transaction_entries(&space_id, 3));
+		assert_ok!(Space::increment_usage(&space_id));
+		assert_eq!(Spaces::<Test>::get(&space_id).unwrap().txn_count, 1);
+	});
+}
+
+#[test]
+fn purchasing_space_capacity_should_charge_the_sender() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let space = [2u8; 256].to_vec();
+	let subspace = [3u8; 256].to_vec();
+	let capacity = 3u64;
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+	let subspace_digest = <Test as frame_system::Config>::Hashing::hash(&subspace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let subspace_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&subspace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+	let subspace_id: SpaceIdOf = generate_space_id::<Test>(&subspace_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_err!(
+			Space::purchase_capacity(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				5,
+			),
+			Error::<Test>::SpaceNotApproved
+		);
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_err!(
+			Space::purchase_capacity(
+				DoubleOrigin(author.clone(), DID_01).into(),
+				space_id.clone(),
+				5,
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+		assert_err!(
+			Space::purchase_capacity(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				space_id.clone(),
+				60,
+			),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Space::purchase_capacity(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			5,
+		));
+		let cost = CapacityPrice::get() * 5;
+		System::assert_last_event(
+			Event::CapacityPurchased {
+				space: space_id.clone(),
+				amount: 5,
+				cost,
+				payer: author.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(Spaces::<Test>::get(&space_id).unwrap().txn_capacity, 8);
+		assert_eq!(Balances::free_balance(&author), INITIAL_BALANCE - cost);
+		assert_eq!(Balances::total_issuance(), INITIAL_BALANCE - cost);
+
+		assert_ok!(Space::subspace_create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			subspace_digest,
+			Some(2),
+			space_id.clone(),
+		));
+		assert_err!(
+			Space::purchase_capacity(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				subspace_id.clone(),
+				1,
+			),
+			Error::<Test>::CapacityNotPurchasable
+		);
+	});
+}
+
+fn nested_space_ids(creator: &SubjectId, levels: u8) -> Vec<(SpaceCodeOf<Test>, SpaceIdOf)> {
+	(0..levels)
+		.map(|level| {
+			let space = [level + 2; 256].to_vec();
+			let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			let id_digest = <Test as frame_system::Config>::Hashing::hash(
+				&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			(space_digest, generate_space_id::<Test>(&id_digest))
+		})
+		.collect()
+}
+
+#[test]
+fn nested_sub_spaces_should_reserve_capacity_up_the_chain() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let spaces = nested_space_ids(&creator, 6);
+	let (root_digest, root_id) = spaces[0].clone();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			root_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), root_id.clone(), 20));
+
+		for (level, count) in [(1, 10u64), (2, 5), (3, 2)] {
+			assert_ok!(Space::subspace_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				spaces[level].0,
+				Some(count),
+				spaces[level - 1].1.clone(),
+			));
+		}
+
+		assert_err!(
+			Space::subspace_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				spaces[4].0,
+				Some(1),
+				spaces[3].1.clone(),
+			),
+			Error::<Test>::SpaceDepthExceeded
+		);
+		assert_err!(
+			Space::subspace_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				spaces[5].0,
+				Some(0),
+				spaces[1].1.clone(),
+			),
+			Error::<Test>::InvalidSubSpaceCapacity
+		);
+		assert_err!(
+			Space::subspace_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				spaces[5].0,
+				Some(5),
+				spaces[1].1.clone(),
+			),
+			Error::<Test>::CapacityLimitExceeded
+		);
+
+		assert_err!(
+			Space::update_transaction_capacity_sub(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				spaces[3].1.clone(),
+				0,
+			),
+			Error::<Test>::InvalidSubSpaceCapacity
+		);
+		assert_ok!(Space::update_transaction_capacity_sub(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			spaces[3].1.clone(),
+			3,
+		));
+		assert_eq!(Spaces::<Test>::get(&spaces[2].1).unwrap().txn_reserve, 3);
+		assert_err!(
+			Space::update_transaction_capacity_sub(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				spaces[3].1.clone(),
+				5,
+			),
+			Error::<Test>::CapacityLessThanUsage
+		);
+
+		assert_eq!(Space::sub_spaces(&root_id), vec![spaces[1].1.clone()]);
+		assert_eq!(
+			Space::aggregated_usage(&root_id),
+			Some(SpaceUsage {
+				txn_capacity: 20,
+				txn_reserve: 10,
+				txn_count: 1,
+				total_txn_count: 3,
+				sub_spaces: 3,
+			})
+		);
+	});
+}
+
+#[test]
+fn archiving_or_revoking_a_space_should_cascade_to_sub_spaces() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let spaces = nested_space_ids(&creator, 3);
+	let (root_digest, root_id) = spaces[0].clone();
+	let (_, child_id) = spaces[1].clone();
+	let (_, grandchild_id) = spaces[2].clone();
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&child_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let child_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			root_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), root_id.clone(), 20));
+		for level in 1..3 {
+			assert_ok!(Space::subspace_create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				spaces[level].0,
+				Some(5),
+				spaces[level - 1].1.clone(),
+			));
+		}
+
+		assert_ok!(Space::archive(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			child_id.clone(),
+			child_authorization_id.clone(),
+		));
+		assert!(!Spaces::<Test>::get(&grandchild_id).unwrap().archive);
+		assert!(Space::effective_space_details(&grandchild_id).unwrap().archive);
+		assert_err!(
+			Space::validate_space_for_transaction(&grandchild_id),
+			Error::<Test>::ArchivedSpace
+		);
+		assert_ok!(Space::validate_space_for_transaction(&root_id));
+
+		assert_ok!(Space::restore(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			child_id.clone(),
+			child_authorization_id,
+		));
+		assert_ok!(Space::validate_space_for_transaction(&grandchild_id));
+
+		assert_ok!(Space::approval_revoke(RawOrigin::Root.into(), root_id.clone()));
+		assert!(!Space::effective_space_details(&grandchild_id).unwrap().approved);
+		assert_err!(
+			Space::validate_space_for_transaction_entries(&grandchild_id, 1),
+			Error::<Test>::SpaceNotApproved
+		);
+
+		assert_ok!(Space::approval_restore(RawOrigin::Root.into(), root_id.clone()));
+		assert_ok!(Space::validate_space_for_transaction_entries(&grandchild_id, 1));
+	});
+}
@@ -24,7 +24,10 @@
 use bitflags::bitflags;
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{
+	traits::{Saturating, UniqueSaturatedInto, Zero},
+	RuntimeDebug,
+};
 
 bitflags! {
 	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -108,3 +111,139 @@ pub struct SpaceAuthorization<SpaceIdOf, SpaceCreatorOf, Permissions> {
 	pub permissions: Permissions,
 	pub delegator: SpaceCreatorOf,
 }
+
+/// Operations a delegation may be used for.
+///
+/// - `Full`: The delegate may use every operation its permissions grant.
+/// - `CreateOnly`: The delegate may only create new entries; updates, revocations and other
+///   changes are refused.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo,
+)]
+pub enum DelegationScope {
+	#[default]
+	Full,
+	CreateOnly,
+}
+
+/// Reason a delegation stopped granting access.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum DelegationLapse {
+	/// The delegation reached its expiry block.
+	Expired,
+	/// The delegate created the maximum number of entries allowed.
+	QuotaExhausted,
+}
+
+/// Limits placed on a space delegation in addition to its permissions.
+///
+/// ## Fields
+///
+/// - `expires_at`: (Optional) The block from which the delegation no longer grants access.
+/// - `max_creates`: (Optional) The maximum number of entries the delegate may create.
+/// - `creates`: The number of entries created against `max_creates` so far.
+/// - `scope`: The operations the delegation may be used for.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegationConstraints<BlockNumber> {
+	pub expires_at: Option<BlockNumber>,
+	pub max_creates: Option<u32>,
+	pub creates: u32,
+	pub scope: DelegationScope,
+}
+
+impl<BlockNumber: PartialOrd> DelegationConstraints<BlockNumber> {
+	/// Returns `true` if the delegation has expired at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().is_some_and(|expires_at| now >= expires_at)
+	}
+
+	/// Returns `true` if no limits are placed on the delegation.
+	pub fn is_unconstrained(&self) -> bool {
+		self.expires_at.is_none() &&
+			self.max_creates.is_none() &&
+			self.scope == DelegationScope::Full
+	}
+}
+
+/// Schedule by which the used capacity of a space is given back.
+///
+/// ## Fields
+///
+/// - `amount`: The number of transactions credited back to the space every `interval`.
+/// - `interval`: The number of blocks between two refills.
+/// - `reset_usage`: Whether the usage of the space is reset to zero on every refill, in which case
+///   `amount` is ignored.
+/// - `last_refill`: The block at which the space was last refilled.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CapacityRefill<BlockNumber> {
+	pub amount: u64,
+	pub interval: BlockNumber,
+	pub reset_usage: bool,
+	pub last_refill: BlockNumber,
+}
+
+impl<BlockNumber> CapacityRefill<BlockNumber>
+where
+	BlockNumber: Copy + PartialOrd + Saturating + Zero + UniqueSaturatedInto<u64>,
+	BlockNumber: sp_std::ops::Div<Output = BlockNumber> + sp_std::ops::Mul<Output = BlockNumber>,
+{
+	/// Applies the refills due up to block `now` to `txn_count`.
+	///
+	/// Returns the usage after the refills, or `None` if no refill is due.
+	pub fn refill(&mut self, txn_count: u64, now: BlockNumber) -> Option<u64> {
+		if self.interval.is_zero() || now < self.last_refill {
+			return None;
+		}
+
+		let periods = now.saturating_sub(self.last_refill) / self.interval;
+		if periods.is_zero() {
+			return None;
+		}
+		self.last_refill = self.last_refill.saturating_add(periods * self.interval);
+
+		if self.reset_usage {
+			Some(0)
+		} else {
+			let periods: u64 = periods.unique_saturated_into();
+			Some(txn_count.saturating_sub(self.amount.saturating_mul(periods)))
+		}
+	}
+}
+
+/// Usage of a space aggregated over its sub-spaces.
+///
+/// ## Fields
+///
+/// - `txn_capacity`: The capacity of the space. A value of zero denotes unlimited capacity.
+/// - `txn_reserve`: The capacity of the space reserved for its direct sub-spaces.
+/// - `txn_count`: The usage of the space itself.
+/// - `total_txn_count`: The usage of the space and all of its descendants.
+/// - `sub_spaces`: The number of descendants of the space.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SpaceUsage {
+	pub txn_capacity: u64,
+	pub txn_reserve: u64,
+	pub txn_count: u64,
+	pub total_txn_count: u64,
+	pub sub_spaces: u32,
+}
+
+/// Usage of a space by one of its delegates.
+///
+/// ## Fields
+///
+/// - `txn_count`: The number of transactions the delegate made in the space.
+/// - `txn_cap`: (Optional) The maximum number of transactions the delegate may make in the space.
+#[derive(Encode, Decode, Clone, Default, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegateUsage {
+	pub txn_count: u64,
+	pub txn_cap: Option<u64>,
+}
+
+impl DelegateUsage {
+	/// Returns `true` if the delegate may make `count` more transactions.
+	pub fn has_headroom(&self, count: u64) -> bool {
+		self.txn_cap
+			.map_or(true, |txn_cap| self.txn_count.saturating_add(count) <= txn_cap)
+	}
+}
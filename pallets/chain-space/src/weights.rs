@@ -62,6 +62,11 @@ pub trait WeightInfo {
 	fn approval_revoke() -> Weight;
 	fn approval_restore() -> Weight;
 	fn subspace_create() -> Weight;
+	fn set_delegation_constraints() -> Weight;
+	fn set_capacity_refill() -> Weight;
+	fn remove_capacity_refill() -> Weight;
+	fn purchase_capacity() -> Weight;
+	fn set_delegate_usage_cap() -> Weight;
 }
 
 /// Weights for `pallet_chain_space` using the CORD node and recommended hardware.
@@ -75,14 +80,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_470_000 picoseconds.
 		Weight::from_parts(41_550_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -92,14 +101,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn add_admin_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_851_000 picoseconds.
 		Weight::from_parts(41_909_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -109,14 +122,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn add_delegator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_730_000 picoseconds.
 		Weight::from_parts(41_720_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -126,19 +143,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn remove_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1061`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_860_000 picoseconds.
 		Weight::from_parts(41_970_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -149,21 +174,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3671`
 		// Minimum execution time: 27_280_000 picoseconds.
 		Weight::from_parts(27_960_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `494`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_331_000 picoseconds.
 		Weight::from_parts(23_009_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -171,14 +200,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn archive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `715`
 		//  Estimated: `3671`
 		// Minimum execution time: 31_040_000 picoseconds.
 		Weight::from_parts(31_750_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -186,84 +219,215 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `724`
 		//  Estimated: `3671`
 		// Minimum execution time: 30_580_000 picoseconds.
 		Weight::from_parts(31_880_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn update_transaction_capacity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_570_000 picoseconds.
 		Weight::from_parts(23_300_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn reset_transaction_count() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_330_000 picoseconds.
 		Weight::from_parts(23_040_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn approval_revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_000_000 picoseconds.
 		Weight::from_parts(22_650_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn approval_restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `512`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_150_000 picoseconds.
 		Weight::from_parts(22_570_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::SubSpaces` (r:0 w:1)
+	/// Proof: `ChainSpace::SubSpaces` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	fn subspace_create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `6352`
 		// Minimum execution time: 36_090_000 picoseconds.
 		Weight::from_parts(36_930_000, 6352)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:2 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationConstraints` (r:2 w:1)
+	/// Proof: `ChainSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::DelegationExpiries` (r:2 w:2)
+	/// Proof: `ChainSpace::DelegationExpiries` (`max_values`: None, `max_size`: Some(5058), added: 7533, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::NextDelegationExpirySweep` (r:1 w:1)
+	/// Proof: `ChainSpace::NextDelegationExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_delegation_constraints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1337`
+		//  Estimated: `16056`
+		// Minimum execution time: 75_670_000 picoseconds.
+		Weight::from_parts(78_825_000, 16056)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::CapacityRefills` (r:1 w:1)
+	/// Proof: `ChainSpace::CapacityRefills` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_capacity_refill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `708`
+		//  Estimated: `3671`
+		// Minimum execution time: 31_550_000 picoseconds.
+		Weight::from_parts(32_872_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ChainSpace::CapacityRefills` (r:1 w:1)
+	/// Proof: `ChainSpace::CapacityRefills` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn remove_capacity_refill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `677`
+		//  Estimated: `3671`
+		// Minimum execution time: 34_770_000 picoseconds.
+		Weight::from_parts(36_219_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn purchase_capacity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `3671`
+		// Minimum execution time: 34_850_000 picoseconds.
+		Weight::from_parts(36_308_000, 3671)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:2 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `ChainSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::DelegateUsages` (r:2 w:2)
+	/// Proof: `ChainSpace::DelegateUsages` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_delegate_usage_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `6308`
+		// Minimum execution time: 58_580_000 picoseconds.
+		Weight::from_parts(61_024_000, 6308)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -276,14 +440,18 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_470_000 picoseconds.
 		Weight::from_parts(41_550_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -293,14 +461,18 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn add_admin_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_851_000 picoseconds.
 		Weight::from_parts(41_909_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -310,14 +482,18 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn add_delegator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_730_000 picoseconds.
 		Weight::from_parts(41_720_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -327,19 +503,27 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn remove_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1061`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_860_000 picoseconds.
 		Weight::from_parts(41_970_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -350,21 +534,25 @@ impl WeightInfo for () {
 		//  Estimated: `3671`
 		// Minimum execution time: 27_280_000 picoseconds.
 		Weight::from_parts(27_960_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `494`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_331_000 picoseconds.
 		Weight::from_parts(23_009_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -372,14 +560,18 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn archive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `715`
 		//  Estimated: `3671`
 		// Minimum execution time: 31_040_000 picoseconds.
 		Weight::from_parts(31_750_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -387,82 +579,213 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `724`
 		//  Estimated: `3671`
 		// Minimum execution time: 30_580_000 picoseconds.
 		Weight::from_parts(31_880_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn update_transaction_capacity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_570_000 picoseconds.
 		Weight::from_parts(23_300_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn reset_transaction_count() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_330_000 picoseconds.
 		Weight::from_parts(23_040_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn approval_revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_000_000 picoseconds.
 		Weight::from_parts(22_650_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn approval_restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `512`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_150_000 picoseconds.
 		Weight::from_parts(22_570_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::SubSpaces` (r:0 w:1)
+	/// Proof: `ChainSpace::SubSpaces` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
 	fn subspace_create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `6352`
 		// Minimum execution time: 36_090_000 picoseconds.
 		Weight::from_parts(36_930_000, 6352)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:2 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationConstraints` (r:2 w:1)
+	/// Proof: `ChainSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::DelegationExpiries` (r:2 w:2)
+	/// Proof: `ChainSpace::DelegationExpiries` (`max_values`: None, `max_size`: Some(5058), added: 7533, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::NextDelegationExpirySweep` (r:1 w:1)
+	/// Proof: `ChainSpace::NextDelegationExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_delegation_constraints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1337`
+		//  Estimated: `16056`
+		// Minimum execution time: 75_670_000 picoseconds.
+		Weight::from_parts(78_825_000, 16056)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChainSpace::Spaces` (r:1 w:0)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::CapacityRefills` (r:1 w:1)
+	/// Proof: `ChainSpace::CapacityRefills` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_capacity_refill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `708`
+		//  Estimated: `3671`
+		// Minimum execution time: 31_550_000 picoseconds.
+		Weight::from_parts(32_872_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `ChainSpace::CapacityRefills` (r:1 w:1)
+	/// Proof: `ChainSpace::CapacityRefills` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn remove_capacity_refill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `677`
+		//  Estimated: `3671`
+		// Minimum execution time: 34_770_000 picoseconds.
+		Weight::from_parts(36_219_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn purchase_capacity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `672`
+		//  Estimated: `3671`
+		// Minimum execution time: 34_850_000 picoseconds.
+		Weight::from_parts(36_308_000, 3671)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:2 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `ChainSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::DelegateUsages` (r:2 w:2)
+	/// Proof: `ChainSpace::DelegateUsages` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_delegate_usage_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1143`
+		//  Estimated: `6308`
+		// Minimum execution time: 58_580_000 picoseconds.
+		Weight::from_parts(61_024_000, 6308)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -22,7 +22,10 @@
 use super::*;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::{
-	pallet_prelude::EnsureOrigin, sp_runtime::SaturatedConversion, traits::Get, BoundedVec,
+	pallet_prelude::EnsureOrigin,
+	sp_runtime::SaturatedConversion,
+	traits::{Currency, Get},
+	BoundedVec,
 };
 use frame_system::RawOrigin;
 use sp_runtime::app_crypto::sr25519;
@@ -42,6 +45,27 @@ fn generate_did_name_input(length: usize) -> Vec<u8> {
 	name_vec
 }
 
+const PARENT_NAME_PREFIX: &[u8] = b"acme";
+
+fn generate_parent_name_input() -> Vec<u8> {
+	[PARENT_NAME_PREFIX, b"@cord"].concat()
+}
+
+fn generate_sub_name_input(length: usize) -> Vec<u8> {
+	let suffix = [PARENT_NAME_PREFIX, b".cord"].concat();
+	let prefix_length = length.saturating_sub(suffix.len().saturating_add(1));
+	let mut name_vec = vec![b'b'; prefix_length];
+	name_vec.push(b'@');
+	name_vec.extend(suffix);
+	name_vec
+}
+
+fn make_free_for_did_name<T: Config>(account: &AccountIdOf<T>) {
+	let balance = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::minimum_balance()
+		.saturating_add(T::Deposit::get());
+	CurrencyOf::<T>::make_free_balance_be(account, balance);
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -54,6 +78,7 @@ benchmarks! {
 	register {
 		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
 		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		make_free_for_did_name::<T>(&caller);
 		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
 		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
 		let did_name_input_clone = did_name_input.clone();
@@ -68,6 +93,7 @@ benchmarks! {
 
 	release {
 		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		make_free_for_did_name::<T>(&caller);
 		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
 		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
 		let origin = T::EnsureOrigin::generate_origin(caller, owner.clone());
@@ -83,6 +109,7 @@ benchmarks! {
 	ban {
 		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
 		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		make_free_for_did_name::<T>(&caller);
 		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
 		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
 		let did_name_input_clone = did_name_input.clone();
@@ -115,6 +142,186 @@ benchmarks! {
 		assert!(Banned::<T>::get(&did_name).is_none());
 	}
 
+	change_deposit_owner {
+		let deposit_owner: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let caller: AccountIdOf<T> = account("caller", 1, CALLER_SEED);
+		make_free_for_did_name::<T>(&deposit_owner);
+		make_free_for_did_name::<T>(&caller);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_origin = T::EnsureOrigin::generate_origin(deposit_owner, owner.clone());
+		let origin = T::EnsureOrigin::generate_origin(caller.clone(), owner);
+
+		Pallet::<T>::register(did_origin, did_name_input.clone()).expect("Should register the did name.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		let deposit = NameDeposits::<T>::get(&did_name).expect("Deposit should be stored.");
+		assert_eq!(deposit.owner, caller);
+	}
+
+	transfer {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		make_free_for_did_name::<T>(&caller);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let target: DidNameOwnerOf<T> = account("owner", 1, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+	}: _<T::RuntimeOrigin>(origin, target.clone())
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		let transfer = PendingTransfers::<T>::get(&did_name).expect("Transfer should be stored.");
+		assert_eq!(transfer.target, target);
+	}
+
+	cancel_transfer {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		make_free_for_did_name::<T>(&caller);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let target: DidNameOwnerOf<T> = account("owner", 1, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+		Pallet::<T>::transfer(origin.clone(), target).expect("Should offer the did name.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(PendingTransfers::<T>::get(&did_name).is_none());
+	}
+
+	accept_transfer {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let target_caller: AccountIdOf<T> = account("caller", 1, CALLER_SEED);
+		make_free_for_did_name::<T>(&caller);
+		make_free_for_did_name::<T>(&target_caller);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let target: DidNameOwnerOf<T> = account("owner", 1, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let owner_origin = T::EnsureOrigin::generate_origin(caller, owner.clone());
+		let origin = T::EnsureOrigin::generate_origin(target_caller, target.clone());
+
+		Pallet::<T>::register(owner_origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+		Pallet::<T>::transfer(owner_origin, target.clone()).expect("Should offer the did name.");
+	}: _<T::RuntimeOrigin>(origin, did_name_input_clone)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(Names::<T>::get(&owner).is_none());
+		assert!(Names::<T>::get(&target).is_some());
+		assert!(PendingTransfers::<T>::get(&did_name).is_none());
+	}
+
+	renew {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		make_free_for_did_name::<T>(&caller);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		let expires_at = frame_system::Pallet::<T>::block_number() + T::RegistrationPeriod::get() + T::RegistrationPeriod::get();
+		assert_eq!(Expirations::<T>::get(&did_name), Some(expires_at));
+	}
+
+	issue_sub_name {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let parent_caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let caller: AccountIdOf<T> = account("caller", 1, CALLER_SEED);
+		make_free_for_did_name::<T>(&parent_caller);
+		make_free_for_did_name::<T>(&caller);
+		let parent_owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 1, OWNER_SEED);
+		let parent_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_parent_name_input()).expect("BoundedVec creation should not fail.");
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_sub_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let parent_origin = T::EnsureOrigin::generate_origin(parent_caller, parent_owner.clone());
+		let origin = T::EnsureOrigin::generate_origin(caller, parent_owner);
+
+		Pallet::<T>::register(parent_origin, parent_name_input).expect("Should register the parent did name.");
+	}: _<T::RuntimeOrigin>(origin, did_name_input_clone, owner.clone())
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert_eq!(Names::<T>::get(&owner), Some(did_name));
+	}
+
+	delegate_sub_name {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let parent_caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let caller: AccountIdOf<T> = account("caller", 1, CALLER_SEED);
+		make_free_for_did_name::<T>(&parent_caller);
+		make_free_for_did_name::<T>(&caller);
+		let parent_owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 1, OWNER_SEED);
+		let new_owner: DidNameOwnerOf<T> = account("owner", 2, OWNER_SEED);
+		let parent_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_parent_name_input()).expect("BoundedVec creation should not fail.");
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_sub_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let parent_origin = T::EnsureOrigin::generate_origin(parent_caller, parent_owner.clone());
+		let origin = T::EnsureOrigin::generate_origin(caller, parent_owner);
+
+		Pallet::<T>::register(parent_origin, parent_name_input).expect("Should register the parent did name.");
+		Pallet::<T>::issue_sub_name(origin.clone(), did_name_input.clone(), owner.clone()).expect("Should issue the nested did name.");
+	}: _<T::RuntimeOrigin>(origin, did_name_input_clone, new_owner.clone())
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(Names::<T>::get(&owner).is_none());
+		assert_eq!(Names::<T>::get(&new_owner), Some(did_name));
+	}
+
+	revoke_sub_name {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let parent_caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let caller: AccountIdOf<T> = account("caller", 1, CALLER_SEED);
+		make_free_for_did_name::<T>(&parent_caller);
+		make_free_for_did_name::<T>(&caller);
+		let parent_owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 1, OWNER_SEED);
+		let parent_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_parent_name_input()).expect("BoundedVec creation should not fail.");
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_sub_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let parent_origin = T::EnsureOrigin::generate_origin(parent_caller, parent_owner.clone());
+		let origin = T::EnsureOrigin::generate_origin(caller, parent_owner);
+
+		Pallet::<T>::register(parent_origin, parent_name_input).expect("Should register the parent did name.");
+		Pallet::<T>::issue_sub_name(origin.clone(), did_name_input.clone(), owner.clone()).expect("Should issue the nested did name.");
+	}: _<T::RuntimeOrigin>(origin, did_name_input_clone)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(Names::<T>::get(&owner).is_none());
+		assert!(Owner::<T>::get(&did_name).is_none());
+	}
+
+	release_lapsed_name {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let parent_caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let caller: AccountIdOf<T> = account("caller", 1, CALLER_SEED);
+		make_free_for_did_name::<T>(&parent_caller);
+		make_free_for_did_name::<T>(&caller);
+		let parent_owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 1, OWNER_SEED);
+		let parent_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_parent_name_input()).expect("BoundedVec creation should not fail.");
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_sub_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let parent_origin = T::EnsureOrigin::generate_origin(parent_caller, parent_owner.clone());
+		let origin = T::EnsureOrigin::generate_origin(caller, parent_owner);
+
+		Pallet::<T>::register(parent_origin.clone(), parent_name_input).expect("Should register the parent did name.");
+		Pallet::<T>::issue_sub_name(origin.clone(), did_name_input.clone(), owner.clone()).expect("Should issue the nested did name.");
+		Pallet::<T>::release(parent_origin).expect("Should release the parent did name.");
+	}: _<T::RuntimeOrigin>(origin, did_name_input_clone)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(Names::<T>::get(&owner).is_none());
+		assert!(Owner::<T>::get(&did_name).is_none());
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -27,12 +27,29 @@ use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 
 const NAME_SEPARATOR: u8 = b'@';
+const LABEL_SEPARATOR: u8 = b'.';
 const NETWORK_SUFFIX: &[u8] = b"cord";
 
+/// A name that can be nested under the name of another owner.
+pub trait HierarchicalName: Sized {
+	/// Return the name this name is nested under, or `None` for a top-level
+	/// name.
+	fn parent(&self) -> Option<Self>;
+}
+
 /// A DID name.
 ///
 /// It is bounded in size (inclusive range [MinLength, MaxLength]) and can only
 /// contain a subset of ASCII characters.
+///
+/// A top-level name has the form `prefix@cord`. A name nested under
+/// `parent@cord` has the form `prefix@parent.cord`, and one nested under
+/// `child@parent.cord` has the form `prefix@child.parent.cord`.
+///
+/// The labels after the `@` cannot contain a `.`, so the parent of a nested
+/// name is always named by the first of them: `x@foo.bar.cord` is nested under
+/// `foo@bar.cord` and never under `foo.bar@cord`. Names with a `.` in their
+/// prefix, such as `abc.123@cord`, can therefore not have nested names.
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T, MinLength, MaxLength))]
 #[codec(mel_bound())]
@@ -71,7 +88,14 @@ impl<T: Config> TryFrom<Vec<u8>> for AsciiDidName<T> {
 		let (prefix, suffix) = (split.next(), split.next());
 
 		if let (Some(prefix), Some(suffix)) = (prefix, suffix) {
-			ensure!(matches!(suffix, NETWORK_SUFFIX), Self::Error::InvalidSuffix);
+			ensure!(
+				is_valid_did_name_suffix(
+					suffix,
+					T::MinNameLength::get().saturated_into(),
+					T::MaxPrefixLength::get().saturated_into(),
+				),
+				Self::Error::InvalidSuffix
+			);
 			ensure!(
 				prefix.len() >= T::MinNameLength::get().saturated_into(),
 				Self::Error::NameTooShort
@@ -89,6 +113,26 @@ impl<T: Config> TryFrom<Vec<u8>> for AsciiDidName<T> {
 	}
 }
 
+/// Verify that a given slice can be used as a name suffix, i.e. that it is a
+/// sequence of labels ending with the network suffix, where each label can be
+/// used as the prefix of a parent name.
+fn is_valid_did_name_suffix(
+	input: &[u8],
+	min_label_length: usize,
+	max_label_length: usize,
+) -> bool {
+	let mut labels = input.rsplit(|c| *c == LABEL_SEPARATOR);
+	if labels.next() != Some(NETWORK_SUFFIX) {
+		return false;
+	}
+
+	labels.all(|label| {
+		(min_label_length..=max_label_length).contains(&label.len()) &&
+			label.first().map_or(false, u8::is_ascii_lowercase) &&
+			label.iter().all(|c| matches!(c, b'a'..=b'z' | b'0'..=b'9'))
+	})
+}
+
 /// Verify that a given slice can be used as a name prefix.
 fn is_valid_did_name_prefix(input: &[u8]) -> bool {
 	// Check prefix is empty or not
@@ -130,6 +174,21 @@ fn is_valid_did_name_prefix(input: &[u8]) -> bool {
 	is_valid
 }
 
+impl<T: Config> HierarchicalName for AsciiDidName<T> {
+	/// The parent of `prefix@label.rest` is `label@rest`, where `label` is
+	/// the first label after the `@`.
+	fn parent(&self) -> Option<Self> {
+		let separator = self.iter().position(|c| *c == NAME_SEPARATOR)?;
+		let suffix = &self[separator.saturating_add(1)..];
+		let label_end = suffix.iter().position(|c| *c == LABEL_SEPARATOR)?;
+
+		let mut parent = suffix[..label_end].to_vec();
+		parent.push(NAME_SEPARATOR);
+		parent.extend_from_slice(&suffix[label_end.saturating_add(1)..]);
+		Self::try_from(parent).ok()
+	}
+}
+
 // FIXME: did not find a way to automatically implement this.
 impl<T: Config> PartialEq for AsciiDidName<T> {
 	fn eq(&self, other: &Self) -> bool {
@@ -153,11 +212,24 @@ pub struct DidNameOwnership<Owner, BlockNumber> {
 	pub registered_at: BlockNumber,
 }
 
+/// DID name transfer offered by the owner of the name.
+#[derive(Clone, Encode, Decode, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct DidNameTransfer<Owner, BlockNumber> {
+	/// The DID the name is offered to.
+	pub target: Owner,
+	/// The block number at which the transfer was offered.
+	pub requested_at: BlockNumber,
+}
+
 #[cfg(test)]
 mod tests {
 	use sp_runtime::SaturatedConversion;
 
-	use crate::{did_name::AsciiDidName, mock::Test, Config};
+	use crate::{
+		did_name::{AsciiDidName, HierarchicalName},
+		mock::Test,
+		Config,
+	};
 
 	const MIN_LENGTH: u32 = <Test as Config>::MinNameLength::get();
 	const MAX_LENGTH: u32 = <Test as Config>::MaxNameLength::get();
@@ -170,6 +242,9 @@ mod tests {
 			b"a123456789@cord".to_vec(),
 			b"abc.123@cord".to_vec(),
 			b"abc.123.xyz@cord".to_vec(),
+			// Nested names
+			b"alice.dept@acme.cord".to_vec(),
+			b"bob@team.acme.cord".to_vec(),
 		];
 
 		let invalid_inputs = vec![
@@ -191,6 +266,14 @@ mod tests {
 			b"abc@newid".to_vec(),
 			b"abc@newid.".to_vec(),
 			b"	@cord".to_vec(),
+			// Invalid parent labels
+			b"abc@ab.cord".to_vec(),
+			b"abc@acme..cord".to_vec(),
+			b"abc@.acme.cord".to_vec(),
+			b"abc@acme.dept.xyz".to_vec(),
+			b"abc@Acme.cord".to_vec(),
+			b"abc@1acme.cord".to_vec(),
+			b"abc@ac.me.cord".to_vec(),
 		];
 
 		for valid in valid_inputs {
@@ -201,4 +284,20 @@ mod tests {
 			assert!(AsciiDidName::<Test>::try_from(invalid).is_err());
 		}
 	}
+
+	#[test]
+	fn did_name_parents() {
+		let name = |input: &[u8]| AsciiDidName::<Test>::try_from(input.to_vec()).unwrap();
+
+		assert_eq!(name(b"alice@cord").parent(), None);
+		assert_eq!(name(b"alice.dept@acme.cord").parent(), Some(name(b"acme@cord")));
+		assert_eq!(name(b"bob@team.acme.cord").parent(), Some(name(b"team@acme.cord")));
+		// The parent is always named by the first label, never by a prefix
+		// containing a `.`.
+		assert_eq!(name(b"xyz@foo.bar.cord").parent(), Some(name(b"foo@bar.cord")));
+		assert_ne!(name(b"xyz@foo.bar.cord").parent(), Some(name(b"foo.bar@cord")));
+		// Names with a `.` in their prefix cannot be referred to as a parent.
+		assert_eq!(name(b"abc.123@cord").parent(), None);
+		assert!(AsciiDidName::<Test>::try_from(b"xyz@abc.123.cord".to_vec()).is_err());
+	}
 }
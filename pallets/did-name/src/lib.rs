@@ -20,11 +20,26 @@
 
 //! # Pallet storing unique nickname <-> DID links for user-friendly DID
 //! nicknames.
+//!
+//! A name is registered for `RegistrationPeriod` blocks and has to be renewed
+//! by its owner before it expires. An expired name no longer resolves, but it
+//! stays with its owner for another `GracePeriod` blocks during which it can
+//! still be renewed. After that, anyone can register it again.
+//!
+//! A name can be handed over to another DID with `transfer`, which only takes
+//! effect once the receiving DID calls `accept_transfer`.
+//!
+//! The owner of a name can issue names nested under it to other DIDs, e.g.
+//! `alice.dept@acme.cord` under `acme@cord`, and later delegate them to a
+//! different DID or revoke them. Nested names do not expire on their own, but
+//! only resolve as long as every name above them is registered and has not
+//! expired.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod did_name;
 
+pub mod migrations;
 pub mod weights;
 
 #[cfg(any(feature = "mock", test))]
@@ -42,24 +57,37 @@ pub use crate::{pallet::*, weights::WeightInfo};
 pub mod pallet {
 	use codec::FullCodec;
 	use frame_support::{
-		pallet_prelude::*, sp_runtime::SaturatedConversion, traits::StorageVersion,
+		pallet_prelude::*,
+		sp_runtime::{
+			traits::{Saturating, Zero},
+			SaturatedConversion,
+		},
+		traits::{Currency, ReservableCurrency, StorageVersion},
 		Blake2_128Concat,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_std::{fmt::Debug, vec::Vec};
+	use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
-	use cord_utilities::traits::CallSources;
+	use cord_utilities::{
+		deposit::Deposit,
+		free_deposit, reserve_deposit,
+		traits::{CallSources, StorageDepositCollector},
+	};
 
 	use super::WeightInfo;
-	use crate::did_name::DidNameOwnership;
+	use crate::did_name::{DidNameOwnership, DidNameTransfer, HierarchicalName};
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type DidNameOwnerOf<T> = <T as Config>::DidNameOwner;
 	pub type DidNameInput<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
 	pub type DidNameOf<T> = <T as Config>::DidName;
 	pub type DidNameOwnershipOf<T> = DidNameOwnership<DidNameOwnerOf<T>, BlockNumberFor<T>>;
+	pub type DidNameTransferOf<T> = DidNameTransfer<DidNameOwnerOf<T>, BlockNumberFor<T>>;
+	pub(crate) type CurrencyOf<T> = <T as Config>::Currency;
+	pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
+	pub type DepositOf<T> = Deposit<AccountIdOf<T>, BalanceOf<T>>;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -79,6 +107,45 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Banned<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, ()>;
 
+	/// Map of name -> deposit reserved for it.
+	///
+	/// Names registered before deposits were introduced are given a zero
+	/// deposit owned by the account of the name owner when migrating to
+	/// storage version 2.
+	#[pallet::storage]
+	pub type NameDeposits<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, DepositOf<T>>;
+
+	/// Map of name -> block number at which its registration expires.
+	///
+	/// Names registered before expiry was introduced have no entry and do not
+	/// expire until they are renewed.
+	#[pallet::storage]
+	pub type Expirations<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, BlockNumberFor<T>>;
+
+	/// Map of name -> transfer offered by its owner and not yet accepted.
+	#[pallet::storage]
+	pub type PendingTransfers<T> =
+		StorageMap<_, Blake2_128Concat, DidNameOf<T>, DidNameTransferOf<T>>;
+
+	/// Map of name -> number identifying its current registration.
+	///
+	/// Names registered before nested names were introduced have no entry
+	/// until a name is first nested under them.
+	#[pallet::storage]
+	pub type Registrations<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, u64>;
+
+	/// The number identifying the next registration of a name.
+	#[pallet::storage]
+	pub type NextRegistration<T> = StorageValue<_, u64, ValueQuery>;
+
+	/// Map of nested name -> registration of the parent it was issued under.
+	///
+	/// A nested name is only valid while its parent keeps that registration,
+	/// so it does not survive its parent being released, banned or
+	/// registered again after lapsing.
+	#[pallet::storage]
+	pub type ParentRegistrations<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, u64>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type BanOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -97,9 +164,10 @@ pub mod pallet {
 			+ Clone
 			+ TypeInfo
 			+ TryFrom<Vec<u8>, Error = Error<Self>>
+			+ HierarchicalName
 			+ MaxEncodedLen;
 		/// The type of a name owner.
-		type DidNameOwner: Parameter + MaxEncodedLen;
+		type DidNameOwner: Parameter + MaxEncodedLen + Into<AccountIdOf<Self>>;
 		/// The min encoded length of a name.
 		#[pallet::constant]
 		type MinNameLength: Get<u32>;
@@ -109,6 +177,20 @@ pub mod pallet {
 		/// The max encoded length of a prefix.
 		#[pallet::constant]
 		type MaxPrefixLength: Get<u32>;
+		/// The currency that is used to reserve funds for each name.
+		type Currency: ReservableCurrency<AccountIdOf<Self>>;
+		/// The amount of balance that will be taken for each name as a
+		/// deposit.
+		#[pallet::constant]
+		type Deposit: Get<BalanceOf<Self>>;
+		/// The number of blocks a registration or renewal keeps a name
+		/// registered for.
+		#[pallet::constant]
+		type RegistrationPeriod: Get<BlockNumberFor<Self>>;
+		/// The number of blocks an expired name stays with its owner, who can
+		/// still renew it, before anyone can register it.
+		#[pallet::constant]
+		type GracePeriod: Get<BlockNumberFor<Self>>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -124,6 +206,26 @@ pub mod pallet {
 		DidNameBanned { name: DidNameOf<T> },
 		/// A name has been unbanned.
 		DidNameUnbanned { name: DidNameOf<T> },
+		/// The deposit of a name has been moved to another account.
+		DepositOwnerChanged { name: DidNameOf<T>, from: AccountIdOf<T>, to: AccountIdOf<T> },
+		/// The owner of a name offered it to another DID.
+		DidNameTransferRequested {
+			name: DidNameOf<T>,
+			from: DidNameOwnerOf<T>,
+			to: DidNameOwnerOf<T>,
+		},
+		/// The owner of a name withdrew its transfer offer.
+		DidNameTransferCancelled { name: DidNameOf<T> },
+		/// A name has been transferred to a new owner.
+		DidNameTransferred { name: DidNameOf<T>, from: DidNameOwnerOf<T>, to: DidNameOwnerOf<T> },
+		/// The registration of a name has been extended.
+		DidNameRenewed { name: DidNameOf<T>, expires_at: BlockNumberFor<T> },
+		/// The owner of a name issued a name nested under it.
+		DidSubNameIssued { name: DidNameOf<T>, owner: DidNameOwnerOf<T> },
+		/// The owner of a name moved a name nested under it to another DID.
+		DidSubNameDelegated { name: DidNameOf<T>, from: DidNameOwnerOf<T>, to: DidNameOwnerOf<T> },
+		/// The owner of a name revoked a name nested under it.
+		DidSubNameRevoked { name: DidNameOf<T>, owner: DidNameOwnerOf<T> },
 	}
 
 	#[pallet::error]
@@ -161,6 +263,15 @@ pub mod pallet {
 		SuffixTooLong,
 		/// A name that contains not allowed characters is being claimed.
 		InvalidFormat,
+		/// The registration of the specified name has expired.
+		Expired,
+		/// The specified name has no pending transfer to the origin.
+		TransferNotFound,
+		/// A name cannot be transferred to its current owner.
+		SameOwner,
+		/// The grace period of the specified name has not ended and it has
+		/// not been orphaned.
+		NotLapsed,
 	}
 
 	#[pallet::hooks]
@@ -172,21 +283,30 @@ pub mod pallet {
 		/// origin.
 		///
 		/// The name must not have already been registered by someone else and
-		/// the owner must not already own another name.
+		/// the owner must not already own another name. The deposit is
+		/// reserved from the account submitting the call.
+		///
+		/// Only top-level names can be registered; nested names are issued by
+		/// the owner of their parent with `issue_sub_name`.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::register(name.len().saturated_into()))]
 		pub fn register(origin: OriginFor<T>, name: DidNameInput<T>) -> DispatchResult {
-			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let owner = source.subject();
+			let payer = source.sender();
 
-			let decoded_name = Self::check_claiming_preconditions(name, &owner)?;
+			let decoded_name = Self::check_claiming_preconditions(name, &owner, &payer)?;
+			ensure!(decoded_name.parent().is_none(), Error::<T>::NotAuthorized);
 
-			Self::register_name(decoded_name.clone(), owner.clone());
+			Self::release_lapsed(&decoded_name, &owner);
+			Self::register_name(decoded_name.clone(), owner.clone(), payer)?;
 			Self::deposit_event(Event::<T>::DidNameRegistered { owner, name: decoded_name });
 
 			Ok(())
 		}
 
-		/// Release the provided name from its owner.
+		/// Release the provided name from its owner and return the deposit to
+		/// its payer.
 		///
 		/// The origin must be the owner of the specified name.
 		#[pallet::call_index(1)]
@@ -241,24 +361,263 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Move the deposit of the name owned by the origin to the account
+		/// submitting the call.
+		///
+		/// The deposit is freed for the current deposit owner and the current
+		/// deposit amount is reserved from the new one.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::change_deposit_owner())]
+		pub fn change_deposit_owner(origin: OriginFor<T>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let new_owner = source.sender();
+
+			let name = Self::check_releasing_preconditions(&source.subject())?;
+
+			let previous_owner = Self::move_deposit(&name, new_owner.clone())?;
+
+			Self::deposit_event(Event::<T>::DepositOwnerChanged {
+				name,
+				from: previous_owner,
+				to: new_owner,
+			});
+
+			Ok(())
+		}
+
+		/// Offer the name owned by the origin to the `target` DID.
+		///
+		/// The name changes hands once `target` calls `accept_transfer`.
+		/// A new offer replaces any previous one. The name must not have
+		/// expired.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer())]
+		pub fn transfer(origin: OriginFor<T>, target: DidNameOwnerOf<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let name = Self::check_releasing_preconditions(&owner)?;
+			ensure!(owner != target, Error::<T>::SameOwner);
+			ensure!(!Self::is_expired(&name), Error::<T>::Expired);
+
+			let requested_at = frame_system::Pallet::<T>::block_number();
+			PendingTransfers::<T>::insert(
+				&name,
+				DidNameTransferOf::<T> { target: target.clone(), requested_at },
+			);
+			Self::deposit_event(Event::<T>::DidNameTransferRequested {
+				name,
+				from: owner,
+				to: target,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw the transfer offer for the name owned by the origin.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_transfer())]
+		pub fn cancel_transfer(origin: OriginFor<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let name = Self::check_releasing_preconditions(&owner)?;
+			ensure!(PendingTransfers::<T>::take(&name).is_some(), Error::<T>::TransferNotFound);
+			Self::deposit_event(Event::<T>::DidNameTransferCancelled { name });
+
+			Ok(())
+		}
+
+		/// Accept the transfer of `name` offered to the origin.
+		///
+		/// The origin must not own a name already. The deposit of the name is
+		/// moved to the account submitting the call, while the registration
+		/// keeps its expiry.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_transfer(name.len().saturated_into()))]
+		pub fn accept_transfer(origin: OriginFor<T>, name: DidNameInput<T>) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let target = source.subject();
+			let payer = source.sender();
+
+			let (decoded_name, previous_owner) =
+				Self::check_accepting_preconditions(name, &target, &payer)?;
+
+			if let Some(lapsed) = Names::<T>::get(&target) {
+				Self::release_lapsed(&lapsed, &target);
+			}
+			Self::transfer_name(&decoded_name, &previous_owner, target.clone(), payer)?;
+			Self::deposit_event(Event::<T>::DidNameTransferred {
+				name: decoded_name,
+				from: previous_owner,
+				to: target,
+			});
+
+			Ok(())
+		}
+
+		/// Extend the registration of the name owned by the origin by
+		/// `RegistrationPeriod` blocks.
+		///
+		/// An expired name can be renewed until its grace period ends, in
+		/// which case the new registration period starts at the current block.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew())]
+		pub fn renew(origin: OriginFor<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let name = Self::check_releasing_preconditions(&owner)?;
+			ensure!(name.parent().is_none(), Error::<T>::NotAuthorized);
+			ensure!(!Self::is_lapsed(&name), Error::<T>::Expired);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let expires_at = Expirations::<T>::get(&name)
+				.map_or(current_block, |expires_at| expires_at.max(current_block))
+				.saturating_add(T::RegistrationPeriod::get());
+			Expirations::<T>::insert(&name, expires_at);
+			Self::deposit_event(Event::<T>::DidNameRenewed { name, expires_at });
+
+			Ok(())
+		}
+
+		/// Issue `name`, nested under the name owned by the origin, to the
+		/// `owner` DID.
+		///
+		/// The name must not have already been registered and the owner must
+		/// not already own another name. The deposit is reserved from the
+		/// account submitting the call.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_sub_name(name.len().saturated_into()))]
+		pub fn issue_sub_name(
+			origin: OriginFor<T>,
+			name: DidNameInput<T>,
+			owner: DidNameOwnerOf<T>,
+		) -> DispatchResult {
+			let source = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
+			let payer = source.sender();
+
+			let decoded_name = Self::check_claiming_preconditions(name, &owner, &payer)?;
+			Self::ensure_parent_owner(&decoded_name, &source.subject())?;
+
+			Self::release_lapsed(&decoded_name, &owner);
+			Self::register_name(decoded_name.clone(), owner.clone(), payer)?;
+			Self::deposit_event(Event::<T>::DidSubNameIssued { name: decoded_name, owner });
+
+			Ok(())
+		}
+
+		/// Move `name`, nested under the name owned by the origin, to the
+		/// `owner` DID.
+		///
+		/// The owner must not already own another name. The deposit stays
+		/// with its current payer.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::delegate_sub_name(name.len().saturated_into()))]
+		pub fn delegate_sub_name(
+			origin: OriginFor<T>,
+			name: DidNameInput<T>,
+			owner: DidNameOwnerOf<T>,
+		) -> DispatchResult {
+			let parent_owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let (decoded_name, previous_owner) =
+				Self::check_sub_name_preconditions(name, &parent_owner)?;
+			ensure!(previous_owner != owner, Error::<T>::SameOwner);
+			ensure!(!Self::is_expired(&decoded_name), Error::<T>::Expired);
+			ensure!(
+				Names::<T>::get(&owner).map_or(true, |owned| Self::is_lapsed(&owned)),
+				Error::<T>::OwnerAlreadyExists
+			);
+
+			if let Some(lapsed) = Names::<T>::get(&owner) {
+				Self::release_lapsed(&lapsed, &owner);
+			}
+			PendingTransfers::<T>::remove(&decoded_name);
+			Names::<T>::remove(&previous_owner);
+			Names::<T>::insert(&owner, decoded_name.clone());
+			Owner::<T>::mutate(&decoded_name, |ownership| {
+				if let Some(ownership) = ownership {
+					ownership.owner = owner.clone();
+				}
+			});
+			Self::deposit_event(Event::<T>::DidSubNameDelegated {
+				name: decoded_name,
+				from: previous_owner,
+				to: owner,
+			});
+
+			Ok(())
+		}
+
+		/// Revoke `name`, nested under the name owned by the origin, and
+		/// return the deposit to its payer.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_sub_name(name.len().saturated_into()))]
+		pub fn revoke_sub_name(origin: OriginFor<T>, name: DidNameInput<T>) -> DispatchResult {
+			let parent_owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let (decoded_name, owner) = Self::check_sub_name_preconditions(name, &parent_owner)?;
+
+			Self::unregister_name(&decoded_name);
+			Self::deposit_event(Event::<T>::DidSubNameRevoked { name: decoded_name, owner });
+
+			Ok(())
+		}
+
+		/// Release `name` if its grace period has ended or it has been
+		/// orphaned, and return the deposit to its payer.
+		///
+		/// Nested names are not released together with their parent, so this
+		/// lets the payer of a nested name reclaim the deposit once the parent
+		/// has been released or banned. The origin can be any DID.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::release_lapsed_name(name.len().saturated_into()))]
+		pub fn release_lapsed_name(origin: OriginFor<T>, name: DidNameInput<T>) -> DispatchResult {
+			<T as Config>::EnsureOrigin::ensure_origin(origin)?;
+
+			let decoded_name =
+				DidNameOf::<T>::try_from(name.into_inner()).map_err(DispatchError::from)?;
+			ensure!(Owner::<T>::contains_key(&decoded_name), Error::<T>::NotFound);
+			ensure!(Self::is_lapsed(&decoded_name), Error::<T>::NotLapsed);
+
+			let ownership = Self::unregister_name(&decoded_name);
+			Self::deposit_event(Event::<T>::DidNameReleased {
+				owner: ownership.owner,
+				name: decoded_name,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		/// Verify that the claiming preconditions are verified. Specifically:
 		/// - The name input data can be decoded as a valid name
-		/// - The name does not already exist
-		/// - The owner does not already own a name
+		/// - The name does not already exist, or its grace period has ended
+		/// - The owner does not already own a name whose grace period is
+		///   still running
 		/// - The name has not been banned
+		/// - The payer can pay the deposit
 		fn check_claiming_preconditions(
 			name_input: DidNameInput<T>,
 			owner: &DidNameOwnerOf<T>,
+			payer: &AccountIdOf<T>,
 		) -> Result<DidNameOf<T>, DispatchError> {
 			let name =
 				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
 
-			ensure!(!Names::<T>::contains_key(owner), Error::<T>::OwnerAlreadyExists);
-			ensure!(!Owner::<T>::contains_key(&name), Error::<T>::AlreadyExists);
+			ensure!(
+				Names::<T>::get(owner).map_or(true, |owned| Self::is_lapsed(&owned)),
+				Error::<T>::OwnerAlreadyExists
+			);
+			ensure!(
+				!Owner::<T>::contains_key(&name) || Self::is_lapsed(&name),
+				Error::<T>::AlreadyExists
+			);
 			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::Banned);
+			ensure!(
+				CurrencyOf::<T>::can_reserve(payer, T::Deposit::get()),
+				Error::<T>::InsufficientFunds
+			);
 
 			Ok(name)
 		}
@@ -267,14 +626,37 @@ pub mod pallet {
 		/// the provided account. This function must be called after
 		/// `check_claiming_preconditions` as it does not verify all the
 		/// preconditions again.
-		pub(crate) fn register_name(name: DidNameOf<T>, owner: DidNameOwnerOf<T>) {
+		pub(crate) fn register_name(
+			name: DidNameOf<T>,
+			owner: DidNameOwnerOf<T>,
+			payer: AccountIdOf<T>,
+		) -> DispatchResult {
 			let block_number = frame_system::Pallet::<T>::block_number();
 
+			let deposit =
+				reserve_deposit::<AccountIdOf<T>, CurrencyOf<T>>(payer, T::Deposit::get())
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+			NameDeposits::<T>::insert(&name, deposit);
+			// Nested names expire together with the registration of their
+			// parent.
+			match name.parent() {
+				Some(parent) => {
+					ParentRegistrations::<T>::insert(&name, Self::registration_of(&parent));
+				},
+				None => Expirations::<T>::insert(
+					&name,
+					block_number.saturating_add(T::RegistrationPeriod::get()),
+				),
+			}
+			Registrations::<T>::insert(&name, Self::next_registration());
+
 			Names::<T>::insert(&owner, name.clone());
 			Owner::<T>::insert(
 				&name,
 				DidNameOwnershipOf::<T> { owner, registered_at: block_number },
 			);
+
+			Ok(())
 		}
 
 		/// Verify that the releasing preconditions for an owner are verified.
@@ -295,10 +677,212 @@ pub mod pallet {
 		fn unregister_name(name: &DidNameOf<T>) -> DidNameOwnershipOf<T> {
 			let name_ownership = Owner::<T>::take(name).unwrap();
 			Names::<T>::remove(&name_ownership.owner);
+			if let Some(deposit) = NameDeposits::<T>::take(name) {
+				free_deposit::<AccountIdOf<T>, CurrencyOf<T>>(&deposit);
+			}
+			Expirations::<T>::remove(name);
+			PendingTransfers::<T>::remove(name);
+			Registrations::<T>::remove(name);
+			ParentRegistrations::<T>::remove(name);
 
 			name_ownership
 		}
 
+		/// Return the number identifying a new registration.
+		fn next_registration() -> u64 {
+			NextRegistration::<T>::mutate(|next| {
+				let registration = *next;
+				*next = next.saturating_add(1);
+				registration
+			})
+		}
+
+		/// Return the number identifying the current registration of the
+		/// provided name, giving one to names registered before nested names
+		/// were introduced.
+		fn registration_of(name: &DidNameOf<T>) -> u64 {
+			Registrations::<T>::get(name).unwrap_or_else(|| {
+				let registration = Self::next_registration();
+				Registrations::<T>::insert(name, registration);
+				registration
+			})
+		}
+
+		/// Whether the provided name is nested under a parent that is no
+		/// longer registered, or registered again since the name was issued.
+		fn is_orphaned(name: &DidNameOf<T>) -> bool {
+			name.parent().map_or(false, |parent| {
+				let issued_under = ParentRegistrations::<T>::get(name);
+				issued_under.is_none() || issued_under != Registrations::<T>::get(&parent)
+			})
+		}
+
+		/// Verify that the accepting preconditions are verified. Specifically:
+		/// - The name input data can be decoded as a valid name
+		/// - The name has a pending transfer to the target
+		/// - The name has not expired
+		/// - The target does not already own a name whose grace period is
+		///   still running
+		/// - The payer can pay the deposit
+		///
+		/// If the preconditions are verified, return a tuple containing the
+		/// parsed name value and its current owner.
+		fn check_accepting_preconditions(
+			name_input: DidNameInput<T>,
+			target: &DidNameOwnerOf<T>,
+			payer: &AccountIdOf<T>,
+		) -> Result<(DidNameOf<T>, DidNameOwnerOf<T>), DispatchError> {
+			let name =
+				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+
+			let transfer = PendingTransfers::<T>::get(&name).ok_or(Error::<T>::TransferNotFound)?;
+			ensure!(&transfer.target == target, Error::<T>::TransferNotFound);
+			ensure!(!Self::is_expired(&name), Error::<T>::Expired);
+			ensure!(
+				Names::<T>::get(target).map_or(true, |owned| Self::is_lapsed(&owned)),
+				Error::<T>::OwnerAlreadyExists
+			);
+			ensure!(
+				CurrencyOf::<T>::can_reserve(payer, T::Deposit::get()),
+				Error::<T>::InsufficientFunds
+			);
+			let ownership = Owner::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+
+			Ok((name, ownership.owner))
+		}
+
+		/// Move the deposit of the provided name to `new_owner`, topping it up
+		/// to the current deposit amount. Returns the previous deposit owner.
+		fn move_deposit(
+			name: &DidNameOf<T>,
+			new_owner: AccountIdOf<T>,
+		) -> Result<AccountIdOf<T>, Error<T>> {
+			let deposit =
+				DidNameDepositCollector::<T>::deposit(name).map_err(|_| Error::<T>::NotFound)?;
+
+			DidNameDepositCollector::<T>::change_deposit_owner(name, new_owner)
+				.map_err(|_| Error::<T>::InsufficientFunds)?;
+			if deposit.amount != T::Deposit::get() {
+				DidNameDepositCollector::<T>::update_deposit(name)
+					.map_err(|_| Error::<T>::InsufficientFunds)?;
+			}
+
+			Ok(deposit.owner)
+		}
+
+		/// Move the provided name from its current owner to the target and
+		/// its deposit to the payer. This function must be called after
+		/// `check_accepting_preconditions` as it does not verify all the
+		/// preconditions again.
+		fn transfer_name(
+			name: &DidNameOf<T>,
+			previous_owner: &DidNameOwnerOf<T>,
+			target: DidNameOwnerOf<T>,
+			payer: AccountIdOf<T>,
+		) -> DispatchResult {
+			Self::move_deposit(name, payer)?;
+
+			PendingTransfers::<T>::remove(name);
+			Names::<T>::remove(previous_owner);
+			Names::<T>::insert(&target, name.clone());
+			Owner::<T>::mutate(name, |ownership| {
+				if let Some(ownership) = ownership {
+					ownership.owner = target;
+				}
+			});
+
+			Ok(())
+		}
+
+		/// Whether the registration of the provided name, or of any name it
+		/// is nested under, has expired or been removed.
+		pub(crate) fn is_expired(name: &DidNameOf<T>) -> bool {
+			let expired = Expirations::<T>::get(name).map_or(false, |expires_at| {
+				expires_at <= frame_system::Pallet::<T>::block_number()
+			});
+
+			expired ||
+				Self::is_orphaned(name) ||
+				name.parent().map_or(false, |parent| Self::is_expired(&parent))
+		}
+
+		/// Verify that the provided owner holds the parent of the provided
+		/// name, and that it has not expired.
+		fn ensure_parent_owner(
+			name: &DidNameOf<T>,
+			owner: &DidNameOwnerOf<T>,
+		) -> Result<(), DispatchError> {
+			let parent = name.parent().ok_or(Error::<T>::NotAuthorized)?;
+			ensure!(Self::owner_of(&parent).as_ref() == Some(owner), Error::<T>::NotAuthorized);
+
+			Ok(())
+		}
+
+		/// Verify that the preconditions to manage a nested name are
+		/// verified. Specifically:
+		/// - The name input data can be decoded as a valid name
+		/// - The name is registered
+		/// - The parent owner holds the parent of the name
+		///
+		/// If the preconditions are verified, return a tuple containing the
+		/// parsed name value and its current owner.
+		fn check_sub_name_preconditions(
+			name_input: DidNameInput<T>,
+			parent_owner: &DidNameOwnerOf<T>,
+		) -> Result<(DidNameOf<T>, DidNameOwnerOf<T>), DispatchError> {
+			let name =
+				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+
+			let ownership = Owner::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+			Self::ensure_parent_owner(&name, parent_owner)?;
+
+			Ok((name, ownership.owner))
+		}
+
+		/// Whether the grace period of the provided name, or of any name it
+		/// is nested under, has ended, or the name has been orphaned, so that
+		/// it can be registered again.
+		pub(crate) fn is_lapsed(name: &DidNameOf<T>) -> bool {
+			let lapsed = Expirations::<T>::get(name).map_or(false, |expires_at| {
+				expires_at.saturating_add(T::GracePeriod::get()) <=
+					frame_system::Pallet::<T>::block_number()
+			});
+
+			lapsed ||
+				Self::is_orphaned(name) ||
+				name.parent().map_or(false, |parent| Self::is_lapsed(&parent))
+		}
+
+		/// Release the provided name and the name currently owned by the
+		/// provided owner if their grace periods have ended.
+		fn release_lapsed(name: &DidNameOf<T>, owner: &DidNameOwnerOf<T>) {
+			let owned_name = Names::<T>::get(owner);
+			for lapsed in [Some(name.clone()), owned_name].into_iter().flatten() {
+				if Owner::<T>::contains_key(&lapsed) && Self::is_lapsed(&lapsed) {
+					let ownership = Self::unregister_name(&lapsed);
+					Self::deposit_event(Event::<T>::DidNameReleased {
+						owner: ownership.owner,
+						name: lapsed,
+					});
+				}
+			}
+		}
+
+		/// Return the name owned by the provided owner, unless its
+		/// registration has expired.
+		pub fn name_of(owner: &DidNameOwnerOf<T>) -> Option<DidNameOf<T>> {
+			Names::<T>::get(owner).filter(|name| !Self::is_expired(name))
+		}
+
+		/// Return the owner of the provided name, unless its registration has
+		/// expired.
+		pub fn owner_of(name: &DidNameOf<T>) -> Option<DidNameOwnerOf<T>> {
+			if Self::is_expired(name) {
+				return None;
+			}
+			Owner::<T>::get(name).map(|ownership| ownership.owner)
+		}
+
 		/// Verify that the banning preconditions are verified.
 		/// Specifically:
 		/// - The name input data can be decoded as a valid name
@@ -349,4 +933,37 @@ pub mod pallet {
 			Banned::<T>::remove(name);
 		}
 	}
+
+	/// Gives access to the deposits reserved for names.
+	pub struct DidNameDepositCollector<T: Config>(PhantomData<T>);
+
+	impl<T: Config> StorageDepositCollector<AccountIdOf<T>, DidNameOf<T>>
+		for DidNameDepositCollector<T>
+	{
+		type Currency = CurrencyOf<T>;
+
+		/// Names registered before deposits were introduced have no deposit
+		/// recorded. They are given a zero deposit owned by the account of the
+		/// name owner, which is stored the first time the deposit is moved.
+		fn deposit(name: &DidNameOf<T>) -> Result<DepositOf<T>, DispatchError> {
+			NameDeposits::<T>::get(name)
+				.or_else(|| {
+					Owner::<T>::get(name).map(|ownership| DepositOf::<T> {
+						owner: ownership.owner.into(),
+						amount: Zero::zero(),
+					})
+				})
+				.ok_or_else(|| Error::<T>::NotFound.into())
+		}
+
+		fn deposit_amount(_name: &DidNameOf<T>) -> BalanceOf<T> {
+			T::Deposit::get()
+		}
+
+		fn store_deposit(name: &DidNameOf<T>, deposit: DepositOf<T>) -> Result<(), DispatchError> {
+			ensure!(Owner::<T>::contains_key(name), Error::<T>::NotFound);
+			NameDeposits::<T>::insert(name, deposit);
+			Ok(())
+		}
+	}
 }
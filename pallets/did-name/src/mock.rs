@@ -25,7 +25,7 @@ use cord_utilities::mock::{mock_origin, SubjectId};
 use frame_support::{derive_impl, parameter_types};
 use sp_runtime::{
 	traits::{IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature,
+	AccountId32, BuildStorage, MultiSignature,
 };
 
 use frame_system::EnsureRoot;
@@ -33,11 +33,14 @@ use frame_system::EnsureRoot;
 type Signature = MultiSignature;
 type AccountPublic = <Signature as Verify>::Signer;
 type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+pub(crate) type Balance = u128;
+pub(crate) type BlockNumber = u64;
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
 
 frame_support::construct_runtime!(
 	pub enum Test{
 		System: frame_system,
+		Balances: pallet_balances,
 		DidName: pallet_did_name,
 		MockOrigin: mock_origin,
 	}
@@ -55,6 +58,18 @@ impl frame_system::Config for Test {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type SS58Prefix = SS58Prefix;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
 }
 
 pub(crate) type TestDidName = AsciiDidName<Test>;
@@ -69,6 +84,9 @@ parameter_types! {
 	pub const MaxNameLength: u32 = 64;
 	pub const MinNameLength: u32 = 3;
 	pub const MaxPrefixLength: u32 = 54;
+	pub const NameDeposit: Balance = 10;
+	pub const RegistrationPeriod: BlockNumber = 100;
+	pub const GracePeriod: BlockNumber = 10;
 }
 
 impl Config for Test {
@@ -81,6 +99,10 @@ impl Config for Test {
 	type MaxPrefixLength = MaxPrefixLength;
 	type DidName = TestDidName;
 	type DidNameOwner = TestDidNameOwner;
+	type Currency = Balances;
+	type Deposit = NameDeposit;
+	type RegistrationPeriod = RegistrationPeriod;
+	type GracePeriod = GracePeriod;
 	type WeightInfo = ();
 }
 
@@ -90,9 +112,18 @@ impl mock_origin::Config for Test {
 	type SubjectId = SubjectId;
 }
 
+pub(crate) const ACCOUNT_00: TestDidNamePayer = AccountId32::new([1u8; 32]);
+pub(crate) const ACCOUNT_01: TestDidNamePayer = AccountId32::new([2u8; 32]);
+pub(crate) const INITIAL_BALANCE: Balance = 100;
+
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ACCOUNT_00, INITIAL_BALANCE), (ACCOUNT_01, INITIAL_BALANCE)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(t);
 	#[cfg(feature = "runtime-benchmarks")]
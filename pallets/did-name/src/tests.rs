@@ -20,19 +20,31 @@
 
 use super::*;
 use crate::{did_name::AsciiDidName, mock::*};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
 // use crate::{did_name::AsciiDidName, Banned, DidNameOwnershipOf, Error, Names,
 // Owner, Pallet};
-use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
+use cord_utilities::{
+	deposit::Deposit,
+	free_deposit,
+	mock::{mock_origin::DoubleOrigin, SubjectId},
+	traits::StorageDepositCollector,
+};
 use frame_system::RawOrigin;
 use sp_runtime::{AccountId32, DispatchError};
 
-pub(crate) const ACCOUNT_00: TestDidNamePayer = AccountId32::new([1u8; 32]);
-pub(crate) const ACCOUNT_01: TestDidNamePayer = AccountId32::new([2u8; 32]);
+pub(crate) const ACCOUNT_02: TestDidNamePayer = AccountId32::new([3u8; 32]);
 pub(crate) const DID_00: TestDidNameOwner = SubjectId(ACCOUNT_00);
 pub(crate) const DID_01: TestDidNameOwner = SubjectId(ACCOUNT_01);
+pub(crate) const DID_02: TestDidNameOwner = SubjectId(ACCOUNT_02);
 pub(crate) const DID_NAME_00_INPUT: &[u8; 16] = b"did.name.00@cord";
 pub(crate) const DID_NAME_01_INPUT: &[u8; 16] = b"did.name.01@cord";
+pub(crate) const PARENT_NAME_INPUT: &[u8; 9] = b"acme@cord";
+pub(crate) const SUB_NAME_INPUT: &[u8; 14] = b"team@acme.cord";
+pub(crate) const NESTED_SUB_NAME_INPUT: &[u8; 18] = b"bob@team.acme.cord";
 
 pub(crate) fn get_did_name(did_name_input: &[u8]) -> TestDidName {
 	AsciiDidName::try_from(did_name_input.to_vec()).expect("Invalid did name input.")
@@ -59,6 +71,12 @@ fn registering_successful() {
 		assert_eq!(did_name, did_name_00);
 		// Test that the ownership details match
 		assert_eq!(owner_details, DidNameOwnershipOf::<Test> { owner: DID_00, registered_at: 1 });
+		// Test that the deposit was reserved from the payer
+		assert_eq!(
+			NameDeposits::<Test>::get(&did_name_00),
+			Some(Deposit { owner: ACCOUNT_00, amount: NameDeposit::get() })
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), NameDeposit::get());
 
 		// Test that the same name cannot be claimed again.
 		assert_noop!(
@@ -118,6 +136,17 @@ fn registering_invalid() {
 	})
 }
 
+#[test]
+fn registering_insufficient_funds() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::register(DoubleOrigin(ACCOUNT_02, DID_00).into(), did_name_00.0),
+			Error::<Test>::InsufficientFunds
+		);
+	})
+}
+
 #[test]
 fn registering_banned() {
 	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
@@ -144,6 +173,8 @@ fn releasing_successful() {
 		assert_ok!(Pallet::<Test>::release(DoubleOrigin(ACCOUNT_01, DID_00).into(),));
 		assert!(Names::<Test>::get(&DID_00).is_none());
 		assert!(Owner::<Test>::get(&did_name_00).is_none());
+		assert!(NameDeposits::<Test>::get(&did_name_00).is_none());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), 0);
 	})
 }
 
@@ -181,11 +212,18 @@ fn banning_successful() {
 	let did_name_01 = get_did_name(DID_NAME_01_INPUT);
 	new_test_ext().execute_with(|| {
 		// Ban a claimed name
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
 		assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), did_name_00.clone().0));
 
 		assert!(Names::<Test>::get(&DID_00).is_none());
 		assert!(Owner::<Test>::get(&did_name_00).is_none());
 		assert!(Banned::<Test>::get(&did_name_00).is_some());
+		// The deposit of a banned name is returned to its payer.
+		assert!(NameDeposits::<Test>::get(&did_name_00).is_none());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
 
 		// Ban an unclaimed name
 		assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), did_name_01.clone().0));
@@ -269,3 +307,566 @@ fn unbanning_unauthorized_origin() {
 		);
 	})
 }
+
+// #############################################################################
+// Deposit owner changing
+
+#[test]
+fn changing_deposit_owner_successful() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::change_deposit_owner(DoubleOrigin(ACCOUNT_01, DID_00).into()));
+
+		assert_eq!(
+			NameDeposits::<Test>::get(&did_name_00),
+			Some(Deposit { owner: ACCOUNT_01, amount: NameDeposit::get() })
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), NameDeposit::get());
+	})
+}
+
+#[test]
+fn changing_deposit_owner_tops_up_zero_deposit() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		// A name registered before deposits were introduced.
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		let deposit = NameDeposits::<Test>::take(&did_name_00).expect("Deposit should be stored.");
+		free_deposit::<TestDidNamePayer, Balances>(&deposit);
+		NameDeposits::<Test>::insert(&did_name_00, Deposit { owner: ACCOUNT_00, amount: 0 });
+
+		assert_ok!(Pallet::<Test>::change_deposit_owner(DoubleOrigin(ACCOUNT_01, DID_00).into()));
+		assert_eq!(
+			NameDeposits::<Test>::get(&did_name_00),
+			Some(Deposit { owner: ACCOUNT_01, amount: NameDeposit::get() })
+		);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), NameDeposit::get());
+	})
+}
+
+#[test]
+fn migrating_to_v2_leaves_zero_deposits_to_be_recorded_lazily() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		// A name registered before deposits were introduced.
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		let deposit = NameDeposits::<Test>::take(&did_name_00).expect("Deposit should be stored.");
+		free_deposit::<TestDidNamePayer, Balances>(&deposit);
+		StorageVersion::new(1).put::<Pallet<Test>>();
+
+		crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert!(NameDeposits::<Test>::get(&did_name_00).is_none());
+		assert_eq!(
+			DidNameDepositCollector::<Test>::deposit(&did_name_00),
+			Ok(Deposit { owner: ACCOUNT_00, amount: 0 })
+		);
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+	})
+}
+
+#[test]
+fn changing_deposit_owner_not_found() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::change_deposit_owner(DoubleOrigin(ACCOUNT_01, DID_00).into()),
+			Error::<Test>::OwnerNotFound
+		);
+	})
+}
+
+#[test]
+fn changing_deposit_owner_insufficient_funds() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_noop!(
+			Pallet::<Test>::change_deposit_owner(DoubleOrigin(ACCOUNT_02, DID_00).into()),
+			Error::<Test>::InsufficientFunds
+		);
+	})
+}
+
+// #############################################################################
+// Name transferring
+
+#[test]
+fn transferring_successful() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+		// The name does not change hands until the transfer is accepted.
+		assert_eq!(Names::<Test>::get(&DID_00), Some(did_name_00.clone()));
+
+		assert_ok!(Pallet::<Test>::accept_transfer(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			did_name_00.clone().0,
+		));
+
+		assert!(Names::<Test>::get(&DID_00).is_none());
+		assert_eq!(Names::<Test>::get(&DID_01), Some(did_name_00.clone()));
+		assert_eq!(
+			Owner::<Test>::get(&did_name_00),
+			Some(DidNameOwnershipOf::<Test> { owner: DID_01, registered_at: 1 })
+		);
+		assert!(PendingTransfers::<Test>::get(&did_name_00).is_none());
+		// The deposit moves to the account accepting the transfer.
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), NameDeposit::get());
+	})
+}
+
+#[test]
+fn transferring_cancelled() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_noop!(
+			Pallet::<Test>::cancel_transfer(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::TransferNotFound
+		);
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+		assert_ok!(Pallet::<Test>::cancel_transfer(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				did_name_00.clone().0,
+			),
+			Error::<Test>::TransferNotFound
+		);
+	})
+}
+
+#[test]
+fn transferring_invalid() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	let did_name_01 = get_did_name(DID_NAME_01_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01),
+			Error::<Test>::OwnerNotFound
+		);
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_noop!(
+			Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_00),
+			Error::<Test>::SameOwner
+		);
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+
+		// Only the target can accept the transfer.
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_01, DID_00).into(),
+				did_name_00.clone().0,
+			),
+			Error::<Test>::TransferNotFound
+		);
+		// The target must pay the deposit.
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_02, DID_01).into(),
+				did_name_00.clone().0,
+			),
+			Error::<Test>::InsufficientFunds
+		);
+		// The target must not own a name already.
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			did_name_01.clone().0,
+		));
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				did_name_00.clone().0,
+			),
+			Error::<Test>::OwnerAlreadyExists
+		);
+	})
+}
+
+// #############################################################################
+// Name expiry and renewal
+
+#[test]
+fn expiring_and_renewing() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		let expires_at = 1 + RegistrationPeriod::get();
+		assert_eq!(Expirations::<Test>::get(&did_name_00), Some(expires_at));
+		assert_eq!(Pallet::<Test>::name_of(&DID_00), Some(did_name_00.clone()));
+		assert_eq!(Pallet::<Test>::owner_of(&did_name_00), Some(DID_00));
+
+		// An expired name does not resolve, and cannot be transferred.
+		System::set_block_number(expires_at);
+		assert!(Pallet::<Test>::name_of(&DID_00).is_none());
+		assert!(Pallet::<Test>::owner_of(&did_name_00).is_none());
+		assert_noop!(
+			Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01),
+			Error::<Test>::Expired
+		);
+		// It cannot be registered by someone else during its grace period.
+		assert_noop!(
+			Pallet::<Test>::register(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				did_name_00.clone().0
+			),
+			Error::<Test>::AlreadyExists
+		);
+
+		// Renewing an expired name starts a new period at the current block.
+		assert_ok!(Pallet::<Test>::renew(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert_eq!(
+			Expirations::<Test>::get(&did_name_00),
+			Some(expires_at + RegistrationPeriod::get())
+		);
+		assert_eq!(Pallet::<Test>::name_of(&DID_00), Some(did_name_00.clone()));
+
+		// Renewing an active name extends its current period.
+		assert_ok!(Pallet::<Test>::renew(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert_eq!(
+			Expirations::<Test>::get(&did_name_00),
+			Some(expires_at + 2 * RegistrationPeriod::get())
+		);
+	})
+}
+
+#[test]
+fn registering_lapsed_name() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		System::set_block_number(1 + RegistrationPeriod::get() + GracePeriod::get());
+
+		// The owner cannot renew the name once the grace period is over.
+		assert_noop!(
+			Pallet::<Test>::renew(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::Expired
+		);
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			did_name_00.clone().0,
+		));
+
+		assert!(Names::<Test>::get(&DID_00).is_none());
+		assert_eq!(Names::<Test>::get(&DID_01), Some(did_name_00.clone()));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), NameDeposit::get());
+	})
+}
+
+// #############################################################################
+// Nested names
+
+#[test]
+fn issuing_sub_names_successful() {
+	let parent_name = get_did_name(PARENT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	let nested_sub_name = get_did_name(NESTED_SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			parent_name.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01,
+		));
+		// The owner of a nested name can issue names nested under it.
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			nested_sub_name.clone().0,
+			DID_02,
+		));
+
+		assert_eq!(Pallet::<Test>::owner_of(&sub_name), Some(DID_01));
+		assert_eq!(Pallet::<Test>::name_of(&DID_02), Some(nested_sub_name.clone()));
+		// Nested names expire together with their parent.
+		assert!(Expirations::<Test>::get(&sub_name).is_none());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 2 * NameDeposit::get());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), NameDeposit::get());
+
+		System::set_block_number(1 + RegistrationPeriod::get());
+		assert!(Pallet::<Test>::owner_of(&sub_name).is_none());
+		assert!(Pallet::<Test>::name_of(&DID_02).is_none());
+	})
+}
+
+#[test]
+fn issuing_sub_names_unauthorized() {
+	let parent_name = get_did_name(PARENT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		// Nested names cannot be registered directly.
+		assert_noop!(
+			Pallet::<Test>::register(DoubleOrigin(ACCOUNT_01, DID_01).into(), sub_name.clone().0),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0,
+				DID_01,
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			parent_name.clone().0,
+		));
+		// Only the owner of the parent can issue nested names.
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				sub_name.clone().0,
+				DID_01,
+			),
+			Error::<Test>::NotAuthorized
+		);
+		// The parent owner cannot own a second name.
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0,
+				DID_00,
+			),
+			Error::<Test>::OwnerAlreadyExists
+		);
+
+		// A name with a `.` in its prefix is never the parent of a nested
+		// name.
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			BoundedVec::try_from(b"foo.bar@cord".to_vec()).unwrap(),
+		));
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				BoundedVec::try_from(b"xyz@foo.bar.cord".to_vec()).unwrap(),
+				DID_02,
+			),
+			Error::<Test>::NotAuthorized
+		);
+	})
+}
+
+#[test]
+fn sub_names_follow_parent_registration() {
+	let parent_name = get_did_name(PARENT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			parent_name.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01,
+		));
+
+		// Releasing the parent orphans the nested name.
+		assert_ok!(Pallet::<Test>::release(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert!(Pallet::<Test>::owner_of(&sub_name).is_none());
+		assert!(Pallet::<Test>::is_lapsed(&sub_name));
+
+		// The nested name does not revive under the new parent owner, who can
+		// issue it again, returning the previous deposit.
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_02).into(),
+			parent_name.clone().0,
+		));
+		assert!(Pallet::<Test>::owner_of(&sub_name).is_none());
+		assert_noop!(
+			Pallet::<Test>::delegate_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_02).into(),
+				sub_name.clone().0,
+				DID_00,
+			),
+			Error::<Test>::Expired
+		);
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_01, DID_02).into(),
+			sub_name.clone().0,
+			DID_00,
+		));
+		assert!(Names::<Test>::get(&DID_01).is_none());
+		assert_eq!(Pallet::<Test>::owner_of(&sub_name), Some(DID_00));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), 2 * NameDeposit::get());
+
+		// Banning the parent orphans the nested name, whose owner can still
+		// release it.
+		assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), parent_name.clone().0));
+		assert!(Pallet::<Test>::owner_of(&sub_name).is_none());
+		assert_ok!(Pallet::<Test>::release(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), 0);
+	})
+}
+
+#[test]
+fn sub_names_lapse_with_parent() {
+	let parent_name = get_did_name(PARENT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			parent_name.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01,
+		));
+
+		System::set_block_number(1 + RegistrationPeriod::get());
+		assert!(!Pallet::<Test>::is_lapsed(&sub_name));
+
+		System::set_block_number(1 + RegistrationPeriod::get() + GracePeriod::get());
+		assert!(Pallet::<Test>::is_lapsed(&sub_name));
+
+		// The owner of the nested name is free to own another name.
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			get_did_name(DID_NAME_01_INPUT).0,
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), NameDeposit::get());
+	})
+}
+
+#[test]
+fn delegating_and_revoking_sub_names() {
+	let parent_name = get_did_name(PARENT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			parent_name.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01,
+		));
+
+		// Only the owner of the parent can delegate or revoke nested names.
+		assert_noop!(
+			Pallet::<Test>::delegate_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				sub_name.clone().0,
+				DID_02,
+			),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			Pallet::<Test>::revoke_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				sub_name.clone().0
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(Pallet::<Test>::delegate_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_02,
+		));
+		assert!(Names::<Test>::get(&DID_01).is_none());
+		assert_eq!(Pallet::<Test>::owner_of(&sub_name), Some(DID_02));
+
+		assert_ok!(Pallet::<Test>::revoke_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+		));
+		assert!(Names::<Test>::get(&DID_02).is_none());
+		assert!(Owner::<Test>::get(&sub_name).is_none());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), NameDeposit::get());
+		assert_noop!(
+			Pallet::<Test>::revoke_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0
+			),
+			Error::<Test>::NotFound
+		);
+	})
+}
+
+#[test]
+fn releasing_orphaned_sub_names() {
+	let parent_name = get_did_name(PARENT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			parent_name.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01,
+		));
+
+		// A nested name in use cannot be released by anyone else.
+		assert_noop!(
+			Pallet::<Test>::release_lapsed_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0
+			),
+			Error::<Test>::NotLapsed
+		);
+
+		// Releasing the parent leaves the deposit of the nested name reserved
+		// until the payer reclaims it.
+		assert_ok!(Pallet::<Test>::release(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), NameDeposit::get());
+
+		assert_ok!(Pallet::<Test>::release_lapsed_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+		));
+		assert!(Names::<Test>::get(&DID_01).is_none());
+		assert!(Owner::<Test>::get(&sub_name).is_none());
+		assert!(NameDeposits::<Test>::get(&sub_name).is_none());
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
+		assert_noop!(
+			Pallet::<Test>::release_lapsed_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0
+			),
+			Error::<Test>::NotFound
+		);
+	})
+}
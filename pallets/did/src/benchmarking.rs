@@ -23,7 +23,8 @@ use codec::Encode;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::assert_ok;
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
-use sp_core::{crypto::KeyTypeId, ecdsa, ed25519, sr25519};
+use p256::ecdsa::{signature::Signer, SigningKey};
+use sp_core::{bls381, crypto::KeyTypeId, ecdsa, ed25519, sr25519, Pair};
 use sp_io::crypto::{
 	ecdsa_generate, ecdsa_sign, ed25519_generate, ed25519_sign, sr25519_generate, sr25519_sign,
 };
@@ -35,12 +36,13 @@ use cord_utilities::signature::VerifySignature;
 use crate::{
 	did_details::{
 		DeriveDidCallAuthorizationVerificationKeyRelationship, DidAuthorizedCallOperation,
-		DidPublicKey, DidSignature, DidVerificationKey,
+		DidPublicKey, DidSignature, DidVerificationKey, P256Public, P256Signature,
 	},
 	mock_utils::{
 		generate_base_did_creation_details, generate_base_did_details, get_key_agreement_keys,
 		get_service_endpoints,
 	},
+	recovery::{DidGuardiansOf, DidRecoveryConfig, DidRecoveryRequest},
 	service_endpoints::DidEndpoint,
 	signature::DidSignatureVerify,
 	AccountIdOf, DidAuthorizedCallOperationOf, DidIdentifierOf,
@@ -90,6 +92,16 @@ fn get_ecdsa_public_delegation_key() -> ecdsa::Public {
 	ecdsa_generate(DELEGATION_KEY_ID, None)
 }
 
+// The keystore does not support P-256 and BLS12-381 keys, so these are derived
+// from a fixed seed instead.
+fn get_p256_authentication_key() -> SigningKey {
+	SigningKey::from_slice(&[1u8; 32]).expect("Failed to create P-256 key from seed.")
+}
+
+fn get_bls381_authentication_key() -> bls381::Pair {
+	bls381::Pair::from_seed(&[1u8; 32])
+}
+
 // Must always be dispatched with the DID authentication key
 fn generate_base_did_call_operation<T: Config>(
 	did: DidIdentifierOf<T>,
@@ -106,6 +118,34 @@ fn generate_base_did_call_operation<T: Config>(
 	}
 }
 
+/// Funds `account` with enough balance to cover the deposit of a DID with the
+/// maximum number of key agreement keys and service endpoints.
+fn make_free_for_did<T: Config>(account: &AccountIdOf<T>) {
+	let balance = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::minimum_balance()
+		.saturating_add(T::BaseDeposit::get())
+		.saturating_add(
+			T::KeyDeposit::get().saturating_mul(T::MaxTotalKeyAgreementKeys::get().into()),
+		)
+		.saturating_add(
+			T::ServiceEndpointDeposit::get()
+				.saturating_mul(T::MaxNumberOfServicesPerDid::get().into()),
+		);
+	CurrencyOf::<T>::make_free_balance_be(account, balance);
+}
+
+/// Reserves the deposit of `did_subject` from a funded account, so that the
+/// benchmarked call also has to update or free it.
+fn save_deposit<T: Config>(did_subject: &DidIdentifierOf<T>) {
+	let owner: AccountIdOf<T> = account(DEFAULT_ACCOUNT_ID, 0, DEFAULT_ACCOUNT_SEED);
+	make_free_for_did::<T>(&owner);
+	let deposit = reserve_deposit::<AccountIdOf<T>, CurrencyOf<T>>(
+		owner,
+		DidDepositCollector::<T>::deposit_amount(did_subject),
+	)
+	.expect("Deposit should be reserved.");
+	DidDeposits::<T>::insert(did_subject, deposit);
+}
+
 fn save_service_endpoints<T: Config>(
 	did_subject: &DidIdentifierOf<T>,
 	endpoints: &[DidEndpoint<T>],
@@ -116,6 +156,56 @@ fn save_service_endpoints<T: Config>(
 	DidEndpointsCount::<T>::insert(did_subject, endpoints.len().saturated_into::<u32>());
 }
 
+fn recovery_guardians<T: Config>() -> DidGuardiansOf<T>
+where
+	T::DidIdentifier: From<AccountId32>,
+{
+	(0..T::MaxGuardians::get())
+		.map(|index| account::<AccountId32>("guardian", index, DEFAULT_ACCOUNT_SEED).into())
+		.collect::<Vec<DidIdentifierOf<T>>>()
+		.try_into()
+		.expect("Guardians should not exceed MaxGuardians.")
+}
+
+/// Opts `did_subject` into recovery with every guardian required, and starts a
+/// recovery to `new_key` approved by the first `approvals` guardians, which can
+/// be completed from block `executable_at`.
+fn save_recovery<T: Config>(
+	did_subject: &DidIdentifierOf<T>,
+	new_key: DidVerificationKey<AccountIdOf<T>>,
+	approvals: u32,
+	executable_at: Option<BlockNumberFor<T>>,
+) -> DidGuardiansOf<T>
+where
+	T::DidIdentifier: From<AccountId32>,
+{
+	let guardians = recovery_guardians::<T>();
+	RecoveryConfigs::<T>::insert(
+		did_subject,
+		DidRecoveryConfig {
+			guardians: guardians.clone(),
+			threshold: T::MaxGuardians::get(),
+			delay: T::MinRecoveryDelay::get(),
+		},
+	);
+	ActiveRecoveries::<T>::insert(
+		did_subject,
+		DidRecoveryRequest {
+			proposals: guardians
+				.iter()
+				.take(approvals as usize)
+				.map(|guardian| (guardian.clone(), new_key.clone()))
+				.collect::<Vec<_>>()
+				.try_into()
+				.expect("Approvals should not exceed MaxGuardians."),
+			initiated_at: BlockNumberFor::<T>::zero(),
+			executable: executable_at.map(|block| (new_key, block)),
+		},
+	);
+
+	guardians
+}
+
 benchmarks! {
 	where_clause {
 		where
@@ -134,6 +224,7 @@ benchmarks! {
 		let c in 1 .. T::MaxNumberOfServicesPerDid::get();
 
 		let submitter: AccountIdOf<T> = account(DEFAULT_ACCOUNT_ID, 0, DEFAULT_ACCOUNT_SEED);
+		make_free_for_did::<T>(&submitter);
 
 		let did_public_auth_key = get_ed25519_public_authentication_key();
 		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
@@ -201,6 +292,7 @@ benchmarks! {
 		let c in 1 .. T::MaxNumberOfServicesPerDid::get();
 
 		let submitter: AccountIdOf<T> = account(DEFAULT_ACCOUNT_ID, 0, DEFAULT_ACCOUNT_SEED);
+		make_free_for_did::<T>(&submitter);
 
 		let did_public_auth_key = get_sr25519_public_authentication_key();
 		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
@@ -266,6 +358,7 @@ benchmarks! {
 		let c in 1 .. T::MaxNumberOfServicesPerDid::get();
 
 		let submitter: AccountIdOf<T> = account(DEFAULT_ACCOUNT_ID, 0, DEFAULT_ACCOUNT_SEED);
+		make_free_for_did::<T>(&submitter);
 
 		let did_public_auth_key = get_ecdsa_public_authentication_key();
 		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
@@ -344,6 +437,7 @@ benchmarks! {
 
 		Did::<T>::insert(&did_subject, did_details);
 		save_service_endpoints(&did_subject, &service_endpoints);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: _(origin, c)
 	verify {
@@ -408,6 +502,41 @@ benchmarks! {
 		let boxed_did_call = Box::new(did_call_op);
 	}: submit_did_call(origin, boxed_did_call, did_call_signature)
 
+	submit_did_call_p256_key {
+		let submitter: AccountIdOf<T> = account(DEFAULT_ACCOUNT_ID, 0, DEFAULT_ACCOUNT_SEED);
+
+		let did_auth_key = get_p256_authentication_key();
+		let did_public_auth_key = P256Public::from(did_auth_key.verifying_key());
+		let did_subject: DidIdentifierOf<T> = DidVerificationKey::<AccountId32>::from(did_public_auth_key).into_account().into();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+
+		let did_call_op = generate_base_did_call_operation::<T>(did_subject, submitter.clone());
+
+		let raw_signature: p256::ecdsa::Signature = did_auth_key.sign(did_call_op.encode().as_ref());
+		let did_call_signature = DidSignature::from(P256Signature::from(raw_signature));
+		let origin = RawOrigin::Signed(submitter);
+		let boxed_did_call = Box::new(did_call_op);
+	}: submit_did_call(origin, boxed_did_call, did_call_signature)
+
+	submit_did_call_bls381_key {
+		let submitter: AccountIdOf<T> = account(DEFAULT_ACCOUNT_ID, 0, DEFAULT_ACCOUNT_SEED);
+
+		let did_auth_key = get_bls381_authentication_key();
+		let did_public_auth_key = did_auth_key.public();
+		let did_subject: DidIdentifierOf<T> = DidVerificationKey::<AccountId32>::from(did_public_auth_key).into_account().into();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+
+		let did_call_op = generate_base_did_call_operation::<T>(did_subject, submitter.clone());
+
+		let did_call_signature = DidSignature::from(did_auth_key.sign(did_call_op.encode().as_ref()));
+		let origin = RawOrigin::Signed(submitter);
+		let boxed_did_call = Box::new(did_call_op);
+	}: submit_did_call(origin, boxed_did_call, did_call_signature)
+
 	/* set_authentication_key extrinsic */
 	set_ed25519_authentication_key {
 		let block_number = BlockNumberFor::<T>::zero();
@@ -761,6 +890,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ed25519_public_assertion_key()), block_number));
 
 		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: add_key_agreement_key(origin, new_key_agreement_key)
 	verify {
@@ -785,6 +915,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_sr25519_public_assertion_key()), block_number));
 
 		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: add_key_agreement_key(origin, new_key_agreement_key)
 	verify {
@@ -809,6 +940,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ecdsa_public_assertion_key()), block_number));
 
 		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: add_key_agreement_key(origin, new_key_agreement_key)
 	verify {
@@ -834,6 +966,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ed25519_public_assertion_key()), block_number));
 
 		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_key_agreement_key(origin, key_agreement_key_id)
 	verify {
@@ -857,6 +990,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_sr25519_public_assertion_key()), block_number));
 
 		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_key_agreement_key(origin, key_agreement_key_id)
 	verify {
@@ -880,6 +1014,7 @@ benchmarks! {
 		assert_ok!(did_details.update_assertion_key(DidVerificationKey::from(get_ecdsa_public_assertion_key()), block_number));
 
 		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 	}: remove_key_agreement_key(origin, key_agreement_key_id)
 	verify {
@@ -913,6 +1048,7 @@ benchmarks! {
 		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
 		Did::<T>::insert(&did_subject, did_details);
 		save_service_endpoints(&did_subject, &old_service_endpoints);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_service_endpoint = new_service_endpoint.clone();
 	}: _(origin, cloned_service_endpoint)
@@ -948,6 +1084,7 @@ benchmarks! {
 		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(public_auth_key));
 		Did::<T>::insert(&did_subject, did_details);
 		save_service_endpoints(&did_subject, &old_service_endpoints);
+		save_deposit::<T>(&did_subject);
 		let origin = RawOrigin::Signed(did_subject.clone());
 		let cloned_endpoint_id = endpoint_id.clone();
 	}: _(origin, cloned_endpoint_id)
@@ -1048,6 +1185,7 @@ benchmarks! {
 		let did_public_auth_key = get_ecdsa_public_authentication_key();
 		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
 		let did_account: AccountIdOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		make_free_for_did::<T>(&did_account);
 
 		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key));
 
@@ -1069,6 +1207,123 @@ benchmarks! {
 	verify {
 			Did::<T>::get(&did_subject).expect("DID entry should be created");
 	}
+
+	set_recovery_config {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+
+		// Replacing the setup cancels the recovery in progress.
+		let new_key = DidVerificationKey::from(get_ed25519_public_delegation_key());
+		let guardians = save_recovery::<T>(&did_subject, new_key, T::MaxGuardians::get(), None);
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin, guardians, T::MaxGuardians::get(), T::MinRecoveryDelay::get())
+	verify {
+		assert!(RecoveryConfigs::<T>::contains_key(&did_subject));
+		assert!(!ActiveRecoveries::<T>::contains_key(&did_subject));
+	}
+
+	remove_recovery_config {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let new_key = DidVerificationKey::from(get_ed25519_public_delegation_key());
+		save_recovery::<T>(&did_subject, new_key, T::MaxGuardians::get(), None);
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin)
+	verify {
+		assert!(!RecoveryConfigs::<T>::contains_key(&did_subject));
+		assert!(!ActiveRecoveries::<T>::contains_key(&did_subject));
+	}
+
+	approve_recovery {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+
+		// The last approval reaches the threshold.
+		let new_key = DidVerificationKey::from(get_ed25519_public_delegation_key());
+		let guardians = save_recovery::<T>(&did_subject, new_key.clone(), T::MaxGuardians::get() - 1, None);
+		let guardian = guardians.last().cloned().expect("There should be at least one guardian.");
+		let origin = RawOrigin::Signed(guardian);
+	}: _(origin, did_subject.clone(), new_key)
+	verify {
+		let request = ActiveRecoveries::<T>::get(&did_subject).expect("Recovery should be in progress.");
+		assert!(request.executable.is_some());
+	}
+
+	withdraw_recovery_approval {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		// The withdrawal drops the executable key below the threshold.
+		let new_key = DidVerificationKey::from(get_ed25519_public_delegation_key());
+		let guardians = save_recovery::<T>(&did_subject, new_key, T::MaxGuardians::get(), Some(BlockNumberFor::<T>::zero()));
+		let guardian = guardians.last().cloned().expect("There should be at least one guardian.");
+		let origin = RawOrigin::Signed(guardian);
+	}: _(origin, did_subject.clone())
+	verify {
+		let request = ActiveRecoveries::<T>::get(&did_subject).expect("Recovery should be in progress.");
+		assert!(request.executable.is_none());
+	}
+
+	cancel_recovery {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let new_key = DidVerificationKey::from(get_ed25519_public_delegation_key());
+		save_recovery::<T>(&did_subject, new_key, T::MaxGuardians::get(), None);
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin)
+	verify {
+		assert!(!ActiveRecoveries::<T>::contains_key(&did_subject));
+	}
+
+	finalize_recovery {
+		let submitter: AccountIdOf<T> = account(DEFAULT_ACCOUNT_ID, 1, DEFAULT_ACCOUNT_SEED);
+		make_free_for_did::<T>(&submitter);
+		let did_public_auth_key = get_ecdsa_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
+
+		let new_key = DidVerificationKey::from(get_ed25519_public_delegation_key());
+		save_recovery::<T>(&did_subject, new_key.clone(), T::MaxGuardians::get(), Some(BlockNumberFor::<T>::zero()));
+		let origin = RawOrigin::Signed(submitter);
+	}: _(origin, did_subject.clone())
+	verify {
+		let did_details = Did::<T>::get(&did_subject).expect("DID entry should be present");
+		assert_eq!(
+			did_details.authentication_key,
+			utils::calculate_key_id::<T>(&new_key.into())
+		);
+		assert!(!ActiveRecoveries::<T>::contains_key(&did_subject));
+		let deposit = DidDeposits::<T>::get(&did_subject).expect("Deposit should be stored.");
+		assert_eq!(deposit.owner, account(DEFAULT_ACCOUNT_ID, 1, DEFAULT_ACCOUNT_SEED));
+	}
+
+	change_deposit_owner {
+		let did_public_auth_key = get_ed25519_public_authentication_key();
+		let did_subject: DidIdentifierOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		let did_account: AccountIdOf<T> = MultiSigner::from(did_public_auth_key).into_account().into();
+		make_free_for_did::<T>(&did_account);
+
+		let did_details = generate_base_did_details::<T>(DidVerificationKey::from(did_public_auth_key));
+		Did::<T>::insert(&did_subject, did_details);
+		save_deposit::<T>(&did_subject);
+		let origin = RawOrigin::Signed(did_subject.clone());
+	}: _(origin)
+	verify {
+		let deposit = DidDeposits::<T>::get(&did_subject).expect("Deposit should be stored.");
+		assert_eq!(deposit.owner, did_account);
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -34,12 +34,12 @@ use crate::{
 };
 // use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
-use sp_core::{ecdsa, ed25519, sr25519};
+use sp_core::{bls381, ecdsa, ed25519, sr25519, Pair};
 use sp_runtime::{
 	traits::{IdentifyAccount, Verify},
 	MultiSignature, SaturatedConversion,
 };
-use sp_std::{convert::TryInto, vec::Vec};
+use sp_std::{collections::btree_set::BTreeSet, convert::TryInto, vec::Vec};
 
 /// Public verification key that a DID can control.
 #[derive(
@@ -54,6 +54,17 @@ pub enum DidVerificationKey<AccountId> {
 	Ecdsa(ecdsa::Public),
 	/// Account Identifier
 	Account(AccountId),
+	/// A P-256 (secp256r1) public key. See [`P256Signature`] for the
+	/// signatures it verifies.
+	P256(P256Public),
+	/// A BLS12-381 public key, in the 144 bytes form of `sp_core::bls381`:
+	/// a G1 point followed by a G2 point, both derived from the same secret.
+	///
+	/// This is not the 96 bytes G2 public key of BBS+ or of the BLS
+	/// signature suites used for verifiable credentials, and such keys
+	/// cannot be used here: a signature verifies only if it was produced
+	/// by `sp_core::bls381::Pair` over the payload.
+	Bls381(bls381::Public),
 }
 
 impl<AccountId> DidVerificationKey<AccountId> {
@@ -77,6 +88,17 @@ impl<AccountId> DidVerificationKey<AccountId> {
 				ensure!(sig.verify(payload, public_key), SignatureError::InvalidData);
 				Ok(())
 			},
+			(DidVerificationKey::P256(public_key), DidSignature::P256(sig)) => {
+				ensure!(sig.verify(payload, public_key), SignatureError::InvalidData);
+				Ok(())
+			},
+			(DidVerificationKey::Bls381(public_key), DidSignature::Bls381(sig)) => {
+				ensure!(
+					bls381::Pair::verify(sig, payload, public_key),
+					SignatureError::InvalidData
+				);
+				Ok(())
+			},
 			_ => Err(SignatureError::InvalidFormat),
 		}
 	}
@@ -96,6 +118,9 @@ where
 			// one byte too long)
 			DidVerificationKey::Ecdsa(pub_key) => sp_io::hashing::blake2_256(pub_key.as_ref()),
 			DidVerificationKey::Account(acc_id) => *acc_id.as_ref(),
+			// Neither key fits in 32 bytes, so they are hashed like the Ecdsa key
+			DidVerificationKey::P256(pub_key) => sp_io::hashing::blake2_256(pub_key.as_ref()),
+			DidVerificationKey::Bls381(pub_key) => sp_io::hashing::blake2_256(pub_key.as_ref()),
 		};
 
 		bytes.into()
@@ -120,6 +145,98 @@ impl<AccountId> From<ecdsa::Public> for DidVerificationKey<AccountId> {
 	}
 }
 
+impl<AccountId> From<P256Public> for DidVerificationKey<AccountId> {
+	fn from(key: P256Public) -> Self {
+		DidVerificationKey::P256(key)
+	}
+}
+
+impl<AccountId> From<bls381::Public> for DidVerificationKey<AccountId> {
+	fn from(key: bls381::Public) -> Self {
+		DidVerificationKey::Bls381(key)
+	}
+}
+
+/// A P-256 (secp256r1) public key, in its 33 bytes SEC1 compressed form.
+///
+/// These are the keys produced by hardware wallets, mobile secure enclaves
+/// and WebAuthn authenticators. Only raw signatures of the payload are
+/// accepted, see [`P256Signature`].
+#[derive(
+	Clone,
+	Copy,
+	Decode,
+	RuntimeDebug,
+	Encode,
+	Eq,
+	Ord,
+	PartialEq,
+	PartialOrd,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct P256Public(pub [u8; 33]);
+
+impl AsRef<[u8]> for P256Public {
+	fn as_ref(&self) -> &[u8] {
+		&self.0[..]
+	}
+}
+
+impl From<[u8; 33]> for P256Public {
+	fn from(raw: [u8; 33]) -> Self {
+		Self(raw)
+	}
+}
+
+impl From<&p256::ecdsa::VerifyingKey> for P256Public {
+	fn from(key: &p256::ecdsa::VerifyingKey) -> Self {
+		let mut raw = [0u8; 33];
+		raw.copy_from_slice(key.to_encoded_point(true).as_bytes());
+		Self(raw)
+	}
+}
+
+/// A P-256 ECDSA signature, as the 64 bytes `r || s` of a signature over the
+/// SHA-256 hash of the payload.
+///
+/// DER encoded signatures are not accepted, and neither are WebAuthn
+/// assertions: an authenticator signs its authenticator data followed by the
+/// hash of the client data, not the payload itself, so the assertion does not
+/// verify against the payload. A WebAuthn key can only be used by a signer
+/// that signs the payload directly.
+#[derive(Clone, Decode, RuntimeDebug, Encode, Eq, PartialEq, TypeInfo)]
+pub struct P256Signature(pub [u8; 64]);
+
+impl P256Signature {
+	/// Verify the signature of `message` against the given public key.
+	pub fn verify(&self, message: &[u8], public_key: &P256Public) -> bool {
+		use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+		let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(public_key.as_ref()) else {
+			return false;
+		};
+		let Ok(signature) = Signature::from_slice(&self.0[..]) else {
+			return false;
+		};
+		verifying_key.verify(message, &signature).is_ok()
+	}
+}
+
+impl From<[u8; 64]> for P256Signature {
+	fn from(raw: [u8; 64]) -> Self {
+		Self(raw)
+	}
+}
+
+impl From<p256::ecdsa::Signature> for P256Signature {
+	fn from(sig: p256::ecdsa::Signature) -> Self {
+		let mut raw = [0u8; 64];
+		raw.copy_from_slice(&sig.to_bytes());
+		Self(raw)
+	}
+}
+
 /// Types of encryption keys a DID can control.
 #[derive(
 	Clone,
@@ -176,6 +293,87 @@ pub enum DidVerificationKeyRelationship {
 	AssertionMethod,
 }
 
+/// Relationships a key can be used for by a DID, as recorded in the key
+/// history.
+#[derive(
+	Clone,
+	Copy,
+	RuntimeDebug,
+	Decode,
+	Encode,
+	PartialEq,
+	Eq,
+	Ord,
+	PartialOrd,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum DidKeyRelationship {
+	/// The authentication key.
+	Authentication,
+	/// The assertion key.
+	AssertionMethod,
+	/// The delegation key.
+	CapabilityDelegation,
+	/// One of the key agreement keys.
+	KeyAgreement,
+}
+
+/// A key a DID has used for a relationship, along with the DID versions and
+/// blocks it was used in.
+///
+/// Versions count the changes to the keys of a DID, the creation of the DID
+/// being version 0. A key is active from `added_in` (inclusive) until
+/// `removed_in` (exclusive), and in the same way from `active_from` until
+/// `active_until` in terms of blocks.
+#[derive(Clone, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DidKeyHistoryEntry<KeyId, BlockNumber, AccountId> {
+	/// The ID of the key.
+	pub key_id: KeyId,
+	/// The key itself.
+	pub key: DidPublicKey<AccountId>,
+	/// What the key was used for.
+	pub relationship: DidKeyRelationship,
+	/// The DID version the key was added in.
+	pub added_in: u32,
+	/// The block the key was added in.
+	pub active_from: BlockNumber,
+	/// \[OPTIONAL\] The DID version the key was removed in, if any.
+	pub removed_in: Option<u32>,
+	/// \[OPTIONAL\] The block the key was removed in, if any.
+	pub active_until: Option<BlockNumber>,
+}
+
+impl<KeyId, BlockNumber: PartialOrd, AccountId> DidKeyHistoryEntry<KeyId, BlockNumber, AccountId> {
+	/// Whether the key was active in the given DID version.
+	pub fn is_active_in_version(&self, version: u32) -> bool {
+		self.added_in <= version && self.removed_in.map_or(true, |removed| version < removed)
+	}
+
+	/// Whether the key was active at the given block.
+	pub fn is_active_at_block(&self, block_number: &BlockNumber) -> bool {
+		self.active_from <= *block_number &&
+			self.active_until.as_ref().map_or(true, |until| block_number < until)
+	}
+}
+
+/// The current DID version and the range of entries in the key history of a
+/// DID.
+#[derive(
+	Clone, Copy, Default, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen,
+)]
+pub struct DidKeyHistoryCounters {
+	/// The current version of the DID keys.
+	pub version: u32,
+	/// The number of entries recorded in the key history, i.e., the index of
+	/// the next entry.
+	pub entries: u32,
+	/// The index of the oldest entry still kept in the key history.
+	pub oldest: u32,
+	/// The oldest version whose keys are all still kept in the key history.
+	pub first_version: u32,
+}
+
 /// Types of signatures supported by this pallet.
 #[derive(Clone, Decode, RuntimeDebug, Encode, Eq, PartialEq, TypeInfo)]
 pub enum DidSignature {
@@ -185,6 +383,10 @@ pub enum DidSignature {
 	Sr25519(sr25519::Signature),
 	/// An Ecdsa signature.
 	Ecdsa(ecdsa::Signature),
+	/// A P-256 signature.
+	P256(P256Signature),
+	/// A BLS12-381 signature.
+	Bls381(bls381::Signature),
 }
 
 impl From<ed25519::Signature> for DidSignature {
@@ -205,6 +407,18 @@ impl From<ecdsa::Signature> for DidSignature {
 	}
 }
 
+impl From<P256Signature> for DidSignature {
+	fn from(sig: P256Signature) -> Self {
+		DidSignature::P256(sig)
+	}
+}
+
+impl From<bls381::Signature> for DidSignature {
+	fn from(sig: bls381::Signature) -> Self {
+		DidSignature::Bls381(sig)
+	}
+}
+
 impl From<MultiSignature> for DidSignature {
 	fn from(sig: MultiSignature) -> Self {
 		match sig {
@@ -267,6 +481,10 @@ impl<I: AsRef<[u8; 32]>, AccountId> DidVerifiableIdentifier<AccountId> for I {
 				// secp256k1_ecdsa_recover_compressed
 				Ok(DidVerificationKey::from(ecdsa::Public::from(recovered_pk)))
 			},
+			// Neither the identifier nor the signature carries enough information to
+			// recover a P-256 or BLS12-381 key, so these keys can only be added to an
+			// existing DID.
+			DidSignature::P256(_) | DidSignature::Bls381(_) => Err(SignatureError::InvalidFormat),
 		}
 	}
 }
@@ -562,6 +780,25 @@ impl<T: Config> DidDetails<T> {
 		}
 	}
 
+	/// Returns every key ID in use along with the relationship it is used for.
+	pub fn key_relationships(&self) -> BTreeSet<(DidKeyRelationship, KeyIdOf<T>)> {
+		let mut relationships = BTreeSet::new();
+		relationships.insert((DidKeyRelationship::Authentication, self.authentication_key));
+		if let Some(key_id) = self.assertion_key {
+			relationships.insert((DidKeyRelationship::AssertionMethod, key_id));
+		}
+		if let Some(key_id) = self.delegation_key {
+			relationships.insert((DidKeyRelationship::CapabilityDelegation, key_id));
+		}
+		relationships.extend(
+			self.key_agreement_keys
+				.iter()
+				.map(|key_id| (DidKeyRelationship::KeyAgreement, *key_id)),
+		);
+
+		relationships
+	}
+
 	/// Increase the tx counter of the DID.
 	pub fn increase_tx_counter(&mut self) -> u64 {
 		// Since we have transaction mortality now, we can safely wrap nonces around.
@@ -24,8 +24,10 @@
 //! a CORD address and must be verifiable, i.e., must be able to generate
 //! digital signatures that can be verified starting from a raw payload, its
 //! signature, and the signer identifier. Currently, the DID pallet supports the
-//! following types of keys: Ed25519, Sr25519, and Ecdsa for signing keys, and
-//! X25519 for encryption keys.
+//! following types of keys: Ed25519, Sr25519, Ecdsa, P-256, and BLS12-381 for
+//! signing keys, and X25519 for encryption keys. P-256 and BLS12-381 keys
+//! cannot be recovered from a signature, so they can only be added to a DID
+//! created with one of the other signing keys.
 //!
 //! - [`Config`]
 //! - [`Call`]
@@ -63,6 +65,14 @@
 //! - A **transaction counter**: acts as a nonce to avoid replay or signature forgery attacks. Each
 //!   time a DID-signed transaction is executed, the counter is incremented.
 //!
+//! - A **deposit**: reserved from the account that created the DID, to pay for the storage its
+//!   details, key agreement keys and service endpoints occupy. It is adjusted as keys and service
+//!   endpoints are added or removed, and refunded when the DID is deleted.
+//!
+//! - A **key history**: records every key the DID has used for each relationship, along with the
+//!   key versions and blocks it was active in, so that the keys of a DID can be resolved as they
+//!   were at a given version or block. The version is bumped each time the keys of the DID change.
+//!
 //! ## Assumptions
 //!
 //! - After it is generated and signed by a client, a DID-authorised operation can be submitted for
@@ -75,7 +85,9 @@
 
 pub mod did_details;
 pub mod errors;
+pub mod migrations;
 pub mod origin;
+pub mod recovery;
 pub mod service_endpoints;
 pub mod weights;
 
@@ -108,21 +120,28 @@ pub use crate::{
 };
 
 use codec::Encode;
+use cord_utilities::{
+	deposit::Deposit, free_deposit, reserve_deposit, traits::StorageDepositCollector,
+};
 use errors::{DidError, InputError, SignatureError, StorageError};
 use frame_support::{
 	dispatch::{DispatchResult, GetDispatchInfo, PostDispatchInfo},
 	ensure,
 	storage::types::StorageMap,
-	traits::Get,
+	traits::{Currency, Get, ReservableCurrency},
 	Parameter,
 };
 use frame_system::ensure_signed;
+use identifier::{
+	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
+	EventEntryOf,
+};
 
 use sp_runtime::{
 	traits::{Dispatchable, Saturating, Zero},
-	SaturatedConversion,
+	DispatchError, SaturatedConversion,
 };
-use sp_std::{boxed::Box, fmt::Debug, prelude::Clone};
+use sp_std::{boxed::Box, fmt::Debug, marker::PhantomData, prelude::Clone, vec::Vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 use frame_system::RawOrigin;
@@ -137,17 +156,21 @@ pub mod pallet {
 
 	use frame_system::pallet_prelude::*;
 
+	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
+
 	use crate::{
 		did_details::{
 			DeriveDidCallAuthorizationVerificationKeyRelationship, DidAuthorizedCallOperation,
-			DidCreationDetails, DidDetails, DidEncryptionKey, DidSignature,
-			DidVerifiableIdentifier, DidVerificationKey, RelationshipDeriveError,
+			DidCreationDetails, DidDetails, DidEncryptionKey, DidKeyHistoryCounters,
+			DidKeyHistoryEntry, DidKeyRelationship, DidSignature, DidVerifiableIdentifier,
+			DidVerificationKey, RelationshipDeriveError,
 		},
+		recovery::{DidGuardiansOf, DidRecoveryConfig, DidRecoveryRequest},
 		service_endpoints::{utils as service_endpoints_utils, DidEndpoint, ServiceEndpointId},
 	};
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	/// Reference to a payload of data of variable size.
 	pub type Payload = [u8];
@@ -164,6 +187,14 @@ pub mod pallet {
 	/// Type for a runtime extrinsic callable under DID-based authorisation.
 	pub type DidCallableOf<T> = <T as Config>::RuntimeCall;
 
+	pub(crate) type CurrencyOf<T> = <T as Config>::Currency;
+
+	/// Type for a balance of the currency deposits are reserved in.
+	pub type BalanceOf<T> = <CurrencyOf<T> as Currency<AccountIdOf<T>>>::Balance;
+
+	/// Type for the deposit reserved for a DID.
+	pub type DepositOf<T> = Deposit<AccountIdOf<T>, BalanceOf<T>>;
+
 	/// Type for origin that supports a DID sender.
 	#[pallet::origin]
 	pub type Origin<T> = DidRawOrigin<DidIdentifierOf<T>, AccountIdOf<T>>;
@@ -183,8 +214,15 @@ pub mod pallet {
 		u64,
 	>;
 
+	/// Type for an entry of the key history of a DID.
+	pub type DidKeyHistoryEntryOf<T> =
+		DidKeyHistoryEntry<KeyIdOf<T>, BlockNumberFor<T>, AccountIdOf<T>>;
+
+	/// The DID pallet records the recovery steps of a DID on the timeline of
+	/// the identifier pallet, under an `Entity` identifier derived from the
+	/// DID, hence the `identifier::Config` bound.
 	#[pallet::config]
-	pub trait Config: frame_system::Config + Debug {
+	pub trait Config: frame_system::Config + identifier::Config + Debug {
 		/// Type for a dispatchable call that can be proxied through the DID
 		/// pallet to support DID-based authorisation.
 		type RuntimeCall: Parameter
@@ -198,7 +236,8 @@ pub mod pallet {
 		type DidIdentifier: Parameter
 			+ DidVerifiableIdentifier<AccountIdOf<Self>>
 			+ MaxEncodedLen
-			+ From<AccountIdOf<Self>>;
+			+ From<AccountIdOf<Self>>
+			+ Into<AccountIdOf<Self>>;
 
 		/// Origin type expected by the proxied dispatchable calls.
 		#[cfg(not(feature = "runtime-benchmarks"))]
@@ -265,6 +304,43 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxNumberOfUrlsPerService: Get<u32>;
 
+		/// The maximum number of entries kept in the key history of a DID.
+		///
+		/// Once it is reached, the oldest entries of keys no longer in use are
+		/// dropped and the versions they covered can no longer be queried.
+		/// Must be greater than `MaxTotalKeyAgreementKeys` plus the
+		/// authentication, assertion and delegation keys.
+		#[pallet::constant]
+		type MaxKeyHistory: Get<u32>;
+
+		/// The maximum number of guardians a DID can register for recovery.
+		#[pallet::constant]
+		type MaxGuardians: Get<u32>;
+
+		/// The minimum number of blocks a DID can choose as the delay between
+		/// its guardians approving a recovery and the recovery becoming
+		/// executable.
+		#[pallet::constant]
+		type MinRecoveryDelay: Get<BlockNumberFor<Self>>;
+
+		/// The currency that is used to reserve funds for each DID.
+		type Currency: ReservableCurrency<AccountIdOf<Self>>;
+
+		/// The amount of balance that will be taken for each DID as a deposit
+		/// to incentivise fair use of the on chain storage.
+		#[pallet::constant]
+		type BaseDeposit: Get<BalanceOf<Self>>;
+
+		/// The amount of balance that will be taken for each key agreement key
+		/// of a DID as a deposit.
+		#[pallet::constant]
+		type KeyDeposit: Get<BalanceOf<Self>>;
+
+		/// The amount of balance that will be taken for each service endpoint
+		/// of a DID as a deposit.
+		#[pallet::constant]
+		type ServiceEndpointDeposit: Get<BalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -307,6 +383,68 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type DidBlacklist<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, ()>;
 
+	/// The keys every DID has used over time.
+	///
+	/// It maps from (DID identifier, history index) to the key, the
+	/// relationship it was used for and the versions and blocks it was active
+	/// in. At most `MaxKeyHistory` entries are kept for a DID, and the history
+	/// is removed along with the DID.
+	#[pallet::storage]
+	pub type KeyHistory<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DidIdentifierOf<T>,
+		Twox64Concat,
+		u32,
+		DidKeyHistoryEntryOf<T>,
+	>;
+
+	/// The history entries of the keys currently in use by each DID.
+	///
+	/// It maps from (DID identifier, (relationship, key ID)) to the index of
+	/// the entry in [`KeyHistory`].
+	#[pallet::storage]
+	pub(crate) type ActiveKeyHistory<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DidIdentifierOf<T>,
+		Blake2_128Concat,
+		(DidKeyRelationship, KeyIdOf<T>),
+		u32,
+	>;
+
+	/// The current key version and the number of key history entries of each
+	/// DID.
+	#[pallet::storage]
+	pub type KeyHistoryCounters<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidKeyHistoryCounters, ValueQuery>;
+
+	/// The recovery setup of the DIDs that opted into recovery.
+	///
+	/// It maps from a DID identifier to its guardians, approval threshold and
+	/// recovery delay.
+	#[pallet::storage]
+	pub type RecoveryConfigs<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidRecoveryConfig<T>>;
+
+	/// The recoveries in progress.
+	///
+	/// It maps from a DID identifier to the new authentication key proposed by
+	/// its guardians and the approvals collected so far.
+	#[pallet::storage]
+	pub type ActiveRecoveries<T> =
+		StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DidRecoveryRequest<T>>;
+
+	/// The deposits reserved for DIDs.
+	///
+	/// It maps from a DID identifier to the account that paid the deposit and
+	/// the amount reserved. DIDs created before deposits were introduced are
+	/// given a zero deposit owned by the DID account when migrating to storage
+	/// version 2. It is topped up the next time the DID updates its keys or
+	/// service endpoints, or moves it with `change_deposit_owner`.
+	#[pallet::storage]
+	pub type DidDeposits<T> = StorageMap<_, Blake2_128Concat, DidIdentifierOf<T>, DepositOf<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -322,6 +460,52 @@ pub mod pallet {
 		/// A DID-authorised call has been executed.
 		/// \[DID caller, dispatch result\]
 		CallDispatched { identifier: DidIdentifierOf<T>, result: DispatchResult },
+		/// The recovery setup of a DID has been set.
+		/// \[DID identifier\]
+		RecoveryConfigSet { identifier: DidIdentifierOf<T> },
+		/// The recovery setup of a DID has been removed.
+		/// \[DID identifier\]
+		RecoveryConfigRemoved { identifier: DidIdentifierOf<T> },
+		/// A guardian has initiated the recovery of a DID.
+		/// \[DID identifier, guardian DID, proposed authentication key\]
+		RecoveryInitiated {
+			identifier: DidIdentifierOf<T>,
+			guardian: DidIdentifierOf<T>,
+			new_key: DidVerificationKey<AccountIdOf<T>>,
+		},
+		/// A guardian has approved the recovery of a DID.
+		/// \[DID identifier, guardian DID, proposed authentication key,
+		/// approvals of the key so far, block from which the recovery can be
+		/// completed\]
+		RecoveryApproved {
+			identifier: DidIdentifierOf<T>,
+			guardian: DidIdentifierOf<T>,
+			new_key: DidVerificationKey<AccountIdOf<T>>,
+			approvals: u32,
+			executable_at: Option<BlockNumberFor<T>>,
+		},
+		/// A guardian has withdrawn its approval of the recovery of a DID.
+		/// \[DID identifier, guardian DID, block from which the recovery can
+		/// be completed\]
+		RecoveryApprovalWithdrawn {
+			identifier: DidIdentifierOf<T>,
+			guardian: DidIdentifierOf<T>,
+			executable_at: Option<BlockNumberFor<T>>,
+		},
+		/// The recovery of a DID has been cancelled with its current
+		/// authentication key.
+		/// \[DID identifier\]
+		RecoveryCancelled { identifier: DidIdentifierOf<T> },
+		/// The authentication key of a DID has been replaced through recovery.
+		/// \[DID identifier\]
+		Recovered { identifier: DidIdentifierOf<T> },
+		/// The deposit of a DID has been moved to another account.
+		/// \[DID identifier, previous deposit owner, new deposit owner\]
+		DepositOwnerChanged {
+			identifier: DidIdentifierOf<T>,
+			from: AccountIdOf<T>,
+			to: AccountIdOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -387,6 +571,29 @@ pub mod pallet {
 		MaxStoredEndpointsCountExceeded,
 		/// An error that is not supposed to take place, yet it happened.
 		Internal,
+		/// The guardians or threshold of a recovery setup are not valid, e.g.
+		/// duplicated guardians, the DID guarding itself or a threshold
+		/// larger than the number of guardians.
+		InvalidRecoveryConfig,
+		/// The recovery delay is shorter than `MinRecoveryDelay`.
+		RecoveryDelayTooShort,
+		/// The DID has not opted into recovery.
+		RecoveryConfigNotFound,
+		/// The origin is not a guardian of the DID.
+		NotAGuardian,
+		/// There is no recovery in progress for the DID.
+		RecoveryNotFound,
+		/// The guardian has already approved the recovery with the same key.
+		RecoveryAlreadyApproved,
+		/// The guardian has not approved the recovery.
+		RecoveryNotApproved,
+		/// The recovery has not been approved by enough guardians or its delay
+		/// has not elapsed yet.
+		RecoveryNotExecutable,
+		/// The account does not have enough free balance to pay the deposit.
+		UnableToPayFees,
+		/// The recovery step could not be recorded on the timeline of the DID.
+		TimelineUpdateFailed,
 	}
 
 	impl<T> From<DidError> for Error<T> {
@@ -452,6 +659,14 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			// Entries of keys in use are never dropped from the key history.
+			assert!(
+				T::MaxKeyHistory::get() > T::MaxTotalKeyAgreementKeys::get().saturating_add(3),
+				"MaxKeyHistory must exceed the number of keys a DID can use at once"
+			);
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
 			crate::try_state::do_try_state::<T>()
@@ -951,6 +1166,12 @@ pub mod pallet {
 		/// execute the extrinsic and it does not have to be tied in any way to
 		/// the CORD account identifying the DID subject.
 		///
+		/// The signature is always over the SCALE encoded operation. A P-256
+		/// key only verifies a raw `r || s` signature of it, so WebAuthn
+		/// assertions, which sign the authenticator and client data instead,
+		/// are rejected. A BLS12-381 key is a `sp_core::bls381` double public
+		/// key and only verifies signatures made with that scheme.
+		///
 		/// Emits `DidCallDispatched`.
 		///
 		/// # <weight>
@@ -964,7 +1185,9 @@ pub mod pallet {
 			let di = did_call.call.get_dispatch_info();
 			let max_sig_weight = <T as pallet::Config>::WeightInfo::submit_did_call_ed25519_key()
 			.max(<T as pallet::Config>::WeightInfo::submit_did_call_sr25519_key())
-			.max(<T as pallet::Config>::WeightInfo::submit_did_call_ecdsa_key());
+			.max(<T as pallet::Config>::WeightInfo::submit_did_call_ecdsa_key())
+			.max(<T as pallet::Config>::WeightInfo::submit_did_call_p256_key())
+			.max(<T as pallet::Config>::WeightInfo::submit_did_call_bls381_key());
 
 			(max_sig_weight.saturating_add(di.weight), di.class)
 		})]
@@ -1114,6 +1337,291 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Opt into recovery, or replace the current recovery setup.
+		///
+		/// Once `threshold` of the `guardians` approve the same new
+		/// authentication key, and `delay` blocks have passed since, the
+		/// authentication key of the DID can be replaced with it. The DID can
+		/// cancel a recovery with its current authentication key until then.
+		/// Replacing the setup cancels the recovery in progress, if any.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryConfigSet`, preceded by `RecoveryCancelled` if a
+		/// recovery was in progress.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_recovery_config())]
+		pub fn set_recovery_config(
+			origin: OriginFor<T>,
+			guardians: DidGuardiansOf<T>,
+			threshold: u32,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(Did::<T>::contains_key(&did_subject), Error::<T>::NotFound);
+
+			let unique_guardians = guardians
+				.iter()
+				.enumerate()
+				.all(|(index, guardian)| !guardians[..index].contains(guardian));
+			ensure!(
+				unique_guardians &&
+					!guardians.contains(&did_subject) &&
+					threshold > 0 && threshold as usize <= guardians.len(),
+				Error::<T>::InvalidRecoveryConfig
+			);
+			ensure!(delay >= T::MinRecoveryDelay::get(), Error::<T>::RecoveryDelayTooShort);
+
+			// The approvals were given under the setup being replaced.
+			if ActiveRecoveries::<T>::take(&did_subject).is_some() {
+				Self::update_activity(&did_subject, CallTypeOf::RecoveryCancelled)?;
+				Self::deposit_event(Event::RecoveryCancelled { identifier: did_subject.clone() });
+			}
+
+			RecoveryConfigs::<T>::insert(
+				&did_subject,
+				DidRecoveryConfig { guardians, threshold, delay },
+			);
+
+			Self::deposit_event(Event::RecoveryConfigSet { identifier: did_subject });
+			Ok(())
+		}
+
+		/// Opt out of recovery, dropping any recovery in progress.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryConfigRemoved`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_recovery_config())]
+		pub fn remove_recovery_config(origin: OriginFor<T>) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(
+				RecoveryConfigs::<T>::take(&did_subject).is_some(),
+				Error::<T>::RecoveryConfigNotFound
+			);
+			ActiveRecoveries::<T>::remove(&did_subject);
+
+			Self::deposit_event(Event::RecoveryConfigRemoved { identifier: did_subject });
+			Ok(())
+		}
+
+		/// Approve the recovery of `did` to `new_key` as one of its guardians.
+		///
+		/// The first approval initiates the recovery. Each guardian proposes
+		/// its own key and can change it by approving again. When the
+		/// threshold of guardians proposes the same key, the recovery to it
+		/// becomes executable after the recovery delay of the DID.
+		///
+		/// The dispatch origin must be the DID origin of a guardian, proxied
+		/// via the `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryInitiated` for the first approval, and
+		/// `RecoveryApproved`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::approve_recovery())]
+		pub fn approve_recovery(
+			origin: OriginFor<T>,
+			did: DidIdentifierOf<T>,
+			new_key: DidVerificationKey<AccountIdOf<T>>,
+		) -> DispatchResult {
+			let guardian = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(Did::<T>::contains_key(&did), Error::<T>::NotFound);
+			let config =
+				RecoveryConfigs::<T>::get(&did).ok_or(Error::<T>::RecoveryConfigNotFound)?;
+			ensure!(config.guardians.contains(&guardian), Error::<T>::NotAGuardian);
+
+			let current_block_number = frame_system::Pallet::<T>::block_number();
+			let mut request = match ActiveRecoveries::<T>::get(&did) {
+				Some(request) => request,
+				None => {
+					Self::update_activity(&did, CallTypeOf::RecoveryInitiated)?;
+					Self::deposit_event(Event::RecoveryInitiated {
+						identifier: did.clone(),
+						guardian: guardian.clone(),
+						new_key: new_key.clone(),
+					});
+					DidRecoveryRequest {
+						proposals: BoundedVec::default(),
+						initiated_at: current_block_number,
+						executable: None,
+					}
+				},
+			};
+
+			match request.proposals.iter_mut().find(|(proposer, _)| *proposer == guardian) {
+				Some((_, proposed)) => {
+					ensure!(*proposed != new_key, Error::<T>::RecoveryAlreadyApproved);
+					*proposed = new_key.clone();
+				},
+				// Guardians are bounded by the same limit as proposals.
+				None => request
+					.proposals
+					.try_push((guardian.clone(), new_key.clone()))
+					.map_err(|_| Error::<T>::Internal)?,
+			}
+			request.update_executable(config.threshold, config.delay, current_block_number);
+			let approvals = request.approvals_of(&new_key);
+			let executable_at = request.executable.as_ref().map(|(_, block)| *block);
+			ActiveRecoveries::<T>::insert(&did, request);
+
+			Self::update_activity(&did, CallTypeOf::Approved)?;
+			Self::deposit_event(Event::RecoveryApproved {
+				identifier: did,
+				guardian,
+				new_key,
+				approvals,
+				executable_at,
+			});
+			Ok(())
+		}
+
+		/// Withdraw the approval of a guardian from the recovery of `did`.
+		///
+		/// If the key of an executable recovery drops below the approval
+		/// threshold, the recovery is no longer executable until a key reaches
+		/// the threshold again, restarting the recovery delay. A recovery
+		/// without any approvals left is dropped.
+		///
+		/// The dispatch origin must be the DID origin of a guardian, proxied
+		/// via the `submit_did_call` extrinsic.
+		///
+		/// Emits `RecoveryApprovalWithdrawn`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_recovery_approval())]
+		pub fn withdraw_recovery_approval(
+			origin: OriginFor<T>,
+			did: DidIdentifierOf<T>,
+		) -> DispatchResult {
+			let guardian = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			let config =
+				RecoveryConfigs::<T>::get(&did).ok_or(Error::<T>::RecoveryConfigNotFound)?;
+			let mut request =
+				ActiveRecoveries::<T>::get(&did).ok_or(Error::<T>::RecoveryNotFound)?;
+
+			let position = request
+				.proposals
+				.iter()
+				.position(|(proposer, _)| *proposer == guardian)
+				.ok_or(Error::<T>::RecoveryNotApproved)?;
+			request.proposals.remove(position);
+
+			let executable_at = if request.proposals.is_empty() {
+				ActiveRecoveries::<T>::remove(&did);
+				None
+			} else {
+				request.update_executable(
+					config.threshold,
+					config.delay,
+					frame_system::Pallet::<T>::block_number(),
+				);
+				let executable_at = request.executable.as_ref().map(|(_, block)| *block);
+				ActiveRecoveries::<T>::insert(&did, request);
+				executable_at
+			};
+
+			Self::update_activity(&did, CallTypeOf::RecoveryApprovalWithdrawn)?;
+			Self::deposit_event(Event::RecoveryApprovalWithdrawn {
+				identifier: did,
+				guardian,
+				executable_at,
+			});
+			Ok(())
+		}
+
+		/// Cancel the recovery in progress for the DID.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic, i.e., signed with the current
+		/// authentication key.
+		///
+		/// Emits `RecoveryCancelled`.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_recovery())]
+		pub fn cancel_recovery(origin: OriginFor<T>) -> DispatchResult {
+			let did_subject = T::EnsureOrigin::ensure_origin(origin)?.subject();
+			ensure!(
+				ActiveRecoveries::<T>::take(&did_subject).is_some(),
+				Error::<T>::RecoveryNotFound
+			);
+
+			Self::update_activity(&did_subject, CallTypeOf::RecoveryCancelled)?;
+			Self::deposit_event(Event::RecoveryCancelled { identifier: did_subject });
+			Ok(())
+		}
+
+		/// Replace the authentication key of `did` with the key approved by
+		/// its guardians, once the recovery delay has elapsed.
+		///
+		/// The old key is deleted from the set of public keys if it is not
+		/// used in any other part of the DID.
+		///
+		/// The account of the lost key might own the deposit of the DID, so
+		/// the deposit is moved to the account submitting the call: it is
+		/// freed for the current deposit owner and the deposit the DID
+		/// requires is reserved from the submitter.
+		///
+		/// The dispatch origin can be any CORD account.
+		///
+		/// Emits `Recovered` and `DepositOwnerChanged`.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::finalize_recovery())]
+		pub fn finalize_recovery(origin: OriginFor<T>, did: DidIdentifierOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let request = ActiveRecoveries::<T>::get(&did).ok_or(Error::<T>::RecoveryNotFound)?;
+			let current_block_number = frame_system::Pallet::<T>::block_number();
+			let (new_key, executable_at) =
+				request.executable.ok_or(Error::<T>::RecoveryNotExecutable)?;
+			ensure!(executable_at <= current_block_number, Error::<T>::RecoveryNotExecutable);
+
+			let mut did_details = Did::<T>::get(&did).ok_or(Error::<T>::NotFound)?;
+			did_details
+				.update_authentication_key(new_key, current_block_number)
+				.map_err(Error::<T>::from)?;
+
+			Self::store_did(&did, did_details);
+			ActiveRecoveries::<T>::remove(&did);
+			let previous_owner = Self::move_deposit(&did, sender.clone())?;
+
+			Self::update_activity(&did, CallTypeOf::Recovered)?;
+			Self::deposit_event(Event::DepositOwnerChanged {
+				identifier: did.clone(),
+				from: previous_owner,
+				to: sender,
+			});
+			Self::deposit_event(Event::Recovered { identifier: did });
+			Ok(())
+		}
+
+		/// Move the deposit of the DID to the account submitting the call.
+		///
+		/// The deposit is freed for the current deposit owner and the deposit
+		/// the keys and service endpoints of the DID require is reserved from
+		/// the new one.
+		///
+		/// The dispatch origin must be a DID origin proxied via the
+		/// `submit_did_call` extrinsic.
+		///
+		/// Emits `DepositOwnerChanged`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::change_deposit_owner())]
+		pub fn change_deposit_owner(origin: OriginFor<T>) -> DispatchResult {
+			let source = T::EnsureOrigin::ensure_origin(origin)?;
+			let did_subject = source.subject();
+			let new_owner = source.sender();
+			let previous_owner = Self::move_deposit(&did_subject, new_owner.clone())?;
+
+			Self::deposit_event(Event::DepositOwnerChanged {
+				identifier: did_subject,
+				from: previous_owner,
+				to: new_owner,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T>
@@ -1140,8 +1648,16 @@ pub mod pallet {
 			// otherwise generate a AlreadyExists error.
 			ensure!(!Did::<T>::contains_key(&did_identifier), Error::<T>::AlreadyExists);
 
+			Self::record_key_history(&did_identifier, None, Some(&did_entry));
 			Did::<T>::insert(&did_identifier, did_entry);
 
+			let deposit = reserve_deposit::<AccountIdOf<T>, CurrencyOf<T>>(
+				sender.clone(),
+				DidDepositCollector::<T>::deposit_amount(&did_identifier),
+			)
+			.map_err(|_| Error::<T>::UnableToPayFees)?;
+			DidDeposits::<T>::insert(&did_identifier, deposit);
+
 			Pallet::<T>::deposit_event(Event::Created {
 				author: sender,
 				identifier: did_identifier,
@@ -1160,11 +1676,46 @@ pub mod pallet {
 			did_identifier: &DidIdentifierOf<T>,
 			did_details: DidDetails<T>,
 		) -> DispatchResult {
-			Did::<T>::insert(did_identifier, did_details);
+			Self::store_did(did_identifier, did_details);
+
+			// The number of key agreement keys or service endpoints might have changed,
+			// or the DID might still have the zero deposit of DIDs created before
+			// deposits were introduced.
+			let deposit = DidDepositCollector::<T>::deposit(did_identifier)?;
+			if deposit.amount != DidDepositCollector::<T>::deposit_amount(did_identifier) {
+				DidDepositCollector::<T>::update_deposit(did_identifier)
+					.map_err(|_| Error::<T>::UnableToPayFees)?;
+			}
 
 			Ok(())
 		}
 
+		/// Move the deposit of the DID to `new_owner`, topping it up to the
+		/// deposit the DID requires. Returns the previous deposit owner.
+		fn move_deposit(
+			did_subject: &DidIdentifierOf<T>,
+			new_owner: AccountIdOf<T>,
+		) -> Result<AccountIdOf<T>, DispatchError> {
+			let deposit = DidDepositCollector::<T>::deposit(did_subject)?;
+
+			DidDepositCollector::<T>::change_deposit_owner(did_subject, new_owner)
+				.map_err(|_| Error::<T>::UnableToPayFees)?;
+			if deposit.amount != DidDepositCollector::<T>::deposit_amount(did_subject) {
+				DidDepositCollector::<T>::update_deposit(did_subject)
+					.map_err(|_| Error::<T>::UnableToPayFees)?;
+			}
+
+			Ok(deposit.owner)
+		}
+
+		/// Store the details of a DID, recording the keys they replace in the
+		/// key history. The deposit of the DID is left as is.
+		fn store_did(did_identifier: &DidIdentifierOf<T>, did_details: DidDetails<T>) {
+			let old_details = Did::<T>::get(did_identifier);
+			Self::record_key_history(did_identifier, old_details.as_ref(), Some(&did_details));
+			Did::<T>::insert(did_identifier, did_details);
+		}
+
 		/// Verify the validity (i.e., nonce, signature and mortality) of a
 		/// DID-authorized operation and, if valid, update the DID state with
 		/// the latest nonce.
@@ -1312,9 +1863,19 @@ pub mod pallet {
 			};
 
 			// `take` calls `kill` internally
-			let _did_entry = Did::<T>::take(&did_subject).ok_or(Error::<T>::NotFound)?;
+			ensure!(Did::<T>::take(&did_subject).is_some(), Error::<T>::NotFound);
 
+			// Both are bounded by `MaxKeyHistory`.
+			let _ = KeyHistory::<T>::clear_prefix(&did_subject, T::MaxKeyHistory::get(), None);
+			let _ =
+				ActiveKeyHistory::<T>::clear_prefix(&did_subject, T::MaxKeyHistory::get(), None);
+			KeyHistoryCounters::<T>::remove(&did_subject);
 			DidEndpointsCount::<T>::remove(&did_subject);
+			if let Some(deposit) = DidDeposits::<T>::take(&did_subject) {
+				free_deposit::<AccountIdOf<T>, CurrencyOf<T>>(&deposit);
+			}
+			RecoveryConfigs::<T>::remove(&did_subject);
+			ActiveRecoveries::<T>::remove(&did_subject);
 			// Mark as deleted to prevent potential replay-attacks of re-adding a previously
 			// deleted DID.
 			DidBlacklist::<T>::insert(&did_subject, ());
@@ -1325,5 +1886,261 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Returns the identifier the activity of a DID is recorded under on
+		/// the identifier timeline.
+		pub fn timeline_identifier(
+			did_subject: &DidIdentifierOf<T>,
+		) -> Result<Ss58Identifier, Error<T>> {
+			Ss58Identifier::create_identifier(&did_subject.encode()[..], IdentifierType::Entity)
+				.map_err(|_| Error::<T>::Internal)
+		}
+
+		/// Records a recovery step of a DID on the identifier timeline.
+		fn update_activity(
+			did_subject: &DidIdentifierOf<T>,
+			tx_action: CallTypeOf,
+		) -> Result<(), Error<T>> {
+			let tx_id = Self::timeline_identifier(did_subject)?;
+			let tx_moment = Timepoint {
+				height: frame_system::Pallet::<T>::block_number().saturated_into(),
+				index: frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+			};
+
+			let tx_entry = EventEntryOf { action: tx_action, location: tx_moment };
+			IdentifierTimeline::update_timeline::<T>(&tx_id, IdentifierTypeOf::Did, tx_entry)
+				.map_err(|_| Error::<T>::TimelineUpdateFailed)
+		}
+
+		/// Records the keys added and removed between two states of a DID in
+		/// its key history, bumping the DID key version if anything changed.
+		///
+		/// DIDs written before the key history existed get their current keys
+		/// recorded as version 0 the first time they change. Once the history
+		/// holds more than `MaxKeyHistory` entries, the oldest entries of keys
+		/// no longer in use are dropped.
+		fn record_key_history(
+			did_subject: &DidIdentifierOf<T>,
+			old_details: Option<&DidDetails<T>>,
+			new_details: Option<&DidDetails<T>>,
+		) {
+			let old_keys =
+				old_details.map(|details| details.key_relationships()).unwrap_or_default();
+			let new_keys =
+				new_details.map(|details| details.key_relationships()).unwrap_or_default();
+			if old_keys == new_keys {
+				return;
+			}
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let mut counters = KeyHistoryCounters::<T>::get(did_subject);
+
+			if let Some(old_details) = old_details {
+				if counters.entries == 0 {
+					for relationship in old_keys.iter() {
+						let active_from = old_details
+							.public_keys
+							.get(&relationship.1)
+							.map(|key_details| key_details.block_number)
+							.unwrap_or_default();
+						Self::open_key_history_entry(
+							did_subject,
+							&mut counters,
+							old_details,
+							*relationship,
+							active_from,
+						);
+					}
+				}
+				counters.version = counters.version.saturating_add(1);
+			}
+
+			for relationship in old_keys.difference(&new_keys) {
+				if let Some(index) = ActiveKeyHistory::<T>::take(did_subject, relationship) {
+					KeyHistory::<T>::mutate(did_subject, index, |entry| {
+						if let Some(entry) = entry {
+							entry.removed_in = Some(counters.version);
+							entry.active_until = Some(block_number);
+						}
+					});
+				}
+			}
+			if let Some(new_details) = new_details {
+				for relationship in new_keys.difference(&old_keys) {
+					Self::open_key_history_entry(
+						did_subject,
+						&mut counters,
+						new_details,
+						*relationship,
+						block_number,
+					);
+				}
+			}
+
+			Self::prune_key_history(did_subject, &mut counters);
+			KeyHistoryCounters::<T>::insert(did_subject, counters);
+		}
+
+		/// Drops the oldest entries of the key history of a DID until at most
+		/// `MaxKeyHistory` are left. The versions a dropped entry was active in
+		/// are no longer complete, so they are no longer available. Entries of
+		/// keys still in use are moved to the end of the history instead.
+		fn prune_key_history(
+			did_subject: &DidIdentifierOf<T>,
+			counters: &mut DidKeyHistoryCounters,
+		) {
+			while counters.entries.saturating_sub(counters.oldest) > T::MaxKeyHistory::get() {
+				let index = counters.oldest;
+				counters.oldest = counters.oldest.saturating_add(1);
+				let Some(entry) = KeyHistory::<T>::take(did_subject, index) else {
+					continue;
+				};
+
+				match entry.removed_in {
+					Some(removed_in) =>
+						counters.first_version = counters.first_version.max(removed_in),
+					None => {
+						let moved_to = counters.entries;
+						ActiveKeyHistory::<T>::insert(
+							did_subject,
+							(entry.relationship, entry.key_id),
+							moved_to,
+						);
+						KeyHistory::<T>::insert(did_subject, moved_to, entry);
+						counters.entries = counters.entries.saturating_add(1);
+					},
+				}
+			}
+		}
+
+		/// Adds a key history entry for a key starting to be used by a DID in
+		/// the current key version.
+		fn open_key_history_entry(
+			did_subject: &DidIdentifierOf<T>,
+			counters: &mut DidKeyHistoryCounters,
+			details: &DidDetails<T>,
+			(relationship, key_id): (DidKeyRelationship, KeyIdOf<T>),
+			active_from: BlockNumberFor<T>,
+		) {
+			let Some(key_details) = details.public_keys.get(&key_id) else {
+				return;
+			};
+
+			let index = counters.entries;
+			KeyHistory::<T>::insert(
+				did_subject,
+				index,
+				DidKeyHistoryEntry {
+					key_id,
+					key: key_details.key.clone(),
+					relationship,
+					added_in: counters.version,
+					active_from,
+					removed_in: None,
+					active_until: None,
+				},
+			);
+			ActiveKeyHistory::<T>::insert(did_subject, (relationship, key_id), index);
+			counters.entries = counters.entries.saturating_add(1);
+		}
+
+		/// Returns the keys a DID had in the given key version, along with the
+		/// version itself, or `None` if no key history exists for the DID or
+		/// the version is in the future or no longer kept.
+		pub fn keys_at_version(
+			did_subject: &DidIdentifierOf<T>,
+			version: u32,
+		) -> Option<(u32, Vec<DidKeyHistoryEntryOf<T>>)> {
+			let counters = KeyHistoryCounters::<T>::get(did_subject);
+			if counters.entries == 0 ||
+				version > counters.version ||
+				version < counters.first_version
+			{
+				return None;
+			}
+
+			let keys = KeyHistory::<T>::iter_prefix_values(did_subject)
+				.filter(|entry| entry.is_active_in_version(version))
+				.collect();
+
+			Some((version, keys))
+		}
+
+		/// Returns the keys a DID had at the given block, along with the key
+		/// version in effect at that block, or `None` if no key history exists
+		/// for the DID, the DID did not exist yet or the version in effect is no
+		/// longer kept.
+		pub fn keys_at_block(
+			did_subject: &DidIdentifierOf<T>,
+			block_number: BlockNumberFor<T>,
+		) -> Option<(u32, Vec<DidKeyHistoryEntryOf<T>>)> {
+			let entries: Vec<DidKeyHistoryEntryOf<T>> =
+				KeyHistory::<T>::iter_prefix_values(did_subject).collect();
+
+			// Every change of version opens or closes at least one entry, so the
+			// version at a block is the latest one that touched an entry by then.
+			let version = entries
+				.iter()
+				.flat_map(|entry| {
+					let added = (entry.active_from <= block_number).then_some(entry.added_in);
+					let removed = entry
+						.active_until
+						.filter(|until| *until <= block_number)
+						.and(entry.removed_in);
+					added.into_iter().chain(removed)
+				})
+				.max()?;
+			if version < KeyHistoryCounters::<T>::get(did_subject).first_version {
+				return None;
+			}
+
+			let keys = entries
+				.into_iter()
+				.filter(|entry| entry.is_active_at_block(&block_number))
+				.collect();
+
+			Some((version, keys))
+		}
+	}
+}
+
+/// Gives access to the deposits reserved for DIDs.
+pub struct DidDepositCollector<T: Config>(PhantomData<T>);
+
+impl<T: Config> StorageDepositCollector<AccountIdOf<T>, DidIdentifierOf<T>>
+	for DidDepositCollector<T>
+{
+	type Currency = CurrencyOf<T>;
+
+	/// DIDs created before deposits were introduced have no deposit recorded.
+	/// They are given a zero deposit owned by the DID account, which is
+	/// stored the first time the deposit is updated or moved.
+	fn deposit(did_subject: &DidIdentifierOf<T>) -> Result<DepositOf<T>, DispatchError> {
+		match DidDeposits::<T>::get(did_subject) {
+			Some(deposit) => Ok(deposit),
+			None if Did::<T>::contains_key(did_subject) =>
+				Ok(DepositOf::<T> { owner: did_subject.clone().into(), amount: Zero::zero() }),
+			None => Err(Error::<T>::NotFound.into()),
+		}
+	}
+
+	fn deposit_amount(did_subject: &DidIdentifierOf<T>) -> BalanceOf<T> {
+		let key_agreement_keys: u32 = Did::<T>::get(did_subject)
+			.map(|details| details.key_agreement_keys.len().saturated_into())
+			.unwrap_or_default();
+		let endpoints = DidEndpointsCount::<T>::get(did_subject);
+
+		T::BaseDeposit::get()
+			.saturating_add(T::KeyDeposit::get().saturating_mul(key_agreement_keys.into()))
+			.saturating_add(T::ServiceEndpointDeposit::get().saturating_mul(endpoints.into()))
+	}
+
+	fn store_deposit(
+		did_subject: &DidIdentifierOf<T>,
+		deposit: DepositOf<T>,
+	) -> Result<(), DispatchError> {
+		ensure!(Did::<T>::contains_key(did_subject), Error::<T>::NotFound);
+		DidDeposits::<T>::insert(did_subject, deposit);
+		Ok(())
 	}
 }
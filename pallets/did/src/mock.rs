@@ -25,7 +25,7 @@ use frame_support::{derive_impl, parameter_types};
 use frame_system::EnsureRoot;
 use pallet_chain_space::IsPermissioned;
 use scale_info::TypeInfo;
-use sp_core::{ecdsa, ed25519, sr25519, Pair};
+use sp_core::{bls381, ecdsa, ed25519, sr25519, Pair};
 use sp_runtime::{
 	testing::H256,
 	traits::{IdentifyAccount, IdentityLookup, Verify},
@@ -40,7 +40,7 @@ use crate::{
 		DeriveDidCallAuthorizationVerificationKeyRelationship, DeriveDidCallKeyRelationshipResult,
 		DidAuthorizedCallOperation, DidAuthorizedCallOperationWithVerificationRelationship,
 		DidDetails, DidEncryptionKey, DidPublicKey, DidPublicKeyDetails, DidVerificationKey,
-		DidVerificationKeyRelationship, RelationshipDeriveError,
+		DidVerificationKeyRelationship, P256Public, RelationshipDeriveError,
 	},
 	utils as crate_utils, Config, KeyIdOf,
 };
@@ -58,6 +58,7 @@ frame_support::construct_runtime!(
 	{
 		Did: pallet_did,
 		System: frame_system,
+		Balances: pallet_balances,
 		Space: pallet_chain_space,
 		Identifier: identifier,
 		MockOrigin: mock_origin,
@@ -77,6 +78,20 @@ impl frame_system::Config for Test {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type SS58Prefix = SS58Prefix;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+pub(crate) type Balance = u128;
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
 }
 
 parameter_types! {
@@ -94,6 +109,12 @@ parameter_types! {
 	pub const MaxServiceUrlLength: u32 = 100u32;
 	pub const MaxNumberOfTypesPerService: u32 = 1u32;
 	pub const MaxNumberOfUrlsPerService: u32 = 1u32;
+	pub const MaxKeyHistory: u32 = 20u32;
+	pub const MaxGuardians: u32 = 3u32;
+	pub const MinRecoveryDelay: u64 = 5u64;
+	pub const BaseDeposit: Balance = 10;
+	pub const KeyDeposit: Balance = 2;
+	pub const ServiceEndpointDeposit: Balance = 3;
 }
 
 impl Config for Test {
@@ -122,6 +143,13 @@ impl Config for Test {
 	type MaxServiceUrlLength = MaxServiceUrlLength;
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+	type MaxKeyHistory = MaxKeyHistory;
+	type MaxGuardians = MaxGuardians;
+	type MinRecoveryDelay = MinRecoveryDelay;
+	type Currency = Balances;
+	type BaseDeposit = BaseDeposit;
+	type KeyDeposit = KeyDeposit;
+	type ServiceEndpointDeposit = ServiceEndpointDeposit;
 }
 
 impl mock_origin::Config for Test {
@@ -142,6 +170,12 @@ impl IsPermissioned for NetworkPermission {
 	}
 }
 
+parameter_types! {
+	pub const MaxSpaceDelegationExpiriesPerBlock: u32 = 2;
+	pub const CapacityPrice: Balance = 2;
+	pub const MaxSpaceDepth: u32 = 3;
+}
+
 impl pallet_chain_space::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
@@ -150,19 +184,33 @@ impl pallet_chain_space::Config for Test {
 	type MaxSpaceDelegates = MaxSpaceDelegates;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type MaxDelegationExpiriesPerBlock = MaxSpaceDelegationExpiriesPerBlock;
+	type MaxSpaceDepth = MaxSpaceDepth;
+	type Currency = Balances;
+	type CapacityPrice = CapacityPrice;
+	type CapacityPayment = ();
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MaxEventsHistory: u32 = 6u32;
+	pub const TimelineOverflow: identifier::TimelineOverflowMode =
+		identifier::TimelineOverflowMode::Drop;
+	pub const MaxArchivedEventsPerPage: u32 = 10u32;
 }
 
 impl identifier::Config for Test {
 	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
 }
 
 pub(crate) const ACCOUNT_00: AccountId = AccountId::new([1u8; 32]);
 pub(crate) const ACCOUNT_01: AccountId = AccountId::new([2u8; 32]);
+/// An account without any balance.
+pub(crate) const ACCOUNT_02: AccountId = AccountId::new([3u8; 32]);
+
+pub(crate) const INITIAL_BALANCE: Balance = 1_000;
 
 pub(crate) const AUTH_SEED_0: [u8; 32] = [4u8; 32];
 pub(crate) const AUTH_SEED_1: [u8; 32] = [40u8; 32];
@@ -206,6 +254,14 @@ pub fn get_did_identifier_from_ecdsa_key(public_key: ecdsa::Public) -> DidIdenti
 	MultiSigner::from(public_key).into_account()
 }
 
+pub fn get_did_identifier_from_p256_key(public_key: P256Public) -> DidIdentifier {
+	DidVerificationKey::<AccountId>::from(public_key).into_account()
+}
+
+pub fn get_did_identifier_from_bls381_key(public_key: bls381::Public) -> DidIdentifier {
+	DidVerificationKey::<AccountId>::from(public_key).into_account()
+}
+
 pub fn get_ed25519_authentication_key(seed: &[u8; 32]) -> ed25519::Pair {
 	ed25519::Pair::from_seed(seed)
 }
@@ -218,6 +274,14 @@ pub fn get_ecdsa_authentication_key(seed: &[u8; 32]) -> ecdsa::Pair {
 	ecdsa::Pair::from_seed(seed)
 }
 
+pub fn get_p256_authentication_key(seed: &[u8; 32]) -> p256::ecdsa::SigningKey {
+	p256::ecdsa::SigningKey::from_slice(seed).expect("Seed should be a valid P-256 secret key.")
+}
+
+pub fn get_bls381_authentication_key(seed: &[u8; 32]) -> bls381::Pair {
+	bls381::Pair::from_seed(seed)
+}
+
 pub fn get_x25519_encryption_key(seed: &[u8; 32]) -> DidEncryptionKey {
 	DidEncryptionKey::X25519(*seed)
 }
@@ -338,7 +402,12 @@ pub fn generate_test_did_call(
 	}
 }
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
-	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(ACCOUNT_00, INITIAL_BALANCE), (ACCOUNT_01, INITIAL_BALANCE)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(t);
 	#[cfg(feature = "runtime-benchmarks")]
@@ -0,0 +1,96 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::BoundedVec;
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+
+use crate::{did_details::DidVerificationKey, AccountIdOf, Config, DidIdentifierOf};
+
+/// A bounded vector of guardian DIDs.
+pub type DidGuardiansOf<T> = BoundedVec<DidIdentifierOf<T>, <T as Config>::MaxGuardians>;
+
+/// The recovery setup a DID opted into.
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct DidRecoveryConfig<T: Config> {
+	/// The DIDs allowed to approve the recovery of the DID.
+	pub guardians: DidGuardiansOf<T>,
+	/// The number of guardian approvals needed before the recovery can be
+	/// completed.
+	pub threshold: u32,
+	/// The number of blocks between the threshold being reached and the
+	/// recovery becoming executable, during which the current authentication
+	/// key can cancel it.
+	pub delay: BlockNumberFor<T>,
+}
+
+/// The authentication keys proposed by the guardians approving a recovery.
+pub type DidRecoveryProposalsOf<T> = BoundedVec<
+	(DidIdentifierOf<T>, DidVerificationKey<AccountIdOf<T>>),
+	<T as Config>::MaxGuardians,
+>;
+
+/// A recovery of a DID being approved by its guardians.
+#[derive(Clone, Decode, RuntimeDebug, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct DidRecoveryRequest<T: Config> {
+	/// The authentication key proposed by each guardian that approved the
+	/// recovery so far. A guardian can change or withdraw its proposal.
+	pub proposals: DidRecoveryProposalsOf<T>,
+	/// The block the recovery was initiated in.
+	pub initiated_at: BlockNumberFor<T>,
+	/// \[OPTIONAL\] The authentication key proposed by the threshold of
+	/// guardians and the block from which the recovery to it can be
+	/// completed. Cleared again if the key drops below the threshold.
+	pub executable: Option<(DidVerificationKey<AccountIdOf<T>>, BlockNumberFor<T>)>,
+}
+
+impl<T: Config> DidRecoveryRequest<T> {
+	/// The number of guardians proposing `key`.
+	pub fn approvals_of(&self, key: &DidVerificationKey<AccountIdOf<T>>) -> u32 {
+		self.proposals.iter().filter(|(_, proposed)| proposed == key).count() as u32
+	}
+
+	/// Keeps the recovery executable only while its key is proposed by at
+	/// least `threshold` guardians. Otherwise, the first key proposed by
+	/// `threshold` guardians becomes executable `delay` blocks after `now`.
+	pub fn update_executable(
+		&mut self,
+		threshold: u32,
+		delay: BlockNumberFor<T>,
+		now: BlockNumberFor<T>,
+	) {
+		if let Some((key, _)) = &self.executable {
+			if self.approvals_of(key) >= threshold {
+				return;
+			}
+		}
+
+		self.executable = self
+			.proposals
+			.iter()
+			.map(|(_, key)| key)
+			.find(|key| self.approvals_of(key) >= threshold)
+			.map(|key| (key.clone(), now.saturating_add(delay)));
+	}
+}
@@ -19,9 +19,14 @@
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
 use codec::Encode;
-use frame_support::{assert_noop, assert_ok};
+use cord_utilities::{deposit::Deposit, traits::StorageDepositCollector};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use frame_system::pallet_prelude::BlockNumberFor;
 
+use p256::ecdsa::signature::Signer;
 use sp_core::{ed25519, Pair};
 use sp_runtime::{traits::BadOrigin, SaturatedConversion};
 use sp_std::{
@@ -31,7 +36,10 @@ use sp_std::{
 
 use crate::{
 	self as did,
-	did_details::{DidEncryptionKey, DidVerificationKey, DidVerificationKeyRelationship},
+	did_details::{
+		DidEncryptionKey, DidVerificationKey, DidVerificationKeyRelationship, P256Public,
+		P256Signature,
+	},
 	mock::*,
 	mock_utils::*,
 	service_endpoints::DidEndpoint,
@@ -326,6 +334,27 @@ fn check_invalid_signature_did_creation() {
 	});
 }
 
+#[test]
+fn check_p256_did_creation_error() {
+	let auth_key = get_p256_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_p256_key(P256Public::from(auth_key.verifying_key()));
+	let details = generate_base_did_creation_details::<Test>(alice_did, ACCOUNT_00);
+
+	let signature: p256::ecdsa::Signature = auth_key.sign(details.encode().as_ref());
+
+	new_test_ext().execute_with(|| {
+		// P-256 keys cannot be recovered from the DID identifier
+		assert_noop!(
+			Did::create(
+				RuntimeOrigin::signed(ACCOUNT_00),
+				Box::new(details),
+				did::DidSignature::from(P256Signature::from(signature))
+			),
+			did::Error::<Test>::InvalidSignatureFormat
+		);
+	});
+}
+
 #[test]
 fn check_swapped_did_subject_did_creation() {
 	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
@@ -641,6 +670,100 @@ fn check_successful_authentication_key_update() {
 	});
 }
 
+#[test]
+fn check_key_history_authentication_key_update() {
+	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(old_auth_key.public());
+	let new_auth_key = get_ed25519_authentication_key(&AUTH_SEED_1);
+	let new_att_key = get_ed25519_assertion_key(&ATT_SEED_0);
+	let old_auth_key_id = generate_key_id(&DidVerificationKey::from(old_auth_key.public()).into());
+	let new_auth_key_id = generate_key_id(&DidVerificationKey::from(new_auth_key.public()).into());
+
+	let old_did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(old_auth_key.public()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	// Rotate the authentication key at block 1 and add an assertion key at block
+	// 5. The keys in use before the first rotation are recorded as version 0.
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(alice_did.clone(), old_did_details);
+
+		System::set_block_number(1);
+		assert_ok!(Did::set_authentication_key(
+			origin.clone(),
+			DidVerificationKey::from(new_auth_key.public())
+		));
+		System::set_block_number(5);
+		assert_ok!(Did::set_assertion_key(origin, DidVerificationKey::from(new_att_key.public())));
+
+		let (version, keys) =
+			Did::keys_at_version(&alice_did, 0).expect("Version 0 should be recorded.");
+		assert_eq!(version, 0);
+		assert_eq!(keys.len(), 1);
+		assert_eq!(keys[0].key_id, old_auth_key_id);
+		assert_eq!(keys[0].active_until, Some(1));
+
+		let (version, keys) =
+			Did::keys_at_block(&alice_did, 3).expect("Block 3 should be covered.");
+		assert_eq!(version, 1);
+		assert_eq!(keys.len(), 1);
+		assert_eq!(keys[0].key_id, new_auth_key_id);
+
+		let (version, keys) =
+			Did::keys_at_block(&alice_did, 5).expect("Block 5 should be covered.");
+		assert_eq!(version, 2);
+		assert_eq!(keys.len(), 2);
+		assert!(keys.iter().all(|entry| entry.key_id != old_auth_key_id));
+
+		assert!(Did::keys_at_version(&alice_did, 3).is_none());
+	});
+}
+
+#[test]
+fn check_key_history_pruning_and_deletion() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+
+	let origin = build_test_origin(alice_did.clone(), alice_did.clone());
+
+	// Each rotation closes one entry and opens another, so after 25 rotations
+	// the history holds 26 entries, 6 more than `MaxKeyHistory` allows.
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(alice_did.clone(), did_details);
+
+		for i in 1..=25u8 {
+			System::set_block_number(i.into());
+			let new_auth_key = get_ed25519_authentication_key(&[100 + i; 32]);
+			assert_ok!(Did::set_authentication_key(
+				origin.clone(),
+				DidVerificationKey::from(new_auth_key.public())
+			));
+		}
+
+		assert_eq!(did::KeyHistory::<Test>::iter_prefix(&alice_did).count(), 20);
+		assert!(Did::keys_at_version(&alice_did, 5).is_none());
+		assert!(Did::keys_at_block(&alice_did, 3).is_none());
+
+		let (_, keys) = Did::keys_at_version(&alice_did, 6).expect("Version 6 should be kept.");
+		assert_eq!(keys.len(), 1);
+		let (version, keys) =
+			Did::keys_at_block(&alice_did, 25).expect("Block 25 should be covered.");
+		assert_eq!(version, 25);
+		assert_eq!(keys.len(), 1);
+
+		assert_ok!(Did::delete(origin, 0));
+		assert_eq!(did::KeyHistory::<Test>::iter_prefix(&alice_did).count(), 0);
+		assert_eq!(did::ActiveKeyHistory::<Test>::iter_prefix(&alice_did).count(), 0);
+		assert!(Did::keys_at_version(&alice_did, 25).is_none());
+	});
+}
+
 #[test]
 fn check_successful_authentication_key_max_public_keys_update() {
 	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
@@ -2023,6 +2146,417 @@ fn check_service_count_too_small_deletion_error() {
 	});
 }
 
+// deposits
+
+#[test]
+fn check_did_deposit_follows_keys_and_service_endpoints() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let signature = auth_key.sign(details.encode().as_ref());
+	let new_key_agreement_key = get_x25519_encryption_key(&ENC_SEED_0);
+	let new_service_endpoint =
+		DidEndpoint::new(b"id".to_vec(), vec![b"type".to_vec()], vec![b"url".to_vec()]);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Did::create(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			Box::new(details),
+			did::DidSignature::from(signature),
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), BaseDeposit::get());
+
+		assert_ok!(Did::add_key_agreement_key(
+			build_test_origin(ACCOUNT_00, alice_did.clone()),
+			new_key_agreement_key
+		));
+		assert_ok!(Did::add_service_endpoint(
+			build_test_origin(ACCOUNT_00, alice_did.clone()),
+			new_service_endpoint.clone()
+		));
+		let full_deposit = BaseDeposit::get() + KeyDeposit::get() + ServiceEndpointDeposit::get();
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), full_deposit);
+		assert_eq!(
+			did::DidDeposits::<Test>::get(&alice_did),
+			Some(Deposit { owner: ACCOUNT_00, amount: full_deposit })
+		);
+
+		assert_ok!(Did::remove_service_endpoint(
+			build_test_origin(ACCOUNT_00, alice_did.clone()),
+			new_service_endpoint.id
+		));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), BaseDeposit::get() + KeyDeposit::get());
+
+		assert_ok!(Did::delete(build_test_origin(ACCOUNT_00, alice_did.clone()), 0));
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
+		assert!(did::DidDeposits::<Test>::get(&alice_did).is_none());
+	});
+}
+
+#[test]
+fn check_did_creation_without_funds_error() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did, ACCOUNT_02);
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Did::create(
+				RuntimeOrigin::signed(ACCOUNT_02),
+				Box::new(details),
+				did::DidSignature::from(signature),
+			),
+			did::Error::<Test>::UnableToPayFees
+		);
+	});
+}
+
+#[test]
+fn check_change_deposit_owner() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let details = generate_base_did_creation_details::<Test>(alice_did.clone(), ACCOUNT_00);
+	let signature = auth_key.sign(details.encode().as_ref());
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Did::create(
+			RuntimeOrigin::signed(ACCOUNT_00),
+			Box::new(details),
+			did::DidSignature::from(signature),
+		));
+
+		assert_noop!(
+			Did::change_deposit_owner(build_test_origin(ACCOUNT_02, alice_did.clone())),
+			did::Error::<Test>::UnableToPayFees
+		);
+		assert_ok!(Did::change_deposit_owner(build_test_origin(ACCOUNT_01, alice_did.clone())));
+
+		assert_eq!(Balances::reserved_balance(ACCOUNT_00), 0);
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), BaseDeposit::get());
+		System::assert_last_event(
+			did::Event::<Test>::DepositOwnerChanged {
+				identifier: alice_did,
+				from: ACCOUNT_00,
+				to: ACCOUNT_01,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn check_change_deposit_owner_tops_up_zero_deposit() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+
+	new_test_ext().execute_with(|| {
+		// A DID stored before deposits were introduced.
+		did::Did::<Test>::insert(alice_did.clone(), did_details);
+		did::DidDeposits::<Test>::insert(
+			alice_did.clone(),
+			Deposit { owner: alice_did.clone(), amount: 0 },
+		);
+
+		assert_ok!(Did::change_deposit_owner(build_test_origin(ACCOUNT_01, alice_did.clone())));
+
+		assert_eq!(Balances::reserved_balance(ACCOUNT_01), BaseDeposit::get());
+		assert_eq!(
+			did::DidDeposits::<Test>::get(&alice_did),
+			Some(Deposit { owner: ACCOUNT_01, amount: BaseDeposit::get() })
+		);
+	});
+}
+
+#[test]
+fn check_migration_to_v2_leaves_zero_deposits_to_be_recorded_lazily() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+
+	new_test_ext().execute_with(|| {
+		// A DID stored before deposits were introduced.
+		did::Did::<Test>::insert(alice_did.clone(), did_details);
+		StorageVersion::new(1).put::<Did>();
+
+		did::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert!(did::DidDeposits::<Test>::get(&alice_did).is_none());
+		assert_eq!(
+			did::DidDepositCollector::<Test>::deposit(&alice_did),
+			Ok(Deposit { owner: alice_did.clone(), amount: 0 })
+		);
+		assert_eq!(Did::on_chain_storage_version(), 2);
+	});
+}
+
+// recovery
+
+#[test]
+fn check_successful_did_recovery() {
+	let old_auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(old_auth_key.public());
+	let new_auth_key = get_ed25519_authentication_key(&AUTH_SEED_1);
+	let new_key = DidVerificationKey::from(new_auth_key.public());
+	let bob_did = AccountId::new([2u8; 32]);
+	let charlie_did = AccountId::new([3u8; 32]);
+
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(old_auth_key.public()));
+	let guardians: did::recovery::DidGuardiansOf<Test> =
+		vec![bob_did.clone(), charlie_did.clone()].try_into().unwrap();
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(alice_did.clone(), did_details);
+		System::set_block_number(1);
+
+		assert_noop!(
+			Did::set_recovery_config(
+				build_test_origin(alice_did.clone(), alice_did.clone()),
+				guardians.clone(),
+				3,
+				MinRecoveryDelay::get()
+			),
+			did::Error::<Test>::InvalidRecoveryConfig
+		);
+		assert_ok!(Did::set_recovery_config(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			guardians,
+			2,
+			MinRecoveryDelay::get()
+		));
+
+		assert_noop!(
+			Did::approve_recovery(
+				build_test_origin(ACCOUNT_00, ACCOUNT_00),
+				alice_did.clone(),
+				new_key.clone()
+			),
+			did::Error::<Test>::NotAGuardian
+		);
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			new_key.clone()
+		));
+		assert_noop!(
+			Did::approve_recovery(
+				build_test_origin(bob_did.clone(), bob_did.clone()),
+				alice_did.clone(),
+				new_key.clone()
+			),
+			did::Error::<Test>::RecoveryAlreadyApproved
+		);
+		assert_noop!(
+			Did::finalize_recovery(RuntimeOrigin::signed(ACCOUNT_00), alice_did.clone()),
+			did::Error::<Test>::RecoveryNotExecutable
+		);
+
+		// The threshold is reached, the recovery becomes executable after the delay.
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(charlie_did.clone(), charlie_did.clone()),
+			alice_did.clone(),
+			new_key.clone()
+		));
+		assert_noop!(
+			Did::finalize_recovery(RuntimeOrigin::signed(ACCOUNT_00), alice_did.clone()),
+			did::Error::<Test>::RecoveryNotExecutable
+		);
+
+		System::set_block_number(1 + MinRecoveryDelay::get());
+		assert_ok!(Did::finalize_recovery(RuntimeOrigin::signed(ACCOUNT_00), alice_did.clone()));
+
+		let new_did_details =
+			did::Did::<Test>::get(&alice_did).expect("ALICE_DID should be present on chain.");
+		assert_eq!(new_did_details.authentication_key, generate_key_id(&new_key.into()));
+		assert!(did::ActiveRecoveries::<Test>::get(&alice_did).is_none());
+		assert!(did::RecoveryConfigs::<Test>::get(&alice_did).is_some());
+		// The account finalizing the recovery takes over the deposit.
+		assert_eq!(
+			did::DidDeposits::<Test>::get(&alice_did),
+			Some(Deposit { owner: ACCOUNT_00, amount: BaseDeposit::get() })
+		);
+
+		let timeline_id = Did::timeline_identifier(&alice_did).unwrap();
+		let actions: Vec<_> =
+			Identifier::timeline(&timeline_id, identifier::types::IdentifierTypeOf::Did, 0, 10)
+				.into_iter()
+				.map(|entry| entry.action)
+				.collect();
+		assert_eq!(
+			actions,
+			vec![
+				identifier::types::CallTypeOf::RecoveryInitiated,
+				identifier::types::CallTypeOf::Approved,
+				identifier::types::CallTypeOf::Approved,
+				identifier::types::CallTypeOf::Recovered,
+			]
+		);
+	});
+}
+
+#[test]
+fn check_conflicting_did_recovery_proposals() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let first_key = DidVerificationKey::from(get_ed25519_authentication_key(&AUTH_SEED_1).public());
+	let second_key =
+		DidVerificationKey::from(get_sr25519_authentication_key(&AUTH_SEED_1).public());
+	let bob_did = AccountId::new([2u8; 32]);
+	let charlie_did = AccountId::new([3u8; 32]);
+	let dave_did = AccountId::new([4u8; 32]);
+
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+	let guardians: did::recovery::DidGuardiansOf<Test> =
+		vec![bob_did.clone(), charlie_did.clone(), dave_did.clone()].try_into().unwrap();
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(alice_did.clone(), did_details);
+		System::set_block_number(1);
+
+		assert_ok!(Did::set_recovery_config(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			guardians,
+			2,
+			MinRecoveryDelay::get()
+		));
+
+		// Withdrawing the only approval drops the recovery.
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			first_key.clone()
+		));
+		assert_ok!(Did::withdraw_recovery_approval(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone()
+		));
+		assert!(did::ActiveRecoveries::<Test>::get(&alice_did).is_none());
+
+		// Conflicting proposals do not reach the threshold.
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			first_key.clone()
+		));
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(charlie_did.clone(), charlie_did.clone()),
+			alice_did.clone(),
+			second_key.clone()
+		));
+		assert!(did::ActiveRecoveries::<Test>::get(&alice_did).unwrap().executable.is_none());
+
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(dave_did.clone(), dave_did.clone()),
+			alice_did.clone(),
+			second_key.clone()
+		));
+		assert_eq!(
+			did::ActiveRecoveries::<Test>::get(&alice_did).unwrap().executable,
+			Some((second_key.clone(), 1 + MinRecoveryDelay::get()))
+		);
+
+		// A withdrawal below the threshold resets the recovery.
+		assert_ok!(Did::withdraw_recovery_approval(
+			build_test_origin(dave_did.clone(), dave_did.clone()),
+			alice_did.clone()
+		));
+		assert_noop!(
+			Did::withdraw_recovery_approval(
+				build_test_origin(dave_did.clone(), dave_did.clone()),
+				alice_did.clone()
+			),
+			did::Error::<Test>::RecoveryNotApproved
+		);
+		assert!(did::ActiveRecoveries::<Test>::get(&alice_did).unwrap().executable.is_none());
+
+		System::set_block_number(1 + MinRecoveryDelay::get());
+		assert_noop!(
+			Did::finalize_recovery(RuntimeOrigin::signed(ACCOUNT_00), alice_did.clone()),
+			did::Error::<Test>::RecoveryNotExecutable
+		);
+
+		// A guardian changing its proposal restarts the delay for the other key.
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(charlie_did.clone(), charlie_did.clone()),
+			alice_did.clone(),
+			first_key.clone()
+		));
+		assert_noop!(
+			Did::finalize_recovery(RuntimeOrigin::signed(ACCOUNT_00), alice_did.clone()),
+			did::Error::<Test>::RecoveryNotExecutable
+		);
+
+		System::set_block_number(1 + 2 * MinRecoveryDelay::get());
+		assert_ok!(Did::finalize_recovery(RuntimeOrigin::signed(ACCOUNT_00), alice_did.clone()));
+		assert_eq!(
+			did::Did::<Test>::get(&alice_did).unwrap().authentication_key,
+			generate_key_id(&first_key.into())
+		);
+	});
+}
+
+#[test]
+fn check_cancelled_did_recovery() {
+	let auth_key = get_ed25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_ed25519_key(auth_key.public());
+	let new_key = DidVerificationKey::from(get_ed25519_authentication_key(&AUTH_SEED_1).public());
+	let bob_did = AccountId::new([2u8; 32]);
+
+	let did_details =
+		generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+	let guardians: did::recovery::DidGuardiansOf<Test> = vec![bob_did.clone()].try_into().unwrap();
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(alice_did.clone(), did_details.clone());
+		System::set_block_number(1);
+
+		assert_ok!(Did::set_recovery_config(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			guardians.clone(),
+			1,
+			MinRecoveryDelay::get()
+		));
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			new_key.clone()
+		));
+
+		// The current authentication key cancels during the delay window.
+		assert_ok!(Did::cancel_recovery(build_test_origin(alice_did.clone(), alice_did.clone())));
+
+		// Replacing the recovery setup also cancels the recovery in progress, so
+		// a guardian cannot lock the setup by keeping a recovery open.
+		assert_ok!(Did::approve_recovery(
+			build_test_origin(bob_did.clone(), bob_did.clone()),
+			alice_did.clone(),
+			new_key
+		));
+		assert_ok!(Did::set_recovery_config(
+			build_test_origin(alice_did.clone(), alice_did.clone()),
+			guardians,
+			1,
+			MinRecoveryDelay::get()
+		));
+		assert!(did::ActiveRecoveries::<Test>::get(&alice_did).is_none());
+
+		System::set_block_number(1 + MinRecoveryDelay::get());
+		assert_noop!(
+			Did::finalize_recovery(RuntimeOrigin::signed(ACCOUNT_00), alice_did.clone()),
+			did::Error::<Test>::RecoveryNotFound
+		);
+		assert_eq!(
+			did::Did::<Test>::get(&alice_did).unwrap().authentication_key,
+			did_details.authentication_key
+		);
+	});
+}
+
 // submit_did_call
 
 #[test]
@@ -2874,3 +3408,116 @@ fn check_invalid_signature_operation_verification() {
 		);
 	});
 }
+
+#[test]
+fn check_p256_operation_verification() {
+	let auth_key = get_p256_authentication_key(&AUTH_SEED_0);
+	let auth_public_key = P256Public::from(auth_key.verifying_key());
+	let did = get_did_identifier_from_p256_key(auth_public_key);
+	let alternative_key = get_p256_authentication_key(&AUTH_SEED_1);
+
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_public_key));
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		did.clone(),
+		ACCOUNT_00,
+	);
+	let signature: p256::ecdsa::Signature = auth_key.sign(&call_operation.encode());
+	let invalid_signature: p256::ecdsa::Signature = alternative_key.sign(&call_operation.encode());
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(did.clone(), mock_did);
+		assert_noop!(
+			Did::verify_did_operation_signature_and_increase_nonce(
+				&call_operation,
+				&did::DidSignature::from(P256Signature::from(invalid_signature))
+			),
+			did::errors::DidError::Signature(did::errors::SignatureError::InvalidData)
+		);
+		assert_ok!(Did::verify_did_operation_signature_and_increase_nonce(
+			&call_operation,
+			&did::DidSignature::from(P256Signature::from(signature))
+		));
+		assert_eq!(did::Did::<Test>::get(&did).unwrap().last_tx_counter, 1u64);
+	});
+}
+
+#[test]
+fn check_p256_webauthn_assertion_is_rejected() {
+	let auth_key = get_p256_authentication_key(&AUTH_SEED_0);
+	let auth_public_key = P256Public::from(auth_key.verifying_key());
+	let did = get_did_identifier_from_p256_key(auth_public_key);
+
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_public_key));
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		did.clone(),
+		ACCOUNT_00,
+	);
+
+	// An assertion of a WebAuthn authenticator whose challenge is the hash of
+	// the operation. The authenticator signs its data (RP ID hash, flags and
+	// signature counter) followed by the SHA-256 hash of the client data.
+	let challenge = sp_core::hashing::sha2_256(&call_operation.encode());
+	let client_data_json = format!(
+		r#"{{"type":"webauthn.get","challenge":"0x{}","origin":"https://cord.network"}}"#,
+		sp_core::hexdisplay::HexDisplay::from(&challenge.to_vec())
+	);
+	let authenticator_data = [
+		&sp_core::hashing::sha2_256(b"cord.network")[..],
+		// User present and user verified flags.
+		&[0x05u8][..],
+		&1u32.to_be_bytes()[..],
+	]
+	.concat();
+	let assertion: p256::ecdsa::Signature = auth_key.sign(
+		&[&authenticator_data[..], &sp_core::hashing::sha2_256(client_data_json.as_bytes())[..]]
+			.concat(),
+	);
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(did.clone(), mock_did);
+		assert_noop!(
+			Did::verify_did_operation_signature_and_increase_nonce(
+				&call_operation,
+				&did::DidSignature::from(P256Signature::from(assertion))
+			),
+			did::errors::DidError::Signature(did::errors::SignatureError::InvalidData)
+		);
+	});
+}
+
+#[test]
+fn check_bls381_operation_verification() {
+	let auth_key = get_bls381_authentication_key(&AUTH_SEED_0);
+	let did = get_did_identifier_from_bls381_key(auth_key.public());
+	let alternative_key = get_bls381_authentication_key(&AUTH_SEED_1);
+
+	let mock_did = generate_base_did_details::<Test>(DidVerificationKey::from(auth_key.public()));
+
+	let call_operation = generate_test_did_call(
+		DidVerificationKeyRelationship::Authentication,
+		did.clone(),
+		ACCOUNT_00,
+	);
+	let signature = auth_key.sign(&call_operation.encode());
+	let invalid_signature = alternative_key.sign(&call_operation.encode());
+
+	new_test_ext().execute_with(|| {
+		did::Did::<Test>::insert(did.clone(), mock_did);
+		assert_noop!(
+			Did::verify_did_operation_signature_and_increase_nonce(
+				&call_operation,
+				&did::DidSignature::from(invalid_signature)
+			),
+			did::errors::DidError::Signature(did::errors::SignatureError::InvalidData)
+		);
+		assert_ok!(Did::verify_did_operation_signature_and_increase_nonce(
+			&call_operation,
+			&did::DidSignature::from(signature)
+		));
+		assert_eq!(did::Did::<Test>::get(&did).unwrap().last_tx_counter, 1u64);
+	});
+}
@@ -16,28 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for `pallet_did`
+//! Placeholder weights for `pallet_did`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-05-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/production/cord
-// benchmark
-// pallet
-// --chain=dev
-// --steps=50
-// --repeat=20
-// --pallet=pallet_did
-// --extrinsic=*
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --output=./pallets/did/src/weights.rs
-// --header=./HEADER-GPL3
-// --template=./.maintain/frame-weight-template.hbs
+//! These weights are not the output of a single benchmark run. The execution
+//! times of the calls that predate DID key history, recovery, P-256 and
+//! BLS12-381 keys and deposits come from a 2024-05-17 run of the benchmark CLI
+//! on `AMD EPYC 7B13`, with their storage access counts raised by hand to cover
+//! the storage those features added. The weights of the calls added with
+//! those features are estimates, including the BLS12-381 pairing check of
+//! `submit_did_call_bls381_key`. Key changes count one pruned key history
+//! entry and `delete` counts clearing a key history of 100 entries, the
+//! `MaxKeyHistory` of the runtimes. They must all be regenerated with
+//! `cord benchmark pallet --pallet=pallet_did` on reference hardware.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -84,6 +74,15 @@ pub trait WeightInfo {
 	fn signature_verification_ecdsa(l: u32, ) -> Weight;
 	fn dispatch_as() -> Weight;
 	fn create_from_account() -> Weight;
+	fn set_recovery_config() -> Weight;
+	fn remove_recovery_config() -> Weight;
+	fn approve_recovery() -> Weight;
+	fn cancel_recovery() -> Weight;
+	fn finalize_recovery() -> Weight;
+	fn submit_did_call_p256_key() -> Weight;
+	fn submit_did_call_bls381_key() -> Weight;
+	fn change_deposit_owner() -> Weight;
+	fn withdraw_recovery_approval() -> Weight;
 }
 
 /// Weights for `pallet_did` using the CORD node and recommended hardware.
@@ -97,6 +96,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
@@ -109,8 +118,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(1_100_329, 0).saturating_mul(n.into()))
 			// Standard Error: 18_439
 			.saturating_add(Weight::from_parts(7_004_725, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -121,6 +131,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
@@ -133,8 +153,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(1_326_418, 0).saturating_mul(n.into()))
 			// Standard Error: 18_961
 			.saturating_add(Weight::from_parts(8_086_917, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -145,6 +166,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
@@ -157,8 +188,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(951_571, 0).saturating_mul(n.into()))
 			// Standard Error: 15_243
 			.saturating_add(Weight::from_parts(6_828_914, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
@@ -169,6 +201,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:0 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:22)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:100)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -178,9 +224,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(26_520_178, 5649)
 			// Standard Error: 4_861
 			.saturating_add(Weight::from_parts(1_245_907, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(130_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2888).saturating_mul(c.into()))
 	}
@@ -219,234 +265,384 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_330_000 picoseconds.
 		Weight::from_parts(26_690_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_811_000 picoseconds.
 		Weight::from_parts(26_830_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 26_100_000 picoseconds.
 		Weight::from_parts(27_670_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_590_000 picoseconds.
 		Weight::from_parts(27_269_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 26_071_000 picoseconds.
 		Weight::from_parts(26_940_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_690_000 picoseconds.
 		Weight::from_parts(27_340_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_100_000 picoseconds.
 		Weight::from_parts(24_850_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_310_000 picoseconds.
 		Weight::from_parts(24_550_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_700_000 picoseconds.
 		Weight::from_parts(24_800_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_690_000 picoseconds.
 		Weight::from_parts(25_600_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_940_000 picoseconds.
 		Weight::from_parts(26_100_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 22_870_000 picoseconds.
 		Weight::from_parts(26_400_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_060_000 picoseconds.
 		Weight::from_parts(24_630_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_949_000 picoseconds.
 		Weight::from_parts(26_110_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 22_349_000 picoseconds.
 		Weight::from_parts(25_229_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1355`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_129_000 picoseconds.
 		Weight::from_parts(25_940_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1355`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_900_000 picoseconds.
 		Weight::from_parts(27_130_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1358`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_020_000 picoseconds.
 		Weight::from_parts(25_380_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_131_000 picoseconds.
 		Weight::from_parts(24_291_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_360_000 picoseconds.
 		Weight::from_parts(24_440_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_490_000 picoseconds.
 		Weight::from_parts(24_791_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -454,14 +650,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:1 w:1)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_service_endpoint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `905`
 		//  Estimated: `5649`
 		// Minimum execution time: 30_550_000 picoseconds.
 		Weight::from_parts(32_000_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -469,14 +669,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_service_endpoint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1286`
 		//  Estimated: `5649`
 		// Minimum execution time: 29_150_000 picoseconds.
 		Weight::from_parts(30_700_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:0)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -531,15 +735,173 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:1)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn create_from_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `5649`
 		// Minimum execution time: 13_889_000 picoseconds.
 		Weight::from_parts(14_910_000, 5649)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	fn set_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `586`
+		//  Estimated: `5649`
+		Weight::from_parts(23_440_000, 5649)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	fn remove_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3842`
+		Weight::from_parts(22_467_000, 3842)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `817`
+		//  Estimated: `5649`
+		Weight::from_parts(37_482_000, 5649)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `599`
+		//  Estimated: `3877`
+		Weight::from_parts(27_512_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn finalize_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1030`
+		//  Estimated: `6196`
+		Weight::from_parts(71_209_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	fn submit_did_call_p256_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `499`
+		//  Estimated: `5649`
+		Weight::from_parts(264_255_000, 5649)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	fn submit_did_call_bls381_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `419`
+		//  Estimated: `5649`
+		Weight::from_parts(3_930_572_000, 5649)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn change_deposit_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `789`
+		//  Estimated: `6196`
+		Weight::from_parts(41_274_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn withdraw_recovery_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `656`
+		//  Estimated: `3877`
+		Weight::from_parts(32_639_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -552,6 +914,16 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
@@ -564,8 +936,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(1_100_329, 0).saturating_mul(n.into()))
 			// Standard Error: 18_439
 			.saturating_add(Weight::from_parts(7_004_725, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -576,6 +949,16 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
@@ -588,8 +971,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(1_326_418, 0).saturating_mul(n.into()))
 			// Standard Error: 18_961
 			.saturating_add(Weight::from_parts(8_086_917, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -600,6 +984,16 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
@@ -612,8 +1006,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(951_571, 0).saturating_mul(n.into()))
 			// Standard Error: 15_243
 			.saturating_add(Weight::from_parts(6_828_914, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
@@ -624,6 +1019,20 @@ impl WeightInfo for () {
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:0 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:22)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:100)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -633,9 +1042,9 @@ impl WeightInfo for () {
 		Weight::from_parts(26_520_178, 5649)
 			// Standard Error: 4_861
 			.saturating_add(Weight::from_parts(1_245_907, 0).saturating_mul(c.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(130_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2888).saturating_mul(c.into()))
 	}
@@ -674,234 +1083,384 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_330_000 picoseconds.
 		Weight::from_parts(26_690_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_811_000 picoseconds.
 		Weight::from_parts(26_830_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 26_100_000 picoseconds.
 		Weight::from_parts(27_670_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_590_000 picoseconds.
 		Weight::from_parts(27_269_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 26_071_000 picoseconds.
 		Weight::from_parts(26_940_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 25_690_000 picoseconds.
 		Weight::from_parts(27_340_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_100_000 picoseconds.
 		Weight::from_parts(24_850_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_310_000 picoseconds.
 		Weight::from_parts(24_550_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_700_000 picoseconds.
 		Weight::from_parts(24_800_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_690_000 picoseconds.
 		Weight::from_parts(25_600_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_940_000 picoseconds.
 		Weight::from_parts(26_100_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 22_870_000 picoseconds.
 		Weight::from_parts(26_400_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_060_000 picoseconds.
 		Weight::from_parts(24_630_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_949_000 picoseconds.
 		Weight::from_parts(26_110_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 22_349_000 picoseconds.
 		Weight::from_parts(25_229_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1355`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_129_000 picoseconds.
 		Weight::from_parts(25_940_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1355`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_900_000 picoseconds.
 		Weight::from_parts(27_130_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1358`
 		//  Estimated: `5649`
 		// Minimum execution time: 24_020_000 picoseconds.
 		Weight::from_parts(25_380_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_131_000 picoseconds.
 		Weight::from_parts(24_291_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_360_000 picoseconds.
 		Weight::from_parts(24_440_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
 		//  Estimated: `5649`
 		// Minimum execution time: 23_490_000 picoseconds.
 		Weight::from_parts(24_791_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -909,14 +1468,18 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:1 w:1)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_service_endpoint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `905`
 		//  Estimated: `5649`
 		// Minimum execution time: 30_550_000 picoseconds.
 		Weight::from_parts(32_000_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -924,14 +1487,18 @@ impl WeightInfo for () {
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_service_endpoint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1286`
 		//  Estimated: `5649`
 		// Minimum execution time: 29_150_000 picoseconds.
 		Weight::from_parts(30_700_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `Did::Did` (r:1 w:0)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -986,13 +1553,171 @@ impl WeightInfo for () {
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:1)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn create_from_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `5649`
 		// Minimum execution time: 13_889_000 picoseconds.
 		Weight::from_parts(14_910_000, 5649)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	fn set_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `586`
+		//  Estimated: `5649`
+		Weight::from_parts(23_440_000, 5649)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	fn remove_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3842`
+		Weight::from_parts(22_467_000, 3842)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `817`
+		//  Estimated: `5649`
+		Weight::from_parts(37_482_000, 5649)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `599`
+		//  Estimated: `3877`
+		Weight::from_parts(27_512_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn finalize_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1030`
+		//  Estimated: `6196`
+		Weight::from_parts(71_209_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	fn submit_did_call_p256_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `499`
+		//  Estimated: `5649`
+		Weight::from_parts(264_255_000, 5649)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	fn submit_did_call_bls381_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `419`
+		//  Estimated: `5649`
+		Weight::from_parts(3_930_572_000, 5649)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn change_deposit_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `789`
+		//  Estimated: `6196`
+		Weight::from_parts(41_274_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn withdraw_recovery_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `656`
+		//  Estimated: `3877`
+		Weight::from_parts(32_639_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -9,7 +9,6 @@ use identifier::{IdentifierType, Ss58Identifier};
 use pallet_namespace::{NameSpaceCodeOf, NameSpaceIdOf};
 use pallet_registries::{RegistryBlobOf, RegistryHashOf, RegistryIdOf};
 use pallet_schema_accounts::{InputSchemaOf, SchemaHashOf, SchemaIdOf};
-use serde_json::json;
 use sp_std::prelude::*;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -52,6 +51,105 @@ pub fn generate_registry_entry_id<T: Config>(digest: &RegistryEntryHashOf<T>) ->
 
 const SEED: u32 = 0;
 
+/// Anchors a namespace and a registry owned by `creator`, returning the registry identifier
+/// along with the namespace and registry authorizations of the creator.
+fn setup_registry<T>(
+	creator: &T::AccountId,
+) -> Result<(RegistryIdOf, NamespaceAuthorizationIdOf, RegistryAuthorizationIdOf), &'static str>
+where
+	T: Config + pallet_namespace::Config + pallet_schema_accounts::Config,
+{
+	let namespace = [1u8; 256].to_vec();
+	let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+	let namespace_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<T>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+
+	let raw_blob = [2u8; 256].to_vec();
+	let blob: RegistryBlobOf<T> = BoundedVec::try_from(raw_blob)
+		.expect("Test blob should fit into the expected input length for the test runtime.");
+
+	let registry_digest = <T as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+
+	let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<T>(&auth_id_digest);
+
+	let raw_schema = [2u8; 256].to_vec();
+	let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+		.expect("Test schema should fit into the expected input length for the test runtime.");
+	let schema_id_digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+	let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+	pallet_namespace::Pallet::<T>::create(
+		RawOrigin::Signed(creator.clone()).into(),
+		namespace_digest,
+		None,
+	)?;
+
+	pallet_schema_accounts::Pallet::<T>::create(RawOrigin::Signed(creator.clone()).into(), schema)?;
+
+	pallet_registries::Pallet::<T>::create(
+		RawOrigin::Signed(creator.clone()).into(),
+		registry_digest,
+		namespace_authorization_id.clone(),
+		Some(schema_id),
+		Some(blob),
+	)?;
+
+	Ok((registry_id, namespace_authorization_id, authorization_id))
+}
+
+/// Builds a Registry Entry blob of `l` bytes along with the digest it hashes to.
+fn entry_blob<T: Config>(l: u32, fill: u8) -> (RegistryEntryBlobOf<T>, RegistryEntryHashOf<T>) {
+	let blob: RegistryEntryBlobOf<T> = BoundedVec::try_from(sp_std::vec![fill; l as usize]).expect(
+		"Test Blob should fit into the expected input length of BLOB for the test runtime.",
+	);
+	let digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+
+	(blob, digest)
+}
+
+/// Derives the Registry Entry identifier the same way `create` expects it.
+fn entry_id<T: Config>(
+	digest: &RegistryEntryHashOf<T>,
+	registry_id: &RegistryIdOf,
+	creator: &T::AccountId,
+) -> RegistryEntryIdOf {
+	let registry_entry_id_digest = <T as frame_system::Config>::Hashing::hash(
+		&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	generate_registry_entry_id::<T>(&registry_entry_id_digest)
+}
+
+/// Builds `n` distinct Registry Entry digests for the batch benchmarks.
+fn batch_digests<T: Config>(n: u32) -> RegistryEntryDigestsOf<T> {
+	let digests: Vec<RegistryEntryHashOf<T>> = (0..n)
+		.map(|i| <T as frame_system::Config>::Hashing::hash(&i.encode()[..]))
+		.collect();
+
+	BoundedVec::try_from(digests)
+		.expect("Test digests should fit into the batch limit for the test runtime.")
+}
+
 benchmarks! {
 		where_clause {
 			where
@@ -63,115 +161,396 @@ benchmarks! {
 
 
 		create {
+			let l in 1 .. T::MaxRegistryEntryBlobSize::get();
+
 			let creator: T::AccountId = account("creator", 0, SEED);
 
-			let namespace = [1u8; 256].to_vec();
-			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
+
+			let (registry_entry_blob, registry_entry_digest) = entry_blob::<T>(l, 1u8);
+			let registry_entry_id = entry_id::<T>(&registry_entry_digest, &registry_id, &creator);
+			let schema_id = pallet_registries::RegistryInfo::<T>::get(&registry_id)
+				.and_then(|registry| registry.schema_id);
 
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			registry_entry_digest,
+			Some(registry_entry_blob),
+			schema_id
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntryCreated {
+					creator: creator,
+					registry_id: registry_id,
+					registry_entry_id: registry_entry_id,
+				}
+				.into()
 			);
-			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+		}
+
+
+		update {
+			let l in 1 .. T::MaxRegistryEntryBlobSize::get();
+
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
+
+			let (registry_entry_blob, registry_entry_digest) = entry_blob::<T>(1, 1u8);
+			let registry_entry_id = entry_id::<T>(&registry_entry_digest, &registry_id, &creator);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				Some(registry_entry_blob),
+				None,
+			)?;
+
+			let (new_registry_entry_blob, new_registry_entry_digest) = entry_blob::<T>(l, 2u8);
 
-			let namespace_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			new_registry_entry_digest,
+			Some(new_registry_entry_blob)
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntryUpdated {
+					updater: creator,
+					registry_entry_id: registry_entry_id,
+				}
+				.into()
 			);
-			let namespace_authorization_id: NamespaceAuthorizationIdOf = generate_namespace_authorization_id::<T>(&namespace_auth_id_digest);
+		}
 
-			let registry = [2u8; 256].to_vec();
 
-			let raw_blob = [2u8; 256].to_vec();
-			let blob: RegistryBlobOf<T> = BoundedVec::try_from(raw_blob)
-				.expect("Test blob should fit into the expected input length for the test runtime.");
+		revoke {
+			let creator: T::AccountId = account("creator", 0, SEED);
 
-			let registry_digest = <T as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
 
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			let (_, registry_entry_digest) = entry_blob::<T>(256, 1u8);
+			let registry_entry_id = entry_id::<T>(&registry_entry_digest, &registry_id, &creator);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				None,
+				None,
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntryRevoked {
+					updater: creator,
+					registry_entry_id: registry_entry_id,
+				}
+				.into()
 			);
+		}
+
+
+		reinstate {
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
 
-			let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+			let (_, registry_entry_digest) = entry_blob::<T>(256, 1u8);
+			let registry_entry_id = entry_id::<T>(&registry_entry_digest, &registry_id, &creator);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				None,
+				None,
+			)?;
+
+			Pallet::<T>::revoke(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+			)?;
 
-			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntryReinstated {
+					updater: creator,
+					registry_entry_id: registry_entry_id,
+				}
+				.into()
 			);
+		}
+
+
+		update_ownership {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let new_owner: T::AccountId = account("new_owner", 0, SEED);
+
+			let (registry_id, namespace_authorization_id, authorization_id) =
+				setup_registry::<T>(&creator)?;
 
-			let authorization_id: RegistryAuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
-
-			let raw_schema = [2u8; 256].to_vec();
-			let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
-				.expect("Test schema should fit into the expected input length for the test runtime.");
-			let schema_id_digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
-			let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
-
-			let registry_entry_json_object = json!({
-				"name": "Alice",
-				"age": 25,
-				"email": "alice@dhiway.com",
-				"isActive": true,
-				"address": {
-					"street": "Koramangala",
-					"city": "Bengaluru",
-					"zipcode": "560001"
-				},
-				"phoneNumbers": [
-					"+91-234787324",
-					"+91-283746823"
-				]
-			});
-
-			let registry_entry_json_string =
-				serde_json::to_string(&registry_entry_json_object).expect("Failed to serialize JSON");
-
-			let registry_entry_raw_bytes = registry_entry_json_string.as_bytes().to_vec();
-
-			let registry_entry_blob: RegistryEntryBlobOf<T> =
-				BoundedVec::try_from(registry_entry_raw_bytes.clone()).expect(
-					"Test Blob should fit into the expected input length of BLOB for the test runtime.",
-				);
-
-			let registry_entry_digest: RegistryHashOf<T> =
-				<T as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
-
-			let registry_entry_id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[
-					&registry_entry_digest.encode()[..],
-					&registry_id.encode()[..],
-					&creator.encode()[..],
-				]
-				.concat()[..],
+			let new_owner_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_id.encode()[..], &new_owner.encode()[..], &creator.encode()[..]].concat()[..],
 			);
+			let new_owner_authorization_id: RegistryAuthorizationIdOf =
+				generate_authorization_id::<T>(&new_owner_auth_id_digest);
+
+			pallet_registries::Pallet::<T>::add_delegate(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				new_owner.clone(),
+				namespace_authorization_id,
+				authorization_id.clone(),
+			)?;
 
-			let registry_entry_id: RegistryEntryIdOf =
-				generate_registry_entry_id::<T>(&registry_entry_id_digest);
+			let (_, registry_entry_digest) = entry_blob::<T>(256, 1u8);
+			let registry_entry_id = entry_id::<T>(&registry_entry_digest, &registry_id, &creator);
 
-			pallet_namespace::Pallet::<T>::create(
+			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
-				namespace_digest,
-				None
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				None,
+				None,
 			)?;
 
-			pallet_registries::Pallet::<T>::create(
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			new_owner.clone(),
+			new_owner_authorization_id
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntryOwnershipUpdated {
+					updater: creator,
+					new_owner: new_owner,
+					registry_entry_id: registry_entry_id,
+				}
+				.into()
+			);
+		}
+
+
+		set_validity {
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
+
+			let (_, registry_entry_digest) = entry_blob::<T>(256, 1u8);
+			let registry_entry_id = entry_id::<T>(&registry_entry_digest, &registry_id, &creator);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				None,
+				None,
+			)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let valid_from = now + 1u32.into();
+			let valid_until = now + 100u32.into();
+
+			/* Worst case: a previously scheduled expiry has to be unscheduled. */
+			Pallet::<T>::set_validity(
 				RawOrigin::Signed(creator.clone()).into(),
-				registry_digest,
-				namespace_authorization_id.clone(),
-				Some(schema_id.clone()),
-				Some(blob),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				None,
+				Some(now + 50u32.into()),
 			)?;
 
 		}: _<T::RuntimeOrigin>(
 			RawOrigin::Signed(creator.clone()).into(),
 			registry_entry_id.clone(),
 			authorization_id.clone(),
-			registry_entry_digest,
-			None
+			Some(valid_from),
+			Some(valid_until)
 		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntryValiditySet {
+					updater: creator,
+					registry_entry_id: registry_entry_id,
+					valid_from: Some(valid_from),
+					valid_until: Some(valid_until),
+				}
+				.into()
+			);
+		}
+
 
+		create_batch {
+			let n in 1 .. T::MaxEntriesPerBatch::get();
+
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
+
+			let digests = batch_digests::<T>(n);
+			let schema_id = pallet_registries::RegistryInfo::<T>::get(&registry_id)
+				.and_then(|registry| registry.schema_id);
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			digests,
+			authorization_id.clone(),
+			schema_id
+		)
 		verify {
 			assert_last_event::<T>(
-				Event::RegistryEntryCreated {
+				Event::RegistryEntriesBatchCreated {
 					creator: creator,
 					registry_id: registry_id,
+					successful: n,
+					failed: 0,
+					indices: BoundedVec::new(),
+				}
+				.into()
+			);
+		}
+
+
+		revoke_batch {
+			let n in 1 .. T::MaxEntriesPerBatch::get();
+
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
+
+			let digests = batch_digests::<T>(n);
+			let registry_entry_ids: Vec<RegistryEntryIdOf> = digests
+				.iter()
+				.map(|digest| entry_id::<T>(digest, &registry_id, &creator))
+				.collect();
+
+			Pallet::<T>::create_batch(
+				RawOrigin::Signed(creator.clone()).into(),
+				digests,
+				authorization_id.clone(),
+				None,
+			)?;
+
+			let registry_entry_ids: RegistryEntryIdsOf<T> = BoundedVec::try_from(registry_entry_ids)
+				.expect("Test identifiers should fit into the batch limit for the test runtime.");
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_ids,
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntriesBatchRevoked {
+					updater: creator,
+					registry_id: registry_id,
+					successful: n,
+					failed: 0,
+					indices: BoundedVec::new(),
+				}
+				.into()
+			);
+		}
+
+
+		reinstate_batch {
+			let n in 1 .. T::MaxEntriesPerBatch::get();
+
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
+
+			let digests = batch_digests::<T>(n);
+			let registry_entry_ids: Vec<RegistryEntryIdOf> = digests
+				.iter()
+				.map(|digest| entry_id::<T>(digest, &registry_id, &creator))
+				.collect();
+			let registry_entry_ids: RegistryEntryIdsOf<T> = BoundedVec::try_from(registry_entry_ids)
+				.expect("Test identifiers should fit into the batch limit for the test runtime.");
+
+			Pallet::<T>::create_batch(
+				RawOrigin::Signed(creator.clone()).into(),
+				digests,
+				authorization_id.clone(),
+				None,
+			)?;
+
+			Pallet::<T>::revoke_batch(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_ids.clone(),
+				authorization_id.clone(),
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_ids,
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntriesBatchReinstated {
+					updater: creator,
+					registry_id: registry_id,
+					successful: n,
+					failed: 0,
+					indices: BoundedVec::new(),
+				}
+				.into()
+			);
+		}
+
+		remove_blob {
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let (registry_id, _, authorization_id) = setup_registry::<T>(&creator)?;
+
+			let (registry_entry_blob, registry_entry_digest) =
+				entry_blob::<T>(T::MaxRegistryEntryBlobSize::get(), 1u8);
+			let registry_entry_id = entry_id::<T>(&registry_entry_digest, &registry_id, &creator);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				Some(registry_entry_blob),
+				None,
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::RegistryEntryBlobRemoved {
+					updater: creator,
 					registry_entry_id: registry_entry_id,
 				}
 				.into()
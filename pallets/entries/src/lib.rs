@@ -35,9 +35,27 @@
 //! * `update` - Updates a existing Registry Entry.
 //! * `revoke` - Revokes a existing Registry Entry.
 //! * `reinstate` - Reinstates a existing Registry Entry.
+//! * `set_validity` - Sets the validity window of a existing Registry Entry.
+//! * `create_batch` - Creates a batch of Registry Entries under one authorization.
+//! * `revoke_batch` - Revokes a batch of existing Registry Entries under one authorization.
+//! * `reinstate_batch` - Reinstates a batch of revoked Registry Entries under one authorization.
+//!
+//! ### Batches
+//!
+//! Batch calls validate the registry authorization once and process every item independently.
+//! Items that cannot be processed are skipped and reported by their index in the summary event;
+//! the call only fails if no item succeeds.
+//!
+//! ### Expiry
+//!
+//! Registry Entries with a `valid_until` block are scheduled for expiry. Scheduled expiries are
+//! swept in `on_idle`, emitting `RegistryEntryExpired` events and recording an `Expire` entry in
+//! the identifier timeline. `registry_entry_status` reports `Expired` as soon as the block is
+//! reached, regardless of whether the sweep has run yet.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod types;
+pub mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
@@ -48,13 +66,20 @@ pub mod mock;
 #[cfg(test)]
 mod tests;
 
+use codec::Encode;
+use cord_utilities::schedule;
 use frame_support::{
 	ensure,
-	pallet_prelude::DispatchResult,
+	pallet_prelude::{DispatchResult, Weight},
+	storage::{with_transaction, TransactionOutcome},
 	traits::{Get, StorageVersion},
 	BoundedVec,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Hash, UniqueSaturatedInto},
+	DispatchError, SaturatedConversion,
+};
 
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
@@ -64,8 +89,8 @@ use identifier::{
 pub use pallet::*;
 use sp_std::{prelude::*, str};
 
-pub use frame_system::WeightInfo;
-pub use types::RegistryEntryDetails;
+pub use types::{RegistryEntryDetails, RegistryEntryStatus, RegistryEntryValidity};
+pub use weights::WeightInfo;
 
 pub use cord_primitives::StatusOf;
 
@@ -88,6 +113,8 @@ pub mod pallet {
 	pub type RegistryIdOf = Ss58Identifier;
 	/// Type of the Resgistry Entry Identifier
 	pub type RegistryEntryIdOf = Ss58Identifier;
+	/// Type of the Schema Identifier
+	pub type SchemaIdOf = Ss58Identifier;
 	/// Type of the Maximum size of Registry Entry Blob
 	pub type MaxRegistryEntryBlobSizeOf<T> = <T as crate::Config>::MaxRegistryEntryBlobSize;
 	/// Type of the Registry Entry Creator
@@ -100,6 +127,20 @@ pub mod pallet {
 	pub type RegistryEntryDetailsOf<T> =
 		RegistryEntryDetails<RegistryEntryHashOf<T>, StatusOf, CreatorOf<T>, RegistryIdOf>;
 
+	/// Type of the Registry Entry Validity window.
+	pub type RegistryEntryValidityOf<T> = RegistryEntryValidity<BlockNumberFor<T>>;
+
+	/// Type of the Registry Entry Digests handled by a batch call.
+	pub type RegistryEntryDigestsOf<T> =
+		BoundedVec<RegistryEntryHashOf<T>, <T as crate::Config>::MaxEntriesPerBatch>;
+	/// Type of the Registry Entry Identifiers handled by a batch call.
+	pub type RegistryEntryIdsOf<T> =
+		BoundedVec<RegistryEntryIdOf, <T as crate::Config>::MaxEntriesPerBatch>;
+	/// Type of the indices of the items of a batch call that could not be handled. Bounded
+	/// like the batch itself, so recording an index never fails.
+	pub type RegistryEntryBatchIndicesOf<T> =
+		BoundedVec<u32, <T as crate::Config>::MaxEntriesPerBatch>;
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config + pallet_registries::Config + identifier::Config
@@ -116,6 +157,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxEncodedInputLength: Get<u32>;
 
+		/// The maximum number of Registry Entries that can expire at a single block.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		/// The maximum number of Registry Entries handled by a single batch call.
+		#[pallet::constant]
+		type MaxEntriesPerBatch: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -127,7 +176,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expiries(n, remaining_weight)
+		}
+	}
 
 	/// Storage for Registry Entries.
 	/// It maps Registry Entry Identifier to Registry Entry Details.
@@ -135,6 +188,39 @@ pub mod pallet {
 	pub type RegistryEntries<T: Config> =
 		StorageMap<_, Blake2_128Concat, RegistryEntryIdOf, RegistryEntryDetailsOf<T>, OptionQuery>;
 
+	/// Storage for Registry Entry Blobs.
+	/// It maps Registry Entry Identifier to the latest Blob supplied for the entry.
+	#[pallet::storage]
+	pub type RegistryEntryBlobs<T: Config> =
+		StorageMap<_, Blake2_128Concat, RegistryEntryIdOf, RegistryEntryBlobOf<T>, OptionQuery>;
+
+	/// Storage for Registry Entry schemas.
+	/// It maps Registry Entry Identifier to the Schema it was created under.
+	#[pallet::storage]
+	pub type RegistryEntrySchemas<T: Config> =
+		StorageMap<_, Blake2_128Concat, RegistryEntryIdOf, SchemaIdOf, OptionQuery>;
+
+	/// Storage for Registry Entry validity windows.
+	/// It maps Registry Entry Identifier to the blocks it is valid from and until.
+	#[pallet::storage]
+	pub type RegistryEntryValidities<T: Config> =
+		StorageMap<_, Blake2_128Concat, RegistryEntryIdOf, RegistryEntryValidityOf<T>, OptionQuery>;
+
+	/// Storage for scheduled Registry Entry expiries.
+	/// It maps a block number to the Registry Entries expiring at that block.
+	#[pallet::storage]
+	pub type RegistryEntryExpiries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<RegistryEntryIdOf, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next block whose scheduled expiries have not been swept yet.
+	#[pallet::storage]
+	pub type NextExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Invalid Identifer Length
@@ -151,6 +237,19 @@ pub mod pallet {
 		RegistryEntryNotRevoked,
 		/// New Registry Entry owner cannot be same as existing owner.
 		NewOwnerCannotBeSameAsExistingOwner,
+		/// Registry Entry Identifier does not match the one derived from
+		/// the digest, registry and creator.
+		RegistryEntryIdentifierMismatch,
+		/// Registry Entry Blob does not match the supplied digest.
+		BlobDigestMismatch,
+		/// Registry Entry has no Blob stored.
+		RegistryEntryBlobNotFound,
+		/// Validity window is empty or ends in the past.
+		InvalidValidityWindow,
+		/// Maximum number of Registry Entries expiring at the block reached.
+		TooManyExpiriesInBlock,
+		/// No item of the batch could be processed.
+		BulkTransactionFailed,
 	}
 
 	#[pallet::event]
@@ -168,6 +267,10 @@ pub mod pallet {
 		/// \[updater, registry_entry_identifier\]
 		RegistryEntryUpdated { updater: T::AccountId, registry_entry_id: RegistryEntryIdOf },
 
+		/// The blob of an existing registry entry has been removed.
+		/// \[updater, registry_entry_identifier\]
+		RegistryEntryBlobRemoved { updater: T::AccountId, registry_entry_id: RegistryEntryIdOf },
+
 		/// A existing registry entry has been revoked.
 		/// \[updater, registry_entry_identifier\]
 		RegistryEntryRevoked { updater: T::AccountId, registry_entry_id: RegistryEntryIdOf },
@@ -183,6 +286,49 @@ pub mod pallet {
 			new_owner: T::AccountId,
 			registry_entry_id: RegistryEntryIdOf,
 		},
+
+		/// A existing registry entry validity window has been set.
+		/// \[updater, registry_entry_identifier, valid_from, valid_until\]
+		RegistryEntryValiditySet {
+			updater: T::AccountId,
+			registry_entry_id: RegistryEntryIdOf,
+			valid_from: Option<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
+		},
+
+		/// A existing registry entry has reached the end of its validity window.
+		/// \[registry_entry_identifier\]
+		RegistryEntryExpired { registry_entry_id: RegistryEntryIdOf },
+
+		/// A batch of registry entries has been created.
+		/// \[creator, registry_identifier, successful, failed, failed indices\]
+		RegistryEntriesBatchCreated {
+			creator: T::AccountId,
+			registry_id: RegistryIdOf,
+			successful: u32,
+			failed: u32,
+			indices: RegistryEntryBatchIndicesOf<T>,
+		},
+
+		/// A batch of registry entries has been revoked.
+		/// \[updater, registry_identifier, successful, failed, failed indices\]
+		RegistryEntriesBatchRevoked {
+			updater: T::AccountId,
+			registry_id: RegistryIdOf,
+			successful: u32,
+			failed: u32,
+			indices: RegistryEntryBatchIndicesOf<T>,
+		},
+
+		/// A batch of registry entries has been reinstated.
+		/// \[updater, registry_identifier, successful, failed, failed indices\]
+		RegistryEntriesBatchReinstated {
+			updater: T::AccountId,
+			registry_id: RegistryIdOf,
+			successful: u32,
+			failed: u32,
+			indices: RegistryEntryBatchIndicesOf<T>,
+		},
 	}
 
 	#[pallet::call]
@@ -194,6 +340,16 @@ pub mod pallet {
 		/// The function verifies that the caller is authorized to create an entry within the
 		/// specified Registry, ensures that the entry does not already exist.
 		///
+		/// The `registry_entry_id` is constructed at the SDK and is validated on-chain. It must
+		/// be a valid SS58 identifier of type `Entries`, derived as
+		/// `H(<scale_encoded_digest>, <scale_encoded_registry_id>, <scale_encoded_creator>)`.
+		///
+		/// If a `blob` is supplied, it must hash to the given `digest`. The blob is persisted
+		/// in `RegistryEntryBlobs` so that the entry content can be reconstructed by indexers.
+		///
+		/// If a `schema_id` is supplied, it must match the schema of the Registry and must not
+		/// be deprecated. Registries with a mandatory schema binding reject entries created
+		/// without a `schema_id`.
 		///
 		/// # Arguments
 		/// * `origin` - The origin of the call, which must be a signed account (creator of the
@@ -203,15 +359,23 @@ pub mod pallet {
 		/// * `digest` - The hash value or digest of the content associated with the Registry entry.
 		/// * `blob` - (Optional) Additional data associated with the Registry entry, provided as an
 		///   optional field.
+		/// * `schema_id` - (Optional) The Schema the Registry entry was issued under.
 		///
 		/// # Errors
 		/// This function returns an error in the following cases:
 		/// * `UnauthorizedOperation` - If the caller does not have permission to create entries
 		///   within the Registry.
+		/// * `InvalidRegistryEntryIdentifier` - If the `registry_entry_id` is not a valid SS58
+		///   identifier of type `Entries`.
+		/// * `RegistryEntryIdentifierMismatch` - If the `registry_entry_id` is not derived from
+		///   the `digest`, registry identifier and creator.
 		/// * `RegistryEntryIdentifierAlreadyExists` - If the `registry_entry_id` already exists in
 		///   the storage.
 		/// * `InvalidIdentifierLength` - If the `registry_entry_id` generated from the hash exceeds
 		///   the expected length for identifiers.
+		/// * `BlobDigestMismatch` - If the `blob` does not hash to the `digest`.
+		/// * `SchemaMismatch`, `SchemaNotFound`, `SchemaDeprecated` or `SchemaBindingRequired` -
+		///   If the `schema_id` does not conform to the Registry (raised by the Registries pallet).
 		///
 		/// # Events
 		/// Emits the `Event::RegistryEntryCreated` event upon successful creation of a new Registry
@@ -220,41 +384,52 @@ pub mod pallet {
 		///
 		/// # Example
 		/// ```rust
-		/// create(origin, registry_entry_id, authorization, digest, Some(blob))?;
+		/// create(origin, registry_entry_id, authorization, digest, Some(blob), Some(schema_id))?;
 		/// ```
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create(
+			blob.as_ref().map_or(0, |b| b.len()).saturated_into()
+		))]
 		pub fn create(
 			origin: OriginFor<T>,
-			_registry_entry_id: RegistryEntryIdOf,
+			registry_entry_id: RegistryEntryIdOf,
 			authorization: RegistryAuthorizationIdOf,
 			digest: RegistryEntryHashOf<T>,
-			_blob: Option<RegistryEntryBlobOf<T>>,
+			blob: Option<RegistryEntryBlobOf<T>>,
+			schema_id: Option<SchemaIdOf>,
 		) -> DispatchResult {
 			let creator = ensure_signed(origin)?;
 
-			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_create_origin(
 				&authorization,
 				&creator,
+				1,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			pallet_registries::Pallet::<T>::ensure_schema_conformance(
+				&registry_id,
+				schema_id.as_ref(),
 			)
 			.map_err(<pallet_registries::Error<T>>::from)?;
 
-			// TODO:
-			/* Identifier Management will happen at SDK.
-			 * It is to be constructed as below.
+			/* Ensure that registry_entry_id is of valid ss58 format,
+			 * and also the type matches to be of `Entries`
 			 */
-			// Id Digest = concat (H(<scale_encoded_statement_digest>,
-			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()
-					[..],
+			ensure!(
+				Self::is_valid_ss58_format(&registry_entry_id),
+				Error::<T>::InvalidRegistryEntryIdentifier
 			);
 
-			let registry_entry_id = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Entries,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+			/* Ensure that the incoming registry_entry_id is derived from the
+			 * digest, registry identifier & creator.
+			 */
+			let expected_registry_entry_id =
+				Self::derive_registry_entry_id(&digest, &registry_id, &creator)?;
+			ensure!(
+				registry_entry_id == expected_registry_entry_id,
+				Error::<T>::RegistryEntryIdentifierMismatch
+			);
 
 			/* Ensure that the registry_entry_id does not already exist */
 			ensure!(
@@ -262,14 +437,10 @@ pub mod pallet {
 				Error::<T>::RegistryEntryIdentifierAlreadyExists
 			);
 
-			// TODO: Validate incoming `registry_entry_id` from SDK.
-			// /* Ensure that registry_id is of valid ss58 format,
-			//  * and also the type matches to be of `Registry`
-			//  */
-			// ensure!(
-			// 	Self::is_valid_ss58_format(&registry_entry_id),
-			// 	Error::<T>::InvalidRegistryEntryIdentifier
-			// );
+			if let Some(ref blob) = blob {
+				Self::ensure_blob_matches_digest(blob, &digest)?;
+			}
+
 			let registry_entry = RegistryEntryDetails {
 				digest,
 				revoked: false,
@@ -279,6 +450,22 @@ pub mod pallet {
 
 			RegistryEntries::<T>::insert(&registry_entry_id, registry_entry);
 
+			if let Some(blob) = blob {
+				RegistryEntryBlobs::<T>::insert(&registry_entry_id, blob);
+			}
+
+			if let Some(schema_id) = schema_id {
+				RegistryEntrySchemas::<T>::insert(&registry_entry_id, schema_id);
+			}
+
+			pallet_registries::Pallet::<T>::record_delegated_creates(
+				&authorization,
+				&registry_id,
+				&creator,
+				1,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
 			Self::update_activity(&registry_entry_id, CallTypeOf::Genesis)
 				.map_err(<Error<T>>::from)?;
 
@@ -297,6 +484,9 @@ pub mod pallet {
 		/// optional `blob`) of an existing Registry Entry. The user must have the necessary
 		/// permissions to perform this operation.
 		///
+		/// If a `blob` is supplied, it must hash to the new `digest` and replaces the stored
+		/// blob. If `None`, the stored blob is kept and must still hash to the new `digest`;
+		/// it can be removed with `remove_blob`.
 		///
 		/// # Arguments
 		/// * `origin` - The origin of the call, which must be a signed account (updater).
@@ -311,7 +501,8 @@ pub mod pallet {
 		///   Registry Entry.
 		/// * `RegistryEntryIdentifierDoesNotExist` - If the specified `registry_entry_id` does not
 		///   exist.
-		/// * `StateNotSupported` - If an unsupported state is provided.
+		/// * `BlobDigestMismatch` - If the `blob`, or the stored blob if `None` is supplied, does
+		///   not hash to the `digest`.
 		///
 		/// # Events
 		/// Emits the `Event::RegistryEntryUpdated` event upon successful update of the Registry
@@ -322,13 +513,16 @@ pub mod pallet {
 		/// update(origin, registry_entry_id, authorization, digest, Some(blob))?;
 		/// ```
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update(
+			blob.as_ref()
+				.map_or(T::MaxRegistryEntryBlobSize::get(), |b| b.len().saturated_into())
+		))]
 		pub fn update(
 			origin: OriginFor<T>,
 			registry_entry_id: RegistryEntryIdOf,
 			authorization: RegistryAuthorizationIdOf,
 			digest: RegistryEntryHashOf<T>,
-			_blob: Option<RegistryEntryBlobOf<T>>,
+			blob: Option<RegistryEntryBlobOf<T>>,
 		) -> DispatchResult {
 			let updater = ensure_signed(origin)?;
 			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
@@ -337,6 +531,9 @@ pub mod pallet {
 			)
 			.map_err(<pallet_registries::Error<T>>::from)?;
 
+			pallet_registries::Pallet::<T>::record_delegate_usage(&registry_id, &updater, 1)
+				.map_err(<pallet_registries::Error<T>>::from)?;
+
 			let mut entry = RegistryEntries::<T>::get(&registry_entry_id)
 				.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
 
@@ -349,10 +546,22 @@ pub mod pallet {
 
 			ensure!(is_admin || is_creator, Error::<T>::UnauthorizedOperation);
 
+			match blob {
+				Some(ref blob) => Self::ensure_blob_matches_digest(blob, &digest)?,
+				None =>
+					if let Some(stored) = RegistryEntryBlobs::<T>::get(&registry_entry_id) {
+						Self::ensure_blob_matches_digest(&stored, &digest)?;
+					},
+			}
+
 			entry.digest = digest;
 
 			RegistryEntries::<T>::insert(&registry_entry_id, entry);
 
+			if let Some(blob) = blob {
+				RegistryEntryBlobs::<T>::insert(&registry_entry_id, blob);
+			}
+
 			Self::update_activity(&registry_entry_id, CallTypeOf::Update)
 				.map_err(<Error<T>>::from)?;
 
@@ -388,7 +597,7 @@ pub mod pallet {
 		/// revoke(origin, registry_entry_id, authorization)?;
 		/// ```
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke())]
 		pub fn revoke(
 			origin: OriginFor<T>,
 			registry_entry_id: RegistryEntryIdOf,
@@ -401,6 +610,9 @@ pub mod pallet {
 			)
 			.map_err(<pallet_registries::Error<T>>::from)?;
 
+			pallet_registries::Pallet::<T>::record_delegate_usage(&registry_id, &updater, 1)
+				.map_err(<pallet_registries::Error<T>>::from)?;
+
 			let mut entry = RegistryEntries::<T>::get(&registry_entry_id)
 				.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
 
@@ -445,7 +657,7 @@ pub mod pallet {
 		/// reinstate(origin, registry_entry_id, authorization)?;
 		/// ```
 		#[pallet::call_index(3)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reinstate())]
 		pub fn reinstate(
 			origin: OriginFor<T>,
 			registry_entry_id: RegistryEntryIdOf,
@@ -458,6 +670,9 @@ pub mod pallet {
 			)
 			.map_err(<pallet_registries::Error<T>>::from)?;
 
+			pallet_registries::Pallet::<T>::record_delegate_usage(&registry_id, &updater, 1)
+				.map_err(<pallet_registries::Error<T>>::from)?;
+
 			let mut entry = RegistryEntries::<T>::get(&registry_entry_id)
 				.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
 
@@ -522,7 +737,7 @@ pub mod pallet {
 		/// )?;
 		/// ```
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update_ownership())]
 		pub fn update_ownership(
 			origin: OriginFor<T>,
 			registry_entry_id: RegistryEntryIdOf,
@@ -537,6 +752,9 @@ pub mod pallet {
 			)
 			.map_err(<pallet_registries::Error<T>>::from)?;
 
+			pallet_registries::Pallet::<T>::record_delegate_usage(&registry_id, &updater, 1)
+				.map_err(<pallet_registries::Error<T>>::from)?;
+
 			let entry = RegistryEntries::<T>::get(&registry_entry_id)
 				.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
 
@@ -578,6 +796,444 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets the validity window of an existing Registry Entry.
+		///
+		/// This function allows an authorized user (creator or admin) to restrict the blocks
+		/// during which a Registry Entry is considered valid. Passing `None` for both bounds
+		/// removes any existing validity window. If a `valid_until` block is supplied, the
+		/// Registry Entry is scheduled to expire at that block.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be a signed account (updater).
+		/// * `registry_entry_id` - The unique identifier of the Registry Entry.
+		/// * `authorization` - The authorization identifier that links the updater to the Registry.
+		/// * `valid_from` - (Optional) The block from which the Registry Entry is valid.
+		/// * `valid_until` - (Optional) The block at which the Registry Entry expires.
+		///
+		/// # Errors
+		/// This function returns an error in the following cases:
+		/// * `UnauthorizedOperation` - If the caller does not have permission to update the
+		///   Registry Entry.
+		/// * `RegistryEntryIdentifierDoesNotExist` - If the specified `registry_entry_id` does not
+		///   exist.
+		/// * `InvalidValidityWindow` - If `valid_until` is not after the current block or not
+		///   after `valid_from`.
+		/// * `TooManyExpiriesInBlock` - If too many Registry Entries already expire at
+		///   `valid_until`.
+		///
+		/// # Events
+		/// Emits the `Event::RegistryEntryValiditySet` event upon success.
+		///
+		/// # Example
+		/// ```rust
+		/// set_validity(origin, registry_entry_id, authorization, None, Some(valid_until))?;
+		/// ```
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_validity())]
+		pub fn set_validity(
+			origin: OriginFor<T>,
+			registry_entry_id: RegistryEntryIdOf,
+			authorization: RegistryAuthorizationIdOf,
+			valid_from: Option<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let updater = ensure_signed(origin)?;
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			pallet_registries::Pallet::<T>::record_delegate_usage(&registry_id, &updater, 1)
+				.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let entry = RegistryEntries::<T>::get(&registry_entry_id)
+				.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
+
+			ensure!(registry_id == entry.registry_id, Error::<T>::UnauthorizedOperation);
+
+			let is_admin =
+				pallet_registries::Pallet::<T>::is_admin_authorization(&authorization, &updater);
+
+			let is_creator = entry.creator == updater;
+
+			ensure!(is_admin || is_creator, Error::<T>::UnauthorizedOperation);
+
+			if let Some(valid_until) = valid_until {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					valid_until > now && valid_from.map_or(true, |from| valid_until > from),
+					Error::<T>::InvalidValidityWindow
+				);
+			}
+
+			if let Some(previous) = RegistryEntryValidities::<T>::take(&registry_entry_id) {
+				if let Some(previous_until) = previous.valid_until {
+					schedule::unschedule::<RegistryEntryExpiries<T>, _, _, _>(
+						previous_until,
+						&registry_entry_id,
+					);
+				}
+			}
+
+			if let Some(valid_until) = valid_until {
+				Self::schedule_expiry(&registry_entry_id, valid_until)?;
+			}
+
+			if valid_from.is_some() || valid_until.is_some() {
+				RegistryEntryValidities::<T>::insert(
+					&registry_entry_id,
+					RegistryEntryValidity { valid_from, valid_until },
+				);
+			}
+
+			Self::update_activity(&registry_entry_id, CallTypeOf::Update)
+				.map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::RegistryEntryValiditySet {
+				updater,
+				registry_entry_id,
+				valid_from,
+				valid_until,
+			});
+
+			Ok(())
+		}
+
+		/// Creates a batch of Registry Entries within a specified Registry.
+		///
+		/// The authorization is validated once for the whole batch: the quota of a constrained
+		/// delegation and the usage cap of the delegate must cover every digest, but only the
+		/// entries that were created are counted. The identifier of each entry is derived
+		/// on-chain from its digest, the registry identifier and the creator, as done for
+		/// `create`. Digests whose entry already exists are skipped and reported in the summary
+		/// event, and each entry is created in its own storage transaction so a failed entry
+		/// leaves nothing behind. Only the weight of the created entries is charged.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be a signed account (creator of the
+		///   entries).
+		/// * `digests` - The digests of the content of the new Registry entries.
+		/// * `authorization` - The authorization identifier that links the creator to the Registry.
+		/// * `schema_id` - (Optional) The Schema all the Registry entries are issued under.
+		///
+		/// # Errors
+		/// This function returns an error in the following cases:
+		/// * `UnauthorizedOperation` - If the caller does not have permission to create entries
+		///   within the Registry.
+		/// * `SchemaMismatch`, `SchemaNotFound`, `SchemaDeprecated` or `SchemaBindingRequired` -
+		///   If the `schema_id` does not conform to the Registry (raised by the Registries pallet).
+		/// * `BulkTransactionFailed` - If none of the entries could be created.
+		///
+		/// # Events
+		/// Emits the `Event::RegistryEntriesBatchCreated` event, summarizing the outcome of the
+		/// batch with the indices of the digests that could not be created.
+		///
+		/// # Example
+		/// ```rust
+		/// create_batch(origin, digests, authorization, Some(schema_id))?;
+		/// ```
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_batch(
+			digests.len().saturated_into()
+		))]
+		pub fn create_batch(
+			origin: OriginFor<T>,
+			digests: RegistryEntryDigestsOf<T>,
+			authorization: RegistryAuthorizationIdOf,
+			schema_id: Option<SchemaIdOf>,
+		) -> DispatchResultWithPostInfo {
+			let creator = ensure_signed(origin)?;
+
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_create_origin(
+				&authorization,
+				&creator,
+				digests.len().saturated_into(),
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			pallet_registries::Pallet::<T>::ensure_schema_conformance(
+				&registry_id,
+				schema_id.as_ref(),
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let mut successful = 0u32;
+			let mut indices = RegistryEntryBatchIndicesOf::<T>::new();
+
+			for (index, digest) in digests.into_iter().enumerate() {
+				let registry_entry_id =
+					match Self::derive_registry_entry_id(&digest, &registry_id, &creator) {
+						Ok(registry_entry_id)
+							if !RegistryEntries::<T>::contains_key(&registry_entry_id) =>
+							registry_entry_id,
+						_ => {
+							let _ = indices.try_push(index.saturated_into());
+							continue;
+						},
+					};
+
+				let created = with_transaction(|| {
+					RegistryEntries::<T>::insert(
+						&registry_entry_id,
+						RegistryEntryDetails {
+							digest,
+							revoked: false,
+							creator: creator.clone(),
+							registry_id: registry_id.clone(),
+						},
+					);
+
+					if let Some(ref schema_id) = schema_id {
+						RegistryEntrySchemas::<T>::insert(&registry_entry_id, schema_id);
+					}
+
+					Self::commit_if_ok(Self::update_activity(
+						&registry_entry_id,
+						CallTypeOf::Genesis,
+					))
+				});
+
+				if created.is_ok() {
+					successful += 1;
+				} else {
+					let _ = indices.try_push(index.saturated_into());
+				}
+			}
+
+			ensure!(successful > 0, Error::<T>::BulkTransactionFailed);
+
+			pallet_registries::Pallet::<T>::record_delegated_creates(
+				&authorization,
+				&registry_id,
+				&creator,
+				successful,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let failed = indices.len() as u32;
+			Self::deposit_event(Event::RegistryEntriesBatchCreated {
+				creator,
+				registry_id,
+				successful,
+				failed,
+				indices,
+			});
+
+			Ok(Some(Self::batch_weight(
+				<T as pallet::Config>::WeightInfo::create_batch(successful),
+				failed,
+			))
+			.into())
+		}
+
+		/// Revokes a batch of existing Registry Entries.
+		///
+		/// The authorization is validated once for the whole batch. Entries that do not exist,
+		/// belong to another Registry or are already revoked are skipped and reported in the
+		/// summary event.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be a signed account (updater).
+		/// * `registry_entry_ids` - The unique identifiers of the Registry Entries to be revoked.
+		/// * `authorization` - The authorization identifier that links the updater to the Registry.
+		///
+		/// # Errors
+		/// This function returns an error in the following cases:
+		/// * `UnauthorizedOperation` - If the caller does not have permission to revoke entries
+		///   within the Registry.
+		/// * `BulkTransactionFailed` - If none of the entries could be revoked.
+		///
+		/// # Events
+		/// Emits the `Event::RegistryEntriesBatchRevoked` event, summarizing the outcome of the
+		/// batch with the indices of the entries that could not be revoked.
+		///
+		/// # Example
+		/// ```rust
+		/// revoke_batch(origin, registry_entry_ids, authorization)?;
+		/// ```
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_batch(
+			registry_entry_ids.len().saturated_into()
+		))]
+		pub fn revoke_batch(
+			origin: OriginFor<T>,
+			registry_entry_ids: RegistryEntryIdsOf<T>,
+			authorization: RegistryAuthorizationIdOf,
+		) -> DispatchResultWithPostInfo {
+			let updater = ensure_signed(origin)?;
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let (successful, indices) =
+				Self::set_revoked_batch(&registry_id, registry_entry_ids, true);
+
+			ensure!(successful > 0, Error::<T>::BulkTransactionFailed);
+
+			pallet_registries::Pallet::<T>::record_delegate_usage(
+				&registry_id,
+				&updater,
+				successful,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let failed = indices.len() as u32;
+			Self::deposit_event(Event::RegistryEntriesBatchRevoked {
+				updater,
+				registry_id,
+				successful,
+				failed,
+				indices,
+			});
+
+			Ok(Some(Self::batch_weight(
+				<T as pallet::Config>::WeightInfo::revoke_batch(successful),
+				failed,
+			))
+			.into())
+		}
+
+		/// Reinstates a batch of revoked Registry Entries.
+		///
+		/// The authorization is validated once for the whole batch. Entries that do not exist,
+		/// belong to another Registry or are not revoked are skipped and reported in the
+		/// summary event.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be a signed account (updater).
+		/// * `registry_entry_ids` - The unique identifiers of the Registry Entries to be
+		///   reinstated.
+		/// * `authorization` - The authorization identifier that links the updater to the Registry.
+		///
+		/// # Errors
+		/// This function returns an error in the following cases:
+		/// * `UnauthorizedOperation` - If the caller does not have permission to reinstate entries
+		///   within the Registry.
+		/// * `BulkTransactionFailed` - If none of the entries could be reinstated.
+		///
+		/// # Events
+		/// Emits the `Event::RegistryEntriesBatchReinstated` event, summarizing the outcome of the
+		/// batch with the indices of the entries that could not be reinstated.
+		///
+		/// # Example
+		/// ```rust
+		/// reinstate_batch(origin, registry_entry_ids, authorization)?;
+		/// ```
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reinstate_batch(
+			registry_entry_ids.len().saturated_into()
+		))]
+		pub fn reinstate_batch(
+			origin: OriginFor<T>,
+			registry_entry_ids: RegistryEntryIdsOf<T>,
+			authorization: RegistryAuthorizationIdOf,
+		) -> DispatchResultWithPostInfo {
+			let updater = ensure_signed(origin)?;
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let (successful, indices) =
+				Self::set_revoked_batch(&registry_id, registry_entry_ids, false);
+
+			ensure!(successful > 0, Error::<T>::BulkTransactionFailed);
+
+			pallet_registries::Pallet::<T>::record_delegate_usage(
+				&registry_id,
+				&updater,
+				successful,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let failed = indices.len() as u32;
+			Self::deposit_event(Event::RegistryEntriesBatchReinstated {
+				updater,
+				registry_id,
+				successful,
+				failed,
+				indices,
+			});
+
+			Ok(Some(Self::batch_weight(
+				<T as pallet::Config>::WeightInfo::reinstate_batch(successful),
+				failed,
+			))
+			.into())
+		}
+
+		/// Removes the blob stored for an existing Registry Entry.
+		///
+		/// `update` keeps the stored blob when no new blob is supplied, so the blob has to be
+		/// removed explicitly. The caller must be the creator of the Registry Entry or an admin
+		/// of its Registry.
+		///
+		/// # Arguments
+		/// * `origin` - The origin of the call, which must be a signed account (updater).
+		/// * `registry_entry_id` - The unique identifier of the Registry Entry.
+		/// * `authorization` - The authorization identifier that links the updater to the Registry.
+		///
+		/// # Errors
+		/// This function returns an error in the following cases:
+		/// * `UnauthorizedOperation` - If the caller does not have permission to update the
+		///   Registry Entry.
+		/// * `RegistryEntryIdentifierDoesNotExist` - If the specified `registry_entry_id` does not
+		///   exist.
+		/// * `RegistryEntryBlobNotFound` - If the Registry Entry has no blob stored.
+		///
+		/// # Events
+		/// Emits the `Event::RegistryEntryBlobRemoved` event upon successful removal of the blob.
+		///
+		/// # Example
+		/// ```rust
+		/// remove_blob(origin, registry_entry_id, authorization)?;
+		/// ```
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_blob())]
+		pub fn remove_blob(
+			origin: OriginFor<T>,
+			registry_entry_id: RegistryEntryIdOf,
+			authorization: RegistryAuthorizationIdOf,
+		) -> DispatchResult {
+			let updater = ensure_signed(origin)?;
+			let registry_id = pallet_registries::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_registries::Error<T>>::from)?;
+
+			pallet_registries::Pallet::<T>::record_delegate_usage(&registry_id, &updater, 1)
+				.map_err(<pallet_registries::Error<T>>::from)?;
+
+			let entry = RegistryEntries::<T>::get(&registry_entry_id)
+				.ok_or(Error::<T>::RegistryEntryIdentifierDoesNotExist)?;
+
+			ensure!(registry_id == entry.registry_id, Error::<T>::UnauthorizedOperation);
+
+			let is_admin =
+				pallet_registries::Pallet::<T>::is_admin_authorization(&authorization, &updater);
+
+			ensure!(is_admin || entry.creator == updater, Error::<T>::UnauthorizedOperation);
+
+			ensure!(
+				RegistryEntryBlobs::<T>::contains_key(&registry_entry_id),
+				Error::<T>::RegistryEntryBlobNotFound
+			);
+
+			RegistryEntryBlobs::<T>::remove(&registry_entry_id);
+
+			Self::update_activity(&registry_entry_id, CallTypeOf::Update)
+				.map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::RegistryEntryBlobRemoved { updater, registry_entry_id });
+
+			Ok(())
+		}
 	}
 }
 
@@ -601,6 +1257,172 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Derives the registry entry identifier expected for an entry.
+	///
+	/// The identifier is constructed from the hash of the SCALE encoded
+	/// entry digest, registry identifier and creator, and encoded as an
+	/// SS58 identifier of type `Entries`. This mirrors the construction
+	/// performed at the SDK.
+	pub fn derive_registry_entry_id(
+		digest: &RegistryEntryHashOf<T>,
+		registry_id: &RegistryIdOf,
+		creator: &CreatorOf<T>,
+	) -> Result<RegistryEntryIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_registry_entry_digest>,
+		// <scale_encoded_registry_identifier>, <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Entries)
+			.map_err(|_| Error::<T>::InvalidIdentifierLength)
+	}
+
+	/// Ensures that the supplied blob hashes to the registry entry digest.
+	///
+	/// The digest is expected to be computed over the raw blob bytes, as
+	/// the SDK does, which lets indexers verify a stored blob against the
+	/// on-chain digest.
+	pub fn ensure_blob_matches_digest(
+		blob: &RegistryEntryBlobOf<T>,
+		digest: &RegistryEntryHashOf<T>,
+	) -> Result<(), Error<T>> {
+		let blob_digest = <T as frame_system::Config>::Hashing::hash(&blob[..]);
+		ensure!(blob_digest == *digest, Error::<T>::BlobDigestMismatch);
+
+		Ok(())
+	}
+
+	/// Returns the status of a Registry Entry at the current block, taking
+	/// revocation and its validity window into account.
+	pub fn registry_entry_status(
+		registry_entry_id: &RegistryEntryIdOf,
+	) -> Option<RegistryEntryStatus> {
+		let entry = RegistryEntries::<T>::get(registry_entry_id)?;
+		if entry.revoked {
+			return Some(RegistryEntryStatus::Revoked);
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let status = match RegistryEntryValidities::<T>::get(registry_entry_id) {
+			Some(validity) if validity.valid_until.is_some_and(|until| now >= until) =>
+				RegistryEntryStatus::Expired,
+			Some(validity) if validity.valid_from.is_some_and(|from| now < from) =>
+				RegistryEntryStatus::NotYetValid,
+			_ => RegistryEntryStatus::Active,
+		};
+
+		Some(status)
+	}
+
+	/// Schedules a Registry Entry to expire at the given block.
+	fn schedule_expiry(
+		registry_entry_id: &RegistryEntryIdOf,
+		valid_until: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		schedule::schedule::<NextExpirySweep<T>, RegistryEntryExpiries<T>, _, _, _>(
+			valid_until,
+			registry_entry_id.clone(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+		.map_err(|_| Error::<T>::TooManyExpiriesInBlock)
+	}
+
+	/// Expires the Registry Entries scheduled up to and including block `now`,
+	/// as far as `remaining_weight` allows.
+	pub(crate) fn sweep_expiries(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		schedule::sweep::<NextExpirySweep<T>, RegistryEntryExpiries<T>, _, _, _>(
+			now,
+			remaining_weight,
+			db_weight,
+			db_weight.reads_writes(5, 3),
+			|registry_entry_id, at| Self::expire(&registry_entry_id, at),
+		)
+	}
+
+	/// Marks a Registry Entry as expired if it is still scheduled to expire at
+	/// block `at`.
+	fn expire(registry_entry_id: &RegistryEntryIdOf, at: BlockNumberFor<T>) {
+		let scheduled = RegistryEntryValidities::<T>::get(registry_entry_id)
+			.is_some_and(|validity| validity.valid_until == Some(at));
+		if !scheduled {
+			return;
+		}
+
+		if let Err(e) = Self::update_activity(registry_entry_id, CallTypeOf::Expire) {
+			log::error!(
+				"Failed to record the expiry of registry entry {:?}: {:?}",
+				registry_entry_id,
+				e
+			);
+		}
+
+		Self::deposit_event(Event::RegistryEntryExpired {
+			registry_entry_id: registry_entry_id.clone(),
+		});
+	}
+
+	/// Sets the revocation status of a batch of Registry Entries of a Registry.
+	///
+	/// Entries that do not exist, belong to another Registry or already have the requested
+	/// status are skipped. Each entry is updated in its own storage transaction, so an entry
+	/// whose timeline cannot be updated keeps its status. Returns the number of updated
+	/// entries and the indices of the skipped ones.
+	fn set_revoked_batch(
+		registry_id: &RegistryIdOf,
+		registry_entry_ids: RegistryEntryIdsOf<T>,
+		revoked: bool,
+	) -> (u32, RegistryEntryBatchIndicesOf<T>) {
+		let action = if revoked { CallTypeOf::Revoke } else { CallTypeOf::Reinstate };
+		let mut successful = 0u32;
+		let mut indices = RegistryEntryBatchIndicesOf::<T>::new();
+
+		for (index, registry_entry_id) in registry_entry_ids.into_iter().enumerate() {
+			let updated = with_transaction(|| {
+				let changed =
+					RegistryEntries::<T>::mutate(&registry_entry_id, |entry| match entry {
+						Some(entry)
+							if entry.registry_id == *registry_id && entry.revoked != revoked =>
+						{
+							entry.revoked = revoked;
+							true
+						},
+						_ => false,
+					});
+
+				Self::commit_if_ok(if changed {
+					Self::update_activity(&registry_entry_id, action)
+				} else {
+					Err(Error::<T>::RegistryEntryIdentifierDoesNotExist)
+				})
+			});
+
+			if updated.is_ok() {
+				successful += 1;
+			} else {
+				let _ = indices.try_push(index.saturated_into());
+			}
+		}
+
+		(successful, indices)
+	}
+
+	/// Keeps the changes of a batch item's storage transaction only if the item
+	/// succeeded.
+	fn commit_if_ok(result: Result<(), Error<T>>) -> TransactionOutcome<Result<(), DispatchError>> {
+		match result {
+			Ok(()) => TransactionOutcome::Commit(Ok(())),
+			Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+		}
+	}
+
+	/// The weight actually consumed by a batch call: the benchmarked weight of
+	/// the items that went through plus a lookup for each skipped item.
+	fn batch_weight(processed: Weight, skipped: u32) -> Weight {
+		processed.saturating_add(T::DbWeight::get().reads(skipped.into()))
+	}
+
 	/// Updates the global timeline with a new activity event for a registry entry.
 	/// This function is called whenever a significant action is performed on a
 	/// registry entry, ensuring that all such activities are logged with a timestamp
@@ -19,7 +19,7 @@
 use super::*;
 use crate::mock::*;
 use codec::Encode;
-use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_support::{assert_err, assert_ok, traits::Hooks, BoundedVec};
 use serde_json::json;
 use sp_runtime::traits::Hash;
 use sp_std::prelude::*;
@@ -134,6 +134,12 @@ fn create_registry_entry_should_work() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
@@ -171,7 +177,7 @@ fn create_registry_entry_should_work() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -191,6 +197,7 @@ fn create_registry_entry_should_work() {
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Check if the Entry was created */
@@ -202,6 +209,12 @@ fn create_registry_entry_should_work() {
 		assert_eq!(entry.revoked, false);
 		assert_eq!(entry.registry_id, registry_id);
 
+		/* Check if the Blob was persisted */
+		assert_eq!(
+			RegistryEntryBlobs::<Test>::get(registry_entry_id.clone()),
+			Some(registry_entry_blob.clone())
+		);
+
 		/* Check for successful event emission of RegistryCreated */
 		System::assert_last_event(
 			Event::RegistryEntryCreated {
@@ -268,6 +281,12 @@ fn update_registry_entry_should_work() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
@@ -305,7 +324,7 @@ fn update_registry_entry_should_work() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -325,6 +344,7 @@ fn update_registry_entry_should_work() {
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Assumed JSON for Registry Entry */
@@ -357,9 +377,7 @@ fn update_registry_entry_should_work() {
 			);
 
 		let updated_registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(
-				&updated_registry_entry_raw_bytes.encode()[..],
-			);
+			<Test as frame_system::Config>::Hashing::hash(&updated_registry_entry_raw_bytes[..]);
 
 		assert_ok!(Entries::update(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
@@ -378,6 +396,12 @@ fn update_registry_entry_should_work() {
 		assert_eq!(entry.registry_id, registry_id);
 		assert_eq!(entry.revoked, false);
 
+		/* Check if the updated Blob replaced the existing one */
+		assert_eq!(
+			RegistryEntryBlobs::<Test>::get(registry_entry_id.clone()),
+			Some(updated_registry_entry_blob.clone())
+		);
+
 		/* Check for successful event emission of RegistryCreated */
 		System::assert_last_event(
 			Event::RegistryEntryUpdated {
@@ -443,6 +467,12 @@ fn revoke_registry_entry_should_work() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
@@ -480,7 +510,7 @@ fn revoke_registry_entry_should_work() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -500,6 +530,7 @@ fn revoke_registry_entry_should_work() {
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		assert_ok!(Entries::revoke(
@@ -579,6 +610,12 @@ fn reinstating_revoked_registry_entry_should_work() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
@@ -616,7 +653,7 @@ fn reinstating_revoked_registry_entry_should_work() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -636,6 +673,7 @@ fn reinstating_revoked_registry_entry_should_work() {
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		assert_ok!(Entries::revoke(
@@ -736,6 +774,12 @@ fn update_registry_entry_should_work_for_valid_creator() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -782,7 +826,7 @@ fn update_registry_entry_should_work_for_valid_creator() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -802,6 +846,7 @@ fn update_registry_entry_should_work_for_valid_creator() {
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Assumed JSON for Registry Entry */
@@ -834,9 +879,7 @@ fn update_registry_entry_should_work_for_valid_creator() {
 			);
 
 		let updated_registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(
-				&updated_registry_entry_raw_bytes.encode()[..],
-			);
+			<Test as frame_system::Config>::Hashing::hash(&updated_registry_entry_raw_bytes[..]);
 
 		/* Entries will be updated from the creator of the Registry Entry */
 		assert_ok!(Entries::update(
@@ -921,6 +964,12 @@ fn update_registry_entry_should_work_for_valid_admin() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
@@ -958,7 +1007,7 @@ fn update_registry_entry_should_work_for_valid_admin() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -978,6 +1027,7 @@ fn update_registry_entry_should_work_for_valid_admin() {
 			authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Assumed JSON for Registry Entry */
@@ -1010,9 +1060,7 @@ fn update_registry_entry_should_work_for_valid_admin() {
 			);
 
 		let updated_registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(
-				&updated_registry_entry_raw_bytes.encode()[..],
-			);
+			<Test as frame_system::Config>::Hashing::hash(&updated_registry_entry_raw_bytes[..]);
 
 		/* Entries will be updated from the admin of the Registry */
 		assert_ok!(Entries::update(
@@ -1113,6 +1161,12 @@ fn update_registry_entry_should_fail_for_non_registry_entry_creator() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -1167,7 +1221,7 @@ fn update_registry_entry_should_fail_for_non_registry_entry_creator() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -1187,6 +1241,7 @@ fn update_registry_entry_should_fail_for_non_registry_entry_creator() {
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Assumed JSON for Registry Entry */
@@ -1219,9 +1274,7 @@ fn update_registry_entry_should_fail_for_non_registry_entry_creator() {
 			);
 
 		let updated_registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(
-				&updated_registry_entry_raw_bytes.encode()[..],
-			);
+			<Test as frame_system::Config>::Hashing::hash(&updated_registry_entry_raw_bytes[..]);
 
 		/* Entries updation by a non-creator of the Registry Entry
 		 * but part of the Registry will fail.
@@ -1334,6 +1387,12 @@ fn update_registry_entry_should_fail_for_non_registry_admin() {
 			namespace_authorization_id.clone()
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -1388,7 +1447,7 @@ fn update_registry_entry_should_fail_for_non_registry_admin() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -1408,6 +1467,7 @@ fn update_registry_entry_should_fail_for_non_registry_admin() {
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Assumed JSON for Registry Entry */
@@ -1440,9 +1500,7 @@ fn update_registry_entry_should_fail_for_non_registry_admin() {
 			);
 
 		let updated_registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(
-				&updated_registry_entry_raw_bytes.encode()[..],
-			);
+			<Test as frame_system::Config>::Hashing::hash(&updated_registry_entry_raw_bytes[..]);
 
 		/* Entries updation by a invalid-admin of the Registry
 		 * but a Admin of a different Registry should fail.
@@ -1530,6 +1588,12 @@ fn update_ownership_of_registry_entry_creator_should_work_for_creator() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -1585,7 +1649,7 @@ fn update_ownership_of_registry_entry_creator_should_work_for_creator() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -1605,6 +1669,7 @@ fn update_ownership_of_registry_entry_creator_should_work_for_creator() {
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Check if the Entry was created */
@@ -1718,6 +1783,12 @@ fn update_ownership_of_registry_entry_creator_should_work_for_admin() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -1773,7 +1844,7 @@ fn update_ownership_of_registry_entry_creator_should_work_for_admin() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -1793,6 +1864,7 @@ fn update_ownership_of_registry_entry_creator_should_work_for_admin() {
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Check if the Entry was created */
@@ -1906,6 +1978,12 @@ fn new_owner_should_be_able_to_perform_registry_entry_operations_after_ownership
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -1961,7 +2039,7 @@ fn new_owner_should_be_able_to_perform_registry_entry_operations_after_ownership
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -1981,6 +2059,7 @@ fn new_owner_should_be_able_to_perform_registry_entry_operations_after_ownership
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Check if the Entry was created */
@@ -2052,9 +2131,7 @@ fn new_owner_should_be_able_to_perform_registry_entry_operations_after_ownership
 			);
 
 		let updated_registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(
-				&updated_registry_entry_raw_bytes.encode()[..],
-			);
+			<Test as frame_system::Config>::Hashing::hash(&updated_registry_entry_raw_bytes[..]);
 
 		/* Update, revoke, restore etc or any registry entry related tasks should
 		 * be able to be performed by the new-owner */
@@ -2146,6 +2223,12 @@ fn old_owner_should_not_be_able_to_perform_registry_entry_operations_after_owner
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -2201,7 +2284,7 @@ fn old_owner_should_not_be_able_to_perform_registry_entry_operations_after_owner
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -2221,6 +2304,7 @@ fn old_owner_should_not_be_able_to_perform_registry_entry_operations_after_owner
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Check if the Entry was created */
@@ -2292,9 +2376,7 @@ fn old_owner_should_not_be_able_to_perform_registry_entry_operations_after_owner
 			);
 
 		let updated_registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(
-				&updated_registry_entry_raw_bytes.encode()[..],
-			);
+			<Test as frame_system::Config>::Hashing::hash(&updated_registry_entry_raw_bytes[..]);
 
 		/* Update, revoke, restore etc or any registry entry related tasks should not
 		 * be able to be performed by the old-owner (creator) */
@@ -2374,6 +2456,12 @@ fn update_ownership_should_fail_for_updating_themselves() {
 			None,
 		));
 
+		/* Test creation of a Schema */
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(admin.clone()).into(),
+			schema.clone()
+		));
+
 		/* Test creation of a Registry */
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(admin.clone()).into(),
@@ -2429,7 +2517,7 @@ fn update_ownership_should_fail_for_updating_themselves() {
 			);
 
 		let registry_entry_digest: RegistryHashOf<Test> =
-			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes.encode()[..]);
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
 
 		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
 			&[
@@ -2449,6 +2537,7 @@ fn update_ownership_should_fail_for_updating_themselves() {
 			creator_authorization_id.clone(),
 			registry_entry_digest,
 			Some(registry_entry_blob.clone()),
+			None,
 		));
 
 		/* Check if the Entry was created */
@@ -2474,3 +2563,944 @@ fn update_ownership_should_fail_for_updating_themselves() {
 		);
 	});
 }
+
+#[test]
+fn create_registry_entry_should_fail_for_invalid_registry_entry_identifier() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None,
+		));
+
+		let registry_entry_raw_bytes = [3u8; 64].to_vec();
+
+		let registry_entry_digest: RegistryHashOf<Test> =
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
+
+		/* Identifier of a type other than `Entries` should be rejected */
+		assert_err!(
+			Entries::create(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidRegistryEntryIdentifier
+		);
+
+		/* Identifier not derived from (digest, registry, creator) should be rejected */
+		let other_registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[
+				&registry_entry_digest.encode()[..],
+				&registry_id.encode()[..],
+				&ACCOUNT_01.encode()[..],
+			]
+			.concat()[..],
+		);
+
+		let other_registry_entry_id: RegistryEntryIdOf =
+			generate_registry_entry_id::<Test>(&other_registry_entry_id_digest);
+
+		assert_err!(
+			Entries::create(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				other_registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				None,
+				None,
+			),
+			Error::<Test>::RegistryEntryIdentifierMismatch
+		);
+
+		assert!(!RegistryEntries::<Test>::contains_key(other_registry_entry_id));
+	});
+}
+
+#[test]
+fn create_registry_entry_should_fail_for_blob_digest_mismatch() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None,
+		));
+
+		let registry_entry_raw_bytes = [3u8; 64].to_vec();
+
+		let registry_entry_digest: RegistryHashOf<Test> =
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
+
+		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[
+				&registry_entry_digest.encode()[..],
+				&registry_id.encode()[..],
+				&creator.encode()[..],
+			]
+			.concat()[..],
+		);
+
+		let registry_entry_id: RegistryEntryIdOf =
+			generate_registry_entry_id::<Test>(&registry_entry_id_digest);
+
+		/* Blob whose hash differs from the digest should be rejected */
+		let tampered_blob: RegistryEntryBlobOf<Test> = BoundedVec::try_from([4u8; 64].to_vec())
+			.expect(
+				"Test Blob should fit into the expected input length of BLOB for the test runtime.",
+			);
+
+		assert_err!(
+			Entries::create(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				registry_entry_digest,
+				Some(tampered_blob),
+				None,
+			),
+			Error::<Test>::BlobDigestMismatch
+		);
+
+		/* Creation without a blob should still be possible */
+		assert_ok!(Entries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			registry_entry_digest,
+			None,
+			None,
+		));
+
+		assert!(RegistryEntries::<Test>::contains_key(registry_entry_id.clone()));
+		assert!(!RegistryEntryBlobs::<Test>::contains_key(registry_entry_id));
+	});
+}
+
+#[test]
+fn update_registry_entry_should_keep_blob_until_removed() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None,
+		));
+
+		/* The digest is computed over the raw blob bytes, as the SDK does */
+		let registry_entry_raw_bytes = [3u8; 64].to_vec();
+		let registry_entry_blob: RegistryEntryBlobOf<Test> =
+			BoundedVec::try_from(registry_entry_raw_bytes.clone()).expect(
+				"Test Blob should fit into the expected input length of BLOB for the test runtime.",
+			);
+
+		let registry_entry_digest: RegistryHashOf<Test> =
+			<Test as frame_system::Config>::Hashing::hash(&registry_entry_raw_bytes[..]);
+
+		let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[
+				&registry_entry_digest.encode()[..],
+				&registry_id.encode()[..],
+				&creator.encode()[..],
+			]
+			.concat()[..],
+		);
+
+		let registry_entry_id: RegistryEntryIdOf =
+			generate_registry_entry_id::<Test>(&registry_entry_id_digest);
+
+		assert_ok!(Entries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			registry_entry_digest,
+			Some(registry_entry_blob.clone()),
+			None,
+		));
+
+		/* Updating without a blob keeps the stored one, which must match the digest */
+		let updated_registry_entry_digest: RegistryHashOf<Test> =
+			<Test as frame_system::Config>::Hashing::hash(&[4u8; 64][..]);
+
+		assert_err!(
+			Entries::update(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				updated_registry_entry_digest,
+				None,
+			),
+			Error::<Test>::BlobDigestMismatch
+		);
+
+		assert_ok!(Entries::update(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			registry_entry_digest,
+			None,
+		));
+
+		assert_eq!(
+			RegistryEntryBlobs::<Test>::get(registry_entry_id.clone()),
+			Some(registry_entry_blob)
+		);
+
+		/* The blob has to be removed explicitly */
+		assert_ok!(Entries::remove_blob(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+		));
+
+		assert!(!RegistryEntryBlobs::<Test>::contains_key(registry_entry_id.clone()));
+
+		System::assert_last_event(
+			Event::RegistryEntryBlobRemoved {
+				updater: creator.clone(),
+				registry_entry_id: registry_entry_id.clone(),
+			}
+			.into(),
+		);
+
+		assert_ok!(Entries::update(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			updated_registry_entry_digest,
+			None,
+		));
+
+		assert_err!(
+			Entries::remove_blob(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id,
+				authorization_id,
+			),
+			Error::<Test>::RegistryEntryBlobNotFound
+		);
+	});
+}
+
+#[test]
+fn registry_entry_validity_window_and_expiry_sweep_should_work() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	let registry_entry_digest: RegistryHashOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&[3u8; 64].encode()[..]);
+
+	let registry_entry_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_entry_digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]]
+			.concat()[..],
+	);
+	let registry_entry_id: RegistryEntryIdOf =
+		generate_registry_entry_id::<Test>(&registry_entry_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None,
+		));
+
+		assert_ok!(Entries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			registry_entry_digest,
+			None,
+			None,
+		));
+
+		assert_eq!(
+			Entries::registry_entry_status(&registry_entry_id),
+			Some(RegistryEntryStatus::Active)
+		);
+
+		/* Validity window must end after the current block */
+		assert_err!(
+			Entries::set_validity(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				None,
+				Some(1),
+			),
+			Error::<Test>::InvalidValidityWindow
+		);
+
+		/* Rescheduling removes the previously scheduled expiry */
+		assert_ok!(Entries::set_validity(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			None,
+			Some(10),
+		));
+		assert_ok!(Entries::set_validity(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			Some(3),
+			Some(5),
+		));
+		assert!(!RegistryEntryExpiries::<Test>::get(10).contains(&registry_entry_id));
+		assert!(RegistryEntryExpiries::<Test>::get(5).contains(&registry_entry_id));
+
+		assert_eq!(
+			Entries::registry_entry_status(&registry_entry_id),
+			Some(RegistryEntryStatus::NotYetValid)
+		);
+
+		System::set_block_number(3);
+		assert_eq!(
+			Entries::registry_entry_status(&registry_entry_id),
+			Some(RegistryEntryStatus::Active)
+		);
+
+		/* Status reports expiry before the sweep has run */
+		System::set_block_number(5);
+		assert_eq!(
+			Entries::registry_entry_status(&registry_entry_id),
+			Some(RegistryEntryStatus::Expired)
+		);
+
+		Entries::on_idle(5, Weight::MAX);
+
+		System::assert_last_event(
+			Event::RegistryEntryExpired { registry_entry_id: registry_entry_id.clone() }.into(),
+		);
+		assert!(RegistryEntryExpiries::<Test>::get(5).is_empty());
+		assert_eq!(NextExpirySweep::<Test>::get(), Some(6));
+
+		let timeline =
+			identifier::Identifiers::<Test>::get(&registry_entry_id, IdentifierTypeOf::Entries)
+				.expect("Registry Entry timeline should exist");
+		assert_eq!(timeline.last().map(|e| e.action), Some(CallTypeOf::Expire));
+	});
+}
+
+#[test]
+fn create_registry_entry_should_enforce_schema_conformance() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([2u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let other_schema: InputSchemaOf<Test> = BoundedVec::try_from([3u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let other_schema_id_digest =
+		<Test as frame_system::Config>::Hashing::hash(&other_schema.encode()[..]);
+	let other_schema_id: SchemaIdOf = generate_schema_id::<Test>(&other_schema_id_digest);
+
+	let entry = |content: &[u8]| -> (RegistryEntryHashOf<Test>, RegistryEntryIdOf) {
+		let digest = <Test as frame_system::Config>::Hashing::hash(&content.encode()[..]);
+		let id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+		(digest, generate_registry_entry_id::<Test>(&id_digest))
+	};
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			other_schema.clone()
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			Some(schema_id.clone()),
+			None,
+		));
+
+		/* An entry issued under a different schema is rejected */
+		let (digest, registry_entry_id) = entry(b"first");
+		assert_err!(
+			Entries::create(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				digest,
+				None,
+				Some(other_schema_id.clone()),
+			),
+			pallet_registries::Error::<Test>::SchemaMismatch
+		);
+
+		/* Without a mandatory binding an entry may omit the schema */
+		assert_ok!(Entries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			digest,
+			None,
+			None,
+		));
+		assert!(!RegistryEntrySchemas::<Test>::contains_key(&registry_entry_id));
+
+		assert_ok!(Registries::set_schema_binding(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+			true,
+		));
+
+		/* Once binding is mandatory the schema must be supplied */
+		let (digest, registry_entry_id) = entry(b"second");
+		assert_err!(
+			Entries::create(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_entry_id.clone(),
+				authorization_id.clone(),
+				digest,
+				None,
+				None,
+			),
+			pallet_registries::Error::<Test>::SchemaBindingRequired
+		);
+
+		assert_ok!(Entries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_entry_id.clone(),
+			authorization_id.clone(),
+			digest,
+			None,
+			Some(schema_id.clone()),
+		));
+		assert_eq!(RegistryEntrySchemas::<Test>::get(&registry_entry_id), Some(schema_id.clone()));
+	});
+}
+
+#[test]
+fn registry_entry_batches_should_report_partial_failures() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	let entry = |content: &[u8]| -> (RegistryEntryHashOf<Test>, RegistryEntryIdOf) {
+		let digest = <Test as frame_system::Config>::Hashing::hash(&content.encode()[..]);
+		let id_digest = <Test as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &registry_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+		(digest, generate_registry_entry_id::<Test>(&id_digest))
+	};
+
+	let (first_digest, first_id) = entry(b"first");
+	let (second_digest, second_id) = entry(b"second");
+	let (_, unknown_id) = entry(b"unknown");
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None,
+		));
+
+		/* A repeated digest in the batch is reported as failed */
+		let digests: RegistryEntryDigestsOf<Test> =
+			BoundedVec::try_from(vec![first_digest, second_digest, first_digest])
+				.expect("Digests should fit into the batch limit of the test runtime.");
+		assert_ok!(Entries::create_batch(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			digests,
+			authorization_id.clone(),
+			None,
+		));
+		System::assert_last_event(
+			Event::RegistryEntriesBatchCreated {
+				creator: creator.clone(),
+				registry_id: registry_id.clone(),
+				successful: 2,
+				failed: 1,
+				indices: BoundedVec::truncate_from(vec![2]),
+			}
+			.into(),
+		);
+		assert!(RegistryEntries::<Test>::contains_key(&first_id));
+		assert!(RegistryEntries::<Test>::contains_key(&second_id));
+
+		/* A batch without a single new entry fails as a whole */
+		let digests: RegistryEntryDigestsOf<Test> = BoundedVec::try_from(vec![first_digest])
+			.expect("Digests should fit into the batch limit of the test runtime.");
+		assert_err!(
+			Entries::create_batch(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				digests,
+				authorization_id.clone(),
+				None,
+			),
+			Error::<Test>::BulkTransactionFailed
+		);
+
+		/* Unknown entries are skipped while the rest are revoked */
+		let ids: RegistryEntryIdsOf<Test> =
+			BoundedVec::try_from(vec![first_id.clone(), unknown_id.clone(), second_id.clone()])
+				.expect("Identifiers should fit into the batch limit of the test runtime.");
+		assert_ok!(Entries::revoke_batch(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			ids,
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::RegistryEntriesBatchRevoked {
+				updater: creator.clone(),
+				registry_id: registry_id.clone(),
+				successful: 2,
+				failed: 1,
+				indices: BoundedVec::truncate_from(vec![1]),
+			}
+			.into(),
+		);
+		assert!(RegistryEntries::<Test>::get(&first_id).unwrap().revoked);
+		assert!(RegistryEntries::<Test>::get(&second_id).unwrap().revoked);
+
+		/* Revoking already revoked entries has no effect */
+		let ids: RegistryEntryIdsOf<Test> = BoundedVec::try_from(vec![first_id.clone()])
+			.expect("Identifiers should fit into the batch limit of the test runtime.");
+		assert_err!(
+			Entries::revoke_batch(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				ids.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::BulkTransactionFailed
+		);
+
+		assert_ok!(Entries::reinstate_batch(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			ids,
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::RegistryEntriesBatchReinstated {
+				updater: creator.clone(),
+				registry_id: registry_id.clone(),
+				successful: 1,
+				failed: 0,
+				indices: BoundedVec::truncate_from(vec![]),
+			}
+			.into(),
+		);
+		assert!(!RegistryEntries::<Test>::get(&first_id).unwrap().revoked);
+		assert!(RegistryEntries::<Test>::get(&second_id).unwrap().revoked);
+	});
+}
+
+#[test]
+fn delegated_create_batch_should_charge_created_entries_only() {
+	let creator = ACCOUNT_00;
+	let delegate = ACCOUNT_01;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	let first_digest = <Test as frame_system::Config>::Hashing::hash(&b"first".encode()[..]);
+	let second_digest = <Test as frame_system::Config>::Hashing::hash(&b"second".encode()[..]);
+	let third_digest = <Test as frame_system::Config>::Hashing::hash(&b"third".encode()[..]);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None,
+		));
+
+		assert_ok!(Registries::add_delegate(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate.clone(),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+
+		assert_ok!(Registries::set_delegation_constraints(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id.clone(),
+			None,
+			Some(2),
+			pallet_registries::DelegationScope::CreateOnly,
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+
+		/* The quota is checked for the whole batch upfront */
+		let digests: RegistryEntryDigestsOf<Test> =
+			BoundedVec::try_from(vec![first_digest, second_digest, third_digest])
+				.expect("Digests should fit into the batch limit of the test runtime.");
+		assert_err!(
+			Entries::create_batch(
+				frame_system::RawOrigin::Signed(delegate.clone()).into(),
+				digests,
+				delegate_authorization_id.clone(),
+				None,
+			),
+			pallet_registries::Error::<Test>::DelegationQuotaExhausted
+		);
+
+		/* The repeated digest is not charged */
+		let digests: RegistryEntryDigestsOf<Test> =
+			BoundedVec::try_from(vec![first_digest, first_digest])
+				.expect("Digests should fit into the batch limit of the test runtime.");
+		assert_ok!(Entries::create_batch(
+			frame_system::RawOrigin::Signed(delegate.clone()).into(),
+			digests,
+			delegate_authorization_id.clone(),
+			None,
+		));
+		System::assert_last_event(
+			Event::RegistryEntriesBatchCreated {
+				creator: delegate.clone(),
+				registry_id: registry_id.clone(),
+				successful: 1,
+				failed: 1,
+				indices: BoundedVec::truncate_from(vec![1]),
+			}
+			.into(),
+		);
+		assert_eq!(
+			pallet_registries::AuthorizationConstraints::<Test>::get(&delegate_authorization_id)
+				.map(|constraints| constraints.creates),
+			Some(1)
+		);
+		assert_eq!(
+			pallet_registries::DelegateUsages::<Test>::get(&registry_id, &delegate).txn_count,
+			1
+		);
+
+		/* Only one creation is left in the quota */
+		let digests: RegistryEntryDigestsOf<Test> =
+			BoundedVec::try_from(vec![second_digest, third_digest])
+				.expect("Digests should fit into the batch limit of the test runtime.");
+		assert_err!(
+			Entries::create_batch(
+				frame_system::RawOrigin::Signed(delegate.clone()).into(),
+				digests,
+				delegate_authorization_id.clone(),
+				None,
+			),
+			pallet_registries::Error::<Test>::DelegationQuotaExhausted
+		);
+	});
+}
+
+#[test]
+fn expiry_sweep_should_make_progress_within_a_block() {
+	let entry_id = |content: &[u8]| -> RegistryEntryIdOf {
+		let digest = <Test as frame_system::Config>::Hashing::hash(&content.encode()[..]);
+		generate_registry_entry_id::<Test>(&digest)
+	};
+	let first_id = entry_id(b"first");
+	let second_id = entry_id(b"second");
+
+	let db_weight = frame_support::weights::RuntimeDbWeight { read: 1, write: 1 };
+	let item_weight = Weight::from_parts(1, 0);
+
+	new_test_ext().execute_with(|| {
+		RegistryEntryExpiries::<Test>::insert(
+			5,
+			BoundedVec::truncate_from(vec![first_id.clone(), second_id.clone()]),
+		);
+		NextExpirySweep::<Test>::put(5);
+
+		let sweep = |remaining_weight: Weight, swept: &mut Vec<RegistryEntryIdOf>| {
+			schedule::sweep::<NextExpirySweep<Test>, RegistryEntryExpiries<Test>, _, _, _>(
+				5,
+				remaining_weight,
+				db_weight,
+				item_weight,
+				|registry_entry_id, _| swept.push(registry_entry_id),
+			)
+		};
+
+		/* Only one of the expiries of the block fits in the weight */
+		let mut swept = Vec::new();
+		assert_eq!(sweep(Weight::from_parts(5, 0), &mut swept), Weight::from_parts(5, 0));
+		assert_eq!(swept, vec![first_id.clone()]);
+		assert_eq!(RegistryEntryExpiries::<Test>::get(5).into_inner(), vec![second_id.clone()]);
+		assert_eq!(NextExpirySweep::<Test>::get(), Some(5));
+
+		/* The next sweep resumes with the rest of the block */
+		let mut swept = Vec::new();
+		sweep(Weight::MAX, &mut swept);
+		assert_eq!(swept, vec![second_id]);
+		assert!(!RegistryEntryExpiries::<Test>::contains_key(5));
+		assert_eq!(NextExpirySweep::<Test>::get(), Some(6));
+	});
+}
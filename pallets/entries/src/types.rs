@@ -36,3 +36,25 @@ pub struct RegistryEntryDetails<RegistryEntryHashOf, StatusOf, CreatorOf, Regist
 	/// Type of Reistry Entry Identifier.
 	pub registry_id: RegistryIdOf,
 }
+
+/// Validity window of a Registry Entry, expressed in block numbers.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RegistryEntryValidity<BlockNumber> {
+	/// Block from which the Registry Entry is valid, if restricted.
+	pub valid_from: Option<BlockNumber>,
+	/// Block at which the Registry Entry expires, if it does.
+	pub valid_until: Option<BlockNumber>,
+}
+
+/// Status of a Registry Entry at a given block.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RegistryEntryStatus {
+	/// The Registry Entry is valid.
+	Active,
+	/// The Registry Entry has been revoked.
+	Revoked,
+	/// The validity window of the Registry Entry has not started yet.
+	NotYetValid,
+	/// The validity window of the Registry Entry has ended.
+	Expired,
+}
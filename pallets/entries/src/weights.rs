@@ -0,0 +1,611 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_entries`.
+//!
+//! These weights were not produced by the benchmark CLI. They are estimated
+//! from the storage accesses of each call and the measured weights of similar
+//! calls. They must be regenerated with
+//! `cord benchmark pallet --pallet=pallet_entries` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_entries`.
+pub trait WeightInfo {
+	fn create(l: u32, ) -> Weight;
+	fn update(l: u32, ) -> Weight;
+	fn revoke() -> Weight;
+	fn reinstate() -> Weight;
+	fn update_ownership() -> Weight;
+	fn set_validity() -> Weight;
+	fn create_batch(n: u32, ) -> Weight;
+	fn revoke_batch(n: u32, ) -> Weight;
+	fn reinstate_batch(n: u32, ) -> Weight;
+	fn remove_blob() -> Weight;
+}
+
+/// Weights for `pallet_entries` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15427), added: 17902, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:0 w:1)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4164), added: 6639, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntrySchemas` (r:0 w:1)
+	/// Proof: `Entries::RegistryEntrySchemas` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn create(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1206`
+		//  Estimated: `19007`
+		// Minimum execution time: 64_830_000 picoseconds.
+		Weight::from_parts(67_541_000, 19007)
+			// Standard Error: 29
+			.saturating_add(Weight::from_parts(1_312, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4164), added: 6639, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn update(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `926`
+		//  Estimated: `6639`
+		// Minimum execution time: 47_010_000 picoseconds.
+		Weight::from_parts(48_976_000, 6639)
+			// Standard Error: 31
+			.saturating_add(Weight::from_parts(1_287, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn revoke() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `918`
+		//  Estimated: `3698`
+		// Minimum execution time: 41_250_000 picoseconds.
+		Weight::from_parts(42_971_000, 3698)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn reinstate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `851`
+		//  Estimated: `3698`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(42_956_000, 3698)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn update_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1013`
+		//  Estimated: `6308`
+		// Minimum execution time: 45_580_000 picoseconds.
+		Weight::from_parts(47_482_000, 6308)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:0)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryValidities` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryValidities` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryExpiries` (r:2 w:2)
+	/// Proof: `Entries::RegistryEntryExpiries` (`max_values`: None, `max_size`: Some(5022), added: 7497, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::NextExpirySweep` (r:1 w:1)
+	/// Proof: `Entries::NextExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_validity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1295`
+		//  Estimated: `15984`
+		// Minimum execution time: 71_760_000 picoseconds.
+		Weight::from_parts(74_759_000, 15984)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15427), added: 17902, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntrySchemas` (r:0 w:1000)
+	/// Proof: `Entries::RegistryEntrySchemas` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1000 w:1000)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn create_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1023 + n * (68 ±0)`
+		//  Estimated: `19007 + n * (5130 ±0)`
+		// Minimum execution time: 38_250_000 picoseconds.
+		Weight::from_parts(39_852_000, 19007)
+			// Standard Error: 7_153
+			.saturating_add(Weight::from_parts(23_418_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5130).saturating_mul(n.into()))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1000 w:1000)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn revoke_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `667 + n * (125 ±0)`
+		//  Estimated: `3698 + n * (5130 ±0)`
+		// Minimum execution time: 23_380_000 picoseconds.
+		Weight::from_parts(24_361_000, 3698)
+			// Standard Error: 5_482
+			.saturating_add(Weight::from_parts(17_862_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5130).saturating_mul(n.into()))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1000 w:1000)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn reinstate_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693 + n * (70 ±0)`
+		//  Estimated: `3698 + n * (5130 ±0)`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_006_000, 3698)
+			// Standard Error: 5_519
+			.saturating_add(Weight::from_parts(17_905_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5130).saturating_mul(n.into()))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:0)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4164), added: 6639, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn remove_blob() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `926`
+		//  Estimated: `6639`
+		Weight::from_parts(48_976_000, 6639)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15427), added: 17902, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:0 w:1)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4164), added: 6639, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntrySchemas` (r:0 w:1)
+	/// Proof: `Entries::RegistryEntrySchemas` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn create(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1206`
+		//  Estimated: `19007`
+		// Minimum execution time: 64_830_000 picoseconds.
+		Weight::from_parts(67_541_000, 19007)
+			// Standard Error: 29
+			.saturating_add(Weight::from_parts(1_312, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4164), added: 6639, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[1, 4096]`.
+	fn update(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `926`
+		//  Estimated: `6639`
+		// Minimum execution time: 47_010_000 picoseconds.
+		Weight::from_parts(48_976_000, 6639)
+			// Standard Error: 31
+			.saturating_add(Weight::from_parts(1_287, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn revoke() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `918`
+		//  Estimated: `3698`
+		// Minimum execution time: 41_250_000 picoseconds.
+		Weight::from_parts(42_971_000, 3698)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn reinstate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `851`
+		//  Estimated: `3698`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(42_956_000, 3698)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:2 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn update_ownership() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1013`
+		//  Estimated: `6308`
+		// Minimum execution time: 45_580_000 picoseconds.
+		Weight::from_parts(47_482_000, 6308)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:0)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryValidities` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryValidities` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryExpiries` (r:2 w:2)
+	/// Proof: `Entries::RegistryEntryExpiries` (`max_values`: None, `max_size`: Some(5022), added: 7497, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::NextExpirySweep` (r:1 w:1)
+	/// Proof: `Entries::NextExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_validity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1295`
+		//  Estimated: `15984`
+		// Minimum execution time: 71_760_000 picoseconds.
+		Weight::from_parts(74_759_000, 15984)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `SchemaAccounts::Schemas` (r:1 w:0)
+	/// Proof: `SchemaAccounts::Schemas` (`max_values`: None, `max_size`: Some(15427), added: 17902, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntrySchemas` (r:0 w:1000)
+	/// Proof: `Entries::RegistryEntrySchemas` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1000 w:1000)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn create_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1023 + n * (68 ±0)`
+		//  Estimated: `19007 + n * (5130 ±0)`
+		// Minimum execution time: 38_250_000 picoseconds.
+		Weight::from_parts(39_852_000, 19007)
+			// Standard Error: 7_153
+			.saturating_add(Weight::from_parts(23_418_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5130).saturating_mul(n.into()))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1000 w:1000)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn revoke_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `667 + n * (125 ±0)`
+		//  Estimated: `3698 + n * (5130 ±0)`
+		// Minimum execution time: 23_380_000 picoseconds.
+		Weight::from_parts(24_361_000, 3698)
+			// Standard Error: 5_482
+			.saturating_add(Weight::from_parts(17_862_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5130).saturating_mul(n.into()))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1000 w:1000)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1000 w:1000)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1000 w:1000)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn reinstate_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `693 + n * (70 ±0)`
+		//  Estimated: `3698 + n * (5130 ±0)`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_006_000, 3698)
+			// Standard Error: 5_519
+			.saturating_add(Weight::from_parts(17_905_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5130).saturating_mul(n.into()))
+	}
+	/// Storage: `Registries::Authorizations` (r:1 w:0)
+	/// Proof: `Registries::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `Registries::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Registries::RegistryInfo` (r:1 w:0)
+	/// Proof: `Registries::RegistryInfo` (`max_values`: None, `max_size`: Some(233), added: 2708, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntries` (r:1 w:0)
+	/// Proof: `Entries::RegistryEntries` (`max_values`: None, `max_size`: Some(181), added: 2656, mode: `MaxEncodedLen`)
+	/// Storage: `Entries::RegistryEntryBlobs` (r:1 w:1)
+	/// Proof: `Entries::RegistryEntryBlobs` (`max_values`: None, `max_size`: Some(4164), added: 6639, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn remove_blob() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `926`
+		//  Estimated: `6639`
+		Weight::from_parts(48_976_000, 6639)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+}
@@ -0,0 +1,398 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::sp_runtime::traits::Hash;
+use frame_system::RawOrigin;
+use identifier::{IdentifierType, Ss58Identifier};
+use sp_std::prelude::*;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+pub fn generate_namespace_id<T: Config>(digest: &NameSpaceCodeOf<T>) -> NameSpaceIdOf {
+	Ss58Identifier::create_identifier(&(digest).encode()[..], IdentifierType::NameSpace).unwrap()
+}
+
+pub fn generate_authorization_id<T: Config>(digest: &NameSpaceCodeOf<T>) -> AuthorizationIdOf {
+	Ss58Identifier::create_identifier(
+		&(digest).encode()[..],
+		IdentifierType::NameSpaceAuthorization,
+	)
+	.unwrap()
+}
+
+const SEED: u32 = 0;
+
+benchmarks! {
+		add_delegate {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let delegate: T::AccountId = account("delegate", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&delegate_auth_id_digest);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::Authorization {
+					namespace: namespace_id,
+					authorization: delegate_authorization_id,
+					delegate: delegate,
+				}
+				.into()
+			);
+		}
+
+
+		add_admin_delegate {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let delegate: T::AccountId = account("delegate", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&delegate_auth_id_digest);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::Authorization {
+					namespace: namespace_id,
+					authorization: delegate_authorization_id,
+					delegate: delegate,
+				}
+				.into()
+			);
+		}
+
+
+		add_delegator {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let delegate: T::AccountId = account("delegate", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&delegate_auth_id_digest);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::Authorization {
+					namespace: namespace_id,
+					authorization: delegate_authorization_id,
+					delegate: delegate,
+				}
+				.into()
+			);
+		}
+
+
+		remove_delegate {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let delegate: T::AccountId = account("delegate", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&delegate_auth_id_digest);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+			Pallet::<T>::add_delegate(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate_authorization_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::Deauthorization {
+					namespace: namespace_id,
+					authorization: delegate_authorization_id,
+				}
+				.into()
+			);
+		}
+
+
+		create {
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let raw_blob = [2u8; 256].to_vec();
+			let blob: NameSpaceBlobOf<T> = BoundedVec::try_from(raw_blob)
+				.expect("Test blob should fit into the expected input length for the test runtime.");
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			Some(blob)
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::Create {
+					namespace: namespace_id,
+					creator: creator,
+					authorization: authorization_id,
+				}
+				.into()
+			);
+		}
+
+
+		archive {
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::Archive {
+					namespace: namespace_id,
+					authority: creator,
+				}
+				.into()
+			);
+		}
+
+
+		restore {
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+			Pallet::<T>::archive(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_id.clone(),
+				authorization_id.clone(),
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::Restore {
+					namespace: namespace_id,
+					authority: creator,
+				}
+				.into()
+			);
+		}
+
+		set_delegation_constraints {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let delegate: T::AccountId = account("delegate", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let delegate_authorization_id: AuthorizationIdOf = generate_authorization_id::<T>(&delegate_auth_id_digest);
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+			Pallet::<T>::add_delegate(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_id.clone(),
+				delegate.clone(),
+				authorization_id.clone(),
+			)?;
+
+			let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(expires_at),
+			Some(10),
+			DelegationScope::CreateOnly,
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::DelegationConstraintsSet {
+					namespace: namespace_id,
+					authorization: delegate_authorization_id,
+					expires_at: Some(expires_at),
+					max_creates: Some(10),
+					scope: DelegationScope::CreateOnly,
+					authority: creator,
+				}
+				.into(),
+			);
+		}
+
+		impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
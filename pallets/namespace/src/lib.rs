@@ -45,6 +45,7 @@
 //!   permissions.
 //! - `add_audit_delegate`: Adds an audit delegate to a namespace, granting them audit permissions.
 //! - `remove_delegate`: Removes a delegate from a namespace, revoking their permissions.
+//! - `set_delegation_constraints`: Limits a delegation by expiry, creation quota or scope.
 //!
 //! ## Permissions
 //!
@@ -52,6 +53,14 @@
 //! can perform within a namespace. Permissions are granular and can be assigned to
 //! different roles, such as an admin or a regular delegate.
 //!
+//! ## Delegation Constraints
+//!
+//! Namespace admins can further limit a delegation: it can expire at a block, be
+//! restricted to a maximum number of creations, or be scoped to creating
+//! only. The `ensure_authorization_*` helpers refuse lapsed delegations. A
+//! `DelegationLapsed` event is emitted when the quota is used up and, from
+//! `on_idle`, when the expiry block is reached.
+//!
 //! ## Data Privacy
 //!
 //! The NameSpace pallet is designed with data privacy as a core consideration.
@@ -88,14 +97,19 @@
 #[cfg(any(feature = "mock", test))]
 pub mod mock;
 
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
 #[cfg(test)]
 mod tests;
 
-use frame_support::{ensure, storage::types::StorageMap, BoundedVec};
+use frame_support::{ensure, storage::types::StorageMap, traits::Get, weights::Weight, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
 pub mod types;
-pub use crate::{pallet::*, types::*};
+pub mod weights;
+pub use crate::{pallet::*, types::*, weights::WeightInfo};
 use codec::Encode;
-use frame_system::WeightInfo;
+use cord_utilities::schedule;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
@@ -141,6 +155,9 @@ pub type NameSpaceDetailsOf<T> = NameSpaceDetails<
 pub type NameSpaceAuthorizationOf<T> =
 	NameSpaceAuthorization<NameSpaceIdOf, NameSpaceCreatorOf<T>, Permissions>;
 
+/// Type of the Namespace Delegation Constraints
+pub type DelegationConstraintsOf<T> = DelegationConstraints<BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -166,6 +183,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxNameSpaceBlobSize: Get<u32>;
 
+		/// Maximum delegations expiring at a single block
+		#[pallet::constant]
+		type MaxDelegationExpiriesPerBlock: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -175,7 +196,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_delegation_expiries(n, remaining_weight)
+		}
+	}
 
 	/// Namespace information stored on chain.
 	/// It maps from an identifier to its details.
@@ -206,6 +231,27 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Namespace delegation constraints stored on chain.
+	/// It maps from an authorization identifier to the limits placed on it.
+	#[pallet::storage]
+	pub type AuthorizationConstraints<T> =
+		StorageMap<_, Blake2_128Concat, AuthorizationIdOf, DelegationConstraintsOf<T>, OptionQuery>;
+
+	/// Scheduled namespace delegation expiries.
+	/// It maps a block number to the authorizations expiring at that block.
+	#[pallet::storage]
+	pub type DelegationExpiries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<AuthorizationIdOf, T::MaxDelegationExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next block whose scheduled delegation expiries have not been swept.
+	#[pallet::storage]
+	pub type NextDelegationExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -235,6 +281,23 @@ pub mod pallet {
 		/// A namespace has been restored.
 		/// \[namespace identifier, \]
 		Revoke { namespace: NameSpaceIdOf },
+		/// Constraints on a namespace delegation have been changed.
+		/// \[namespace identifier, authorization, expires at, max creates, scope, authority\]
+		DelegationConstraintsSet {
+			namespace: NameSpaceIdOf,
+			authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			max_creates: Option<u32>,
+			scope: DelegationScope,
+			authority: NameSpaceCreatorOf<T>,
+		},
+		/// A namespace delegation has lapsed.
+		/// \[namespace identifier, authorization, reason\]
+		DelegationLapsed {
+			namespace: NameSpaceIdOf,
+			authorization: AuthorizationIdOf,
+			reason: DelegationLapse,
+		},
 	}
 
 	#[pallet::error]
@@ -268,6 +331,16 @@ pub mod pallet {
 		DelegateNotFound,
 		/// Namespace Registry list limit exceeded.
 		NameSpaceRegistryListLimitExceeded,
+		/// Delegation has expired.
+		DelegationExpired,
+		/// Delegation has used up its creation quota.
+		DelegationQuotaExhausted,
+		/// Delegation is not scoped for the operation.
+		DelegationScopeExceeded,
+		/// Delegation expiry is not in the future.
+		InvalidDelegationExpiry,
+		/// Maximum number of delegations expiring at the block reached.
+		TooManyDelegationExpiriesInBlock,
 	}
 
 	#[pallet::call]
@@ -297,7 +370,7 @@ pub mod pallet {
 		/// - `UnauthorizedOperation`: If the caller is not an admin of the namespace.
 		/// - Propagates errors from `space_delegate_addition` if it fails.
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegate())]
 		pub fn add_delegate(
 			origin: OriginFor<T>,
 			namespace_id: NameSpaceIdOf,
@@ -342,7 +415,7 @@ pub mod pallet {
 		/// - `UnauthorizedOperation`: If the caller is not an admin of the namespace.
 		/// - Propagates errors from `space_delegate_addition` if it fails.
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_admin_delegate())]
 		pub fn add_admin_delegate(
 			origin: OriginFor<T>,
 			namespace_id: NameSpaceIdOf,
@@ -382,7 +455,7 @@ pub mod pallet {
 		/// Returns `Ok(())` if the audit delegate was successfully added, or an
 		/// `Err` with an appropriate error if the operation fails.
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegator())]
 		pub fn add_delegator(
 			origin: OriginFor<T>,
 			namespace_id: NameSpaceIdOf,
@@ -436,7 +509,7 @@ pub mod pallet {
 		/// - `Deauthorization`: Emitted when a delegate is successfully removed from a namespace.
 		///   The event includes the namespace ID and the authorization ID of the removed delegate.
 		#[pallet::call_index(3)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_delegate())]
 		pub fn remove_delegate(
 			origin: OriginFor<T>,
 			namespace_id: NameSpaceIdOf,
@@ -460,6 +533,7 @@ pub mod pallet {
 				Delegates::<T>::insert(&namespace_id, delegates);
 
 				Authorizations::<T>::remove(&remove_authorization);
+				Self::clear_delegation_constraints(&remove_authorization);
 
 				Self::update_activity(
 					&namespace_id,
@@ -510,7 +584,7 @@ pub mod pallet {
 		/// - `Create`: Emitted when a new namespace is successfully created. It includes the
 		///   namespace identifier, the creator's identifier, and the authorization ID.
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create())]
 		pub fn create(
 			origin: OriginFor<T>,
 			digest: NameSpaceHashOf<T>,
@@ -622,7 +696,7 @@ pub mod pallet {
 		/// - `Archive`: Emitted when a namespace is successfully archived. It includes the
 		///   namespace ID and the authority who performed the archival.
 		#[pallet::call_index(6)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::archive())]
 		pub fn archive(
 			origin: OriginFor<T>,
 			namespace_id: NameSpaceIdOf,
@@ -681,7 +755,7 @@ pub mod pallet {
 		/// - `Restore`: Emitted when a namespace is successfully restored. It includes the
 		///   namespace ID and the authority who performed the restoration.
 		#[pallet::call_index(7)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore())]
 		pub fn restore(
 			origin: OriginFor<T>,
 			namespace_id: NameSpaceIdOf,
@@ -709,6 +783,103 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets the constraints of a namespace delegation.
+		///
+		/// A delegation can be limited to expire at a block, to at most
+		/// `max_creates` creations, or to the `CreateOnly` scope, which allows
+		/// creating but no other operation. Creations made before the
+		/// constraints changed keep counting towards the quota. Passing no
+		/// expiry, no quota and the `Full` scope removes all constraints.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed by an admin of the
+		///   namespace.
+		/// - `namespace_id`: The identifier of the namespace.
+		/// - `delegate_authorization`: The authorization ID of the delegation to constrain.
+		/// - `expires_at`: The block at which the delegation lapses, if any.
+		/// - `max_creates`: The maximum number of creations allowed to the delegation, if any.
+		/// - `scope`: The operations the delegation is allowed to perform.
+		/// - `authorization`: The authorization ID used to validate the caller's admin permission.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the namespace, the delegation
+		///   belongs to another namespace or is the caller's own authorization.
+		/// - `AuthorizationNotFound`: If the delegation does not exist.
+		/// - `InvalidDelegationExpiry`: If `expires_at` is not in the future.
+		/// - `TooManyDelegationExpiriesInBlock`: If too many delegations expire at `expires_at`.
+		///
+		/// # Events
+		/// - `DelegationConstraintsSet`: Emitted when the constraints have been changed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_delegation_constraints())]
+		pub fn set_delegation_constraints(
+			origin: OriginFor<T>,
+			namespace_id: NameSpaceIdOf,
+			delegate_authorization: AuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			max_creates: Option<u32>,
+			scope: DelegationScope,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+			let auth_space_id = Self::ensure_authorization_admin_origin(&authorization, &creator)?;
+
+			ensure!(auth_space_id == namespace_id, Error::<T>::UnauthorizedOperation);
+			ensure!(delegate_authorization != authorization, Error::<T>::UnauthorizedOperation);
+
+			let delegation = <Authorizations<T>>::get(&delegate_authorization)
+				.ok_or(Error::<T>::AuthorizationNotFound)?;
+			ensure!(delegation.namespace_id == namespace_id, Error::<T>::UnauthorizedOperation);
+
+			if let Some(expiry) = expires_at {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidDelegationExpiry
+				);
+			}
+
+			let previous = AuthorizationConstraints::<T>::get(&delegate_authorization);
+			let constraints = DelegationConstraintsOf::<T> {
+				expires_at,
+				max_creates,
+				creates: previous.as_ref().map_or(0, |c| c.creates),
+				scope,
+			};
+
+			let previous_expiry = previous.and_then(|c| c.expires_at);
+			if previous_expiry != expires_at {
+				if let Some(expiry) = previous_expiry {
+					Self::unschedule_delegation_expiry(&delegate_authorization, expiry);
+				}
+				if let Some(expiry) = expires_at {
+					Self::schedule_delegation_expiry(&delegate_authorization, expiry)?;
+				}
+			}
+
+			if constraints.is_unconstrained() {
+				AuthorizationConstraints::<T>::remove(&delegate_authorization);
+			} else {
+				AuthorizationConstraints::<T>::insert(&delegate_authorization, constraints);
+			}
+
+			Self::update_activity(
+				&namespace_id,
+				IdentifierTypeOf::NameSpaceAuthorization,
+				CallTypeOf::Authorization,
+			)?;
+
+			Self::deposit_event(Event::DelegationConstraintsSet {
+				namespace: namespace_id,
+				authorization: delegate_authorization,
+				expires_at,
+				max_creates,
+				scope,
+				authority: creator,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -797,6 +968,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		// TODO: Update all similar function names.
 		Self::validate_space_for_transaction(&d.namespace_id)?;
 
@@ -805,6 +978,40 @@ impl<T: Config> Pallet<T> {
 		Ok(d.namespace_id)
 	}
 
+	/// Verifies that a delegate may make `count` new creations in a namespace.
+	///
+	/// This function performs the same checks as `ensure_authorization_origin`,
+	/// but also accepts `CreateOnly` delegations and checks that `count`
+	/// creations fit in the quota of the delegation. Nothing is counted here;
+	/// callers record the creations they actually made with
+	/// `record_delegated_creates`.
+	pub fn ensure_authorization_create_origin(
+		authorization_id: &AuthorizationIdOf,
+		delegate: &NameSpaceCreatorOf<T>,
+		count: u32,
+	) -> Result<NameSpaceIdOf, Error<T>> {
+		let d =
+			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+
+		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+
+		let constraints =
+			Self::ensure_delegation_permits(authorization_id, DelegationScope::CreateOnly)?;
+
+		Self::validate_space_for_transaction(&d.namespace_id)?;
+
+		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+
+		let within_quota = constraints.map_or(true, |c| {
+			c.max_creates.map_or(true, |max_creates| {
+				c.creates.checked_add(count).is_some_and(|creates| creates <= max_creates)
+			})
+		});
+		ensure!(within_quota, Error::<T>::DelegationQuotaExhausted);
+
+		Ok(d.namespace_id)
+	}
+
 	pub fn ensure_authorization_restore_origin(
 		authorization_id: &AuthorizationIdOf,
 		delegate: &NameSpaceCreatorOf<T>,
@@ -814,6 +1021,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_space_for_restore_transaction(&d.namespace_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -836,6 +1045,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_space_for_transaction(&d.namespace_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -859,6 +1070,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_space_for_transaction(&d.namespace_id)?;
 
 		ensure!(
@@ -884,6 +1097,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_space_for_transaction(&d.namespace_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -891,6 +1106,129 @@ impl<T: Config> Pallet<T> {
 		Ok(d.namespace_id)
 	}
 
+	/// Ensures that a delegation has not lapsed and is scoped for an operation.
+	///
+	/// Delegations without constraints always pass. The constraints, if any,
+	/// are returned so that callers can account for creations.
+	pub fn ensure_delegation_permits(
+		authorization_id: &AuthorizationIdOf,
+		scope: DelegationScope,
+	) -> Result<Option<DelegationConstraintsOf<T>>, Error<T>> {
+		let Some(constraints) = AuthorizationConstraints::<T>::get(authorization_id) else {
+			return Ok(None);
+		};
+
+		ensure!(
+			!constraints.is_expired(&frame_system::Pallet::<T>::block_number()),
+			Error::<T>::DelegationExpired
+		);
+		ensure!(
+			scope == DelegationScope::CreateOnly || constraints.scope == DelegationScope::Full,
+			Error::<T>::DelegationScopeExceeded
+		);
+
+		Ok(Some(constraints))
+	}
+
+	/// Counts `count` creations towards the quota of a delegation. Called by
+	/// the dispatchables once the creations have been made.
+	///
+	/// Emits `DelegationLapsed` once the quota has been used up.
+	pub fn record_delegated_creates(
+		authorization_id: &AuthorizationIdOf,
+		namespace_id: &NameSpaceIdOf,
+		count: u32,
+	) -> Result<(), Error<T>> {
+		let Some(mut constraints) = AuthorizationConstraints::<T>::get(authorization_id) else {
+			return Ok(());
+		};
+		let Some(max_creates) = constraints.max_creates else {
+			return Ok(());
+		};
+
+		let creates = constraints
+			.creates
+			.checked_add(count)
+			.filter(|creates| *creates <= max_creates)
+			.ok_or(Error::<T>::DelegationQuotaExhausted)?;
+		constraints.creates = creates;
+		AuthorizationConstraints::<T>::insert(authorization_id, constraints);
+
+		if creates == max_creates {
+			Self::deposit_event(Event::DelegationLapsed {
+				namespace: namespace_id.clone(),
+				authorization: authorization_id.clone(),
+				reason: DelegationLapse::QuotaExhausted,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Removes the constraints of a delegation along with its scheduled expiry.
+	fn clear_delegation_constraints(authorization_id: &AuthorizationIdOf) {
+		if let Some(expiry) =
+			AuthorizationConstraints::<T>::take(authorization_id).and_then(|c| c.expires_at)
+		{
+			Self::unschedule_delegation_expiry(authorization_id, expiry);
+		}
+	}
+
+	/// Schedules a delegation to lapse at block `expires_at`.
+	fn schedule_delegation_expiry(
+		authorization_id: &AuthorizationIdOf,
+		expires_at: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		schedule::schedule::<NextDelegationExpirySweep<T>, DelegationExpiries<T>, _, _, _>(
+			expires_at,
+			authorization_id.clone(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+		.map_err(|_| Error::<T>::TooManyDelegationExpiriesInBlock)
+	}
+
+	/// Removes a delegation from the expiries scheduled at block `expires_at`.
+	fn unschedule_delegation_expiry(
+		authorization_id: &AuthorizationIdOf,
+		expires_at: BlockNumberFor<T>,
+	) {
+		schedule::unschedule::<DelegationExpiries<T>, _, _, _>(expires_at, authorization_id);
+	}
+
+	/// Reports the delegations scheduled to lapse up to and including block
+	/// `now`, as far as `remaining_weight` allows.
+	pub(crate) fn sweep_delegation_expiries(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		let db_weight = T::DbWeight::get();
+		schedule::sweep::<NextDelegationExpirySweep<T>, DelegationExpiries<T>, _, _, _>(
+			now,
+			remaining_weight,
+			db_weight,
+			db_weight.reads(2),
+			|authorization_id, at| Self::lapse_delegation(&authorization_id, at),
+		)
+	}
+
+	/// Reports a delegation as lapsed if it is still scheduled to expire at
+	/// block `at`.
+	fn lapse_delegation(authorization_id: &AuthorizationIdOf, at: BlockNumberFor<T>) {
+		let scheduled = AuthorizationConstraints::<T>::get(authorization_id)
+			.is_some_and(|constraints| constraints.expires_at == Some(at));
+		if !scheduled {
+			return;
+		}
+
+		if let Some(d) = <Authorizations<T>>::get(authorization_id) {
+			Self::deposit_event(Event::DelegationLapsed {
+				namespace: d.namespace_id,
+				authorization: authorization_id.clone(),
+				reason: DelegationLapse::Expired,
+			});
+		}
+	}
+
 	/// Validates that a namespace is eligible for a new transaction.
 	///
 	/// This function ensures that a namespace is not archived and has
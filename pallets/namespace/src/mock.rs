@@ -74,21 +74,31 @@ parameter_types! {
 	pub const MaxNameSpaceBlobSize: u32 = 4u32 * 1024;
 }
 
+parameter_types! {
+	pub const MaxNameSpaceDelegationExpiriesPerBlock: u32 = 2;
+}
+
 impl pallet_namespace::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type MaxNameSpaceDelegates = MaxNameSpaceDelegates;
 	type MaxNameSpaceBlobSize = MaxNameSpaceBlobSize;
+	type MaxDelegationExpiriesPerBlock = MaxNameSpaceDelegationExpiriesPerBlock;
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MaxEventsHistory: u32 = 6u32;
+	pub const TimelineOverflow: identifier::TimelineOverflowMode =
+		identifier::TimelineOverflowMode::Drop;
+	pub const MaxArchivedEventsPerPage: u32 = 10u32;
 }
 
 impl identifier::Config for Test {
 	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
 }
 
 parameter_types! {
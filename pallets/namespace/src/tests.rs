@@ -1,7 +1,7 @@
 use super::*;
 use crate::mock::*;
 use codec::Encode;
-use frame_support::assert_ok;
+use frame_support::{assert_err, assert_ok, traits::Hooks};
 use sp_runtime::traits::Hash;
 use sp_std::prelude::*;
 
@@ -60,3 +60,117 @@ fn add_delegate_should_succeed() {
 		));
 	});
 }
+
+#[test]
+fn delegation_constraints_should_limit_expiry_quota_and_scope() {
+	let creator = ACCOUNT_00;
+	let delegate = ACCOUNT_01;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: AuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(NameSpace::add_delegate(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate.clone(),
+			authorization_id.clone(),
+		));
+
+		assert_err!(
+			NameSpace::set_delegation_constraints(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				namespace_id.clone(),
+				delegate_authorization_id.clone(),
+				Some(1),
+				None,
+				DelegationScope::Full,
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidDelegationExpiry
+		);
+
+		assert_ok!(NameSpace::set_delegation_constraints(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(10),
+			Some(1),
+			DelegationScope::CreateOnly,
+			authorization_id.clone(),
+		));
+
+		assert_err!(
+			NameSpace::ensure_authorization_origin(&delegate_authorization_id, &delegate),
+			Error::<Test>::DelegationScopeExceeded
+		);
+		assert_ok!(NameSpace::ensure_authorization_create_origin(
+			&delegate_authorization_id,
+			&delegate,
+			1
+		));
+		assert_ok!(NameSpace::record_delegated_creates(
+			&delegate_authorization_id,
+			&namespace_id,
+			1
+		));
+		System::assert_last_event(
+			Event::DelegationLapsed {
+				namespace: namespace_id.clone(),
+				authorization: delegate_authorization_id.clone(),
+				reason: DelegationLapse::QuotaExhausted,
+			}
+			.into(),
+		);
+		assert_err!(
+			NameSpace::ensure_authorization_create_origin(&delegate_authorization_id, &delegate, 1),
+			Error::<Test>::DelegationQuotaExhausted
+		);
+
+		System::set_block_number(10);
+		NameSpace::on_idle(10, Weight::MAX);
+
+		System::assert_last_event(
+			Event::DelegationLapsed {
+				namespace: namespace_id.clone(),
+				authorization: delegate_authorization_id.clone(),
+				reason: DelegationLapse::Expired,
+			}
+			.into(),
+		);
+		assert_err!(
+			NameSpace::ensure_authorization_create_origin(&delegate_authorization_id, &delegate, 1),
+			Error::<Test>::DelegationExpired
+		);
+
+		assert_ok!(NameSpace::remove_delegate(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_id.clone(),
+			delegate_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(AuthorizationConstraints::<Test>::get(&delegate_authorization_id).is_none());
+	});
+}
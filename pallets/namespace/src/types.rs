@@ -100,3 +100,56 @@ pub struct NameSpaceAuthorization<NameSpaceIdOf, NameSpaceCreatorOf, Permissions
 	pub permissions: Permissions,
 	pub delegator: NameSpaceCreatorOf,
 }
+
+/// Operations a delegation may be used for.
+///
+/// - `Full`: The delegate may use every operation its permissions grant.
+/// - `CreateOnly`: The delegate may only create new entries; updates, revocations and other
+///   changes are refused.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo,
+)]
+pub enum DelegationScope {
+	#[default]
+	Full,
+	CreateOnly,
+}
+
+/// Reason a delegation stopped granting access.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum DelegationLapse {
+	/// The delegation reached its expiry block.
+	Expired,
+	/// The delegate created the maximum number of entries allowed.
+	QuotaExhausted,
+}
+
+/// Limits placed on a namespace delegation in addition to its permissions.
+///
+/// ## Fields
+///
+/// - `expires_at`: (Optional) The block from which the delegation no longer grants access.
+/// - `max_creates`: (Optional) The maximum number of entries the delegate may create.
+/// - `creates`: The number of entries created against `max_creates` so far.
+/// - `scope`: The operations the delegation may be used for.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegationConstraints<BlockNumber> {
+	pub expires_at: Option<BlockNumber>,
+	pub max_creates: Option<u32>,
+	pub creates: u32,
+	pub scope: DelegationScope,
+}
+
+impl<BlockNumber: PartialOrd> DelegationConstraints<BlockNumber> {
+	/// Returns `true` if the delegation has expired at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().is_some_and(|expires_at| now >= expires_at)
+	}
+
+	/// Returns `true` if no limits are placed on the delegation.
+	pub fn is_unconstrained(&self) -> bool {
+		self.expires_at.is_none() &&
+			self.max_creates.is_none() &&
+			self.scope == DelegationScope::Full
+	}
+}
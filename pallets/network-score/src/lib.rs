@@ -360,9 +360,10 @@ pub mod pallet {
 			let tx_authors = <T as Config>::EnsureOrigin::ensure_origin(origin)?;
 			let provider = tx_authors.subject();
 			let creator = tx_authors.sender();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_create_origin(
 				&authorization,
 				&provider,
+				1,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -420,7 +421,7 @@ pub mod pallet {
 					entry,
 					digest,
 					message_id: message_id.clone(),
-					space: space_id,
+					space: space_id.clone(),
 					creator_id: creator.clone(),
 					entry_type: EntryTypeOf::Credit,
 					reference_id: None,
@@ -430,6 +431,14 @@ pub mod pallet {
 
 			<MessageIdentifiers<T>>::insert(message_id, &provider_did, &identifier);
 
+			pallet_chain_space::Pallet::<T>::record_delegated_creates(
+				&authorization,
+				&space_id,
+				&provider,
+				1,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(Error::<T>::from)?;
 
 			Self::deposit_event(Event::RatingEntryAdded {
@@ -554,6 +563,9 @@ pub mod pallet {
 
 			<MessageIdentifiers<T>>::insert(&message_id, &provider_did, &identifier);
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &provider, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(Error::<T>::from)?;
 			Self::update_activity(&entry_identifier, CallTypeOf::Debit)
 				.map_err(Error::<T>::from)?;
@@ -697,7 +709,7 @@ pub mod pallet {
 				RatingEntryOf::<T> {
 					entry,
 					digest,
-					space: space_id,
+					space: space_id.clone(),
 					message_id: message_id.clone(),
 					creator_id: creator.clone(),
 					entry_type: EntryTypeOf::Credit,
@@ -708,6 +720,9 @@ pub mod pallet {
 
 			<MessageIdentifiers<T>>::insert(message_id, &provider_did, &identifier);
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &provider, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(Error::<T>::from)?;
 			if let Some(reference_id) = reference_id_option {
 				Self::update_activity(&reference_id, CallTypeOf::Credit)
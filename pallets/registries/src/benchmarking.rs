@@ -110,6 +110,11 @@ benchmarks! {
 				None
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -192,6 +197,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -276,6 +286,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -358,6 +373,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -440,6 +460,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 		}: _<T::RuntimeOrigin>(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -514,6 +539,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -590,6 +620,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -663,6 +698,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -743,6 +783,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -816,6 +861,11 @@ benchmarks! {
 				None,
 			)?;
 
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
 			Pallet::<T>::create(
 				RawOrigin::Signed(creator.clone()).into(),
 				registry_digest,
@@ -847,5 +897,280 @@ benchmarks! {
 			);
 		}
 
+		set_schema_binding {
+			let creator: T::AccountId = account("creator", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let namespace_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_authorization_id: NamespaceAuthorizationIdOf = generate_namespace_authorization_id::<T>(&namespace_auth_id_digest);
+
+			let registry = [2u8; 256].to_vec();
+
+			let raw_blob = [2u8; 256].to_vec();
+			let blob: RegistryBlobOf<T> = BoundedVec::try_from(raw_blob)
+				.expect("Test blob should fit into the expected input length for the test runtime.");
+
+			let registry_digest = <T as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let authorization_id: RegistryAuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let raw_schema = [2u8; 256].to_vec();
+			let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+				.expect("Test schema should fit into the expected input length for the test runtime.");
+			let schema_id_digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+			let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+			pallet_namespace::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None,
+			)?;
+
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_digest,
+				namespace_authorization_id.clone(),
+				Some(schema_id.clone()),
+				Some(blob),
+			)?;
+
+		}: _<T::RuntimeOrigin>(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				namespace_authorization_id.clone(),
+				authorization_id.clone(),
+				true
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::SchemaBindingSet {
+					registry_id: registry_id,
+					mandatory: true,
+					authority: creator,
+				}
+				.into(),
+			);
+		}
+
+		set_delegation_constraints {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let delegate: T::AccountId = account("delegate", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let namespace_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_authorization_id: NamespaceAuthorizationIdOf = generate_namespace_authorization_id::<T>(&namespace_auth_id_digest);
+
+			let registry = [2u8; 256].to_vec();
+
+			let raw_blob = [2u8; 256].to_vec();
+			let blob: RegistryBlobOf<T> = BoundedVec::try_from(raw_blob)
+				.expect("Test blob should fit into the expected input length for the test runtime.");
+
+			let registry_digest = <T as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let authorization_id: RegistryAuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let delegate_authorization_id: RegistryAuthorizationIdOf = generate_authorization_id::<T>(&delegate_auth_id_digest);
+
+			let raw_schema = [2u8; 256].to_vec();
+			let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+				.expect("Test schema should fit into the expected input length for the test runtime.");
+			let schema_id_digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+			let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+			pallet_namespace::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_digest,
+				namespace_authorization_id.clone(),
+				Some(schema_id.clone()),
+				Some(blob),
+			)?;
+
+			Pallet::<T>::add_delegate(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				delegate.clone(),
+				namespace_authorization_id.clone(),
+				authorization_id.clone(),
+			)?;
+
+			let expires_at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(expires_at),
+			Some(10),
+			DelegationScope::CreateOnly,
+			namespace_authorization_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::DelegationConstraintsSet {
+					registry_id: registry_id,
+					authorization: delegate_authorization_id,
+					expires_at: Some(expires_at),
+					max_creates: Some(10),
+					scope: DelegationScope::CreateOnly,
+					authority: creator,
+				}
+				.into(),
+			);
+		}
+
+		set_delegate_usage_cap {
+			let creator: T::AccountId = account("creator", 0, SEED);
+			let delegate: T::AccountId = account("delegate", 0, SEED);
+
+			let namespace = [1u8; 256].to_vec();
+			let namespace_digest = <T as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_id: NameSpaceIdOf = generate_namespace_id::<T>(&id_digest);
+
+			let namespace_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+			let namespace_authorization_id: NamespaceAuthorizationIdOf = generate_namespace_authorization_id::<T>(&namespace_auth_id_digest);
+
+			let registry = [2u8; 256].to_vec();
+
+			let raw_blob = [2u8; 256].to_vec();
+			let blob: RegistryBlobOf<T> = BoundedVec::try_from(raw_blob)
+				.expect("Test blob should fit into the expected input length for the test runtime.");
+
+			let registry_digest = <T as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+			let id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let registry_id: RegistryIdOf = generate_registry_id::<T>(&id_digest);
+
+			let auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let authorization_id: RegistryAuthorizationIdOf = generate_authorization_id::<T>(&auth_id_digest);
+
+			let delegate_auth_id_digest = <T as frame_system::Config>::Hashing::hash(
+				&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+			);
+
+			let delegate_authorization_id: RegistryAuthorizationIdOf = generate_authorization_id::<T>(&delegate_auth_id_digest);
+
+			let raw_schema = [2u8; 256].to_vec();
+			let schema: InputSchemaOf<T> = BoundedVec::try_from(raw_schema)
+				.expect("Test schema should fit into the expected input length for the test runtime.");
+			let schema_id_digest = <T as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+			let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+			pallet_namespace::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				namespace_digest,
+				None
+			)?;
+
+			pallet_schema_accounts::Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				schema.clone(),
+			)?;
+
+			Pallet::<T>::create(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_digest,
+				namespace_authorization_id.clone(),
+				Some(schema_id.clone()),
+				Some(blob),
+			)?;
+
+			Pallet::<T>::add_delegate(
+				RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				delegate.clone(),
+				namespace_authorization_id.clone(),
+				authorization_id.clone(),
+			)?;
+		}: _<T::RuntimeOrigin>(
+			RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id,
+			Some(10),
+			namespace_authorization_id.clone(),
+			authorization_id.clone()
+		)
+		verify {
+			assert_last_event::<T>(
+				Event::DelegateUsageCapSet {
+					registry_id: registry_id,
+					delegate,
+					txn_cap: Some(10),
+					authority: creator,
+				}
+				.into(),
+			);
+		}
+
 		impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
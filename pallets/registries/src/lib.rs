@@ -48,7 +48,15 @@
 //!   permissions.
 //! - `add_audit_delegate`: Adds an audit delegate to a registry, granting them audit permissions.
 //! - `remove_delegate`: Removes a delegate from a registry, revoking their permissions.
+//! - `set_schema_binding`: Makes binding new entries to the registry schema mandatory or optional.
+//! - `set_delegation_constraints`: Limits a delegation by expiry, entry quota or scope.
 //!
+//! ## Schema Conformance
+//!
+//! A registry may reference a schema, which must be anchored through the configured
+//! `SchemaProvider` and must not be deprecated. Entries may name the schema they were issued
+//! under; when the registry has a schema the two must match, and when schema binding is
+//! mandatory every new entry must name it.
 //!
 //! ## Permissions
 //!
@@ -56,6 +64,16 @@
 //! can perform within a registry. Permissions are granular and can be assigned to
 //! different roles, such as an admin or a regular delegate.
 //!
+//! ## Delegation Constraints
+//!
+//! Registry admins can further limit a delegation: it can expire at a block,
+//! be restricted to a maximum number of created entries, or be scoped to
+//! creating entries only. The `ensure_authorization_*` helpers refuse lapsed
+//! delegations but count nothing; the pallets acting on registry content record
+//! delegate transactions and created entries with `record_delegate_usage` and
+//! `record_delegated_creates`. A `DelegationLapsed` event is emitted when the
+//! quota is used up and, from `on_idle`, when the expiry block is reached.
+//!
 //! ## Data Privacy
 //!
 //! The Registries pallet is designed with data privacy as a core consideration.
@@ -98,15 +116,20 @@ pub mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{ensure, storage::types::StorageMap, BoundedVec};
+use frame_support::{ensure, storage::types::StorageMap, traits::Get, weights::Weight, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
 pub mod types;
-pub use crate::{pallet::*, types::*};
+pub mod weights;
+pub use crate::{pallet::*, types::*, weights::WeightInfo};
 use codec::Encode;
+use cord_primitives::SchemaLookup;
+use cord_utilities::schedule;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
 use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_std::vec::Vec;
 
 /// Registry Authorization Identifier
 pub type RegistryAuthorizationIdOf = Ss58Identifier;
@@ -134,6 +157,8 @@ pub type RegistryAuthorizationOf<T> =
 /// Type of Registry Details
 pub type RegistryDetailsOf<T> =
 	RegistryDetails<RegistryCreatorOf<T>, StatusOf, RegistryHashOf<T>, NameSpaceIdOf, SchemaIdOf>;
+/// Type of the Registry Delegation Constraints
+pub type DelegationConstraintsOf<T> = DelegationConstraints<BlockNumberFor<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -141,7 +166,6 @@ pub mod pallet {
 	pub use cord_primitives::{IsPermissioned, StatusOf};
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	pub use frame_system::WeightInfo;
 	pub use identifier::{
 		CordIdentifierType, IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier,
 	};
@@ -162,6 +186,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxEncodedInputLength: Get<u32>;
 
+		/// Lookup of the schemas registries and their entries may reference.
+		type SchemaProvider: SchemaLookup<SchemaIdOf, Ss58Identifier>;
+
+		/// Maximum delegations expiring at a single block
+		#[pallet::constant]
+		type MaxDelegationExpiriesPerBlock: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -171,7 +202,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_delegation_expiries(n, remaining_weight)
+		}
+	}
 
 	/// Registry information stored on chain.
 	/// It maps from an identifier to its details.
@@ -202,6 +237,51 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Registries requiring new entries to be bound to the registry schema.
+	#[pallet::storage]
+	pub type MandatorySchemaBinding<T> =
+		StorageMap<_, Blake2_128Concat, RegistryIdOf, bool, ValueQuery>;
+
+	/// Registry delegation constraints stored on chain.
+	/// It maps from an authorization identifier to the limits placed on it.
+	#[pallet::storage]
+	pub type AuthorizationConstraints<T> = StorageMap<
+		_,
+		Blake2_128Concat,
+		RegistryAuthorizationIdOf,
+		DelegationConstraintsOf<T>,
+		OptionQuery,
+	>;
+
+	/// Scheduled registry delegation expiries.
+	/// It maps a block number to the authorizations expiring at that block.
+	#[pallet::storage]
+	pub type DelegationExpiries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<RegistryAuthorizationIdOf, T::MaxDelegationExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next block whose scheduled delegation expiries have not been swept.
+	#[pallet::storage]
+	pub type NextDelegationExpirySweep<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Registry usage by delegate stored on chain.
+	/// It maps from a registry identifier and a delegate to the usage of the
+	/// registry by the delegate.
+	#[pallet::storage]
+	pub type DelegateUsages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RegistryIdOf,
+		Blake2_128Concat,
+		RegistryCreatorOf<T>,
+		DelegateUsage,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -241,6 +321,38 @@ pub mod pallet {
 		/// A registry has been restored.
 		/// \[registry identifier,authority\]
 		Restore { registry_id: RegistryIdOf, authority: RegistryCreatorOf<T> },
+		/// Schema binding for a registry has been changed.
+		/// \[registry identifier, mandatory, authority\]
+		SchemaBindingSet {
+			registry_id: RegistryIdOf,
+			mandatory: bool,
+			authority: RegistryCreatorOf<T>,
+		},
+		/// Constraints on a registry delegation have been changed.
+		/// \[registry identifier, authorization, expires at, max creates, scope, authority\]
+		DelegationConstraintsSet {
+			registry_id: RegistryIdOf,
+			authorization: RegistryAuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			max_creates: Option<u32>,
+			scope: DelegationScope,
+			authority: RegistryCreatorOf<T>,
+		},
+		/// A registry delegation has lapsed.
+		/// \[registry identifier, authorization, reason\]
+		DelegationLapsed {
+			registry_id: RegistryIdOf,
+			authorization: RegistryAuthorizationIdOf,
+			reason: DelegationLapse,
+		},
+		/// The usage cap of a registry delegate has been changed.
+		/// \[registry identifier, delegate, cap, authority\]
+		DelegateUsageCapSet {
+			registry_id: RegistryIdOf,
+			delegate: RegistryCreatorOf<T>,
+			txn_cap: Option<u64>,
+			authority: RegistryCreatorOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -276,6 +388,28 @@ pub mod pallet {
 		RegistryAlreadyArchived,
 		/// Registry not archived.
 		RegistryArchived,
+		/// Schema identifier not found.
+		SchemaNotFound,
+		/// Schema has been deprecated.
+		SchemaDeprecated,
+		/// Schema does not match the registry schema.
+		SchemaMismatch,
+		/// Registry requires entries to be bound to its schema.
+		SchemaBindingRequired,
+		/// Registry has no schema to bind entries to.
+		RegistrySchemaNotSet,
+		/// Delegation has expired.
+		DelegationExpired,
+		/// Delegation has created the maximum number of entries allowed.
+		DelegationQuotaExhausted,
+		/// Delegation is not scoped for the operation.
+		DelegationScopeExceeded,
+		/// Delegation expiry is not in the future.
+		InvalidDelegationExpiry,
+		/// Maximum number of delegations expiring at the block reached.
+		TooManyDelegationExpiriesInBlock,
+		/// The delegate has reached its usage cap in the registry.
+		DelegateUsageCapExceeded,
 	}
 
 	#[pallet::call]
@@ -313,7 +447,7 @@ pub mod pallet {
 		///   for the registry.
 		/// - Propagates errors from `registry_delegate_addition` if the addition fails.
 		#[pallet::call_index(0)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegate())]
 		pub fn add_delegate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -374,7 +508,7 @@ pub mod pallet {
 		///   registry.
 		/// - Propagates errors from `registry_delegate_addition` if delegate addition fails.
 		#[pallet::call_index(1)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_admin_delegate())]
 		pub fn add_admin_delegate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -436,7 +570,7 @@ pub mod pallet {
 		///   for the registry.
 		/// - Propagates errors from `registry_delegate_addition` if delegate addition fails.
 		#[pallet::call_index(2)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_delegator())]
 		pub fn add_delegator(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -504,7 +638,7 @@ pub mod pallet {
 		/// - `Deauthorization`: Emitted when a delegate is successfully removed from the registry.
 		///   The event includes the registry ID and the authorization ID of the removed delegate.
 		#[pallet::call_index(3)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_delegate())]
 		pub fn remove_delegate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -545,6 +679,7 @@ pub mod pallet {
 				Delegates::<T>::insert(&registry_id, delegates);
 
 				Authorizations::<T>::remove(&remove_authorization);
+				Self::clear_delegation_constraints(&remove_authorization);
 
 				Self::update_activity(
 					&registry_id,
@@ -591,6 +726,8 @@ pub mod pallet {
 		/// # Errors
 		/// - `InvalidIdentifierLength`: If the generated identifiers for the registry or
 		///   authorization have invalid lengths.
+		/// - `SchemaNotFound`: If the `schema_id` is not anchored.
+		/// - `SchemaDeprecated`: If the `schema_id` has been deprecated.
 		/// - `RegistryAlreadyAnchored`: If the registry identifier already exists.
 		/// - `RegistryDelegatesLimitExceeded`: If the registry exceeds the maximum number of
 		///   allowed delegates.
@@ -599,7 +736,7 @@ pub mod pallet {
 		/// - `Create`: Emitted when a new registry is successfully created. It includes the
 		///   registry identifier, the creator's identifier, and the authorization ID.
 		#[pallet::call_index(4)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create())]
 		pub fn create(
 			origin: OriginFor<T>,
 			digest: RegistryHashOf<T>,
@@ -609,12 +746,17 @@ pub mod pallet {
 		) -> DispatchResult {
 			let creator = ensure_signed(origin)?;
 
-			let namespace_id = pallet_namespace::Pallet::<T>::ensure_authorization_origin(
+			let namespace_id = pallet_namespace::Pallet::<T>::ensure_authorization_create_origin(
 				&namespace_authorization,
 				&creator,
+				1,
 			)
 			.map_err(<pallet_namespace::Error<T>>::from)?;
 
+			if let Some(ref schema_id) = schema_id {
+				Self::ensure_schema_usable(schema_id)?;
+			}
+
 			// Id Digest = concat (H(<scale_encoded_registry_input_digest>,
 			// <scale_encoded_creator_identifier>))
 			let id_digest = <T as frame_system::Config>::Hashing::hash(
@@ -683,6 +825,13 @@ pub mod pallet {
 			)
 			.map_err(<pallet_namespace::Error<T>>::from)?;
 
+			pallet_namespace::Pallet::<T>::record_delegated_creates(
+				&namespace_authorization,
+				&namespace_id,
+				1,
+			)
+			.map_err(<pallet_namespace::Error<T>>::from)?;
+
 			Self::update_activity(&identifier, IdentifierTypeOf::Registries, CallTypeOf::Genesis)
 				.map_err(Error::<T>::from)?;
 
@@ -728,7 +877,7 @@ pub mod pallet {
 		/// - `Revoke`: Emitted when a registry is successfully revoked. It includes the registry ID
 		///   and the authority who performed the revocation.
 		#[pallet::call_index(6)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke())]
 		pub fn revoke(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -802,7 +951,7 @@ pub mod pallet {
 		/// - `Reinstate`: Emitted when a registry is successfully reinstated. It includes the
 		///   registry ID and the authority who performed the reinstatement.
 		#[pallet::call_index(7)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reinstate())]
 		pub fn reinstate(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -883,7 +1032,7 @@ pub mod pallet {
 		/// TODO:
 		/// Move optional parameter as last argument.
 		#[pallet::call_index(8)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update())]
 		pub fn update(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -959,7 +1108,7 @@ pub mod pallet {
 		/// - `Archive`: Emitted when a registry is successfully archived. It includes the registry
 		///   ID and the authority who performed the archival.
 		#[pallet::call_index(9)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::archive())]
 		pub fn archive(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -1033,7 +1182,7 @@ pub mod pallet {
 		/// - `Restore`: Emitted when a registry is successfully restored. It includes the registry
 		///   ID and the authority who performed the restoration.
 		#[pallet::call_index(10)]
-		#[pallet::weight({0})]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore())]
 		pub fn restore(
 			origin: OriginFor<T>,
 			registry_id: RegistryIdOf,
@@ -1073,6 +1222,266 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Makes binding new entries to the registry schema mandatory or
+		/// optional.
+		///
+		/// When binding is mandatory, every new entry in the registry must be
+		/// created with the schema identifier of the registry. Binding can only
+		/// be made mandatory for registries that reference a schema.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed by an admin of the
+		///   registry.
+		/// - `registry_id`: The identifier of the registry.
+		/// - `namespace_authorization`: The Namespace authorization ID used to validate the
+		///   caller's permission inside a namespace.
+		/// - `registry_authorization`: The authorization ID used to validate the caller's admin
+		///   permission.
+		/// - `mandatory`: Whether new entries must be bound to the registry schema.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the registry.
+		/// - `RegistryNotFound`: If the registry does not exist.
+		/// - `RegistrySchemaNotSet`: If binding is made mandatory for a registry without a schema.
+		///
+		/// # Events
+		/// - `SchemaBindingSet`: Emitted when the schema binding has been changed.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_schema_binding())]
+		pub fn set_schema_binding(
+			origin: OriginFor<T>,
+			registry_id: RegistryIdOf,
+			namespace_authorization: NamespaceAuthorizationIdOf,
+			registry_authorization: RegistryAuthorizationIdOf,
+			mandatory: bool,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+
+			let _namespace_id = pallet_namespace::Pallet::<T>::ensure_authorization_origin(
+				&namespace_authorization,
+				&creator,
+			)
+			.map_err(<pallet_namespace::Error<T>>::from)?;
+
+			let auth_registry_id =
+				Self::ensure_authorization_admin_origin(&registry_authorization, &creator)?;
+
+			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
+
+			let registry_details =
+				RegistryInfo::<T>::get(&registry_id).ok_or(Error::<T>::RegistryNotFound)?;
+
+			ensure!(
+				!mandatory || registry_details.schema_id.is_some(),
+				Error::<T>::RegistrySchemaNotSet
+			);
+
+			if mandatory {
+				<MandatorySchemaBinding<T>>::insert(&registry_id, true);
+			} else {
+				<MandatorySchemaBinding<T>>::remove(&registry_id);
+			}
+
+			Self::update_activity(&registry_id, IdentifierTypeOf::Registries, CallTypeOf::Update)
+				.map_err(Error::<T>::from)?;
+
+			Self::deposit_event(Event::SchemaBindingSet {
+				registry_id,
+				mandatory,
+				authority: creator,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the constraints of a registry delegation.
+		///
+		/// A delegation can be limited to expire at a block, to create at most
+		/// `max_creates` entries, or to the `CreateOnly` scope, which allows
+		/// creating entries but no other operation. Entries created before the
+		/// constraints changed keep counting towards the quota. Passing no
+		/// expiry, no quota and the `Full` scope removes all constraints.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed by an admin of the
+		///   registry.
+		/// - `registry_id`: The identifier of the registry.
+		/// - `delegate_authorization`: The authorization ID of the delegation to constrain.
+		/// - `expires_at`: The block at which the delegation lapses, if any.
+		/// - `max_creates`: The maximum number of entries the delegation may create, if any.
+		/// - `scope`: The operations the delegation is allowed to perform.
+		/// - `namespace_authorization`: The Namespace authorization ID used to validate the
+		///   caller's permission inside a namespace.
+		/// - `registry_authorization`: The authorization ID used to validate the caller's admin
+		///   permission.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the registry, the
+		///   delegation belongs to another registry or is the caller's own authorization.
+		/// - `AuthorizationNotFound`: If the delegation does not exist.
+		/// - `InvalidDelegationExpiry`: If `expires_at` is not in the future.
+		/// - `TooManyDelegationExpiriesInBlock`: If too many delegations expire at `expires_at`.
+		///
+		/// # Events
+		/// - `DelegationConstraintsSet`: Emitted when the constraints have been changed.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_delegation_constraints())]
+		pub fn set_delegation_constraints(
+			origin: OriginFor<T>,
+			registry_id: RegistryIdOf,
+			delegate_authorization: RegistryAuthorizationIdOf,
+			expires_at: Option<BlockNumberFor<T>>,
+			max_creates: Option<u32>,
+			scope: DelegationScope,
+			namespace_authorization: NamespaceAuthorizationIdOf,
+			registry_authorization: RegistryAuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+
+			let _namespace_id = pallet_namespace::Pallet::<T>::ensure_authorization_origin(
+				&namespace_authorization,
+				&creator,
+			)
+			.map_err(<pallet_namespace::Error<T>>::from)?;
+
+			let auth_registry_id =
+				Self::ensure_authorization_admin_origin(&registry_authorization, &creator)?;
+
+			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
+			ensure!(
+				delegate_authorization != registry_authorization,
+				Error::<T>::UnauthorizedOperation
+			);
+
+			let delegation = <Authorizations<T>>::get(&delegate_authorization)
+				.ok_or(Error::<T>::AuthorizationNotFound)?;
+			ensure!(delegation.registry_id == registry_id, Error::<T>::UnauthorizedOperation);
+
+			if let Some(expiry) = expires_at {
+				ensure!(
+					expiry > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidDelegationExpiry
+				);
+			}
+
+			let previous = AuthorizationConstraints::<T>::get(&delegate_authorization);
+			let constraints = DelegationConstraintsOf::<T> {
+				expires_at,
+				max_creates,
+				creates: previous.as_ref().map_or(0, |c| c.creates),
+				scope,
+			};
+
+			let previous_expiry = previous.and_then(|c| c.expires_at);
+			if previous_expiry != expires_at {
+				if let Some(expiry) = previous_expiry {
+					Self::unschedule_delegation_expiry(&delegate_authorization, expiry);
+				}
+				if let Some(expiry) = expires_at {
+					Self::schedule_delegation_expiry(&delegate_authorization, expiry)?;
+				}
+			}
+
+			if constraints.is_unconstrained() {
+				AuthorizationConstraints::<T>::remove(&delegate_authorization);
+			} else {
+				AuthorizationConstraints::<T>::insert(&delegate_authorization, constraints);
+			}
+
+			Self::update_activity(
+				&registry_id,
+				IdentifierTypeOf::RegistryAuthorization,
+				CallTypeOf::Authorization,
+			)?;
+
+			Self::deposit_event(Event::DelegationConstraintsSet {
+				registry_id,
+				authorization: delegate_authorization,
+				expires_at,
+				max_creates,
+				scope,
+				authority: creator,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the maximum number of transactions a delegate may make in a
+		/// registry.
+		///
+		/// Every transaction authorized through one of the delegate's
+		/// authorizations counts towards the cap, including the transactions
+		/// made before the cap was set. Passing no cap removes the limit.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed by an admin of the
+		///   registry.
+		/// - `registry_id`: The identifier of the registry.
+		/// - `delegate_authorization`: The authorization ID of the delegation to cap.
+		/// - `txn_cap`: The maximum number of transactions allowed to the delegate, if any.
+		/// - `namespace_authorization`: The Namespace authorization ID used to validate the
+		///   caller's permission inside a namespace.
+		/// - `registry_authorization`: The authorization ID used to validate the caller's admin
+		///   permission.
+		///
+		/// # Errors
+		/// - `UnauthorizedOperation`: If the caller is not an admin of the registry, the
+		///   delegation belongs to another registry or is the caller's own authorization.
+		/// - `AuthorizationNotFound`: If the delegation does not exist.
+		///
+		/// # Events
+		/// - `DelegateUsageCapSet`: Emitted when the cap has been changed.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_delegate_usage_cap())]
+		pub fn set_delegate_usage_cap(
+			origin: OriginFor<T>,
+			registry_id: RegistryIdOf,
+			delegate_authorization: RegistryAuthorizationIdOf,
+			txn_cap: Option<u64>,
+			namespace_authorization: NamespaceAuthorizationIdOf,
+			registry_authorization: RegistryAuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = ensure_signed(origin)?;
+
+			let _namespace_id = pallet_namespace::Pallet::<T>::ensure_authorization_origin(
+				&namespace_authorization,
+				&creator,
+			)
+			.map_err(<pallet_namespace::Error<T>>::from)?;
+
+			let auth_registry_id =
+				Self::ensure_authorization_admin_origin(&registry_authorization, &creator)?;
+
+			ensure!(auth_registry_id == registry_id, Error::<T>::UnauthorizedOperation);
+			ensure!(
+				delegate_authorization != registry_authorization,
+				Error::<T>::UnauthorizedOperation
+			);
+
+			let delegation = <Authorizations<T>>::get(&delegate_authorization)
+				.ok_or(Error::<T>::AuthorizationNotFound)?;
+			ensure!(delegation.registry_id == registry_id, Error::<T>::UnauthorizedOperation);
+
+			DelegateUsages::<T>::mutate(&registry_id, &delegation.delegate, |usage| {
+				usage.txn_cap = txn_cap
+			});
+
+			Self::update_activity(
+				&registry_id,
+				IdentifierTypeOf::RegistryAuthorization,
+				CallTypeOf::Authorization,
+			)?;
+
+			Self::deposit_event(Event::DelegateUsageCapSet {
+				registry_id,
+				delegate: delegation.delegate,
+				txn_cap,
+				authority: creator,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -1139,6 +1548,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Ensures that a schema is anchored and has not been deprecated.
+	pub fn ensure_schema_usable(schema_id: &SchemaIdOf) -> Result<(), Error<T>> {
+		let schema = T::SchemaProvider::schema_info(schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+		ensure!(!schema.deprecated, Error::<T>::SchemaDeprecated);
+		Ok(())
+	}
+
+	/// Checks the schema an entry is created under against its registry.
+	///
+	/// An entry naming a schema must use the registry schema, if the registry
+	/// has one, and the schema must be usable. An entry without a schema is
+	/// rejected when the registry makes schema binding mandatory.
+	pub fn ensure_schema_conformance(
+		registry_id: &RegistryIdOf,
+		schema_id: Option<&SchemaIdOf>,
+	) -> Result<(), Error<T>> {
+		let registry_details =
+			RegistryInfo::<T>::get(registry_id).ok_or(Error::<T>::RegistryNotFound)?;
+
+		match schema_id {
+			Some(schema_id) => {
+				if let Some(ref registry_schema_id) = registry_details.schema_id {
+					ensure!(schema_id == registry_schema_id, Error::<T>::SchemaMismatch);
+				}
+				Self::ensure_schema_usable(schema_id)
+			},
+			None => {
+				ensure!(
+					!<MandatorySchemaBinding<T>>::get(registry_id),
+					Error::<T>::SchemaBindingRequired
+				);
+				Ok(())
+			},
+		}
+	}
+
 	/// Checks if a given entity is a delegate for the specified registry.
 	///
 	/// This function retrieves the list of delegates for a registry and determines
@@ -1161,6 +1606,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
@@ -1168,6 +1615,44 @@ impl<T: Config> Pallet<T> {
 		Ok(d.registry_id)
 	}
 
+	/// Verifies that a delegate may create `count` new entries in a registry.
+	///
+	/// This function performs the same checks as `ensure_authorization_origin`,
+	/// but also accepts `CreateOnly` delegations and checks that `count`
+	/// entries fit in the quota of the delegation and the usage cap of the
+	/// delegate. Nothing is counted here; callers record the entries they
+	/// actually created with `record_delegated_creates`.
+	pub fn ensure_authorization_create_origin(
+		authorization_id: &RegistryAuthorizationIdOf,
+		delegate: &RegistryCreatorOf<T>,
+		count: u32,
+	) -> Result<RegistryIdOf, Error<T>> {
+		let d =
+			<Authorizations<T>>::get(authorization_id).ok_or(Error::<T>::AuthorizationNotFound)?;
+
+		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
+
+		let constraints =
+			Self::ensure_delegation_permits(authorization_id, DelegationScope::CreateOnly)?;
+
+		Self::validate_registry_for_transaction(&d.registry_id)?;
+
+		ensure!(d.permissions.contains(Permissions::ASSERT), Error::<T>::UnauthorizedOperation);
+
+		let within_quota = constraints.map_or(true, |c| {
+			c.max_creates.map_or(true, |max_creates| {
+				c.creates.checked_add(count).is_some_and(|creates| creates <= max_creates)
+			})
+		});
+		ensure!(within_quota, Error::<T>::DelegationQuotaExhausted);
+		ensure!(
+			DelegateUsages::<T>::get(&d.registry_id, delegate).has_headroom(count.into()),
+			Error::<T>::DelegateUsageCapExceeded
+		);
+
+		Ok(d.registry_id)
+	}
+
 	/// Verifies if a given delegate has a specific authorization.
 	///
 	/// This function checks if the provided delegate is associated with the
@@ -1182,6 +1667,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_registry_for_reinstate_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1204,6 +1691,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_registry_for_restore_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1226,6 +1715,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1249,6 +1740,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(
@@ -1274,6 +1767,8 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(d.delegate == *delegate, Error::<T>::UnauthorizedOperation);
 
+		Self::ensure_delegation_permits(authorization_id, DelegationScope::Full)?;
+
 		Self::validate_registry_for_transaction(&d.registry_id)?;
 
 		ensure!(d.permissions.contains(Permissions::ADMIN), Error::<T>::UnauthorizedOperation);
@@ -1281,6 +1776,156 @@ impl<T: Config> Pallet<T> {
 		Ok(d.registry_id)
 	}
 
+	/// Counts `count` transactions made by a delegate in a registry.
+	///
+	/// Called by the dispatchables that act on registry content on behalf of
+	/// a delegate. Fails if the transactions exceed the usage cap of the
+	/// delegate in the registry.
+	pub fn record_delegate_usage(
+		registry_id: &RegistryIdOf,
+		delegate: &RegistryCreatorOf<T>,
+		count: u32,
+	) -> Result<(), Error<T>> {
+		DelegateUsages::<T>::try_mutate(registry_id, delegate, |usage| {
+			ensure!(usage.has_headroom(count.into()), Error::<T>::DelegateUsageCapExceeded);
+			usage.txn_count = usage.txn_count.saturating_add(count.into());
+			Ok(())
+		})
+	}
+
+	/// Returns the usage of a registry by each of its delegates.
+	pub fn delegate_usage(
+		registry_id: &RegistryIdOf,
+	) -> Vec<(RegistryCreatorOf<T>, DelegateUsage)> {
+		DelegateUsages::<T>::iter_prefix(registry_id).collect()
+	}
+
+	/// Ensures that a delegation has not lapsed and is scoped for an operation.
+	///
+	/// Delegations without constraints always pass. The constraints, if any,
+	/// are returned so that callers can account for created entries.
+	pub fn ensure_delegation_permits(
+		authorization_id: &RegistryAuthorizationIdOf,
+		scope: DelegationScope,
+	) -> Result<Option<DelegationConstraintsOf<T>>, Error<T>> {
+		let Some(constraints) = AuthorizationConstraints::<T>::get(authorization_id) else {
+			return Ok(None);
+		};
+
+		ensure!(
+			!constraints.is_expired(&frame_system::Pallet::<T>::block_number()),
+			Error::<T>::DelegationExpired
+		);
+		ensure!(
+			scope == DelegationScope::CreateOnly || constraints.scope == DelegationScope::Full,
+			Error::<T>::DelegationScopeExceeded
+		);
+
+		Ok(Some(constraints))
+	}
+
+	/// Counts `count` entries created by a delegate towards its usage of the
+	/// registry and towards the quota of its delegation.
+	///
+	/// Emits `DelegationLapsed` once the quota has been used up.
+	pub fn record_delegated_creates(
+		authorization_id: &RegistryAuthorizationIdOf,
+		registry_id: &RegistryIdOf,
+		delegate: &RegistryCreatorOf<T>,
+		count: u32,
+	) -> Result<(), Error<T>> {
+		Self::record_delegate_usage(registry_id, delegate, count)?;
+
+		let Some(mut constraints) = AuthorizationConstraints::<T>::get(authorization_id) else {
+			return Ok(());
+		};
+		let Some(max_creates) = constraints.max_creates else {
+			return Ok(());
+		};
+
+		let creates = constraints
+			.creates
+			.checked_add(count)
+			.filter(|creates| *creates <= max_creates)
+			.ok_or(Error::<T>::DelegationQuotaExhausted)?;
+		constraints.creates = creates;
+		AuthorizationConstraints::<T>::insert(authorization_id, constraints);
+
+		if creates == max_creates {
+			Self::deposit_event(Event::DelegationLapsed {
+				registry_id: registry_id.clone(),
+				authorization: authorization_id.clone(),
+				reason: DelegationLapse::QuotaExhausted,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Removes the constraints of a delegation along with its scheduled expiry.
+	fn clear_delegation_constraints(authorization_id: &RegistryAuthorizationIdOf) {
+		if let Some(expiry) =
+			AuthorizationConstraints::<T>::take(authorization_id).and_then(|c| c.expires_at)
+		{
+			Self::unschedule_delegation_expiry(authorization_id, expiry);
+		}
+	}
+
+	/// Schedules a delegation to lapse at block `expires_at`.
+	fn schedule_delegation_expiry(
+		authorization_id: &RegistryAuthorizationIdOf,
+		expires_at: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		schedule::schedule::<NextDelegationExpirySweep<T>, DelegationExpiries<T>, _, _, _>(
+			expires_at,
+			authorization_id.clone(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+		.map_err(|_| Error::<T>::TooManyDelegationExpiriesInBlock)
+	}
+
+	/// Removes a delegation from the expiries scheduled at block `expires_at`.
+	fn unschedule_delegation_expiry(
+		authorization_id: &RegistryAuthorizationIdOf,
+		expires_at: BlockNumberFor<T>,
+	) {
+		schedule::unschedule::<DelegationExpiries<T>, _, _, _>(expires_at, authorization_id);
+	}
+
+	/// Reports the delegations scheduled to lapse up to and including block
+	/// `now`, as far as `remaining_weight` allows.
+	pub(crate) fn sweep_delegation_expiries(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		let db_weight = T::DbWeight::get();
+		schedule::sweep::<NextDelegationExpirySweep<T>, DelegationExpiries<T>, _, _, _>(
+			now,
+			remaining_weight,
+			db_weight,
+			db_weight.reads(2),
+			|authorization_id, at| Self::lapse_delegation(&authorization_id, at),
+		)
+	}
+
+	/// Reports a delegation as lapsed if it is still scheduled to expire at
+	/// block `at`.
+	fn lapse_delegation(authorization_id: &RegistryAuthorizationIdOf, at: BlockNumberFor<T>) {
+		let scheduled = AuthorizationConstraints::<T>::get(authorization_id)
+			.is_some_and(|constraints| constraints.expires_at == Some(at));
+		if !scheduled {
+			return;
+		}
+
+		if let Some(d) = <Authorizations<T>>::get(authorization_id) {
+			Self::deposit_event(Event::DelegationLapsed {
+				registry_id: d.registry_id,
+				authorization: authorization_id.clone(),
+				reason: DelegationLapse::Expired,
+			});
+		}
+	}
+
 	/// Validates that a registry is eligible for a new transaction.
 	///
 	/// This function ensures that a registry is not archived, is not revoked.
@@ -80,12 +80,17 @@ parameter_types! {
 	pub const MaxNameSpaceBlobSize: u32 = 4u32 * 1024;
 }
 
+parameter_types! {
+	pub const MaxNameSpaceDelegationExpiriesPerBlock: u32 = 2;
+}
+
 impl pallet_namespace::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
 	type MaxNameSpaceDelegates = MaxNameSpaceDelegates;
 	type MaxNameSpaceBlobSize = MaxNameSpaceBlobSize;
+	type MaxDelegationExpiriesPerBlock = MaxNameSpaceDelegationExpiriesPerBlock;
 	type WeightInfo = ();
 }
 
@@ -105,20 +110,31 @@ parameter_types! {
 	pub const MaxEncodedInputLength: u32 = 30;
 }
 
+parameter_types! {
+	pub const MaxRegistryDelegationExpiriesPerBlock: u32 = 2;
+}
+
 impl pallet_registries::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxRegistryDelegates = MaxRegistryDelegates;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
 	type MaxRegistryBlobSize = MaxRegistryBlobSize;
+	type SchemaProvider = SchemaAccounts;
+	type MaxDelegationExpiriesPerBlock = MaxRegistryDelegationExpiriesPerBlock;
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MaxEventsHistory: u32 = 6u32;
+	pub const TimelineOverflow: identifier::TimelineOverflowMode =
+		identifier::TimelineOverflowMode::Drop;
+	pub const MaxArchivedEventsPerPage: u32 = 10u32;
 }
 
 impl identifier::Config for Test {
 	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
 }
 
 #[allow(dead_code)]
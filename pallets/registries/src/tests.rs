@@ -1,7 +1,7 @@
 use super::*;
 use crate::mock::*;
 use codec::Encode;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, traits::Hooks};
 use pallet_namespace::{NameSpaceCodeOf, NameSpaceIdOf};
 use pallet_schema_accounts::{InputSchemaOf, SchemaHashOf};
 use sp_runtime::traits::Hash;
@@ -95,6 +95,11 @@ fn add_delegate_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -168,6 +173,11 @@ fn add_admin_delegate_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -241,6 +251,11 @@ fn add_admin_delegate_should_fail_if_admin_delegate_already_exists() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -325,6 +340,11 @@ fn add_delegator_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -397,6 +417,11 @@ fn add_delegator_should_fail_if_delegator_already_exists() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -648,6 +673,11 @@ fn add_delegate_should_fail_if_the_regisrty_is_revoked() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -744,6 +774,11 @@ fn add_delegate_should_fail_if_a_non_delegate_tries_to_add() {
 			namespace_authorization_id.clone(),
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -819,6 +854,11 @@ fn add_delegate_should_fail_if_delegate_already_exists() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -888,6 +928,11 @@ fn creating_a_new_registries_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -938,6 +983,11 @@ fn creating_a_duplicate_registries_should_fail() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1012,6 +1062,11 @@ fn revoking_a_registry_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1083,6 +1138,11 @@ fn reinstating_an_revoked_a_registry_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1168,6 +1228,11 @@ fn reinstating_an_non_revoked_a_registry_should_fail() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1240,6 +1305,11 @@ fn archiving_a_registry_should_succeed() {
 			namespace_digest,
 			None,
 		));
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1310,6 +1380,11 @@ fn restoring_an_archived_a_registry_should_succeed() {
 			namespace_digest,
 			None,
 		));
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1395,6 +1470,11 @@ fn restoring_an_non_archived_a_registry_should_fail() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1462,6 +1542,11 @@ fn registry_delegation_should_fail_if_registry_delegates_limit_exceeded() {
 		));
 
 		// Create the Registries
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1555,6 +1640,11 @@ fn remove_delegate_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1635,6 +1725,11 @@ fn remove_delegate_should_fail_for_creator_removing_themselves() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1723,6 +1818,11 @@ fn update_registry_should_succeed() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1813,6 +1913,11 @@ fn add_delegate_should_fail_if_registry_delegates_limit_exceeded() {
 		));
 
 		// Create the Registries
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -1907,6 +2012,11 @@ fn add_admin_delegate_should_fail_if_registry_delegates_limit_exceeded() {
 		));
 
 		// Create the Registries
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2001,6 +2111,11 @@ fn add_delegator_should_fail_if_registry_delegates_limit_exceeded() {
 		));
 
 		// Create the Registries
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2097,6 +2212,11 @@ fn registry_id_should_be_updated_on_namespace_chainstorage_on_create() {
 		));
 
 		// Create Registry 1
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2218,6 +2338,11 @@ fn remove_delegate_should_fail_if_admin_authorization_is_not_found() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2307,6 +2432,11 @@ fn remove_delegate_should_fail_if_remove_authorization_is_not_found_as_delegate_
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2388,6 +2518,11 @@ fn remove_delegate_should_fail_if_remove_authorization_is_not_found_as_non_exist
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2468,6 +2603,11 @@ fn revoke_should_fail_if_admin_authorization_is_not_found() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2546,6 +2686,11 @@ fn reinstate_should_fail_if_admin_authorization_is_not_found() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2631,6 +2776,11 @@ fn update_should_fail_if_admin_authorization_is_not_found() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2704,6 +2854,11 @@ fn archive_should_fail_if_admin_authorization_is_not_found() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2782,6 +2937,11 @@ fn restore_should_fail_if_admin_authorization_is_not_found() {
 			None,
 		));
 
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+
 		assert_ok!(Registries::create(
 			frame_system::RawOrigin::Signed(creator.clone()).into(),
 			registry_digest,
@@ -2991,3 +3151,534 @@ fn ensure_authorization_admin_remove_origin_should_fail_if_authorization_is_not_
 		);
 	});
 }
+
+#[test]
+fn schema_binding_and_conformance_should_work() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	let raw_schema = [2u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(&schema.encode()[..]);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let other_schema: InputSchemaOf<Test> = BoundedVec::try_from([3u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let other_schema_id_digest =
+		<Test as frame_system::Config>::Hashing::hash(&other_schema.encode()[..]);
+	let other_schema_id: SchemaIdOf = generate_schema_id::<Test>(&other_schema_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		// A registry cannot reference a schema that has not been anchored.
+		assert_err!(
+			Registries::create(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_digest,
+				namespace_authorization_id.clone(),
+				Some(schema_id.clone()),
+				None
+			),
+			Error::<Test>::SchemaNotFound
+		);
+
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			schema.clone()
+		));
+		assert_ok!(SchemaAccounts::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			other_schema.clone()
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			Some(schema_id.clone()),
+			None
+		));
+
+		// Binding is optional until the registry admin makes it mandatory.
+		assert_ok!(Registries::ensure_schema_conformance(&registry_id, None));
+		assert_ok!(Registries::ensure_schema_conformance(&registry_id, Some(&schema_id)));
+		assert_err!(
+			Registries::ensure_schema_conformance(&registry_id, Some(&other_schema_id)),
+			Error::<Test>::SchemaMismatch
+		);
+
+		assert_ok!(Registries::set_schema_binding(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+			true
+		));
+		assert!(MandatorySchemaBinding::<Test>::get(&registry_id));
+		assert_err!(
+			Registries::ensure_schema_conformance(&registry_id, None),
+			Error::<Test>::SchemaBindingRequired
+		);
+		assert_ok!(Registries::ensure_schema_conformance(&registry_id, Some(&schema_id)));
+
+		assert_ok!(Registries::set_schema_binding(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+			false
+		));
+		assert!(!MandatorySchemaBinding::<Test>::contains_key(&registry_id));
+		assert_ok!(Registries::ensure_schema_conformance(&registry_id, None));
+	});
+}
+
+#[test]
+fn set_schema_binding_should_fail_without_registry_schema() {
+	let creator = ACCOUNT_00;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None
+		));
+
+		assert_err!(
+			Registries::set_schema_binding(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				namespace_authorization_id.clone(),
+				authorization_id.clone(),
+				true
+			),
+			Error::<Test>::RegistrySchemaNotSet
+		);
+	});
+}
+
+#[test]
+fn delegation_constraints_should_limit_expiry_quota_and_scope() {
+	let creator = ACCOUNT_00;
+	let delegate = ACCOUNT_01;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None
+		));
+
+		assert_ok!(Registries::add_delegate(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate.clone(),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+
+		/* Expiry must lie in the future */
+		assert_err!(
+			Registries::set_delegation_constraints(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				delegate_authorization_id.clone(),
+				Some(1),
+				None,
+				DelegationScope::Full,
+				namespace_authorization_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::InvalidDelegationExpiry
+		);
+
+		/* Admins cannot constrain their own authorization */
+		assert_err!(
+			Registries::set_delegation_constraints(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				authorization_id.clone(),
+				None,
+				Some(1),
+				DelegationScope::Full,
+				namespace_authorization_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+
+		assert_ok!(Registries::set_delegation_constraints(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(10),
+			Some(2),
+			DelegationScope::CreateOnly,
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(DelegationExpiries::<Test>::get(10).contains(&delegate_authorization_id));
+
+		/* Create-only delegations cannot perform other operations */
+		assert_err!(
+			Registries::ensure_authorization_origin(&delegate_authorization_id, &delegate),
+			Error::<Test>::DelegationScopeExceeded
+		);
+
+		/* Creations count towards the quota */
+		assert_ok!(Registries::ensure_authorization_create_origin(
+			&delegate_authorization_id,
+			&delegate,
+			1
+		));
+		assert_ok!(Registries::record_delegated_creates(
+			&delegate_authorization_id,
+			&registry_id,
+			&delegate,
+			1
+		));
+		assert_err!(
+			Registries::ensure_authorization_create_origin(
+				&delegate_authorization_id,
+				&delegate,
+				2
+			),
+			Error::<Test>::DelegationQuotaExhausted
+		);
+		assert_ok!(Registries::ensure_authorization_create_origin(
+			&delegate_authorization_id,
+			&delegate,
+			1
+		));
+		assert_ok!(Registries::record_delegated_creates(
+			&delegate_authorization_id,
+			&registry_id,
+			&delegate,
+			1
+		));
+		System::assert_last_event(
+			Event::DelegationLapsed {
+				registry_id: registry_id.clone(),
+				authorization: delegate_authorization_id.clone(),
+				reason: DelegationLapse::QuotaExhausted,
+			}
+			.into(),
+		);
+		assert_err!(
+			Registries::ensure_authorization_create_origin(
+				&delegate_authorization_id,
+				&delegate,
+				1
+			),
+			Error::<Test>::DelegationQuotaExhausted
+		);
+
+		/* Lifting the quota keeps the expiry and the creations made so far */
+		assert_ok!(Registries::set_delegation_constraints(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(10),
+			None,
+			DelegationScope::Full,
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+		assert_eq!(
+			AuthorizationConstraints::<Test>::get(&delegate_authorization_id).map(|c| c.creates),
+			Some(2)
+		);
+		assert_ok!(Registries::ensure_authorization_origin(&delegate_authorization_id, &delegate));
+
+		/* The delegation lapses at its expiry block */
+		System::set_block_number(10);
+		assert_err!(
+			Registries::ensure_authorization_origin(&delegate_authorization_id, &delegate),
+			Error::<Test>::DelegationExpired
+		);
+
+		Registries::on_idle(10, Weight::MAX);
+
+		System::assert_last_event(
+			Event::DelegationLapsed {
+				registry_id: registry_id.clone(),
+				authorization: delegate_authorization_id.clone(),
+				reason: DelegationLapse::Expired,
+			}
+			.into(),
+		);
+		assert!(DelegationExpiries::<Test>::get(10).is_empty());
+		assert_eq!(NextDelegationExpirySweep::<Test>::get(), Some(11));
+
+		/* Removing the delegate clears its constraints */
+		assert_ok!(Registries::remove_delegate(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id.clone(),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+		assert!(AuthorizationConstraints::<Test>::get(&delegate_authorization_id).is_none());
+	});
+}
+
+#[test]
+fn delegate_usage_should_be_tracked_and_capped() {
+	let creator = ACCOUNT_00;
+	let delegate = ACCOUNT_01;
+
+	let namespace = [2u8; 256].to_vec();
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(&namespace.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+
+	let namespace_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_authorization_id: NamespaceAuthorizationIdOf =
+		generate_namespace_authorization_id::<Test>(&namespace_auth_id_digest);
+
+	let registry = [2u8; 256].to_vec();
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(&registry.encode()[..]);
+
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&id_digest);
+
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&auth_id_digest);
+
+	let delegate_auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let delegate_authorization_id: RegistryAuthorizationIdOf =
+		generate_authorization_id::<Test>(&delegate_auth_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(NameSpace::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			namespace_digest,
+			None,
+		));
+
+		assert_ok!(Registries::create(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_digest,
+			namespace_authorization_id.clone(),
+			None,
+			None
+		));
+
+		assert_ok!(Registries::add_delegate(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate.clone(),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+
+		/* Usage made before the cap is set counts towards it */
+		assert_ok!(Registries::record_delegate_usage(&registry_id, &delegate, 1));
+		assert_ok!(Registries::set_delegate_usage_cap(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id.clone(),
+			Some(2),
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+		System::assert_last_event(
+			Event::DelegateUsageCapSet {
+				registry_id: registry_id.clone(),
+				delegate: delegate.clone(),
+				txn_cap: Some(2),
+				authority: creator.clone(),
+			}
+			.into(),
+		);
+
+		/* Batches are counted in full */
+		assert_err!(
+			Registries::ensure_authorization_create_origin(
+				&delegate_authorization_id,
+				&delegate,
+				2
+			),
+			Error::<Test>::DelegateUsageCapExceeded
+		);
+		assert_err!(
+			Registries::record_delegated_creates(
+				&delegate_authorization_id,
+				&registry_id,
+				&delegate,
+				2
+			),
+			Error::<Test>::DelegateUsageCapExceeded
+		);
+
+		assert_ok!(Registries::ensure_authorization_create_origin(
+			&delegate_authorization_id,
+			&delegate,
+			1
+		));
+		assert_ok!(Registries::record_delegated_creates(
+			&delegate_authorization_id,
+			&registry_id,
+			&delegate,
+			1
+		));
+		assert_err!(
+			Registries::record_delegate_usage(&registry_id, &delegate, 1),
+			Error::<Test>::DelegateUsageCapExceeded
+		);
+
+		/* Admin operations are not counted */
+		assert_eq!(
+			Registries::delegate_usage(&registry_id),
+			vec![(delegate.clone(), DelegateUsage { txn_count: 2, txn_cap: Some(2) })]
+		);
+
+		/* Lifting the cap keeps the usage made so far */
+		assert_ok!(Registries::set_delegate_usage_cap(
+			frame_system::RawOrigin::Signed(creator.clone()).into(),
+			registry_id.clone(),
+			delegate_authorization_id.clone(),
+			None,
+			namespace_authorization_id.clone(),
+			authorization_id.clone(),
+		));
+		assert_ok!(Registries::record_delegate_usage(&registry_id, &delegate, 1));
+		assert_eq!(DelegateUsages::<Test>::get(&registry_id, &delegate).txn_count, 3);
+
+		/* Admins cannot cap their own authorization */
+		assert_err!(
+			Registries::set_delegate_usage_cap(
+				frame_system::RawOrigin::Signed(creator.clone()).into(),
+				registry_id.clone(),
+				authorization_id.clone(),
+				Some(1),
+				namespace_authorization_id.clone(),
+				authorization_id.clone(),
+			),
+			Error::<Test>::UnauthorizedOperation
+		);
+	});
+}
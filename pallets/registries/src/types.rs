@@ -103,3 +103,77 @@ pub struct RegistryAuthorization<RegistryIdOf, RegistryCreatorOf, Permissions> {
 	pub permissions: Permissions,
 	pub delegator: RegistryCreatorOf,
 }
+
+/// Operations a delegation may be used for.
+///
+/// - `Full`: The delegate may use every operation its permissions grant.
+/// - `CreateOnly`: The delegate may only create new entries; updates, revocations and other
+///   changes are refused.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo,
+)]
+pub enum DelegationScope {
+	#[default]
+	Full,
+	CreateOnly,
+}
+
+/// Reason a delegation stopped granting access.
+#[derive(Encode, Decode, Clone, Copy, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum DelegationLapse {
+	/// The delegation reached its expiry block.
+	Expired,
+	/// The delegate created the maximum number of entries allowed.
+	QuotaExhausted,
+}
+
+/// Limits placed on a registry delegation in addition to its permissions.
+///
+/// ## Fields
+///
+/// - `expires_at`: (Optional) The block from which the delegation no longer grants access.
+/// - `max_creates`: (Optional) The maximum number of entries the delegate may create.
+/// - `creates`: The number of entries created against `max_creates` so far.
+/// - `scope`: The operations the delegation may be used for.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegationConstraints<BlockNumber> {
+	pub expires_at: Option<BlockNumber>,
+	pub max_creates: Option<u32>,
+	pub creates: u32,
+	pub scope: DelegationScope,
+}
+
+impl<BlockNumber: PartialOrd> DelegationConstraints<BlockNumber> {
+	/// Returns `true` if the delegation has expired at block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().is_some_and(|expires_at| now >= expires_at)
+	}
+
+	/// Returns `true` if no limits are placed on the delegation.
+	pub fn is_unconstrained(&self) -> bool {
+		self.expires_at.is_none() &&
+			self.max_creates.is_none() &&
+			self.scope == DelegationScope::Full
+	}
+}
+
+/// Usage of a registry by one of its delegates.
+///
+/// ## Fields
+///
+/// - `txn_count`: The number of transactions the delegate made in the registry.
+/// - `txn_cap`: (Optional) The maximum number of transactions the delegate may make in the
+///   registry.
+#[derive(Encode, Decode, Clone, Default, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct DelegateUsage {
+	pub txn_count: u64,
+	pub txn_cap: Option<u64>,
+}
+
+impl DelegateUsage {
+	/// Returns `true` if the delegate may make `count` more transactions.
+	pub fn has_headroom(&self, count: u64) -> bool {
+		self.txn_cap
+			.map_or(true, |txn_cap| self.txn_count.saturating_add(count) <= txn_cap)
+	}
+}
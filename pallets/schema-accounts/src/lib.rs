@@ -58,6 +58,7 @@ use sp_runtime::traits::UniqueSaturatedInto;
 pub mod types;
 
 pub use crate::types::*;
+use cord_primitives::{SchemaInfo, SchemaLookup};
 use frame_support::ensure;
 
 #[frame_support::pallet]
@@ -255,3 +256,11 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+impl<T: Config, SpaceId> SchemaLookup<SchemaIdOf, SpaceId> for Pallet<T> {
+	/// Account schemas are not scoped to a space and cannot be deprecated.
+	fn schema_info(schema_id: &SchemaIdOf) -> Option<SchemaInfo<SpaceId>> {
+		<Schemas<T>>::contains_key(schema_id)
+			.then_some(SchemaInfo { space: None, deprecated: false })
+	}
+}
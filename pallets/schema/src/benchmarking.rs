@@ -24,12 +24,23 @@ use cord_utilities::traits::GenerateBenchmarkOrigin;
 use frame_benchmarking::{account, benchmarks};
 use frame_support::{sp_runtime::traits::Hash, traits::Get, BoundedVec};
 use frame_system::RawOrigin;
-use sp_std::{
-	convert::{TryFrom, TryInto},
-	vec::Vec,
-};
+use sp_std::{convert::TryFrom, vec::Vec};
 
 const SEED: u32 = 0;
+/// Length of the smallest document produced by `json_schema`.
+const MIN_SCHEMA_LENGTH: u32 = 64;
+
+/// Builds a JSON Schema document of exactly `len` bytes, padded through its
+/// `description`. `seed` keeps documents of the same length distinct.
+fn json_schema(len: u32, seed: u8) -> Vec<u8> {
+	let mut document = b"{\"$id\":\"s".to_vec();
+	document.push(b'0' + seed);
+	document.extend_from_slice(b"\",\"properties\":{},\"description\":\"");
+	let padding = (len as usize).saturating_sub(document.len() + 2);
+	document.extend(sp_std::iter::repeat(b'a').take(padding));
+	document.extend_from_slice(b"\"}");
+	document
+}
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
@@ -57,7 +68,7 @@ benchmarks! {
 		// T::ChainSpaceOrigin: EnsureOrigin<T::RuntimeOrigin>,
 	}
 	create {
-		let l in 1 .. T::MaxEncodedSchemaLength::get();
+		let l in MIN_SCHEMA_LENGTH .. T::MaxEncodedSchemaLength::get();
 
 		let caller: T::AccountId = account("caller", 0, SEED);
 		let did: T::SchemaCreatorId = account("did", 0, SEED);
@@ -71,7 +82,7 @@ benchmarks! {
 		);
 		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
 
-		let raw_schema: Vec<u8> = (0u8..u8::MAX).cycle().take(l.try_into().unwrap()).collect();
+		let raw_schema: Vec<u8> = json_schema(l, 0);
 		let schema = BoundedVec::try_from(raw_schema)
 		.expect("Test Schema should fit into the expected input length of the test runtime.");
 		let digest = <T as frame_system::Config>::Hashing::hash(&schema[..]);
@@ -96,6 +107,93 @@ benchmarks! {
 	verify {
 		assert_last_event::<T>(Event::<T>::Created { identifier: schema_id, creator: did1 }.into());
 	}
+	update {
+		let l in MIN_SCHEMA_LENGTH .. T::MaxEncodedSchemaLength::get();
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let previous_schema = BoundedVec::try_from(json_schema(MIN_SCHEMA_LENGTH, 1))
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let previous_schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&previous_schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let previous_schema_id: SchemaIdOf = generate_schema_id::<T>(&previous_schema_id_digest);
+
+		let schema = BoundedVec::try_from(json_schema(l, 0))
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		Pallet::<T>::create(origin.clone(), previous_schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, previous_schema_id.clone(), schema, authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Updated { identifier: schema_id, previous: previous_schema_id, creator: did1 }.into());
+	}
+
+	deprecate {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SchemaCreatorId = account("did", 0, SEED);
+		let did1: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [2u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let schema = BoundedVec::try_from(json_schema(MIN_SCHEMA_LENGTH, 0))
+		.expect("Test Schema should fit into the expected input length of the test runtime.");
+		let schema_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&schema.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let schema_id: SchemaIdOf = generate_schema_id::<T>(&schema_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		Pallet::<T>::create(origin.clone(), schema, authorization_id.clone())?;
+
+	}: _<T::RuntimeOrigin>(origin, schema_id.clone(), authorization_id)
+	verify {
+		assert_last_event::<T>(Event::<T>::Deprecated { identifier: schema_id, creator: did1 }.into());
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
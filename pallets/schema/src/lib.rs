@@ -26,6 +26,12 @@
 //! - [`Call`]
 //! - [`Pallet`]
 //!
+//! ### Dispatchable Functions
+//!
+//! - `create` - Anchors a new schema.
+//! - `update` - Anchors a new version of a schema, superseding the previous one.
+//! - `deprecate` - Marks a schema as deprecated.
+//!
 //! ### Terminology
 //!
 //! - **Schema:**: Schemas are templates used to guarantee the structure, and by extension the
@@ -33,6 +39,18 @@
 //!   allows all parties to reference data in a known way. An identifier can optionally link to a
 //!   valid schema identifier.
 //!
+//! ## Versioning
+//!
+//! Every schema belongs to a version chain. `update` anchors the new content under a fresh
+//! identifier that points back to the schema it supersedes, and only the latest version of a
+//! chain can be updated. The complete chain can be walked from any of its members with
+//! `version_history`.
+//!
+//! ## Content Validation
+//!
+//! When `ValidateSchemaContent` is enabled, schema content must be a well-formed JSON object
+//! carrying the `$id` and `properties` keys of a JSON Schema document.
+//!
 //! ## Assumptions
 //!
 //! - The Schema hash was created using CORD SDK.
@@ -52,16 +70,19 @@ pub mod benchmarking;
 #[cfg(test)]
 pub mod tests;
 
+use codec::Encode;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_std::vec::Vec;
 
 /// Extra Types for Schema
 pub mod types;
 
 pub use crate::{types::*, weights::WeightInfo};
+use cord_primitives::{SchemaInfo, SchemaLookup};
 use frame_support::ensure;
 
 #[frame_support::pallet]
@@ -93,6 +114,8 @@ pub mod pallet {
 	/// Type for a schema entry
 	pub type SchemaEntryOf<T> =
 		SchemaEntry<InputSchemaOf<T>, SchemaHashOf<T>, SchemaCreatorOf<T>, SpaceIdOf>;
+	/// Type for the version details of a schema
+	pub type SchemaVersionOf = SchemaVersion<SchemaIdOf>;
 
 	#[pallet::config]
 	pub trait Config:
@@ -107,6 +130,9 @@ pub mod pallet {
 		type SchemaCreatorId: Parameter + MaxEncodedLen;
 		#[pallet::constant]
 		type MaxEncodedSchemaLength: Get<u32>;
+		/// Whether schema content must be a structurally valid JSON Schema.
+		#[pallet::constant]
+		type ValidateSchemaContent: Get<bool>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -122,12 +148,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Schemas<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaEntryOf<T>>;
 
+	/// schema version details stored on chain.
+	/// It maps from a schema identifier to its position in the version chain.
+	#[pallet::storage]
+	pub type SchemaVersions<T> = StorageMap<_, Blake2_128Concat, SchemaIdOf, SchemaVersionOf>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new schema has been created.
 		/// \[schema identifier, digest, author\]
 		Created { identifier: SchemaIdOf, creator: SchemaCreatorOf<T> },
+		/// A new version of a schema has been anchored.
+		/// \[schema identifier, previous version identifier, author\]
+		Updated { identifier: SchemaIdOf, previous: SchemaIdOf, creator: SchemaCreatorOf<T> },
+		/// A schema has been deprecated.
+		/// \[schema identifier, author\]
+		Deprecated { identifier: SchemaIdOf, creator: SchemaCreatorOf<T> },
 	}
 
 	#[pallet::error]
@@ -147,6 +184,18 @@ pub mod pallet {
 		MaxEncodedSchemaLimitExceeded,
 		/// Empty transaction.
 		EmptyTransaction,
+		/// Schema content is not a well-formed JSON object.
+		InvalidSchemaContent,
+		/// Schema content does not carry an `$id` key.
+		MissingSchemaIdKey,
+		/// Schema content does not carry a `properties` object.
+		MissingSchemaProperties,
+		/// Schema has been deprecated.
+		SchemaDeprecated,
+		/// Schema has already been superseded by a newer version.
+		SchemaVersionSuperseded,
+		/// Only when the author is not the controller or delegate.
+		UnauthorizedOperation,
 	}
 
 	#[pallet::call]
@@ -176,25 +225,18 @@ pub mod pallet {
 				tx_schema.len() <= T::MaxEncodedSchemaLength::get() as usize,
 				Error::<T>::MaxEncodedSchemaLimitExceeded
 			);
+			if T::ValidateSchemaContent::get() {
+				Self::ensure_valid_content(&tx_schema)?;
+			}
 
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_create_origin(
 				&authorization,
 				&creator,
+				1,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
-			// Id Digest = concat (H(<scale_encoded_schema_input>,
-			// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
-
-			let identifier = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Schema,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+			let identifier = Self::schema_identifier(&tx_schema, &space_id, &creator)?;
 
 			ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
 
@@ -217,16 +259,161 @@ pub mod pallet {
 					schema: tx_schema,
 					digest,
 					creator: creator.clone(),
-					space: space_id,
+					space: space_id.clone(),
 				},
 			);
 
+			pallet_chain_space::Pallet::<T>::record_delegated_creates(
+				&authorization,
+				&space_id,
+				&creator,
+				1,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
 
 			Self::deposit_event(Event::Created { identifier, creator });
 
 			Ok(())
 		}
+
+		/// Anchors a new version of an existing schema.
+		///
+		/// The new content is anchored under a fresh identifier which points
+		/// back to `schema_id`. Only the latest, non-deprecated version of a
+		/// schema can be updated, and the author must hold an authorization
+		/// for the space the schema belongs to.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the updater.
+		/// - `schema_id`: The identifier of the schema being superseded.
+		/// - `tx_schema`: The content of the new schema version.
+		/// - `authorization`: The authorization ID used to validate the update.
+		///
+		/// # Errors
+		/// - `SchemaNotFound` if `schema_id` does not exist.
+		/// - `UnauthorizedOperation` if the authorization is not for the schema's space.
+		/// - `SchemaDeprecated` if the schema has been deprecated.
+		/// - `SchemaVersionSuperseded` if the schema is not the latest version.
+		///
+		/// # Events
+		/// - Emits `Updated` upon success.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::update(tx_schema.len().saturated_into()))]
+		pub fn update(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			tx_schema: InputSchemaOf<T>,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			ensure!(tx_schema.len() > 0, Error::<T>::EmptyTransaction);
+			if T::ValidateSchemaContent::get() {
+				Self::ensure_valid_content(&tx_schema)?;
+			}
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&creator,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let schema = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(schema.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			let mut version = Self::version_of(&schema_id);
+			ensure!(!version.deprecated, Error::<T>::SchemaDeprecated);
+			ensure!(version.next.is_none(), Error::<T>::SchemaVersionSuperseded);
+
+			let identifier = Self::schema_identifier(&tx_schema, &space_id, &creator)?;
+			ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
+
+			let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
+
+			<Schemas<T>>::insert(
+				&identifier,
+				SchemaEntryOf::<T> {
+					schema: tx_schema,
+					digest,
+					creator: creator.clone(),
+					space: space_id.clone(),
+				},
+			);
+			<SchemaVersions<T>>::insert(
+				&identifier,
+				SchemaVersionOf {
+					version: version.version.saturating_add(1),
+					previous: Some(schema_id.clone()),
+					next: None,
+					deprecated: false,
+				},
+			);
+			version.next = Some(identifier.clone());
+			<SchemaVersions<T>>::insert(&schema_id, version);
+
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &creator, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+			Self::update_activity(&schema_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::Updated { identifier, previous: schema_id, creator });
+
+			Ok(())
+		}
+
+		/// Marks a schema as deprecated.
+		///
+		/// A deprecated schema remains readable but can no longer be updated.
+		/// The author must hold an authorization for the space the schema
+		/// belongs to.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the call, which must be signed by the author.
+		/// - `schema_id`: The identifier of the schema to deprecate.
+		/// - `authorization`: The authorization ID used to validate the call.
+		///
+		/// # Errors
+		/// - `SchemaNotFound` if `schema_id` does not exist.
+		/// - `UnauthorizedOperation` if the authorization is not for the schema's space.
+		/// - `SchemaDeprecated` if the schema has already been deprecated.
+		///
+		/// # Events
+		/// - Emits `Deprecated` upon success.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deprecate())]
+		pub fn deprecate(
+			origin: OriginFor<T>,
+			schema_id: SchemaIdOf,
+			authorization: AuthorizationIdOf,
+		) -> DispatchResult {
+			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&creator,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let schema = <Schemas<T>>::get(&schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+			ensure!(schema.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			let mut version = Self::version_of(&schema_id);
+			ensure!(!version.deprecated, Error::<T>::SchemaDeprecated);
+			version.deprecated = true;
+			<SchemaVersions<T>>::insert(&schema_id, version);
+
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &creator, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::update_activity(&schema_id, CallTypeOf::Archive).map_err(<Error<T>>::from)?;
+
+			Self::deposit_event(Event::Deprecated { identifier: schema_id, creator });
+
+			Ok(())
+		}
 	}
 }
 
@@ -246,6 +433,71 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Checks that the schema content is a JSON object carrying the `$id`
+	/// and `properties` keys expected of a JSON Schema document.
+	pub fn ensure_valid_content(tx_schema: &[u8]) -> Result<(), Error<T>> {
+		let document: serde_json::Value =
+			serde_json::from_slice(tx_schema).map_err(|_| Error::<T>::InvalidSchemaContent)?;
+		let document = document.as_object().ok_or(Error::<T>::InvalidSchemaContent)?;
+
+		ensure!(
+			document.get("$id").is_some_and(|id| id.is_string()),
+			Error::<T>::MissingSchemaIdKey
+		);
+		ensure!(
+			document.get("properties").is_some_and(|properties| properties.is_object()),
+			Error::<T>::MissingSchemaProperties
+		);
+
+		Ok(())
+	}
+
+	/// Derives the identifier of a schema from its content, the space it is
+	/// anchored in and its creator.
+	fn schema_identifier(
+		tx_schema: &InputSchemaOf<T>,
+		space_id: &SpaceIdOf,
+		creator: &SchemaCreatorOf<T>,
+	) -> Result<SchemaIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_schema_input>,
+		// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Schema)
+			.map_err(|_| Error::<T>::InvalidIdentifierLength)
+	}
+
+	/// Returns the version details of a schema. Schemas without recorded
+	/// version details are the first version of their chain.
+	pub fn version_of(schema_id: &SchemaIdOf) -> SchemaVersionOf {
+		<SchemaVersions<T>>::get(schema_id).unwrap_or_else(SchemaVersionOf::first)
+	}
+
+	/// Returns the complete version chain `schema_id` belongs to, ordered
+	/// from the first version to the latest one.
+	pub fn version_history(schema_id: &SchemaIdOf) -> Vec<(SchemaIdOf, SchemaVersionOf)> {
+		if !<Schemas<T>>::contains_key(schema_id) {
+			return Vec::new();
+		}
+
+		let mut first = schema_id.clone();
+		while let Some(previous) = Self::version_of(&first).previous {
+			first = previous;
+		}
+
+		let mut history = Vec::new();
+		let mut cursor = Some(first);
+		while let Some(identifier) = cursor {
+			let version = Self::version_of(&identifier);
+			cursor = version.next.clone();
+			history.push((identifier, version));
+		}
+
+		history
+	}
+
 	/// Updates the global timeline with a new activity event for a schema.
 	///
 	/// An `EventEntryOf` struct is created, encapsulating the type of action
@@ -285,3 +537,13 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 }
+
+impl<T: Config> SchemaLookup<SchemaIdOf, SpaceIdOf> for Pallet<T> {
+	fn schema_info(schema_id: &SchemaIdOf) -> Option<SchemaInfo<SpaceIdOf>> {
+		let schema = <Schemas<T>>::get(schema_id)?;
+		Some(SchemaInfo {
+			space: Some(schema.space),
+			deprecated: Self::version_of(schema_id).deprecated,
+		})
+	}
+}
@@ -30,11 +30,13 @@ type Signature = MultiSignature;
 type AccountPublic = <Signature as Verify>::Signer;
 pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
 pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
+pub(crate) type Balance = u128;
 
 frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		Schema: pallet_schema,
 		Space: pallet_chain_space,
 		Identifier: identifier,
@@ -54,6 +56,18 @@ impl frame_system::Config for Test {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type SS58Prefix = SS58Prefix;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
 }
 
 impl mock_origin::Config for Test {
@@ -64,6 +78,7 @@ impl mock_origin::Config for Test {
 
 parameter_types! {
 	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub static ValidateSchemaContent: bool = false;
 }
 
 impl pallet_schema::Config for Test {
@@ -73,6 +88,7 @@ impl pallet_schema::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type ValidateSchemaContent = ValidateSchemaContent;
 }
 
 parameter_types! {
@@ -87,6 +103,12 @@ impl IsPermissioned for NetworkPermission {
 	}
 }
 
+parameter_types! {
+	pub const MaxSpaceDelegationExpiriesPerBlock: u32 = 2;
+	pub const CapacityPrice: Balance = 2;
+	pub const MaxSpaceDepth: u32 = 3;
+}
+
 impl pallet_chain_space::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
@@ -95,15 +117,25 @@ impl pallet_chain_space::Config for Test {
 	type MaxSpaceDelegates = MaxSpaceDelegates;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type MaxDelegationExpiriesPerBlock = MaxSpaceDelegationExpiriesPerBlock;
+	type MaxSpaceDepth = MaxSpaceDepth;
+	type Currency = Balances;
+	type CapacityPrice = CapacityPrice;
+	type CapacityPayment = ();
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MaxEventsHistory: u32 = 6u32;
+	pub const TimelineOverflow: identifier::TimelineOverflowMode =
+		identifier::TimelineOverflowMode::Drop;
+	pub const MaxArchivedEventsPerPage: u32 = 10u32;
 }
 
 impl identifier::Config for Test {
 	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
 }
 
 #[allow(dead_code)]
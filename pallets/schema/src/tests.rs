@@ -346,3 +346,200 @@ fn check_schema_not_found() {
 		assert_err!(Schema::is_valid(&schema_id), Error::<Test>::SchemaNotFound);
 	});
 }
+
+// This test checks that schema content is validated as a JSON Schema document
+// when content validation is enabled.
+#[test]
+fn check_schema_content_validation() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let input = |raw: &[u8]| -> InputSchemaOf<Test> {
+		BoundedVec::try_from(raw.to_vec()).expect(
+			"Test Schema should fit into the expected input length of for the test runtime.",
+		)
+	};
+
+	new_test_ext().execute_with(|| {
+		ValidateSchemaContent::set(true);
+
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_err!(
+			Schema::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				input(&[2u8; 256]),
+				authorization_id.clone()
+			),
+			Error::<Test>::InvalidSchemaContent
+		);
+		assert_err!(
+			Schema::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				input(br#"["$id", "properties"]"#),
+				authorization_id.clone()
+			),
+			Error::<Test>::InvalidSchemaContent
+		);
+		assert_err!(
+			Schema::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				input(br#"{"properties": {}}"#),
+				authorization_id.clone()
+			),
+			Error::<Test>::MissingSchemaIdKey
+		);
+		assert_err!(
+			Schema::create(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				input(br#"{"$id": "schema:cord:test", "properties": []}"#),
+				authorization_id.clone()
+			),
+			Error::<Test>::MissingSchemaProperties
+		);
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			input(br#"{"$id": "schema:cord:test", "properties": {"name": {"type": "string"}}}"#),
+			authorization_id.clone()
+		));
+	});
+}
+
+// This test checks that updating a schema anchors a new version which links
+// back to the previous one, that only the latest version can be updated and
+// that deprecated schemas can no longer be updated.
+#[test]
+fn check_schema_versioning_and_deprecation() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 10u64;
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let schemas: Vec<InputSchemaOf<Test>> = [[2u8; 256], [3u8; 256], [4u8; 256]]
+		.iter()
+		.map(|raw| {
+			BoundedVec::try_from(raw.to_vec()).expect(
+				"Test Schema should fit into the expected input length of for the test runtime.",
+			)
+		})
+		.collect();
+	let schema_ids: Vec<SchemaIdOf> = schemas
+		.iter()
+		.map(|schema| {
+			generate_schema_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(
+				&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+			))
+		})
+		.collect();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schemas[0].clone(),
+			authorization_id.clone()
+		));
+		assert_eq!(Schema::version_of(&schema_ids[0]), SchemaVersionOf::first());
+
+		assert_ok!(Schema::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_ids[0].clone(),
+			schemas[1].clone(),
+			authorization_id.clone()
+		));
+		System::assert_last_event(
+			Event::Updated {
+				identifier: schema_ids[1].clone(),
+				previous: schema_ids[0].clone(),
+				creator: creator.clone(),
+			}
+			.into(),
+		);
+
+		// Only the latest version of a chain can be updated.
+		assert_err!(
+			Schema::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_ids[0].clone(),
+				schemas[2].clone(),
+				authorization_id.clone()
+			),
+			Error::<Test>::SchemaVersionSuperseded
+		);
+
+		assert_ok!(Schema::update(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_ids[1].clone(),
+			schemas[2].clone(),
+			authorization_id.clone()
+		));
+
+		let history = Schema::version_history(&schema_ids[1]);
+		assert_eq!(
+			history.iter().map(|(id, _)| id.clone()).collect::<Vec<_>>(),
+			schema_ids.clone()
+		);
+		assert_eq!(history.iter().map(|(_, v)| v.version).collect::<Vec<_>>(), vec![1, 2, 3]);
+		assert_eq!(history[2].1.previous, Some(schema_ids[1].clone()));
+		assert_eq!(history[2].1.next, None);
+
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_ids[2].clone(),
+			authorization_id.clone()
+		));
+		assert!(Schema::version_of(&schema_ids[2]).deprecated);
+		assert_err!(
+			Schema::deprecate(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_ids[2].clone(),
+				authorization_id.clone()
+			),
+			Error::<Test>::SchemaDeprecated
+		);
+		assert_err!(
+			Schema::update(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				schema_ids[2].clone(),
+				schemas[0].clone(),
+				authorization_id.clone()
+			),
+			Error::<Test>::SchemaDeprecated
+		);
+	});
+}
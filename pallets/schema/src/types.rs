@@ -31,3 +31,26 @@ pub struct SchemaEntry<InputSchemaOf, SchemaHashOf, SchemaCreatorOf, SpaceIdOf>
 	/// Identifier of the space asscoaiated with the schema
 	pub space: SpaceIdOf,
 }
+
+/// Position of a schema within its version chain.
+///
+/// Schemas anchored before versioning was introduced have no entry and are
+/// treated as an active first version.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SchemaVersion<SchemaIdOf> {
+	/// Version number, starting at 1 for the first schema in the chain.
+	pub version: u32,
+	/// Identifier of the version this schema supersedes.
+	pub previous: Option<SchemaIdOf>,
+	/// Identifier of the version superseding this schema.
+	pub next: Option<SchemaIdOf>,
+	/// Whether the schema has been deprecated.
+	pub deprecated: bool,
+}
+
+impl<SchemaIdOf> SchemaVersion<SchemaIdOf> {
+	/// The version of a schema that starts a new chain.
+	pub fn first() -> Self {
+		Self { version: 1, previous: None, next: None, deprecated: false }
+	}
+}
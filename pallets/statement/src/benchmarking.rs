@@ -368,6 +368,51 @@ benchmarks! {
 		assert_last_event::<T>(Event::PresentationRemoved { identifier, digest: statement_digest, author: did}.into());
 	}
 
+	set_validity {
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let did: T::SpaceCreatorId = account("did", 0, SEED);
+		let capacity = 5u64;
+
+		let raw_space = [56u8; 256].to_vec();
+		let space_digest = <T as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+		let space_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let space_id: SpaceIdOf = generate_space_id::<T>(&space_id_digest);
+
+		let statement = [77u8; 32].to_vec();
+		let statement_digest = <T as frame_system::Config>::Hashing::hash(&statement[..]);
+		let statement_id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&statement_digest.encode()[..], &space_id.encode()[..], &did.encode()[..]].concat()[..],
+		);
+		let identifier = generate_statement_id::<T>(&statement_id_digest);
+
+		let auth_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_id.encode()[..], &did.encode()[..], &did.encode()[..]].concat()[..],
+		);
+
+		let authorization_id: Ss58Identifier = generate_authorization_id::<T>(&auth_digest);
+
+		let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did.clone());
+		let chain_space_origin = RawOrigin::Root.into();
+
+		pallet_chain_space::Pallet::<T>::create(origin.clone(), space_digest )?;
+		pallet_chain_space::Pallet::<T>::approve(chain_space_origin, space_id, capacity ).expect("Approval should not fail.");
+
+		let _ = Pallet::<T>::register(origin.clone(), statement_digest, authorization_id.clone(), None);
+
+		/* schedule an earlier expiry so the worst case reschedules it */
+		let now = frame_system::Pallet::<T>::block_number();
+		let _ = Pallet::<T>::set_validity(origin.clone(), identifier.clone(), authorization_id.clone(), None, Some(now + 50u32.into()));
+
+		let valid_from = Some(now + 1u32.into());
+		let valid_until = Some(now + 100u32.into());
+	}: _<T::RuntimeOrigin>(origin, identifier.clone(), authorization_id, valid_from, valid_until)
+	verify {
+		assert_last_event::<T>(Event::ValiditySet { identifier, valid_from, valid_until, author: did}.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
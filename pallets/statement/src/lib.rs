@@ -54,6 +54,20 @@
 //! - `revoke`: Marks a statement's reference as inactive.
 //! - `restore`: Reactivates a revoked statement's reference.
 //! - `remove`: Removes a statement's reference from the blockchain.
+//! - `set_validity`: Restricts the blocks during which a statement is valid.
+//!
+//! ## Expiry
+//!
+//! Statements with a `valid_until` block are scheduled for expiry. Scheduled
+//! expiries are swept in `on_idle`, emitting `Expired` events and recording an
+//! `Expire` entry in the statement timeline. Verification reports `Expired` as
+//! soon as the block is reached, regardless of whether the sweep has run yet.
+//!
+//! ## Schema Conformance
+//!
+//! Statements registered under a schema are checked against the configured
+//! `SchemaProvider`: the schema must exist, must not be deprecated and, if it
+//! is scoped to a space, must belong to the space the statement is anchored in.
 //!
 //!## Related Modules
 //!
@@ -80,8 +94,9 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
-use cord_primitives::StatusOf;
-use frame_support::{ensure, storage::types::StorageMap};
+use cord_primitives::{SchemaLookup, StatusOf};
+use cord_utilities::schedule;
+use frame_support::{ensure, storage::types::StorageMap, weights::Weight};
 use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::{prelude::Clone, str};
 pub mod types;
@@ -133,6 +148,10 @@ pub mod pallet {
 		StatementDigestOf<T>,
 		SpaceIdOf,
 	>;
+	/// Type for the statement validity window
+	pub type StatementValidityOf<T> = StatementValidity<BlockNumberFor<T>>;
+	/// Type for the statement verification verdict
+	pub type StatementVerdictOf<T> = StatementVerdict<StatementIdOf, StatementCreatorOf<T>>;
 
 	#[pallet::config]
 	pub trait Config:
@@ -150,6 +169,11 @@ pub mod pallet {
 		/// Maximum removals per call
 		#[pallet::constant]
 		type MaxRemoveEntries: Get<u16>;
+		/// Maximum statements expiring at a single block
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// Source of the schemas statements may be registered under.
+		type SchemaProvider: SchemaLookup<SchemaIdOf, SpaceIdOf>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -159,7 +183,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expiries(n, remaining_weight)
+		}
+	}
 
 	/// statement identifiers stored on chain.
 	/// It maps from an identifier to its details.
@@ -220,6 +248,28 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Validity windows of statements.
+	/// It maps from a statement identifier to the blocks it is valid from and
+	/// until.
+	#[pallet::storage]
+	pub type StatementValidities<T> =
+		StorageMap<_, Blake2_128Concat, StatementIdOf, StatementValidityOf<T>, OptionQuery>;
+
+	/// Scheduled statement expiries.
+	/// It maps from a block number to the statements expiring at that block.
+	#[pallet::storage]
+	pub type StatementExpiries<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<StatementIdOf, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next block whose scheduled expiries have not been swept yet.
+	#[pallet::storage]
+	pub type NextExpirySweep<T> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -272,6 +322,17 @@ pub mod pallet {
 			indices: Vec<u16>,
 			author: StatementCreatorOf<T>,
 		},
+		/// A statement validity window has been set.
+		/// \[statement identifier, valid from, valid until, controller\]
+		ValiditySet {
+			identifier: StatementIdOf,
+			valid_from: Option<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
+			author: StatementCreatorOf<T>,
+		},
+		/// A statement has reached the end of its validity window.
+		/// \[statement identifier\]
+		Expired { identifier: StatementIdOf },
 	}
 
 	#[pallet::error]
@@ -336,6 +397,16 @@ pub mod pallet {
 		PresentationNotFound,
 		/// Statement digest already present on the chain.
 		StatementDigestAlreadyAnchored,
+		/// Validity window is empty or ends in the past.
+		InvalidValidityWindow,
+		/// Maximum statements expiring at the block reached.
+		TooManyExpiriesInBlock,
+		/// Schema not found
+		SchemaNotFound,
+		/// Schema has been deprecated
+		SchemaDeprecated,
+		/// Schema belongs to a different space
+		SchemaSpaceMismatch,
 	}
 
 	#[pallet::call]
@@ -369,7 +440,9 @@ pub mod pallet {
 		///   creator.
 		/// - `digest`: The digest of the statement, serving as a unique identifier.
 		/// - `authorization`: The authorization ID, verifying the creator's delegation status.
-		/// - `schema_id`: An optional schema identifier to be associated with the statement.
+		/// - `schema_id`: An optional schema identifier to be associated with the statement. The
+		///   schema must exist, must not be deprecated and, if it is scoped to a space, must belong
+		///   to the statement's space.
 		///
 		/// # Returns
 		/// A `DispatchResult` indicating the success or failure of the
@@ -380,8 +453,9 @@ pub mod pallet {
 		/// The function can fail for several reasons including unauthorized
 		/// origin, the creator not being a delegate, space capacity being
 		/// exceeded, invalid statement identifier, or the statement already
-		/// being anchored. Errors related to incrementing space usage or
-		/// updating the activity log may also occur.
+		/// being anchored, or the schema not conforming to the space. Errors
+		/// related to incrementing space usage or updating the activity log may
+		/// also occur.
 		///
 		/// # Events
 		/// - `Create`: Emitted when a statement is successfully created, containing the
@@ -395,12 +469,17 @@ pub mod pallet {
 			schema_id: Option<SchemaIdOf>,
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_create_origin(
 				&authorization,
 				&creator,
+				1,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
+			if let Some(ref schema_id) = schema_id {
+				Self::ensure_schema_conformance(schema_id, &space_id)?;
+			}
+
 			// Id Digest = concat (H(<scale_encoded_statement_digest>,
 			// <scale_encoded_space_identifier>, <scale_encoded_creator_identifier>))
 			let id_digest = <T as frame_system::Config>::Hashing::hash(
@@ -430,6 +509,14 @@ pub mod pallet {
 			<Entries<T>>::insert(&identifier, digest, creator.clone());
 			<IdentifierLookup<T>>::insert(digest, &space_id, &identifier);
 
+			pallet_chain_space::Pallet::<T>::record_delegated_creates(
+				&authorization,
+				&space_id,
+				&creator,
+				1,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
 
 			Self::deposit_event(Event::Register { identifier, digest, author: creator });
@@ -532,6 +619,9 @@ pub mod pallet {
 				StatementDetailsOf::<T> { digest: new_statement_digest, ..statement_details },
 			);
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &updater, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&statement_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
 
 			Self::deposit_event(Event::Update {
@@ -622,6 +712,9 @@ pub mod pallet {
 				StatementEntryStatusOf::<T> { creator: updater.clone(), revoked: true },
 			);
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &updater, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&statement_id, CallTypeOf::Revoke).map_err(<Error<T>>::from)?;
 			Self::deposit_event(Event::Revoke { identifier: statement_id, author: updater });
 
@@ -702,6 +795,9 @@ pub mod pallet {
 
 			<RevocationList<T>>::remove(&statement_id, statement_details.digest);
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &updater, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&statement_id, CallTypeOf::Restore).map_err(<Error<T>>::from)?;
 			Self::deposit_event(Event::Restore { identifier: statement_id, author: updater });
 
@@ -797,6 +893,7 @@ pub mod pallet {
 					<RevocationList<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				let _ = <Entries<T>>::clear_prefix(&statement_id, entries_count as u32, None);
 				<Statements<T>>::remove(&statement_id);
+				<StatementValidities<T>>::remove(&statement_id);
 				pallet_chain_space::Pallet::<T>::decrement_usage_entries(
 					&space_id,
 					entries_count as u16,
@@ -818,6 +915,9 @@ pub mod pallet {
 			}
 
 			// Update activity and emit the appropriate event.
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &updater, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(
 				&statement_id,
 				if is_complete_removal { CallTypeOf::Remove } else { CallTypeOf::PartialRemove },
@@ -856,7 +956,8 @@ pub mod pallet {
 		///
 		/// After processing all digests, the function ensures that at least one
 		/// statement was successfully created. It then increments the usage
-		/// count of the space by the number of successful creations. Finally, a
+		/// count of the space, and counts only the successful creations towards
+		/// the usage and the quota of the delegate. Finally, a
 		/// `BatchCreate` event is emitted, summarizing the results of the batch
 		/// operation, including the number of successful and failed creations,
 		/// the indices of the failed digests, and the author of the batch
@@ -868,6 +969,7 @@ pub mod pallet {
 		/// - `digests`: A vector of statement digests to be processed in the batch operation.
 		/// - `authorization`: The authorization ID, verifying the creator's delegation status.
 		/// - `schema_id`: An optional schema identifier that may be associated with the statements.
+		///   It is checked once, the same way as in `register`.
 		///
 		/// # Returns
 		/// A `DispatchResult` indicating the success or failure of the batch
@@ -879,7 +981,8 @@ pub mod pallet {
 		/// # Errors
 		/// The function can fail for several reasons, including exceeding the
 		/// maximum number of digests allowed in a batch, the space capacity
-		/// being exceeded, or if no statements could be successfully created.
+		/// being exceeded, the schema not conforming to the space, or if no
+		/// statements could be successfully created.
 		///
 		/// # Events
 		/// - `BatchCreate`: Emitted upon the completion of the batch operation, providing details
@@ -894,9 +997,10 @@ pub mod pallet {
 			schema_id: Option<SchemaIdOf>,
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_create_origin(
 				&authorization,
 				&creator,
+				digests.len().saturated_into(),
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -905,6 +1009,10 @@ pub mod pallet {
 				Error::<T>::MaxDigestLimitExceeded
 			);
 
+			if let Some(ref schema_id) = schema_id {
+				Self::ensure_schema_conformance(schema_id, &space_id)?;
+			}
+
 			let mut success = 0u32;
 			let mut fail = 0u32;
 			let mut indices: Vec<u16> = Vec::new();
@@ -958,6 +1066,14 @@ pub mod pallet {
 			}
 
 			ensure!(success > 0, Error::<T>::BulkTransactionFailed);
+			pallet_chain_space::Pallet::<T>::record_delegated_creates(
+				&authorization,
+				&space_id,
+				&creator,
+				success,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			if digests.len() > 1 {
 				let increment = (digests.len() - 1) as u16;
 
@@ -1014,9 +1130,10 @@ pub mod pallet {
 			authorization: AuthorizationIdOf,
 		) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
-			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_create_origin(
 				&authorization,
 				&creator,
+				1,
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
@@ -1046,10 +1163,18 @@ pub mod pallet {
 					creator: creator.clone(),
 					presentation_type,
 					digest: statement_details.digest,
-					space: space_id,
+					space: space_id.clone(),
 				},
 			);
 
+			pallet_chain_space::Pallet::<T>::record_delegated_creates(
+				&authorization,
+				&space_id,
+				&creator,
+				1,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&statement_id, CallTypeOf::PresentationAdded)
 				.map_err(<Error<T>>::from)?;
 
@@ -1112,6 +1237,9 @@ pub mod pallet {
 			pallet_chain_space::Pallet::<T>::decrement_usage(&space_id)
 				.map_err(<pallet_chain_space::Error<T>>::from)?;
 
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &remover, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
 			Self::update_activity(&statement_id, CallTypeOf::PresentationRemoved)?;
 
 			Self::deposit_event(Event::PresentationRemoved {
@@ -1122,10 +1250,114 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets the validity window of a statement.
+		///
+		/// The window restricts the blocks during which the statement is
+		/// considered valid by verifiers. Passing `None` for both bounds removes
+		/// any existing window. If a `valid_until` block is supplied, the
+		/// statement is scheduled to expire at that block and an `Expired`
+		/// event is emitted once the expiry has been swept.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the dispatch call, which should be a signed message from the
+		///   updater.
+		/// - `statement_id`: The identifier of the statement.
+		/// - `authorization`: The authorization ID, verifying the updater's delegation status.
+		/// - `valid_from`: The block from which the statement is valid, if restricted.
+		/// - `valid_until`: The block at which the statement expires, if it does.
+		///
+		/// # Errors
+		/// - Returns `StatementNotFound` if the statement does not exist.
+		/// - Returns `UnauthorizedOperation` if the statement is not part of the authorized space.
+		/// - Returns `InvalidValidityWindow` if `valid_until` is not after the current block or not
+		///   after `valid_from`.
+		/// - Returns `TooManyExpiriesInBlock` if too many statements already expire at
+		///   `valid_until`.
+		///
+		/// # Events
+		/// - Emits `ValiditySet` upon success.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_validity())]
+		pub fn set_validity(
+			origin: OriginFor<T>,
+			statement_id: StatementIdOf,
+			authorization: AuthorizationIdOf,
+			valid_from: Option<BlockNumberFor<T>>,
+			valid_until: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let updater = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+			let space_id = pallet_chain_space::Pallet::<T>::ensure_authorization_origin(
+				&authorization,
+				&updater,
+			)
+			.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			let statement_details =
+				<Statements<T>>::get(&statement_id).ok_or(Error::<T>::StatementNotFound)?;
+
+			ensure!(statement_details.space == space_id, Error::<T>::UnauthorizedOperation);
+
+			if let Some(valid_until) = valid_until {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					valid_until > now && valid_from.map_or(true, |from| valid_until > from),
+					Error::<T>::InvalidValidityWindow
+				);
+			}
+
+			if let Some(previous) = <StatementValidities<T>>::take(&statement_id) {
+				if let Some(previous_until) = previous.valid_until {
+					schedule::unschedule::<StatementExpiries<T>, _, _, _>(
+						previous_until,
+						&statement_id,
+					);
+				}
+			}
+
+			if let Some(valid_until) = valid_until {
+				Self::schedule_expiry(&statement_id, valid_until)?;
+			}
+
+			if valid_from.is_some() || valid_until.is_some() {
+				<StatementValidities<T>>::insert(
+					&statement_id,
+					StatementValidity { valid_from, valid_until },
+				);
+			}
+
+			pallet_chain_space::Pallet::<T>::record_delegate_usage(&space_id, &updater, 1)
+				.map_err(<pallet_chain_space::Error<T>>::from)?;
+
+			Self::update_activity(&statement_id, CallTypeOf::Update).map_err(<Error<T>>::from)?;
+			Self::deposit_event(Event::ValiditySet {
+				identifier: statement_id,
+				valid_from,
+				valid_until,
+				author: updater,
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Ensures that a schema exists, has not been deprecated and, if it is
+	/// scoped to a space, belongs to `space_id`.
+	pub fn ensure_schema_conformance(
+		schema_id: &SchemaIdOf,
+		space_id: &SpaceIdOf,
+	) -> Result<(), Error<T>> {
+		let schema = T::SchemaProvider::schema_info(schema_id).ok_or(Error::<T>::SchemaNotFound)?;
+		ensure!(!schema.deprecated, Error::<T>::SchemaDeprecated);
+		ensure!(
+			schema.space.as_ref().map_or(true, |space| space == space_id),
+			Error::<T>::SchemaSpaceMismatch
+		);
+		Ok(())
+	}
+
 	/// Updates the global timeline with a new activity event for a statement.
 	/// This function is called whenever a significant action is performed on a
 	/// statement, ensuring that all such activities are logged with a timestamp
@@ -1159,6 +1391,119 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Verifies a statement digest anchored in `space_id` and returns a single
+	/// verdict for it.
+	///
+	/// The digest may be either a statement digest or a presentation digest.
+	/// Checks are applied in order: the digest must be anchored in the space,
+	/// must not be revoked or superseded, must be within its validity window,
+	/// the space and its ancestors must be approved and not archived, and, if
+	/// `schema_id` is provided, the statement must be bound to that schema.
+	///
+	/// # Parameters
+	/// - `digest`: The digest to verify.
+	/// - `space_id`: The space the digest is expected to be anchored in.
+	/// - `schema_id`: An optional schema the statement is expected to conform to.
+	///
+	/// # Returns
+	/// A `StatementVerdictOf` carrying the verification status, the statement
+	/// identifier and creator, and the `Timepoint` of the last recorded change.
+	pub fn verify_digest(
+		digest: &StatementDigestOf<T>,
+		space_id: &SpaceIdOf,
+		schema_id: Option<&SchemaIdOf>,
+	) -> StatementVerdictOf<T> {
+		let Some(identifier) = <IdentifierLookup<T>>::get(digest, space_id) else {
+			return StatementVerdict::unknown();
+		};
+		let Some(details) = <Statements<T>>::get(&identifier) else {
+			return StatementVerdict::unknown();
+		};
+		let Some(creator) = <Entries<T>>::get(&identifier, digest)
+			.or_else(|| <Presentations<T>>::get(&identifier, digest).map(|p| p.creator))
+		else {
+			return StatementVerdict::unknown();
+		};
+
+		let revoked = <RevocationList<T>>::contains_key(&identifier, digest) ||
+			<RevocationList<T>>::contains_key(&identifier, details.digest);
+		let space = pallet_chain_space::Pallet::<T>::effective_space_details(space_id);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let validity = <StatementValidities<T>>::get(&identifier);
+
+		let status = if revoked {
+			StatementVerificationStatus::Revoked
+		} else if validity.as_ref().is_some_and(|v| v.valid_until.is_some_and(|u| now >= u)) {
+			StatementVerificationStatus::Expired
+		} else if validity.as_ref().is_some_and(|v| v.valid_from.is_some_and(|f| now < f)) {
+			StatementVerificationStatus::NotYetValid
+		} else if space.as_ref().map_or(true, |s| s.archive) {
+			StatementVerificationStatus::SpaceArchived
+		} else if space.as_ref().map_or(true, |s| !s.approved) {
+			StatementVerificationStatus::SpaceUnapproved
+		} else if schema_id.is_some_and(|s| details.schema.as_ref() != Some(s)) {
+			StatementVerificationStatus::SchemaMismatch
+		} else {
+			StatementVerificationStatus::Active
+		};
+
+		let last_change =
+			identifier::Identifiers::<T>::get(&identifier, IdentifierTypeOf::Statement)
+				.and_then(|events| events.last().map(|event| event.location));
+
+		StatementVerdict {
+			status,
+			identifier: Some(identifier),
+			creator: Some(creator),
+			last_change,
+		}
+	}
+
+	/// Schedules a statement to expire at the given block.
+	fn schedule_expiry(
+		statement_id: &StatementIdOf,
+		valid_until: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		schedule::schedule::<NextExpirySweep<T>, StatementExpiries<T>, _, _, _>(
+			valid_until,
+			statement_id.clone(),
+			frame_system::Pallet::<T>::block_number(),
+		)
+		.map_err(|_| Error::<T>::TooManyExpiriesInBlock)
+	}
+
+	/// Expires the statements scheduled up to and including block `now`, as
+	/// far as `remaining_weight` allows.
+	///
+	/// # Returns
+	/// The weight consumed by the sweep.
+	pub(crate) fn sweep_expiries(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		schedule::sweep::<NextExpirySweep<T>, StatementExpiries<T>, _, _, _>(
+			now,
+			remaining_weight,
+			db_weight,
+			db_weight.reads_writes(5, 3),
+			|statement_id, at| Self::expire(&statement_id, at),
+		)
+	}
+
+	/// Marks a statement as expired if it is still scheduled to expire at
+	/// block `at`.
+	fn expire(statement_id: &StatementIdOf, at: BlockNumberFor<T>) {
+		let scheduled = <StatementValidities<T>>::get(statement_id)
+			.is_some_and(|validity| validity.valid_until == Some(at));
+		if !scheduled {
+			return;
+		}
+
+		if let Err(e) = Self::update_activity(statement_id, CallTypeOf::Expire) {
+			log::error!("Failed to record the expiry of statement {:?}: {:?}", statement_id, e);
+		}
+		Self::deposit_event(Event::Expired { identifier: statement_id.clone() });
+	}
+
 	/// Retrieves the current timepoint.
 	///
 	/// This function returns a `Timepoint` structure containing the current
@@ -2,7 +2,7 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_support::{assert_err, assert_ok, traits::Hooks, weights::Weight, BoundedVec};
 use frame_system::RawOrigin;
 use pallet_chain_space::SpaceCodeOf;
 use pallet_schema::{InputSchemaOf, SchemaHashOf};
@@ -1359,3 +1359,327 @@ fn adding_duplicate_presentation_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn verifying_a_statement_digest_should_return_the_expected_verdict() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [11u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema)
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+	let other_schema_id: SchemaIdOf =
+		generate_schema_id::<Test>(&<Test as frame_system::Config>::Hashing::hash(&[9u8; 32]));
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema.clone(),
+			authorization_id.clone()
+		));
+
+		assert_eq!(
+			Statement::verify_digest(&statement_digest, &space_id, None).status,
+			StatementVerificationStatus::Unknown
+		);
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			Some(schema_id.clone())
+		));
+
+		let verdict = Statement::verify_digest(&statement_digest, &space_id, Some(&schema_id));
+		assert_eq!(verdict.status, StatementVerificationStatus::Active);
+		assert_eq!(verdict.identifier, Some(statement_id.clone()));
+		assert_eq!(verdict.creator, Some(creator.clone()));
+		assert!(verdict.last_change.is_some());
+
+		assert_eq!(
+			Statement::verify_digest(&statement_digest, &space_id, Some(&other_schema_id)).status,
+			StatementVerificationStatus::SchemaMismatch
+		);
+
+		assert_ok!(Space::archive(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			authorization_id.clone(),
+		));
+		assert_eq!(
+			Statement::verify_digest(&statement_digest, &space_id, None).status,
+			StatementVerificationStatus::SpaceArchived
+		);
+
+		assert_ok!(Space::restore(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_id.clone(),
+			authorization_id.clone(),
+		));
+
+		assert_ok!(Statement::revoke(
+			DoubleOrigin(author, creator).into(),
+			statement_id,
+			authorization_id,
+		));
+		assert_eq!(
+			Statement::verify_digest(&statement_digest, &space_id, None).status,
+			StatementVerificationStatus::Revoked
+		);
+	});
+}
+
+#[test]
+fn statement_validity_window_and_expiry_sweep_should_work() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement = [77u8; 32];
+	let statement_digest: StatementDigestOf<Test> =
+		<Test as frame_system::Config>::Hashing::hash(&statement[..]);
+
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let statement_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&statement_digest.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let statement_id: StatementIdOf = generate_statement_id::<Test>(&statement_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			authorization_id.clone(),
+			None
+		));
+
+		// Validity must end in the future and after it begins.
+		assert_err!(
+			Statement::set_validity(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+				None,
+				Some(1),
+			),
+			Error::<Test>::InvalidValidityWindow
+		);
+		assert_err!(
+			Statement::set_validity(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_id.clone(),
+				authorization_id.clone(),
+				Some(4),
+				Some(3),
+			),
+			Error::<Test>::InvalidValidityWindow
+		);
+
+		assert_ok!(Statement::set_validity(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+			Some(3),
+			Some(10),
+		));
+		assert_eq!(
+			Statement::verify_digest(&statement_digest, &space_id, None).status,
+			StatementVerificationStatus::NotYetValid
+		);
+
+		// Rescheduling moves the pending expiry to the new block.
+		assert_ok!(Statement::set_validity(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_id.clone(),
+			authorization_id.clone(),
+			Some(3),
+			Some(5),
+		));
+		assert!(StatementExpiries::<Test>::get(10).is_empty());
+		assert_eq!(StatementExpiries::<Test>::get(5).into_inner(), vec![statement_id.clone()]);
+
+		System::set_block_number(3);
+		assert_eq!(
+			Statement::verify_digest(&statement_digest, &space_id, None).status,
+			StatementVerificationStatus::Active
+		);
+
+		System::set_block_number(5);
+		Statement::on_idle(5, Weight::MAX);
+		assert_eq!(
+			Statement::verify_digest(&statement_digest, &space_id, None).status,
+			StatementVerificationStatus::Expired
+		);
+		System::assert_last_event(Event::Expired { identifier: statement_id.clone() }.into());
+		assert!(StatementExpiries::<Test>::get(5).is_empty());
+		assert_eq!(NextExpirySweep::<Test>::get(), Some(6));
+	});
+}
+
+#[test]
+fn register_statement_should_check_schema_conformance() {
+	let creator = DID_00;
+	let author = ACCOUNT_00;
+	let capacity = 5u64;
+	let statement_digest = <Test as frame_system::Config>::Hashing::hash(&[77u8; 32][..]);
+
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&[2u8; 256].encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+	let auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: Ss58Identifier = generate_authorization_id::<Test>(&auth_digest);
+
+	let other_space_digest =
+		<Test as frame_system::Config>::Hashing::hash(&[3u8; 256].encode()[..]);
+	let other_space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&other_space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let other_space_id: SpaceIdOf = generate_space_id::<Test>(&other_space_id_digest);
+	let other_auth_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&other_space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let other_authorization_id: Ss58Identifier =
+		generate_authorization_id::<Test>(&other_auth_digest);
+
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from([11u8; 256].to_vec())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+	let other_schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &other_space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let other_schema_id: SchemaIdOf = generate_schema_id::<Test>(&other_schema_id_digest);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), capacity));
+		assert_ok!(Space::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			other_space_digest,
+		));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), other_space_id.clone(), capacity));
+
+		// Unknown schemas are rejected.
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				Some(schema_id.clone())
+			),
+			Error::<Test>::SchemaNotFound
+		);
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema.clone(),
+			other_authorization_id.clone()
+		));
+
+		// Schemas scoped to another space are rejected, individually and in batches.
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				Some(other_schema_id.clone())
+			),
+			Error::<Test>::SchemaSpaceMismatch
+		);
+		assert_err!(
+			Statement::register_batch(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				vec![statement_digest],
+				authorization_id.clone(),
+				Some(other_schema_id.clone())
+			),
+			Error::<Test>::SchemaSpaceMismatch
+		);
+
+		assert_ok!(Schema::create(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema.clone(),
+			authorization_id.clone()
+		));
+		assert_ok!(Schema::deprecate(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			schema_id.clone(),
+			authorization_id.clone()
+		));
+
+		// Deprecated schemas cannot be used for new statements.
+		assert_err!(
+			Statement::register(
+				DoubleOrigin(author.clone(), creator.clone()).into(),
+				statement_digest,
+				authorization_id.clone(),
+				Some(schema_id.clone())
+			),
+			Error::<Test>::SchemaDeprecated
+		);
+
+		assert_ok!(Statement::register(
+			DoubleOrigin(author.clone(), creator.clone()).into(),
+			statement_digest,
+			other_authorization_id.clone(),
+			Some(other_schema_id.clone())
+		));
+	});
+}
@@ -221,3 +221,72 @@ impl MaxEncodedLen for PresentationTypeOf {
 		1 // Since all variants are unit variants, they encode to a single byte.
 	}
 }
+
+/// `StatementValidity` restricts the blocks during which a statement is
+/// considered valid.
+///
+/// ## Fields
+///
+/// - `valid_from`: The block from which the statement is valid, if restricted.
+/// - `valid_until`: The block at which the statement expires, if it does.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct StatementValidity<BlockNumber> {
+	/// Block from which the statement is valid.
+	pub valid_from: Option<BlockNumber>,
+	/// Block at which the statement expires.
+	pub valid_until: Option<BlockNumber>,
+}
+
+/// Outcome of verifying a statement digest against a space.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum StatementVerificationStatus {
+	/// The digest is not anchored in the given space.
+	Unknown,
+	/// The digest is anchored, not revoked and its space is usable.
+	Active,
+	/// The digest has been revoked or superseded by an update.
+	Revoked,
+	/// The space the statement belongs to has been archived.
+	SpaceArchived,
+	/// The space the statement belongs to is not approved.
+	SpaceUnapproved,
+	/// The statement is not bound to the expected schema.
+	SchemaMismatch,
+	/// The validity window of the statement has not started yet.
+	NotYetValid,
+	/// The validity window of the statement has ended.
+	Expired,
+}
+
+/// `StatementVerdict` is the single answer returned when checking whether a
+/// statement digest can be trusted.
+///
+/// ## Fields
+///
+/// - `status`: The `StatementVerificationStatus` of the digest.
+/// - `identifier`: The statement identifier the digest resolves to, if any.
+/// - `creator`: The creator of the digest or presentation, if it is known.
+/// - `last_change`: The `Timepoint` of the latest activity recorded for the statement.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct StatementVerdict<StatementIdOf, StatementCreatorOf> {
+	/// The verification outcome.
+	pub status: StatementVerificationStatus,
+	/// Identifier of the statement the digest resolves to.
+	pub identifier: Option<StatementIdOf>,
+	/// The creator of the digest.
+	pub creator: Option<StatementCreatorOf>,
+	/// Location of the most recent change to the statement.
+	pub last_change: Option<Timepoint>,
+}
+
+impl<StatementIdOf, StatementCreatorOf> StatementVerdict<StatementIdOf, StatementCreatorOf> {
+	/// A verdict for a digest that is not anchored in the requested space.
+	pub fn unknown() -> Self {
+		Self {
+			status: StatementVerificationStatus::Unknown,
+			identifier: None,
+			creator: None,
+			last_change: None,
+		}
+	}
+}
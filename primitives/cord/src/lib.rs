@@ -101,3 +101,28 @@ pub const AUTHORSHIP_PERIOD: u32 = 20;
 pub trait IsPermissioned {
 	fn is_permissioned() -> bool;
 }
+
+/// Details of an anchored schema as seen by pallets referencing it.
+#[derive(Clone, PartialEq, Eq, sp_runtime::RuntimeDebug)]
+pub struct SchemaInfo<SpaceId> {
+	/// The space the schema is anchored in, if it is scoped to one.
+	pub space: Option<SpaceId>,
+	/// Whether the schema has been deprecated.
+	pub deprecated: bool,
+}
+
+/// Trait definition for looking up anchored schemas
+pub trait SchemaLookup<SchemaId, SpaceId> {
+	/// Returns the details of `schema_id`, or `None` if it is not anchored.
+	fn schema_info(schema_id: &SchemaId) -> Option<SchemaInfo<SpaceId>>;
+}
+
+impl<SchemaId, SpaceId, A, B> SchemaLookup<SchemaId, SpaceId> for (A, B)
+where
+	A: SchemaLookup<SchemaId, SpaceId>,
+	B: SchemaLookup<SchemaId, SpaceId>,
+{
+	fn schema_info(schema_id: &SchemaId) -> Option<SchemaInfo<SpaceId>> {
+		A::schema_info(schema_id).or_else(|| B::schema_info(schema_id))
+	}
+}
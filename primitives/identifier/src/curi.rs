@@ -291,3 +291,18 @@ impl AsRef<[u8]> for Ss58Identifier {
 		&self.0[..]
 	}
 }
+
+/// Builds an identifier from its SS58 encoded representation, e.g. as
+/// received over RPC. The input is only accepted if it carries a valid
+/// identifier prefix.
+impl TryFrom<Vec<u8>> for Ss58Identifier {
+	type Error = IdentifierError;
+
+	fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+		let identifier =
+			Self(value.try_into().map_err(|_| IdentifierError::InvalidIdentifierLength)?);
+		identifier.get_identifier_type()?;
+
+		Ok(identifier)
+	}
+}
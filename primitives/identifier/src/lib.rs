@@ -34,7 +34,7 @@ use frame_support::traits::Get;
 use frame_system::pallet_prelude::BlockNumberFor;
 
 pub use crate::pallet::*;
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 #[cfg(any(feature = "mock", test))]
 pub mod mock;
@@ -67,6 +67,13 @@ pub mod pallet {
 		/// The maximum number of activity for a statement.
 		#[pallet::constant]
 		type MaxEventsHistory: Get<u32>;
+		/// What to do with the oldest entries once `MaxEventsHistory` is
+		/// reached.
+		#[pallet::constant]
+		type TimelineOverflow: Get<TimelineOverflowMode>;
+		/// The maximum number of archived timeline entries stored per page.
+		#[pallet::constant]
+		type MaxArchivedEventsPerPage: Get<u32>;
 	}
 
 	#[pallet::pallet]
@@ -87,6 +94,33 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Timeline entries evicted from `Identifiers`, kept when the overflow
+	/// mode is `TimelineOverflowMode::Archive`.
+	/// It maps from an identifier, its type and a page index to the entries.
+	#[pallet::storage]
+	pub type ArchivedEvents<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, IdentifierOf>,
+			NMapKey<Twox64Concat, IdentifierTypeOf>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		BoundedVec<EventEntryOf, T::MaxArchivedEventsPerPage>,
+		OptionQuery,
+	>;
+
+	/// Number of archived timeline pages stored for an identifier.
+	#[pallet::storage]
+	pub type ArchivedPages<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		IdentifierOf,
+		Twox64Concat,
+		IdentifierTypeOf,
+		u32,
+		ValueQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		// Max exvents history exceeded
@@ -110,7 +144,10 @@ impl<T: Config> IdentifierUpdate<IdentifierOf, IdentifierTypeOf, EventEntryOf, I
 			let events = timeline.get_or_insert_with(BoundedVec::default);
 
 			if events.len() == T::MaxEventsHistory::get() as usize {
-				events.remove(1);
+				let evicted = events.remove(1);
+				if T::TimelineOverflow::get() == TimelineOverflowMode::Archive {
+					Self::archive_event(id, id_type, evicted)?;
+				}
 			}
 
 			events.try_push(entry).map_err(|_| IdentifierError::MaxEventsHistoryExceeded)
@@ -118,3 +155,74 @@ impl<T: Config> IdentifierUpdate<IdentifierOf, IdentifierTypeOf, EventEntryOf, I
 		.map_err(|_| IdentifierError::MaxEventsHistoryExceeded) // Map DispatchError to your custom Error
 	}
 }
+
+impl<T: Config> Pallet<T> {
+	/// Appends an evicted timeline entry to the last archived page of the
+	/// identifier, opening a new page when the current one is full.
+	fn archive_event(
+		id: &IdentifierOf,
+		id_type: IdentifierTypeOf,
+		entry: EventEntryOf,
+	) -> Result<(), IdentifierError> {
+		let pages = ArchivedPages::<T>::get(id, id_type);
+		let (index, mut page) = match pages.checked_sub(1) {
+			Some(last) => {
+				let page = ArchivedEvents::<T>::get((id, id_type, last)).unwrap_or_default();
+				if page.len() < T::MaxArchivedEventsPerPage::get() as usize {
+					(last, page)
+				} else {
+					(pages, BoundedVec::default())
+				}
+			},
+			None => (0, BoundedVec::default()),
+		};
+
+		page.try_push(entry).map_err(|_| IdentifierError::MaxEventsHistoryExceeded)?;
+		ArchivedEvents::<T>::insert((id, id_type, index), page);
+		ArchivedPages::<T>::insert(id, id_type, index.saturating_add(1));
+
+		Ok(())
+	}
+
+	/// Returns one page of the timeline of an identifier in chronological
+	/// order, including entries moved to the archive.
+	///
+	/// The first recorded entry is never evicted, so archived entries are
+	/// placed between it and the entries still held in `Identifiers`.
+	/// `limit` is capped at `MaxArchivedEventsPerPage`.
+	pub fn timeline(
+		id: &IdentifierOf,
+		id_type: IdentifierTypeOf,
+		page: u32,
+		limit: u32,
+	) -> Vec<EventEntryOf> {
+		let mut recent = Identifiers::<T>::get(id, id_type).unwrap_or_default().into_iter();
+		let Some(first) = recent.next() else {
+			return Vec::new();
+		};
+
+		let archived = (0..ArchivedPages::<T>::get(id, id_type))
+			.flat_map(|index| ArchivedEvents::<T>::get((id, id_type, index)).unwrap_or_default());
+		let limit = limit.min(T::MaxArchivedEventsPerPage::get()) as usize;
+
+		vec![first]
+			.into_iter()
+			.chain(archived)
+			.chain(recent)
+			.skip((page as usize).saturating_mul(limit))
+			.take(limit)
+			.collect()
+	}
+
+	/// Returns one page of every timeline recorded for an identifier, one per
+	/// identifier type it has been used with.
+	pub fn timelines(
+		id: &IdentifierOf,
+		page: u32,
+		limit: u32,
+	) -> Vec<(IdentifierTypeOf, Vec<EventEntryOf>)> {
+		Identifiers::<T>::iter_key_prefix(id)
+			.map(|id_type| (id_type, Self::timeline(id, id_type, page, limit)))
+			.collect()
+	}
+}
@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{self as identifier, TimelineOverflowMode};
 use frame_support::{derive_impl, parameter_types};
 
 use sp_runtime::{
@@ -30,7 +31,8 @@ pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
 
 frame_support::construct_runtime!(
 	pub enum Test {
-		System: frame_system
+		System: frame_system,
+		Identifier: identifier,
 	}
 );
 
@@ -43,6 +45,18 @@ impl frame_system::Config for Test {
 	type Block = Block;
 }
 
+parameter_types! {
+	pub const MaxEventsHistory: u32 = 3u32;
+	pub const TimelineOverflow: TimelineOverflowMode = TimelineOverflowMode::Archive;
+	pub const MaxArchivedEventsPerPage: u32 = 2u32;
+}
+
+impl identifier::Config for Test {
+	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
+}
+
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let t: sp_runtime::Storage =
@@ -24,3 +24,58 @@ fn creating_a_invalid_identifier_length_should_fail() {
 		));
 	});
 }
+
+#[test]
+fn evicted_timeline_entries_should_be_archived() {
+	let id = Ss58Identifier::create_identifier(&[7u8; 30].encode()[..], IdentifierType::Statement)
+		.expect("Identifier should be created");
+	let entries: Vec<EventEntryOf> = (0..6u32)
+		.map(|index| EventEntryOf {
+			action: CallTypeOf::Update,
+			location: Timepoint { height: 1, index },
+		})
+		.collect();
+
+	new_test_ext().execute_with(|| {
+		for entry in entries.iter() {
+			assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+				&id,
+				IdentifierTypeOf::Statement,
+				entry.clone()
+			));
+		}
+
+		let recent = Identifiers::<Test>::get(&id, IdentifierTypeOf::Statement)
+			.expect("Timeline should be present");
+		assert_eq!(
+			recent.into_inner(),
+			vec![entries[0].clone(), entries[4].clone(), entries[5].clone()]
+		);
+
+		assert_eq!(ArchivedPages::<Test>::get(&id, IdentifierTypeOf::Statement), 2);
+		assert_eq!(
+			ArchivedEvents::<Test>::get((&id, IdentifierTypeOf::Statement, 0u32))
+				.expect("First archive page should be present")
+				.into_inner(),
+			vec![entries[1].clone(), entries[2].clone()]
+		);
+
+		for page in 0..3u32 {
+			let start = page as usize * 2;
+			assert_eq!(
+				Identifier::timeline(&id, IdentifierTypeOf::Statement, page, 2),
+				entries[start..start + 2].to_vec()
+			);
+		}
+		assert!(Identifier::timeline(&id, IdentifierTypeOf::Statement, 3, 2).is_empty());
+		// The limit is capped at `MaxArchivedEventsPerPage`.
+		assert_eq!(
+			Identifier::timeline(&id, IdentifierTypeOf::Statement, 0, 10),
+			entries[..2].to_vec()
+		);
+		assert_eq!(
+			Identifier::timelines(&id, 1, 2),
+			vec![(IdentifierTypeOf::Statement, entries[2..4].to_vec())]
+		);
+	});
+}
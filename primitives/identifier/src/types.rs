@@ -68,6 +68,12 @@ pub enum CallTypeOf {
 	Credit,
 	Issue,
 	Reinstate,
+	Expire,
+	RecoveryInitiated,
+	RecoveryCancelled,
+	Recovered,
+	RecoveryApprovalWithdrawn,
+	TransferRequest,
 }
 /// Defining the identifier target types.
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -88,3 +94,13 @@ pub enum IdentifierTypeOf {
 	NameSpace,
 	NameSpaceAuthorization,
 }
+
+/// Defines what happens to timeline entries evicted once the bounded history
+/// of an identifier reaches `MaxEventsHistory`.
+#[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum TimelineOverflowMode {
+	/// Evicted entries are discarded.
+	Drop,
+	/// Evicted entries are moved into the paged `ArchivedEvents` storage.
+	Archive,
+}
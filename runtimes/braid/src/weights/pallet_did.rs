@@ -16,27 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
-//! Autogenerated weights for `pallet_did`
+//! Placeholder weights for `pallet_did`.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-03-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
-
-// Executed Command:
-// ./target/production/cord
-// benchmark
-// pallet
-// --chain=dev
-// --steps=50
-// --repeat=20
-// --pallet=pallet_did
-// --extrinsic=*
-// --wasm-execution=compiled
-// --heap-pages=4096
-// --header=./HEADER-GPL3
-// --output=./runtime/src/weights/
+//! These weights are not the output of a single benchmark run. The execution
+//! times of the calls that predate DID key history, recovery, P-256 and
+//! BLS12-381 keys and deposits come from a 2024-03-18 run of the benchmark CLI
+//! on `AMD EPYC 7B12`, with their storage access counts raised by hand to cover
+//! the storage those features added. The weights of the calls added with
+//! those features are estimates, including the BLS12-381 pairing check of
+//! `submit_did_call_bls381_key`. Key changes count one pruned key history
+//! entry and `delete` counts clearing a key history of 100 entries, the
+//! `MaxKeyHistory` of the runtimes. They must all be regenerated with
+//! `cord benchmark pallet --pallet=pallet_did` on reference hardware.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -57,6 +48,16 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ed25519_keys(n: u32, c: u32, ) -> Weight {
@@ -70,8 +71,9 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(762_963, 0).saturating_mul(n.into()))
 			// Standard Error: 12_177
 			.saturating_add(Weight::from_parts(6_535_800, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -82,6 +84,16 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_sr25519_keys(n: u32, c: u32, ) -> Weight {
@@ -95,8 +107,9 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(972_036, 0).saturating_mul(n.into()))
 			// Standard Error: 20_656
 			.saturating_add(Weight::from_parts(7_551_125, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidBlacklist` (r:1 w:0)
@@ -107,6 +120,16 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:0 w:25)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:13)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:13)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 10]`.
 	/// The range of component `c` is `[1, 25]`.
 	fn create_ecdsa_keys(n: u32, c: u32, ) -> Weight {
@@ -120,8 +143,9 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(1_193_414, 0).saturating_mul(n.into()))
 			// Standard Error: 13_633
 			.saturating_add(Weight::from_parts(6_403_591, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
@@ -132,6 +156,20 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidBlacklist` (r:0 w:1)
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:0 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:22)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:100)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `c` is `[1, 25]`.
 	fn delete(c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -142,9 +180,9 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 5649))
 			// Standard Error: 3_783
 			.saturating_add(Weight::from_parts(1_219_880, 0).saturating_mul(c.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(130))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 			.saturating_add(Weight::from_parts(0, 2888).saturating_mul(c.into()))
 	}
@@ -186,6 +224,12 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -193,11 +237,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_320_000 picoseconds.
 		Weight::from_parts(24_350_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -205,11 +255,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_550_000 picoseconds.
 		Weight::from_parts(23_970_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_authentication_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
@@ -217,11 +273,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_440_000 picoseconds.
 		Weight::from_parts(24_080_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -229,11 +291,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_060_000 picoseconds.
 		Weight::from_parts(23_830_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -241,11 +309,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_050_000 picoseconds.
 		Weight::from_parts(23_930_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
@@ -253,11 +327,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 22_910_000 picoseconds.
 		Weight::from_parts(23_770_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ed25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -265,11 +345,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_610_000 picoseconds.
 		Weight::from_parts(22_300_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_sr25519_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -277,11 +363,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_940_000 picoseconds.
 		Weight::from_parts(22_440_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_delegation_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
@@ -289,11 +381,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_649_000 picoseconds.
 		Weight::from_parts(22_550_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -301,11 +399,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_310_000 picoseconds.
 		Weight::from_parts(23_810_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -313,11 +417,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_320_000 picoseconds.
 		Weight::from_parts(24_050_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn set_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
@@ -325,11 +435,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_230_000 picoseconds.
 		Weight::from_parts(23_990_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ed25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -337,11 +453,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_560_000 picoseconds.
 		Weight::from_parts(22_109_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_sr25519_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -349,11 +471,17 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_970_000 picoseconds.
 		Weight::from_parts(22_450_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_assertion_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
@@ -361,11 +489,21 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_571_000 picoseconds.
 		Weight::from_parts(22_629_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1355`
@@ -373,11 +511,21 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 22_690_000 picoseconds.
 		Weight::from_parts(23_520_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1355`
@@ -385,11 +533,21 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 22_930_000 picoseconds.
 		Weight::from_parts(23_469_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1358`
@@ -397,11 +555,21 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_060_000 picoseconds.
 		Weight::from_parts(23_640_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_ed25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -409,11 +577,21 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_980_000 picoseconds.
 		Weight::from_parts(22_660_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_sr25519_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1457`
@@ -421,11 +599,21 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_890_000 picoseconds.
 		Weight::from_parts(22_740_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_ecdsa_key_agreement_key() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1460`
@@ -433,8 +621,8 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 22_209_000 picoseconds.
 		Weight::from_parts(22_860_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -442,6 +630,10 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Did::ServiceEndpoints` (r:1 w:1)
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn add_service_endpoint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `905`
@@ -449,8 +641,8 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 29_710_000 picoseconds.
 		Weight::from_parts(31_020_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -458,6 +650,10 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::ServiceEndpoints` (`max_values`: None, `max_size`: Some(413), added: 2888, mode: `MaxEncodedLen`)
 	/// Storage: `Did::DidEndpointsCount` (r:1 w:1)
 	/// Proof: `Did::DidEndpointsCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn remove_service_endpoint() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1286`
@@ -465,8 +661,8 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_780_000 picoseconds.
 		Weight::from_parts(29_771_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Did::Did` (r:1 w:0)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
@@ -525,6 +721,16 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 	/// Proof: `Did::DidBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Did::Did` (r:1 w:1)
 	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:0 w:1)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:0 w:1)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:0 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn create_from_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
@@ -532,7 +738,164 @@ impl<T: frame_system::Config> pallet_did::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 13_430_000 picoseconds.
 		Weight::from_parts(14_130_000, 0)
 			.saturating_add(Weight::from_parts(0, 5649))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:0 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	fn set_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `586`
+		//  Estimated: `5649`
+		Weight::from_parts(23_440_000, 0)
+			.saturating_add(Weight::from_parts(0, 5649))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:1)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:0 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	fn remove_recovery_config() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3842`
+		Weight::from_parts(22_467_000, 0)
+			.saturating_add(Weight::from_parts(0, 3842))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn approve_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `817`
+		//  Estimated: `5649`
+		Weight::from_parts(37_482_000, 0)
+			.saturating_add(Weight::from_parts(0, 5649))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn cancel_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `599`
+		//  Estimated: `3877`
+		Weight::from_parts(27_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 3877))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistoryCounters` (r:1 w:1)
+	/// Proof: `Did::KeyHistoryCounters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveKeyHistory` (r:1 w:2)
+	/// Proof: `Did::ActiveKeyHistory` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Did::KeyHistory` (r:2 w:3)
+	/// Proof: `Did::KeyHistory` (`max_values`: None, `max_size`: Some(146), added: 2621, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn finalize_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1030`
+		//  Estimated: `6196`
+		Weight::from_parts(71_209_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	fn submit_did_call_p256_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `499`
+		//  Estimated: `5649`
+		Weight::from_parts(264_255_000, 0)
+			.saturating_add(Weight::from_parts(0, 5649))
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Did::Did` (r:1 w:1)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	fn submit_did_call_bls381_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `419`
+		//  Estimated: `5649`
+		Weight::from_parts(3_930_572_000, 0)
+			.saturating_add(Weight::from_parts(0, 5649))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Did::Did` (r:1 w:0)
+	/// Proof: `Did::Did` (`max_values`: None, `max_size`: Some(2184), added: 4659, mode: `MaxEncodedLen`)
+	/// Storage: `Did::DidDeposits` (r:1 w:1)
+	/// Proof: `Did::DidDeposits` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn change_deposit_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `789`
+		//  Estimated: `6196`
+		Weight::from_parts(41_274_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Did::RecoveryConfigs` (r:1 w:0)
+	/// Proof: `Did::RecoveryConfigs` (`max_values`: None, `max_size`: Some(377), added: 2852, mode: `MaxEncodedLen`)
+	/// Storage: `Did::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Did::ActiveRecoveries` (`max_values`: None, `max_size`: Some(752), added: 3227, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn withdraw_recovery_approval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `656`
+		//  Estimated: `3877`
+		Weight::from_parts(32_639_000, 0)
+			.saturating_add(Weight::from_parts(0, 3877))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -0,0 +1,65 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definitions for querying asset classes, asset holdings and
+//! the transfer history of asset instances.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use identifier::Ss58Identifier;
+pub use pallet_asset::{AssetClassEntry, AssetHolding, AssetStatusOf, AssetTransferRecord};
+
+/// Asset class details as returned by the runtime.
+///
+/// The bounded class name is represented as a plain vector so the type does
+/// not depend on runtime specific bounds.
+pub type RawAssetClassDetails<AccountId, BlockNumber> =
+	AssetClassEntry<Vec<u8>, Ss58Identifier, Ss58Identifier, AccountId, BlockNumber>;
+
+/// Asset instance holding as returned by the runtime.
+pub type RawAssetHolding<AccountId> = AssetHolding<Ss58Identifier, Ss58Identifier, AccountId>;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait AssetApi<AccountId, Hash, BlockNumber> where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// Given an asset class identifier this returns the class details.
+		fn asset_class(class_id: Ss58Identifier) -> Option<RawAssetClassDetails<AccountId, BlockNumber>>;
+
+		/// Given an asset class identifier this returns the identifiers of
+		/// the assets created under it.
+		fn assets_by_class(class_id: Ss58Identifier) -> Vec<Ss58Identifier>;
+
+		/// Given an owner this returns the asset instances it holds.
+		fn holdings(owner: AccountId) -> Vec<RawAssetHolding<AccountId>>;
+
+		/// Given an asset identifier this returns the instances issued
+		/// from it.
+		fn asset_instances(asset_id: Ss58Identifier) -> Vec<RawAssetHolding<AccountId>>;
+
+		/// Given an asset instance identifier this returns its most recent
+		/// ownership changes, oldest first.
+		fn instance_history(instance_id: Ss58Identifier) -> Vec<AssetTransferRecord<AccountId, Hash>>;
+	}
+}
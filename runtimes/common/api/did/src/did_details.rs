@@ -20,9 +20,15 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_system::pallet_prelude::BlockNumberFor;
-use pallet_did::{did_details::DidPublicKeyDetails, AccountIdOf, KeyIdOf};
+use pallet_did::{
+	did_details::{DidKeyHistoryEntry, DidKeyRelationship, DidPublicKeyDetails},
+	AccountIdOf, KeyIdOf,
+};
 use scale_info::TypeInfo;
-use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
+use sp_std::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
+};
 
 #[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq, MaxEncodedLen)]
 pub struct DidDetails<Key: Ord, BlockNumber: MaxEncodedLen, AccountId> {
@@ -48,3 +54,51 @@ impl<T: pallet_did::Config> From<pallet_did::did_details::DidDetails<T>>
 		}
 	}
 }
+
+/// The keys of a DID as they were in a given key version, rebuilt from the
+/// DID key history.
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq)]
+pub struct DidHistoricalKeys<Key: Ord, BlockNumber, AccountId> {
+	pub version: u32,
+	pub authentication_key: Option<Key>,
+	pub key_agreement_keys: BTreeSet<Key>,
+	pub delegation_key: Option<Key>,
+	pub assertion_key: Option<Key>,
+	pub public_keys: BTreeMap<Key, DidPublicKeyDetails<BlockNumber, AccountId>>,
+}
+
+impl<Key: Ord + Clone, BlockNumber, AccountId>
+	From<(u32, Vec<DidKeyHistoryEntry<Key, BlockNumber, AccountId>>)>
+	for DidHistoricalKeys<Key, BlockNumber, AccountId>
+{
+	fn from(
+		(version, entries): (u32, Vec<DidKeyHistoryEntry<Key, BlockNumber, AccountId>>),
+	) -> Self {
+		let mut keys = Self {
+			version,
+			authentication_key: None,
+			key_agreement_keys: BTreeSet::new(),
+			delegation_key: None,
+			assertion_key: None,
+			public_keys: BTreeMap::new(),
+		};
+		for entry in entries {
+			match entry.relationship {
+				DidKeyRelationship::Authentication =>
+					keys.authentication_key = Some(entry.key_id.clone()),
+				DidKeyRelationship::AssertionMethod =>
+					keys.assertion_key = Some(entry.key_id.clone()),
+				DidKeyRelationship::CapabilityDelegation =>
+					keys.delegation_key = Some(entry.key_id.clone()),
+				DidKeyRelationship::KeyAgreement => {
+					keys.key_agreement_keys.insert(entry.key_id.clone());
+				},
+			}
+			keys.public_keys
+				.entry(entry.key_id)
+				.or_insert(DidPublicKeyDetails { key: entry.key, block_number: entry.active_from });
+		}
+
+		keys
+	}
+}
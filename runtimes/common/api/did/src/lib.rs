@@ -28,6 +28,9 @@ mod did_details;
 mod service_endpoint;
 
 pub use did_details::*;
+pub use pallet_did::did_details::{
+	DidEncryptionKey, DidPublicKey, DidPublicKeyDetails, DidVerificationKey,
+};
 pub use service_endpoint::*;
 
 #[derive(Encode, Decode, TypeInfo, Eq, PartialEq)]
@@ -56,7 +59,7 @@ pub type RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber> =
 	DidLinkedInfo<DidIdentifier, AccountId, Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, Key, BlockNumber>;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait DidApi<DidIdentifier, AccountId, Key: Ord, BlockNumber: MaxEncodedLen> where
 		DidIdentifier: Codec,
 		AccountId: Codec,
@@ -75,5 +78,26 @@ sp_api::decl_runtime_apis! {
 	/// * public keys stored for the did
 	/// * service endpoints
 	fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber>>;
+
+	/// Given a did this returns the name it owns, unless the registration of
+	/// the name has expired.
+	#[api_version(2)]
+	fn query_name(did: DidIdentifier) -> Option<Vec<u8>>;
+
+	/// Given a did and a key version this returns the keys the DID had in
+	/// that version, as recorded in the DID key history.
+	#[api_version(2)]
+	fn query_at_version(did: DidIdentifier, version: u32) -> Option<DidHistoricalKeys<Key, BlockNumber, AccountId>>;
+
+	/// Given a did and a block number this returns the keys the DID had at
+	/// that block, along with the key version in effect, as recorded in the
+	/// DID key history.
+	#[api_version(2)]
+	fn query_at_block(did: DidIdentifier, block_number: BlockNumber) -> Option<DidHistoricalKeys<Key, BlockNumber, AccountId>>;
+
+	/// Given a did this returns whether it has been deleted, in which case
+	/// it can no longer be created again.
+	#[api_version(2)]
+	fn is_deactivated(did: DidIdentifier) -> bool;
 	}
 }
@@ -0,0 +1,43 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definitions for querying identifier timelines.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+
+pub use identifier::{
+	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
+	EventEntryOf, Ss58Identifier,
+};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait IdentifierApi {
+		/// Given an identifier this returns one page of its activity
+		/// timeline, including archived entries, for every identifier type
+		/// it has been used with. Pages hold at most `limit` entries, capped
+		/// at the runtime's `MaxArchivedEventsPerPage`.
+		fn timeline(
+			identifier: Ss58Identifier,
+			page: u32,
+			limit: u32,
+		) -> Vec<(IdentifierTypeOf, Vec<EventEntryOf>)>;
+	}
+}
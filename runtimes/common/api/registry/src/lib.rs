@@ -0,0 +1,131 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definitions for querying registries, registry entries,
+//! namespaces, statements and chain spaces.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use cord_primitives::StatusOf;
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+pub use identifier::{types::Timepoint, Ss58Identifier};
+pub use pallet_chain_space::{DelegateUsage, SpaceDetails, SpaceUsage};
+pub use pallet_entries::{RegistryEntryDetails, RegistryEntryStatus};
+pub use pallet_namespace::NameSpaceDetails;
+pub use pallet_registries::{DelegateUsage as RegistryDelegateUsage, RegistryDetails};
+pub use pallet_statement::{
+	StatementDetails, StatementEntryStatus, StatementVerdict, StatementVerificationStatus,
+};
+
+/// Registry details as returned by the runtime.
+pub type RawRegistryDetails<AccountId, Hash> =
+	RegistryDetails<AccountId, StatusOf, Hash, Ss58Identifier, Ss58Identifier>;
+
+/// Registry entry details as returned by the runtime.
+pub type RawRegistryEntryDetails<AccountId, Hash> =
+	RegistryEntryDetails<Hash, StatusOf, AccountId, Ss58Identifier>;
+
+/// Namespace details as returned by the runtime.
+///
+/// The bounded list of registries is represented as a plain vector so the
+/// type does not depend on runtime specific bounds.
+pub type RawNameSpaceDetails<AccountId, Hash> =
+	NameSpaceDetails<Hash, AccountId, StatusOf, Vec<Ss58Identifier>>;
+
+/// Chain space details as returned by the runtime.
+pub type RawSpaceDetails<AccountId, Hash> = SpaceDetails<Hash, AccountId, StatusOf, Ss58Identifier>;
+
+/// A statement along with the revocation status of its latest digest.
+#[derive(Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
+pub struct StatementInfo<AccountId, Hash> {
+	pub identifier: Ss58Identifier,
+	pub details: StatementDetails<Hash, Ss58Identifier, Ss58Identifier>,
+	/// The revocation record of the latest digest, if it has been revoked.
+	pub revocation: Option<StatementEntryStatus<AccountId, StatusOf>>,
+}
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait RegistryApi<AccountId, Hash> where
+		AccountId: Codec,
+		Hash: Codec,
+	{
+		/// Given a registry identifier this returns the registry details.
+		fn registry(registry_id: Ss58Identifier) -> Option<RawRegistryDetails<AccountId, Hash>>;
+
+		/// Given a registry entry identifier this returns the entry details.
+		fn registry_entry(registry_entry_id: Ss58Identifier) -> Option<RawRegistryEntryDetails<AccountId, Hash>>;
+
+		/// Given a registry entry identifier this returns its effective
+		/// status, taking revocation and its validity window into account.
+		fn registry_entry_status(registry_entry_id: Ss58Identifier) -> Option<RegistryEntryStatus>;
+
+		/// Given a namespace identifier this returns the namespace details,
+		/// including the registries anchored under it.
+		fn namespace(namespace_id: Ss58Identifier) -> Option<RawNameSpaceDetails<AccountId, Hash>>;
+
+		/// Given a registry identifier this returns the usage of the
+		/// registry by each of its delegates.
+		fn registry_delegate_usage(registry_id: Ss58Identifier) -> Vec<(AccountId, RegistryDelegateUsage)>;
+	}
+
+	#[api_version(1)]
+	pub trait StatementApi<AccountId, Hash> where
+		AccountId: Codec,
+		Hash: Codec,
+	{
+		/// Given a statement identifier this returns:
+		/// * the statement details
+		/// * the revocation status of its latest digest
+		fn statement(statement_id: Ss58Identifier) -> Option<StatementInfo<AccountId, Hash>>;
+
+		/// Given a digest and the space it is anchored in this returns a
+		/// single verdict on its validity, together with the statement
+		/// identifier, its creator and the location of the last change.
+		/// If `schema_id` is provided the statement must be bound to it.
+		fn verify(
+			digest: Hash,
+			space_id: Ss58Identifier,
+			schema_id: Option<Ss58Identifier>,
+		) -> StatementVerdict<Ss58Identifier, AccountId>;
+	}
+
+	#[api_version(1)]
+	pub trait ChainSpaceApi<AccountId, Hash> where
+		AccountId: Codec,
+		Hash: Codec,
+	{
+		/// Given a space identifier this returns the space details.
+		fn space(space_id: Ss58Identifier) -> Option<RawSpaceDetails<AccountId, Hash>>;
+
+		/// Given a space identifier this returns the identifiers of the
+		/// sub-spaces directly below it.
+		fn sub_spaces(space_id: Ss58Identifier) -> Vec<Ss58Identifier>;
+
+		/// Given a space identifier this returns its usage aggregated over
+		/// all of its descendants.
+		fn usage(space_id: Ss58Identifier) -> Option<SpaceUsage>;
+
+		/// Given a space identifier this returns the usage of the space by
+		/// each of its delegates.
+		fn delegate_usage(space_id: Ss58Identifier) -> Vec<(AccountId, DelegateUsage)>;
+	}
+}
@@ -0,0 +1,35 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definitions for querying schema version chains.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sp_std::vec::Vec;
+
+pub use identifier::Ss58Identifier;
+pub use pallet_schema::SchemaVersionOf;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait SchemaApi {
+		/// Given a schema identifier this returns every version of the
+		/// chain it belongs to, ordered from the first to the latest.
+		fn schema_history(schema_id: Ss58Identifier) -> Vec<(Ss58Identifier, SchemaVersionOf)>;
+	}
+}
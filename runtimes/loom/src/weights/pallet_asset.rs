@@ -57,17 +57,37 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetExpiries` (r:1 w:1)
+	/// Proof: `Asset::AssetExpiries` (`max_values`: None, `max_size`: Some(50022), added: 52497, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::NextAssetExpirySweep` (r:1 w:1)
+	/// Proof: `Asset::NextAssetExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClasses` (r:1 w:0)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::ClassAssets` (r:0 w:1)
+	/// Proof: `Asset::ClassAssets` (`max_values`: None, `max_size`: Some(132), added: 2607, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClassOf` (r:0 w:1)
+	/// Proof: `Asset::AssetClassOf` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetExpiry` (r:0 w:1)
+	/// Proof: `Asset::AssetExpiry` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `719`
-		//  Estimated: `6659`
-		// Minimum execution time: 36_120_000 picoseconds.
-		Weight::from_parts(37_030_000, 0)
-			.saturating_add(Weight::from_parts(0, 6659))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+		//  Estimated: `53487`
+		// Minimum execution time: 54_150_000 picoseconds.
+		Weight::from_parts(55_580_000, 0)
+			.saturating_add(Weight::from_parts(0, 53487))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(11))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -79,19 +99,29 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:1)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:1)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
 	fn issue() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `960`
 		//  Estimated: `6659`
-		// Minimum execution time: 50_500_000 picoseconds.
-		Weight::from_parts(51_470_000, 0)
+		// Minimum execution time: 56_620_000 picoseconds.
+		Weight::from_parts(57_590_000, 0)
 			.saturating_add(Weight::from_parts(0, 6659))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(14))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
@@ -99,15 +129,29 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:1 w:0)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `852`
 		//  Estimated: `6795`
-		// Minimum execution time: 29_210_000 picoseconds.
-		Weight::from_parts(30_010_000, 0)
+		// Minimum execution time: 41_420_000 picoseconds.
+		Weight::from_parts(42_220_000, 0)
 			.saturating_add(Weight::from_parts(0, 6795))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
@@ -115,6 +159,10 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `852`
@@ -122,7 +170,217 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_660_000 picoseconds.
 		Weight::from_parts(29_540_000, 0)
 			.saturating_add(Weight::from_parts(0, 6795))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:2 w:2)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Distribution` (r:1 w:1)
+	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:3 w:3)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:3 w:3)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:3 w:3)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetLookup` (r:0 w:1)
+	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Lineage` (r:0 w:1)
+	/// Proof: `Asset::Lineage` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:1)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:1 w:2)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:1 w:0)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	fn split() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `12608`
+		// Minimum execution time: 74_280_000 picoseconds.
+		Weight::from_parts(76_986_000, 0)
+			.saturating_add(Weight::from_parts(0, 12608))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(20))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:2 w:2)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Distribution` (r:1 w:1)
+	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Lineage` (r:1 w:1)
+	/// Proof: `Asset::Lineage` (`max_values`: None, `max_size`: Some(226), added: 2701, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:2 w:2)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:2 w:2)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:1)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::PendingTransfers` (r:2 w:2)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn merge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1079`
+		//  Estimated: `12608`
+		// Minimum execution time: 72_790_000 picoseconds.
+		Weight::from_parts(75_544_000, 0)
+			.saturating_add(Weight::from_parts(0, 12608))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
+	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::DelegateUsages` (r:1 w:1)
+	/// Proof: `ChainSpace::DelegateUsages` (`max_values`: None, `max_size`: Some(131), added: 2606, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::Schemas` (r:1 w:0)
+	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
+	/// Storage: `Schema::SchemaVersions` (r:1 w:0)
+	/// Proof: `Schema::SchemaVersions` (`max_values`: None, `max_size`: Some(107), added: 2582, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::AssetClasses` (r:1 w:1)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn class_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1136`
+		//  Estimated: `19007`
+		// Minimum execution time: 58_130_000 picoseconds.
+		Weight::from_parts(60_554_000, 0)
+			.saturating_add(Weight::from_parts(0, 19007))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Asset::AssetClasses` (r:1 w:1)
+	/// Proof: `Asset::AssetClasses` (`max_values`: None, `max_size`: Some(1229), added: 3704, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn class_status_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `565`
+		//  Estimated: `4694`
+		// Minimum execution time: 26_260_000 picoseconds.
+		Weight::from_parts(27_360_000, 0)
+			.saturating_add(Weight::from_parts(0, 4694))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::TransferPolicies` (r:0 w:1)
+	/// Proof: `Asset::TransferPolicies` (`max_values`: None, `max_size`: Some(228), added: 2703, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_transfer_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `580`
+		//  Estimated: `6667`
+		// Minimum execution time: 26_380_000 picoseconds.
+		Weight::from_parts(27_488_000, 0)
+			.saturating_add(Weight::from_parts(0, 6667))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:1 w:1)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn accept_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `884`
+		//  Estimated: `6799`
+		// Minimum execution time: 59_870_000 picoseconds.
+		Weight::from_parts(62_373_000, 0)
+			.saturating_add(Weight::from_parts(0, 6799))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Assets` (r:1 w:0)
+	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::Issuance` (r:1 w:1)
+	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransferCount` (r:1 w:1)
+	/// Proof: `Asset::InstanceTransferCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::OwnerInstances` (r:0 w:2)
+	/// Proof: `Asset::OwnerInstances` (`max_values`: None, `max_size`: Some(164), added: 2639, mode: `MaxEncodedLen`)
+	/// Storage: `Asset::InstanceTransfers` (r:0 w:2)
+	/// Proof: `Asset::InstanceTransfers` (`max_values`: None, `max_size`: Some(192), added: 2667, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn approve_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1008`
+		//  Estimated: `6799`
+		// Minimum execution time: 64_860_000 picoseconds.
+		Weight::from_parts(67_566_000, 0)
+			.saturating_add(Weight::from_parts(0, 6799))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn reject_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `535`
+		//  Estimated: `3721`
+		// Minimum execution time: 22_560_000 picoseconds.
+		Weight::from_parts(23_508_000, 0)
+			.saturating_add(Weight::from_parts(0, 3721))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Asset::PendingTransfers` (r:1 w:1)
+	/// Proof: `Asset::PendingTransfers` (`max_values`: None, `max_size`: Some(417), added: 2892, mode: `MaxEncodedLen`)
+	fn cancel_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3721`
+		// Minimum execution time: 17_330_000 picoseconds.
+		Weight::from_parts(18_056_000, 0)
+			.saturating_add(Weight::from_parts(0, 3721))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
@@ -119,7 +119,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("weave"),
 	impl_name: create_runtime_str!("dhiway-cord"),
 	authoring_version: 0,
-	spec_version: 9500,
+	spec_version: 9501,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 2,
@@ -780,17 +780,29 @@ impl pallet_network_membership::Config for Runtime {
 
 parameter_types! {
 	pub const MaxRegistryEntryBlobSize: u32 = 4 * 1024; // 4KB in bytes
+	pub const MaxRegistryEntryExpiriesPerBlock: u32 = 100;
+	pub const MaxRegistryEntriesPerBatch: u32 = 1_000;
 }
 
 impl pallet_entries::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
 	type MaxRegistryEntryBlobSize = MaxRegistryEntryBlobSize;
-	type WeightInfo = ();
+	type MaxExpiriesPerBlock = MaxRegistryEntryExpiriesPerBlock;
+	type MaxEntriesPerBatch = MaxRegistryEntriesPerBatch;
+	type WeightInfo = weights::pallet_entries::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const TimelineOverflow: identifier::TimelineOverflowMode =
+		identifier::TimelineOverflowMode::Archive;
+	pub const MaxArchivedEventsPerPage: u32 = 100;
 }
 
 impl identifier::Config for Runtime {
 	type MaxEventsHistory = MaxEventsHistory;
+	type TimelineOverflow = TimelineOverflow;
+	type MaxArchivedEventsPerPage = MaxArchivedEventsPerPage;
 }
 
 impl pallet_runtime_upgrade::Config for Runtime {
@@ -811,6 +823,12 @@ parameter_types! {
 	pub const MaxServiceUrlLength: u32 = 200;
 	pub const MaxNumberOfTypesPerService: u32 = 1;
 	pub const MaxNumberOfUrlsPerService: u32 = 1;
+	pub const MaxDidKeyHistory: u32 = 100;
+	pub const MaxDidGuardians: u32 = 10;
+	pub const MinDidRecoveryDelay: BlockNumber = 2 * DAYS;
+	pub const DidBaseDeposit: Balance = 10 * WAY;
+	pub const DidKeyDeposit: Balance = 1 * WAY;
+	pub const DidServiceEndpointDeposit: Balance = 2 * WAY;
 }
 
 impl pallet_did::Config for Runtime {
@@ -838,6 +856,13 @@ impl pallet_did::Config for Runtime {
 	type MaxServiceUrlLength = MaxServiceUrlLength;
 	type MaxNumberOfTypesPerService = MaxNumberOfTypesPerService;
 	type MaxNumberOfUrlsPerService = MaxNumberOfUrlsPerService;
+	type MaxKeyHistory = MaxDidKeyHistory;
+	type MaxGuardians = MaxDidGuardians;
+	type MinRecoveryDelay = MinDidRecoveryDelay;
+	type Currency = Balances;
+	type BaseDeposit = DidBaseDeposit;
+	type KeyDeposit = DidKeyDeposit;
+	type ServiceEndpointDeposit = DidServiceEndpointDeposit;
 	type WeightInfo = weights::pallet_did::WeightInfo<Runtime>;
 }
 
@@ -845,6 +870,9 @@ parameter_types! {
 	pub const MinNameLength: u32 = 3;
 	pub const MaxNameLength: u32 = 64;
 	pub const MaxPrefixLength: u32 = 54;
+	pub const DidNameDeposit: Balance = 5 * WAY;
+	pub const DidNameRegistrationPeriod: BlockNumber = 365 * DAYS;
+	pub const DidNameGracePeriod: BlockNumber = 30 * DAYS;
 }
 
 impl pallet_did_name::Config for Runtime {
@@ -857,11 +885,16 @@ impl pallet_did_name::Config for Runtime {
 	type MaxPrefixLength = MaxPrefixLength;
 	type DidName = pallet_did_name::did_name::AsciiDidName<Runtime>;
 	type DidNameOwner = DidIdentifier;
+	type Currency = Balances;
+	type Deposit = DidNameDeposit;
+	type RegistrationPeriod = DidNameRegistrationPeriod;
+	type GracePeriod = DidNameGracePeriod;
 	type WeightInfo = weights::pallet_did_name::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const MaxEncodedSchemaLength: u32 = 15_360;
+	pub const ValidateSchemaContent: bool = true;
 }
 
 impl pallet_schema::Config for Runtime {
@@ -870,6 +903,7 @@ impl pallet_schema::Config for Runtime {
 	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxEncodedSchemaLength = MaxEncodedSchemaLength;
+	type ValidateSchemaContent = ValidateSchemaContent;
 	type WeightInfo = weights::pallet_schema::WeightInfo<Runtime>;
 }
 
@@ -883,6 +917,12 @@ parameter_types! {
 	pub const MaxSpaceDelegates: u32 = 10_000;
 }
 
+parameter_types! {
+	pub const MaxSpaceDelegationExpiriesPerBlock: u32 = 100;
+	pub const MaxSpaceDepth: u32 = 5;
+	pub const SpaceCapacityPrice: Balance = WAY / 10;
+}
+
 impl pallet_chain_space::Config for Runtime {
 	type SpaceCreatorId = DidIdentifier;
 	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
@@ -891,6 +931,11 @@ impl pallet_chain_space::Config for Runtime {
 	type ChainSpaceOrigin = MoreThanHalfCouncil;
 	type NetworkPermission = NetworkParameters;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxDelegationExpiriesPerBlock = MaxSpaceDelegationExpiriesPerBlock;
+	type MaxSpaceDepth = MaxSpaceDepth;
+	type Currency = Balances;
+	type CapacityPrice = SpaceCapacityPrice;
+	type CapacityPayment = Treasury;
 	type WeightInfo = weights::pallet_chain_space::WeightInfo<Runtime>;
 }
 
@@ -899,13 +944,18 @@ parameter_types! {
 	pub const MaxNameSpaceBlobSize: u32 = 4 * 1024;
 }
 
+parameter_types! {
+	pub const MaxNameSpaceDelegationExpiriesPerBlock: u32 = 100;
+}
+
 impl pallet_namespace::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkParameters;
 	type MaxNameSpaceDelegates = MaxNameSpaceDelegates;
 	type MaxNameSpaceBlobSize = MaxNameSpaceBlobSize;
-	type WeightInfo = ();
+	type MaxDelegationExpiriesPerBlock = MaxNameSpaceDelegationExpiriesPerBlock;
+	type WeightInfo = weights::pallet_namespace::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -914,17 +964,24 @@ parameter_types! {
 	pub const MaxRegistryDelegates: u32 = 10_000;
 }
 
+parameter_types! {
+	pub const MaxRegistryDelegationExpiriesPerBlock: u32 = 100;
+}
+
 impl pallet_registries::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxRegistryDelegates = MaxRegistryDelegates;
 	type MaxRegistryBlobSize = MaxRegistryBlobSize;
 	type MaxEncodedInputLength = MaxEncodedInputLength;
-	type WeightInfo = ();
+	type SchemaProvider = (Schema, SchemaAccounts);
+	type MaxDelegationExpiriesPerBlock = MaxRegistryDelegationExpiriesPerBlock;
+	type WeightInfo = weights::pallet_registries::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub const MaxDigestsPerBatch: u16 = 1_000;
 	pub const MaxRemoveEntries: u16 = 1_000;
+	pub const MaxStatementExpiriesPerBlock: u32 = 100;
 }
 
 impl pallet_statement::Config for Runtime {
@@ -934,6 +991,31 @@ impl pallet_statement::Config for Runtime {
 	type WeightInfo = weights::pallet_statement::WeightInfo<Runtime>;
 	type MaxDigestsPerBatch = MaxDigestsPerBatch;
 	type MaxRemoveEntries = MaxRemoveEntries;
+	type MaxExpiriesPerBlock = MaxStatementExpiriesPerBlock;
+	type SchemaProvider = Schema;
+}
+
+parameter_types! {
+	pub const MaxAssetValueLength: u32 = 1_024;
+	pub const MaxAssetDistribution: u32 = 25;
+	pub const MaxAssetExpiriesPerBlock: u32 = 100;
+	pub const MaxAssetApprovers: u32 = 5;
+	pub const AssetPendingTransferTimeout: BlockNumber = 7 * DAYS;
+	pub const MaxAssetInstanceTransfers: u32 = 100;
+}
+
+impl pallet_asset::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type EnsureOrigin = pallet_did::EnsureDidOrigin<DidIdentifier, AccountId>;
+	type OriginSuccess = pallet_did::DidRawOrigin<AccountId, DidIdentifier>;
+	type MaxEncodedValueLength = MaxAssetValueLength;
+	type MaxAssetDistribution = MaxAssetDistribution;
+	type MaxAssetExpiriesPerBlock = MaxAssetExpiriesPerBlock;
+	type MaxAssetApprovers = MaxAssetApprovers;
+	type PendingTransferTimeout = AssetPendingTransferTimeout;
+	type MaxInstanceTransfers = MaxAssetInstanceTransfers;
+	type SchemaProvider = Schema;
+	type WeightInfo = weights::pallet_asset::WeightInfo<Runtime>;
 }
 
 impl pallet_remark::Config for Runtime {
@@ -1171,6 +1253,9 @@ mod runtime {
 	#[runtime::pallet_index(64)]
 	pub type NameSpace = pallet_namespace;
 
+	#[runtime::pallet_index(65)]
+	pub type Asset = pallet_asset;
+
 	#[runtime::pallet_index(255)]
 	pub type Sudo = pallet_sudo;
 }
@@ -1219,6 +1304,9 @@ impl pallet_did::DeriveDidCallAuthorizationVerificationKeyRelationship for Runti
 			RuntimeCall::NetworkScore { .. } => {
 				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
 			},
+			RuntimeCall::Asset { .. } => {
+				Ok(pallet_did::DidVerificationKeyRelationship::Authentication)
+			},
 			RuntimeCall::ChainSpace(pallet_chain_space::Call::add_delegate { .. }) => {
 				Ok(pallet_did::DidVerificationKeyRelationship::CapabilityDelegation)
 			},
@@ -1302,6 +1390,13 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_did::migrations::v2::MigrateToV2<Runtime>,
+	pallet_did_name::migrations::v2::MigrateToV2<Runtime>,
+	pallet_asset::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1309,6 +1404,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
@@ -1348,8 +1444,10 @@ mod benches {
 		[pallet_network_membership, NetworkMembership]
 		[pallet_network_score, NetworkScore]
 		[pallet_sudo, Sudo]
+		[pallet_namespace, NameSpace]
 		[pallet_registries, Registries]
 		[pallet_entries, Entries]
+		[pallet_asset, Asset]
 	);
 }
 
@@ -1615,7 +1713,7 @@ sp_api::impl_runtime_apis! {
 			>
 		> {
 			let details = pallet_did::Did::<Runtime>::get(&did)?;
-			let name = pallet_did_name::Names::<Runtime>::get(&did).map(Into::into);
+			let name = pallet_did_name::Pallet::<Runtime>::name_of(&did).map(Into::into);
 			let service_endpoints = pallet_did::ServiceEndpoints::<Runtime>::iter_prefix(&did).map(|e| From::from(e.1)).collect();
 
 			Some(pallet_did_runtime_api::RawDidLinkedInfo {
@@ -1634,22 +1732,177 @@ sp_api::impl_runtime_apis! {
 			>
 		> {
 			let dname: pallet_did_name::did_name::AsciiDidName<Runtime> = name.try_into().ok()?;
-			pallet_did_name::Owner::<Runtime>::get(&dname)
-				.and_then(|owner_info| {
-					pallet_did::Did::<Runtime>::get(&owner_info.owner).map(|details| (owner_info, details))
+			pallet_did_name::Pallet::<Runtime>::owner_of(&dname)
+				.and_then(|owner| {
+					pallet_did::Did::<Runtime>::get(&owner).map(|details| (owner, details))
 				})
-				.map(|(owner_info, details)| {
-					let service_endpoints = pallet_did::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
+				.map(|(owner, details)| {
+					let service_endpoints = pallet_did::ServiceEndpoints::<Runtime>::iter_prefix(&owner).map(|e| From::from(e.1)).collect();
 
 					pallet_did_runtime_api::RawDidLinkedInfo{
-						identifier: owner_info.owner.clone(),
-						account: owner_info.owner,
+						identifier: owner.clone(),
+						account: owner,
 						name: Some(dname.into()),
 						service_endpoints,
 						details: details.into(),
 					}
 			})
 		}
+		fn query_name(did: DidIdentifier) -> Option<Vec<u8>> {
+			pallet_did_name::Pallet::<Runtime>::name_of(&did).map(Into::into)
+		}
+		fn query_at_version(did: DidIdentifier, version: u32) -> Option<
+			pallet_did_runtime_api::DidHistoricalKeys<Hash, BlockNumber, AccountId>
+		> {
+			pallet_did::Pallet::<Runtime>::keys_at_version(&did, version).map(Into::into)
+		}
+		fn query_at_block(did: DidIdentifier, block_number: BlockNumber) -> Option<
+			pallet_did_runtime_api::DidHistoricalKeys<Hash, BlockNumber, AccountId>
+		> {
+			pallet_did::Pallet::<Runtime>::keys_at_block(&did, block_number).map(Into::into)
+		}
+		fn is_deactivated(did: DidIdentifier) -> bool {
+			pallet_did::DidBlacklist::<Runtime>::contains_key(&did)
+		}
+	}
+
+	impl pallet_registry_runtime_api::RegistryApi<Block, AccountId, Hash> for Runtime {
+		fn registry(registry_id: Ss58Identifier) -> Option<
+			pallet_registry_runtime_api::RawRegistryDetails<AccountId, Hash>
+		> {
+			pallet_registries::RegistryInfo::<Runtime>::get(&registry_id)
+		}
+
+		fn registry_entry(registry_entry_id: Ss58Identifier) -> Option<
+			pallet_registry_runtime_api::RawRegistryEntryDetails<AccountId, Hash>
+		> {
+			pallet_entries::RegistryEntries::<Runtime>::get(&registry_entry_id)
+		}
+
+		fn registry_entry_status(registry_entry_id: Ss58Identifier) -> Option<
+			pallet_registry_runtime_api::RegistryEntryStatus
+		> {
+			pallet_entries::Pallet::<Runtime>::registry_entry_status(&registry_entry_id)
+		}
+
+		fn namespace(namespace_id: Ss58Identifier) -> Option<
+			pallet_registry_runtime_api::RawNameSpaceDetails<AccountId, Hash>
+		> {
+			pallet_namespace::NameSpaces::<Runtime>::get(&namespace_id).map(|details| {
+				pallet_registry_runtime_api::NameSpaceDetails {
+					digest: details.digest,
+					creator: details.creator,
+					archive: details.archive,
+					registry_ids: details.registry_ids.map(|ids| ids.into_inner()),
+				}
+			})
+		}
+
+		fn registry_delegate_usage(registry_id: Ss58Identifier) -> Vec<
+			(AccountId, pallet_registry_runtime_api::RegistryDelegateUsage)
+		> {
+			pallet_registries::Pallet::<Runtime>::delegate_usage(&registry_id)
+		}
+	}
+
+	impl pallet_registry_runtime_api::StatementApi<Block, AccountId, Hash> for Runtime {
+		fn statement(statement_id: Ss58Identifier) -> Option<
+			pallet_registry_runtime_api::StatementInfo<AccountId, Hash>
+		> {
+			let details = pallet_statement::Statements::<Runtime>::get(&statement_id)?;
+			let revocation = pallet_statement::RevocationList::<Runtime>::get(&statement_id, details.digest);
+
+			Some(pallet_registry_runtime_api::StatementInfo {
+				identifier: statement_id,
+				details,
+				revocation,
+			})
+		}
+
+		fn verify(
+			digest: Hash,
+			space_id: Ss58Identifier,
+			schema_id: Option<Ss58Identifier>,
+		) -> pallet_registry_runtime_api::StatementVerdict<Ss58Identifier, AccountId> {
+			Statement::verify_digest(&digest, &space_id, schema_id.as_ref())
+		}
+	}
+
+	impl pallet_registry_runtime_api::ChainSpaceApi<Block, AccountId, Hash> for Runtime {
+		fn space(space_id: Ss58Identifier) -> Option<
+			pallet_registry_runtime_api::RawSpaceDetails<AccountId, Hash>
+		> {
+			pallet_chain_space::Spaces::<Runtime>::get(&space_id)
+		}
+
+		fn sub_spaces(space_id: Ss58Identifier) -> Vec<Ss58Identifier> {
+			pallet_chain_space::Pallet::<Runtime>::sub_spaces(&space_id)
+		}
+
+		fn usage(space_id: Ss58Identifier) -> Option<pallet_registry_runtime_api::SpaceUsage> {
+			pallet_chain_space::Pallet::<Runtime>::aggregated_usage(&space_id)
+		}
+
+		fn delegate_usage(space_id: Ss58Identifier) -> Vec<
+			(AccountId, pallet_registry_runtime_api::DelegateUsage)
+		> {
+			pallet_chain_space::Pallet::<Runtime>::delegate_usage(&space_id)
+		}
+	}
+
+	impl pallet_schema_runtime_api::SchemaApi<Block> for Runtime {
+		fn schema_history(schema_id: Ss58Identifier) -> Vec<
+			(Ss58Identifier, pallet_schema_runtime_api::SchemaVersionOf)
+		> {
+			pallet_schema::Pallet::<Runtime>::version_history(&schema_id)
+		}
+	}
+
+	impl pallet_asset_runtime_api::AssetApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn asset_class(class_id: Ss58Identifier) -> Option<
+			pallet_asset_runtime_api::RawAssetClassDetails<AccountId, BlockNumber>
+		> {
+			pallet_asset::AssetClasses::<Runtime>::get(&class_id).map(|class| {
+				pallet_asset_runtime_api::AssetClassEntry {
+					class_name: class.class_name.into_inner(),
+					class_schema: class.class_schema,
+					class_space: class.class_space,
+					class_status: class.class_status,
+					class_issuer: class.class_issuer,
+					created_at: class.created_at,
+				}
+			})
+		}
+
+		fn assets_by_class(class_id: Ss58Identifier) -> Vec<Ss58Identifier> {
+			Asset::assets_by_class(&class_id)
+		}
+
+		fn holdings(owner: AccountId) -> Vec<pallet_asset_runtime_api::RawAssetHolding<AccountId>> {
+			Asset::holdings(&owner)
+		}
+
+		fn asset_instances(asset_id: Ss58Identifier) -> Vec<
+			pallet_asset_runtime_api::RawAssetHolding<AccountId>
+		> {
+			Asset::asset_instances(&asset_id)
+		}
+
+		fn instance_history(instance_id: Ss58Identifier) -> Vec<
+			pallet_asset_runtime_api::AssetTransferRecord<AccountId, Hash>
+		> {
+			Asset::instance_history(&instance_id)
+		}
+	}
+
+	impl identifier_runtime_api::IdentifierApi<Block> for Runtime {
+		fn timeline(
+			identifier: Ss58Identifier,
+			page: u32,
+			limit: u32,
+		) -> Vec<(identifier::IdentifierTypeOf, Vec<identifier::EventEntryOf>)> {
+			Identifier::timelines(&identifier, page, limit)
+		}
 	}
 
 	impl pallet_transaction_weight_runtime_api::TransactionWeightApi<Block> for Runtime {
@@ -19,19 +19,23 @@
 //! A list of the different weight modules for our runtime.
 
 pub mod frame_system;
+pub mod pallet_asset;
 pub mod pallet_babe;
 pub mod pallet_balances;
 pub mod pallet_chain_space;
 pub mod pallet_collective;
 pub mod pallet_did;
 pub mod pallet_did_name;
+pub mod pallet_entries;
 pub mod pallet_identity;
 pub mod pallet_im_online;
 pub mod pallet_indices;
 pub mod pallet_membership;
 pub mod pallet_multisig;
+pub mod pallet_namespace;
 pub mod pallet_network_membership;
 pub mod pallet_network_score;
+pub mod pallet_registries;
 pub mod pallet_preimage;
 pub mod pallet_remark;
 pub mod pallet_scheduler;
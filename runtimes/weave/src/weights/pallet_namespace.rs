@@ -0,0 +1,227 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Placeholder weights for `pallet_namespace`.
+//!
+//! These weights were not produced by the benchmark CLI. They are estimated
+//! from the storage accesses of each call and the measured weights of similar
+//! calls, and are the same for every runtime. They must be regenerated with
+//! `cord benchmark pallet --pallet=pallet_namespace` on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_namespace`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_namespace::WeightInfo for WeightInfo<T> {
+	/// Storage: `NameSpace::Authorizations` (r:2 w:1)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:0)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::Delegates` (r:1 w:1)
+	/// Proof: `NameSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1009`
+		//  Estimated: `503599`
+		// Minimum execution time: 49_850_000 picoseconds.
+		Weight::from_parts(51_929_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `NameSpace::Authorizations` (r:2 w:1)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:0)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::Delegates` (r:1 w:1)
+	/// Proof: `NameSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_admin_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `971`
+		//  Estimated: `503599`
+		// Minimum execution time: 49_640_000 picoseconds.
+		Weight::from_parts(51_710_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `NameSpace::Authorizations` (r:2 w:1)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:0)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::Delegates` (r:1 w:1)
+	/// Proof: `NameSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_delegator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1002`
+		//  Estimated: `503599`
+		// Minimum execution time: 50_100_000 picoseconds.
+		Weight::from_parts(52_193_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `NameSpace::Authorizations` (r:2 w:1)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:0)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::Delegates` (r:1 w:1)
+	/// Proof: `NameSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn remove_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1037`
+		//  Estimated: `503599`
+		// Minimum execution time: 49_560_000 picoseconds.
+		Weight::from_parts(51_627_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:1)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::Delegates` (r:0 w:1)
+	/// Proof: `NameSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::Authorizations` (r:0 w:1)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `656`
+		//  Estimated: `503599`
+		// Minimum execution time: 38_430_000 picoseconds.
+		Weight::from_parts(40_041_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `NameSpace::Authorizations` (r:1 w:0)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:1)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn archive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `773`
+		//  Estimated: `503599`
+		// Minimum execution time: 35_830_000 picoseconds.
+		Weight::from_parts(37_327_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `NameSpace::Authorizations` (r:1 w:0)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:1 w:0)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:1)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn restore() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `794`
+		//  Estimated: `503599`
+		// Minimum execution time: 35_750_000 picoseconds.
+		Weight::from_parts(37_247_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `NameSpace::Authorizations` (r:2 w:0)
+	/// Proof: `NameSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::AuthorizationConstraints` (r:2 w:1)
+	/// Proof: `NameSpace::AuthorizationConstraints` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NameSpaces` (r:1 w:0)
+	/// Proof: `NameSpace::NameSpaces` (`max_values`: None, `max_size`: Some(500134), added: 502609, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::DelegationExpiries` (r:2 w:2)
+	/// Proof: `NameSpace::DelegationExpiries` (`max_values`: None, `max_size`: Some(5058), added: 7533, mode: `MaxEncodedLen`)
+	/// Storage: `NameSpace::NextDelegationExpirySweep` (r:1 w:1)
+	/// Proof: `NameSpace::NextDelegationExpirySweep` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedPages` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedPages` (`max_values`: None, `max_size`: Some(79), added: 2554, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::ArchivedEvents` (r:1 w:1)
+	/// Proof: `Identifier::ArchivedEvents` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn set_delegation_constraints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1337`
+		//  Estimated: `503599`
+		// Minimum execution time: 71_920_000 picoseconds.
+		Weight::from_parts(74_925_000, 0)
+			.saturating_add(Weight::from_parts(0, 503599))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+}
@@ -25,6 +25,7 @@ pub use deposit::{free_deposit, reserve_deposit};
 
 #[cfg(any(feature = "runtime-benchmarks", feature = "mock"))]
 pub mod mock;
+pub mod schedule;
 pub mod signature;
 pub mod traits;
 
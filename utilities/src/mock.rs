@@ -130,6 +130,12 @@ impl From<AccountId32> for SubjectId {
 	}
 }
 
+impl From<SubjectId> for AccountId32 {
+	fn from(subject: SubjectId) -> Self {
+		subject.0
+	}
+}
+
 impl From<sr25519::Public> for SubjectId {
 	fn from(acc: sr25519::Public) -> Self {
 		SubjectId(acc.into())
@@ -0,0 +1,134 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Items scheduled per block and swept from `on_idle`.
+//!
+//! A schedule is a storage map from a block number to the items due at that
+//! block, together with a cursor holding the first block that has not been
+//! fully swept yet. Pallets keep both in their own storage and use the
+//! functions of this module to maintain them.
+
+use codec::FullCodec;
+use frame_support::{
+	storage::{StorageMap, StorageValue},
+	BoundedVec,
+};
+use sp_runtime::traits::{Get, One, Saturating};
+use sp_std::vec::Vec;
+use sp_weights::{RuntimeDbWeight, Weight};
+
+/// Schedules `item` at block `at`, starting the sweep from block `now` if no
+/// sweep is pending.
+///
+/// Fails, handing `item` back, if `Bound` items are already scheduled at `at`.
+pub fn schedule<Cursor, Schedule, BlockNumber, Item, Bound>(
+	at: BlockNumber,
+	item: Item,
+	now: BlockNumber,
+) -> Result<(), Item>
+where
+	Cursor: StorageValue<BlockNumber, Query = Option<BlockNumber>>,
+	Schedule: StorageMap<BlockNumber, BoundedVec<Item, Bound>, Query = BoundedVec<Item, Bound>>,
+	BlockNumber: FullCodec,
+	Item: FullCodec,
+	Bound: Get<u32>,
+{
+	Schedule::try_mutate(at, |items| items.try_push(item))?;
+
+	if !Cursor::exists() {
+		Cursor::put(now);
+	}
+
+	Ok(())
+}
+
+/// Removes `item` from the items scheduled at block `at`.
+pub fn unschedule<Schedule, BlockNumber, Item, Bound>(at: BlockNumber, item: &Item)
+where
+	Schedule: StorageMap<BlockNumber, BoundedVec<Item, Bound>, Query = BoundedVec<Item, Bound>>,
+	BlockNumber: FullCodec,
+	Item: FullCodec + PartialEq,
+	Bound: Get<u32>,
+{
+	let mut items = Schedule::get(&at);
+	items.retain(|scheduled| scheduled != item);
+	if items.is_empty() {
+		Schedule::remove(at);
+	} else {
+		Schedule::insert(at, items);
+	}
+}
+
+/// Hands the items scheduled up to and including block `now` to `on_item`,
+/// as far as `remaining_weight` allows, and returns the weight consumed.
+///
+/// Each item is charged `item_weight`. A block whose items do not all fit is
+/// swept partially: its remaining items are kept and the cursor stays on it,
+/// so the sweep always makes progress as long as one item fits.
+pub fn sweep<Cursor, Schedule, BlockNumber, Item, Bound>(
+	now: BlockNumber,
+	remaining_weight: Weight,
+	db_weight: RuntimeDbWeight,
+	item_weight: Weight,
+	mut on_item: impl FnMut(Item, BlockNumber),
+) -> Weight
+where
+	Cursor: StorageValue<BlockNumber, Query = Option<BlockNumber>>,
+	Schedule: StorageMap<BlockNumber, BoundedVec<Item, Bound>, Query = BoundedVec<Item, Bound>>,
+	BlockNumber: FullCodec + Copy + PartialOrd + One + Saturating,
+	Item: FullCodec,
+	Bound: Get<u32>,
+{
+	let mut consumed = db_weight.reads_writes(1, 1);
+	if consumed.any_gt(remaining_weight) {
+		return Weight::zero();
+	}
+
+	let Some(mut next) = Cursor::get() else {
+		return db_weight.reads(1);
+	};
+
+	while next <= now {
+		let block_weight = db_weight.reads_writes(1, 1);
+		if consumed.saturating_add(block_weight).any_gt(remaining_weight) {
+			break;
+		}
+		consumed.saturating_accrue(block_weight);
+
+		let mut pending = Schedule::get(next).into_inner().into_iter();
+		while !consumed.saturating_add(item_weight).any_gt(remaining_weight) {
+			let Some(item) = pending.next() else {
+				break;
+			};
+			on_item(item, next);
+			consumed.saturating_accrue(item_weight);
+		}
+
+		if !pending.as_slice().is_empty() {
+			Schedule::insert(next, BoundedVec::truncate_from(pending.collect::<Vec<_>>()));
+			break;
+		}
+
+		Schedule::remove(next);
+		next = next.saturating_add(One::one());
+	}
+
+	Cursor::put(next);
+
+	consumed
+}